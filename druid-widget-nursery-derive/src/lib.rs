@@ -5,7 +5,9 @@ use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
 
 mod prism;
+mod tree_node;
 use prism::expand_prism;
+use tree_node::expand_tree_node;
 
 #[proc_macro_derive(Prism)]
 pub fn prism(input: TokenStream) -> TokenStream {
@@ -14,3 +16,11 @@ pub fn prism(input: TokenStream) -> TokenStream {
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
+
+#[proc_macro_derive(TreeNode, attributes(tree_children, tree_branch))]
+pub fn tree_node(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_tree_node(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -43,11 +43,24 @@ pub fn expand_prism(input: DeriveInput) -> syn::Result<TokenStream> {
             let variant_expr;
 
             match &v.fields {
-                Fields::Named(_) => {
-                    return Err(syn::Error::new_spanned(
-                        v,
-                        "variants with named fields are not supported for deriving `Prism`",
-                    ));
+                Fields::Named(f) => {
+                    // The fields are focused as for tuple variants, in order of declaration,
+                    // so `Variant { a: A }` is focused as `A` and `Variant { a: A, b: B }`
+                    // as `(A, B)`.
+                    let types = f.named.iter().map(|f| &f.ty);
+                    inner_type = quote! { (#(#types),*) };
+
+                    let fields: Vec<_> = (0..f.named.len())
+                        .map(|n| format_ident!("_v{}", n + 1))
+                        .collect();
+                    let names = f.named.iter().map(|f| &f.ident);
+                    let cloned = fields
+                        .iter()
+                        .map(|f| quote! { ::std::clone::Clone::clone(#f) });
+
+                    variant_expr = quote! { { #(#names: #fields),* } };
+                    inner_expr = quote! { (#(#fields),*) };
+                    cloned_inner = quote! { (#(#cloned),*) };
                 }
                 Fields::Unnamed(f) => {
                     let fields = f.unnamed.iter();
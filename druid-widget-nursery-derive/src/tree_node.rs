@@ -0,0 +1,102 @@
+// Copyright 2022 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Fields};
+
+pub fn expand_tree_node(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(f) => &f.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input,
+                    "only structs with named fields are supported for deriving `TreeNode`",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "only structs are supported for deriving `TreeNode`",
+            ))
+        }
+    };
+
+    let children = match find_field(fields.iter(), "tree_children")? {
+        Some(field) => field.ident.as_ref().unwrap(),
+        None => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "deriving `TreeNode` requires a field marked with `#[tree_children]`",
+            ))
+        }
+    };
+
+    // without a `#[tree_branch]` field, the default implementation applies
+    let is_branch = find_field(fields.iter(), "tree_branch")?.map(|field| {
+        let branch = field.ident.as_ref().unwrap();
+        quote! {
+            fn is_branch(&self) -> bool {
+                self.#branch
+            }
+        }
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // The children may be stored as `Self` or behind a pointer like `Arc<Self>`, both
+    // support `Borrow<Self>` and `From<Self>`.
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::druid_widget_nursery::TreeNode for #name #ty_generics #where_clause {
+            fn children_count(&self) -> usize {
+                self.#children.len()
+            }
+
+            fn get_child(&self, index: usize) -> &Self {
+                ::std::borrow::Borrow::<Self>::borrow(&self.#children[index])
+            }
+
+            fn for_child_mut(&mut self, index: usize, mut cb: impl FnMut(&mut Self, usize)) {
+                let old = ::std::borrow::Borrow::<Self>::borrow(&self.#children[index]);
+                let mut new = ::std::clone::Clone::clone(old);
+                cb(&mut new, index);
+                // only replace the child if it changed, to keep the data the same
+                if !::druid::Data::same(old, &new) {
+                    self.#children[index] = ::std::convert::From::from(new);
+                }
+            }
+
+            fn rm_child(&mut self, index: usize) {
+                self.#children.remove(index);
+            }
+
+            fn insert_child(&mut self, index: usize, child: Self) {
+                self.#children.insert(index, ::std::convert::From::from(child));
+            }
+
+            #is_branch
+        }
+    })
+}
+
+/// Finds the field marked with the attribute `attr`, which must be unique.
+fn find_field<'a>(
+    mut fields: impl Iterator<Item = &'a Field>,
+    attr: &str,
+) -> syn::Result<Option<&'a Field>> {
+    let mut marked = fields
+        .by_ref()
+        .filter(|f| f.attrs.iter().any(|a| a.path.is_ident(attr)));
+    let field = marked.next();
+    if let Some(duplicate) = marked.next() {
+        return Err(syn::Error::new_spanned(
+            duplicate,
+            format!("only one field can be marked with `#[{}]`", attr),
+        ));
+    }
+    Ok(field)
+}
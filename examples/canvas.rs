@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use druid::lens::Map;
-use druid::widget::{Button, Flex, Label, LabelText, Split, TextBox};
-use druid::{AppLauncher, Data, Env, Lens, Point, Size, Widget, WidgetExt, WindowDesc};
+use druid::widget::{Button, Controller, Flex, Label, LabelText, Split, TextBox};
+use druid::{
+    AppLauncher, Data, Env, Event, EventCtx, Lens, Point, Size, Widget, WidgetExt, WindowDesc,
+};
 use druid_widget_nursery::{Canvas, CanvasWrap};
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
@@ -33,7 +35,28 @@ fn canvas_panel() -> impl Widget<AppData> {
         .with_child(CanvasWrap::new(text_box, |data| data.text_box_pos))
         .with_child(CanvasWrap::new(button, |data| data.button_pos));
 
-    canvas
+    canvas.controller(RaiseOnClick)
+}
+
+/// Brings the clicked child of the canvas to the front.
+struct RaiseOnClick;
+
+impl Controller<AppData, Canvas<AppData>> for RaiseOnClick {
+    fn event(
+        &mut self,
+        child: &mut Canvas<AppData>,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut AppData,
+        env: &Env,
+    ) {
+        if let Event::MouseDown(mouse) = event {
+            if let Some(index) = child.child_at(data, mouse.pos) {
+                child.raise_child(ctx, index);
+            }
+        }
+        child.event(ctx, event, data, env);
+    }
 }
 fn sidebar() -> impl Widget<AppData> {
     Flex::column()
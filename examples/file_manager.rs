@@ -23,7 +23,8 @@ use druid::{
 };
 use druid_widget_nursery::tree::{
     ChrootStatus, Tree, TreeNode, TREE_ACTIVATE_NODE, TREE_CHILD_SHOW, TREE_CHROOT, TREE_CHROOT_UP,
-    TREE_NODE_REMOVE, TREE_NOTIFY_CHROOT, TREE_NOTIFY_PARENT, TREE_OPEN,
+    TREE_CLOSE_ALL, TREE_NODE_REMOVE, TREE_NOTIFY_CHROOT, TREE_NOTIFY_PARENT, TREE_OPEN,
+    TREE_OPEN_ALL,
 };
 
 use druid_widget_nursery::selectors;
@@ -45,6 +46,10 @@ selectors! {
     EDIT_STARTED,
     /// Command sent by the context menu to chroot to the targeted directory
     CHROOT,
+    /// Command sent by the context menu to expand the targeted directory recursively
+    EXPAND_ALL,
+    /// Command sent by the context menu to collapse the targeted directory recursively
+    COLLAPSE_ALL,
 
     /// Internal wiring, mostly to update the filetype and the sorting
     UPDATE_DIR_VIEW,
@@ -207,6 +212,10 @@ impl TreeNode for FSNode {
         self.children.remove(index);
     }
 
+    fn insert_child(&mut self, index: usize, child: Self) {
+        self.children.insert(index, Arc::new(child));
+    }
+
     // those two accessors are the most simple implementation to enable chroot, and should
     // be enough for most use cases.
     fn chroot(&mut self, idx: Option<usize>) {
@@ -352,6 +361,20 @@ fn make_dir_context_menu(widget_id: WidgetId) -> Menu<FSNode> {
                 ctx.submit_command(CHROOT.to(Target::Widget(widget_id)));
             },
         ))
+        .entry(
+            MenuItem::new(LocalizedString::new("Expand All")).on_activate(
+                move |ctx, _data: &mut FSNode, _env| {
+                    ctx.submit_command(EXPAND_ALL.to(Target::Widget(widget_id)));
+                },
+            ),
+        )
+        .entry(
+            MenuItem::new(LocalizedString::new("Collapse All")).on_activate(
+                move |ctx, _data: &mut FSNode, _env| {
+                    ctx.submit_command(COLLAPSE_ALL.to(Target::Widget(widget_id)));
+                },
+            ),
+        )
 }
 
 fn make_file_context_menu(widget_id: WidgetId) -> Menu<FSNode> {
@@ -478,6 +501,14 @@ impl Widget<FSNode> for FSNodeWidget {
                 ctx.submit_notification(TREE_CHROOT);
                 None
             }
+            Event::Command(cmd) if cmd.is(EXPAND_ALL) => {
+                ctx.submit_notification(TREE_OPEN_ALL);
+                None
+            }
+            Event::Command(cmd) if cmd.is(COLLAPSE_ALL) => {
+                ctx.submit_notification(TREE_CLOSE_ALL);
+                None
+            }
             Event::Command(cmd) if cmd.is(TREE_NOTIFY_PARENT) => {
                 let cmd_data = cmd.get(TREE_NOTIFY_PARENT).unwrap();
                 if *cmd_data == UPDATE_DIR_VIEW {
@@ -570,7 +601,8 @@ fn ui_builder() -> impl Widget<FSNode> {
         label: WidgetPod::new(Label::dynamic(|st: &String, _| st.clone())),
         filetype: FileType::Unknown,
         chroot_status: ChrootStatus::NO,
-    });
+    })
+    .draggable(true);
     Scroll::new(tree)
     //.debug_widget_id()
 }
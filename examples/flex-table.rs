@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use druid::widget::{
-    Align, Container, CrossAxisAlignment, Flex, Label, Stepper, TextBox, WidgetExt,
+    Align, Container, CrossAxisAlignment, Flex, Label, Scroll, Stepper, TextBox, WidgetExt,
 };
 use druid::{theme, AppLauncher, Color, Data, Env, Lens, UnitPoint, Widget, WindowDesc};
 
@@ -148,6 +148,29 @@ fn make_cell_alignment_example() -> impl Widget<DemoState> {
         .fix_height(42.)
 }
 
+fn make_sticky_header_example() -> impl Widget<DemoState> {
+    let mut table = FlexTable::new()
+        .inner_border(theme::BORDER_LIGHT, 1.)
+        .with_header_row(0)
+        .with_row(
+            TableRow::new()
+                .with_child(Label::new("Number"))
+                .with_child(Label::new("Square")),
+        );
+    for n in 1..=20 {
+        table.add_row(
+            TableRow::new()
+                .with_child(Label::new(format!("{n}")))
+                .with_child(Label::new(format!("{}", n * n))),
+        );
+    }
+
+    Scroll::new(table)
+        .vertical()
+        .border(Color::WHITE, 1.)
+        .fix_height(100.)
+}
+
 fn make_ui() -> impl Widget<DemoState> {
     Flex::column()
         .cross_axis_alignment(CrossAxisAlignment::Fill)
@@ -161,13 +184,16 @@ fn make_ui() -> impl Widget<DemoState> {
         .with_child(Label::new("Cell alignment example."))
         .with_child(make_cell_alignment_example())
         .with_default_spacer()
+        .with_child(Label::new("Sticky header example."))
+        .with_child(make_sticky_header_example())
+        .with_default_spacer()
         .padding(10.0)
-        .fix_height(500.)
+        .fix_height(650.)
 }
 
 pub fn main() {
     let main_window = WindowDesc::new(make_ui())
-        .window_size((500., 500.))
+        .window_size((500., 650.))
         .with_min_size((50., 50.))
         .title("Flex Table Example");
 
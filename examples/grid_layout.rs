@@ -0,0 +1,49 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use druid::im::{vector, Vector};
+use druid::widget::{Label, Scroll};
+use druid::{theme, AppLauncher, Data, Lens, Widget, WidgetExt, WindowDesc};
+use druid_widget_nursery::{GridLayout, GridTile};
+
+#[derive(Clone, Data, Lens)]
+struct AppData {
+    tiles: Vector<GridTile>,
+}
+
+fn tile(title: &str) -> impl Widget<AppData> {
+    Label::new(title)
+        .center()
+        .background(theme::BACKGROUND_LIGHT)
+        .border(theme::BORDER_DARK, 1.)
+        .rounded(4.)
+}
+
+fn main_widget() -> impl Widget<AppData> {
+    let grid = GridLayout::new(AppData::tiles, 4)
+        .row_height(80.)
+        .with_child(tile("Visitors"))
+        .with_child(tile("Revenue"))
+        .with_child(tile("Errors"))
+        .with_child(tile("Latency"))
+        .with_child(tile("Deployments"));
+    Scroll::new(grid).vertical()
+}
+
+fn main() {
+    let main_window = WindowDesc::new(main_widget())
+        .title("Grid Layout")
+        .window_size((600., 400.));
+    AppLauncher::with_window(main_window)
+        .log_to_console()
+        .launch(AppData {
+            tiles: vector![
+                GridTile::new(0, 0, 1, 2),
+                GridTile::new(0, 2, 1, 2),
+                GridTile::new(1, 0, 2, 1),
+                GridTile::new(1, 1, 1, 2),
+                GridTile::new(2, 1, 1, 3),
+            ],
+        })
+        .expect("launch failed");
+}
@@ -3,18 +3,23 @@
 
 use druid::widget::{Flex, TextBox};
 use druid::{Data, Lens, Widget, WidgetExt};
+use druid_widget_nursery::hot_reload::{serialized, SerializedData};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Data, Clone, Lens)]
+// fields added while the app runs need a default value
+#[derive(Debug, Default, Data, Clone, Lens, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppData {
     name: String,
     checked: String,
 }
 
 #[no_mangle]
-pub fn view() -> Box<dyn Widget<AppData>> {
-    Flex::column()
-        .with_child(TextBox::new().lens(AppData::name))
-        .with_spacer(90.)
-        .with_child(TextBox::new().lens(AppData::checked))
-        .boxed()
+pub fn view() -> Box<dyn Widget<SerializedData>> {
+    serialized(
+        Flex::column()
+            .with_child(TextBox::new().lens(AppData::name))
+            .with_spacer(90.)
+            .with_child(TextBox::new().lens(AppData::checked)),
+    )
 }
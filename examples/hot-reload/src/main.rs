@@ -8,6 +8,7 @@ fn main() {
     let window = WindowDesc::new("target/debug/hot_reload.dll", "view");
     unsafe {
         AppLauncher::with_window(window)
+            .expect("failed to watch the library")
             .launch(AppData::default())
             .unwrap();
     }
@@ -0,0 +1,53 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use druid::widget::{Flex, Label, TextBox};
+use druid::{AppLauncher, Application, Widget, WidgetExt, WindowDesc};
+use druid_widget_nursery::{BarMenu, BarMenuItem, MenuBar};
+
+fn main_widget() -> impl Widget<String> {
+    let content = Flex::column()
+        .with_child(Label::new(
+            "Press Alt to show the mnemonics, or press and release it to use the arrow keys.",
+        ))
+        .with_spacer(8.)
+        .with_child(TextBox::multiline().fix_size(400., 200.))
+        .padding(8.);
+
+    MenuBar::new(content)
+        .with_menu("&File", |_, _| {
+            BarMenu::empty()
+                .entry(BarMenuItem::new("&New").on_activate(|_, data: &mut String, _| data.clear()))
+                .separator()
+                .entry(
+                    BarMenuItem::new("Insert &Hello")
+                        .on_activate(|_, data: &mut String, _| data.push_str("Hello")),
+                )
+                .entry(
+                    BarMenuItem::new("Insert &Goodbye")
+                        .on_activate(|_, data: &mut String, _| data.push_str("Goodbye")),
+                )
+                .separator()
+                .entry(
+                    BarMenuItem::new("&Quit").on_activate(|_, _, _| Application::global().quit()),
+                )
+        })
+        .with_menu("&Edit", |data: &String, _| {
+            BarMenu::empty().entry(
+                BarMenuItem::new("&Uppercase")
+                    .enabled(!data.is_empty())
+                    .on_activate(|_, data: &mut String, _| *data = data.to_uppercase()),
+            )
+        })
+}
+
+fn main() {
+    let main_window = WindowDesc::new(main_widget())
+        .title("Menu bar")
+        .show_titlebar(false)
+        .window_size((500., 300.));
+    AppLauncher::with_window(main_window)
+        .log_to_console()
+        .launch(String::new())
+        .expect("launch failed");
+}
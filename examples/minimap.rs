@@ -0,0 +1,53 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use druid::kurbo::Circle;
+use druid::widget::{Flex, Painter, Scroll};
+use druid::{
+    AppLauncher, Color, Data, Env, Lens, PaintCtx, RenderContext, Widget, WidgetExt, WindowDesc,
+};
+use druid_widget_nursery::{Minimap, MinimapState, MinimapViewport};
+
+const CONTENT_SIZE: f64 = 2000.;
+
+#[derive(Clone, Data, Lens)]
+struct AppData {
+    minimap: MinimapState,
+}
+
+fn paint_content(ctx: &mut PaintCtx, _data: &AppData, _env: &Env) {
+    for x in 0..10 {
+        for y in 0..10 {
+            let center = ((x as f64 + 0.5) * 200., (y as f64 + 0.5) * 200.);
+            let color = Color::hlc((x * 36) as f64, 60., 60.);
+            ctx.fill(Circle::new(center, 20. + 5. * y as f64), &color);
+        }
+    }
+}
+
+fn main_widget() -> impl Widget<AppData> {
+    let content = Painter::new(paint_content)
+        .fix_size(CONTENT_SIZE, CONTENT_SIZE)
+        .controller(MinimapViewport::new(AppData::minimap));
+    let minimap = Minimap::new(AppData::minimap, paint_content)
+        .fix_size(150., 150.)
+        .border(Color::grey(0.5), 1.);
+
+    Flex::row()
+        .with_flex_child(Scroll::new(content), 1.)
+        .with_spacer(8.)
+        .with_child(minimap)
+        .padding(8.)
+}
+
+fn main() {
+    let main_window = WindowDesc::new(main_widget())
+        .title("Minimap")
+        .window_size((700., 500.));
+    AppLauncher::with_window(main_window)
+        .log_to_console()
+        .launch(AppData {
+            minimap: MinimapState::default(),
+        })
+        .expect("launch failed");
+}
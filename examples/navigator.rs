@@ -39,6 +39,7 @@ pub fn navigator() -> impl Widget<AppState> {
     Navigator::new(UiView::new("contacts".to_string()), contacts)
         .with_view_builder(UiView::new("contact details".to_string()), contact_details)
         .with_view_builder(UiView::new("contact edit".to_string()), contact_edit)
+        .with_transition(0.3)
         .controller(NavigatorController)
 }
 
@@ -256,9 +257,7 @@ pub fn contact_details() -> Box<dyn Widget<AppState>> {
     });
 
     let edit_button = Button::new("Edit").on_click(|event, data: &mut AppState, _env| {
-        let views = Arc::make_mut(&mut data.nav_state);
-        views.push(UiView::new("contact edit".to_string()));
-        data.nav_state = Arc::new(views.to_owned());
+        data.push_view(UiView::new("contact edit".to_string()));
         event.submit_command(Command::new(
             CONTACT_EDIT,
             data.selected.unwrap(),
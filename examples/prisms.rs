@@ -3,6 +3,7 @@
 
 use druid::widget::{CrossAxisAlignment, Flex, Slider, TextBox};
 use druid::{AppLauncher, Data, UnitPoint, Widget, WidgetExt, WindowDesc};
+use druid_widget_nursery::animation::AnimationCurve;
 use druid_widget_nursery::prism::{Closures, Prism};
 use druid_widget_nursery::{MultiCheckbox, MultiRadio};
 
@@ -83,7 +84,8 @@ fn main_widget() -> impl Widget<TestData> {
         .with_variant(
             TestDataC,
             MultiCheckbox::new("optional data", TextBox::new(), "".to_string()),
-        );
+        )
+        .with_transition(0.3, AnimationCurve::EASE_IN_OUT);
 
     Flex::row()
         .with_child(left)
@@ -185,6 +185,16 @@ fn build_ui() -> impl Widget<AppState> {
             .with_child(Label::new("Theme Configured Progress Bar"))
             .with_child(ProgressBar::new().lens(AppState::leaping_progress)),
     );
+    flex.add_spacer(10.0);
+    flex.add_child(
+        Flex::column()
+            .with_child(Label::new("Indeterminate Progress Bar"))
+            .with_child(
+                ProgressBar::new()
+                    .with_indeterminate(true)
+                    .lens(AppState::leaping_progress),
+            ),
+    );
 
     flex
 }
@@ -26,6 +26,7 @@ enum Transportation {
 struct AppData {
     destination: Destination,
     transportation: Transportation,
+    seat: u32,
 }
 
 fn main_widget() -> impl Widget<AppData> {
@@ -60,11 +61,19 @@ fn main_widget() -> impl Widget<AppData> {
     let mut col = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
     col.add_child(
         Label::new(|d: &AppData, _: &Env| {
-            format!("Let's go to {:?} by {:?}", d.destination, d.transportation)
+            format!(
+                "Let's go to {:?} by {:?}, seat {}",
+                d.destination, d.transportation, d.seat
+            )
         })
         .padding(Insets::uniform_xy(5., 5.)),
     );
     col.add_child(row);
+    col.add_default_spacer();
+    col.add_child(
+        DropdownSelect::new_searchable((1..=200).map(|seat| (format!("Seat number {seat}"), seat)))
+            .lens(AppData::seat),
+    );
     col
 }
 
@@ -77,6 +86,7 @@ fn main() {
     let app_data = AppData {
         transportation: Transportation::Car,
         destination: Destination::Tokyo,
+        seat: 1,
     };
 
     // start the application
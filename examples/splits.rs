@@ -1,47 +1,84 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use druid::im::Vector;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use druid::im::{vector, Vector};
 use druid::widget::{Container, Flex, Label, Scroll, WidgetExt};
 use druid::{AppLauncher, Data, Env, Lens, Widget, WindowDesc};
-use druid_widget_nursery::splits::Splits;
+use druid_widget_nursery::splits::{SharedPositions, Splits};
 
 #[derive(Data, Clone, Lens)]
 struct AppState {
     collection: Vector<String>,
+    /// The bar positions of the last row, kept in the data so they can be saved
+    saved_positions: Vector<f64>,
+}
+
+fn splits(positions: &SharedPositions) -> impl Widget<AppState> {
+    Container::new(
+        Scroll::new(
+            Splits::new(|| {
+                Label::new(|text: &String, _: &Env| format!("Collection: {text}")).fix_height(60.)
+            })
+            .horizontal()
+            .min_size(180.)
+            .draggable(true)
+            .bar_size(6.)
+            .with_shared_positions(positions.clone()),
+        )
+        .horizontal()
+        .lens(AppState::collection),
+    )
+}
+
+fn persisted_splits() -> impl Widget<AppState> {
+    Splits::new(|| Label::new(|text: &String, _: &Env| format!("Saved: {text}")).fix_height(60.))
+        .horizontal()
+        .min_size(180.)
+        .draggable(true)
+        .with_positions_in_data()
+        .lens(druid::lens::Map::new(
+            |data: &AppState| (data.collection.clone(), data.saved_positions.clone()),
+            |data: &mut AppState, (collection, positions)| {
+                data.collection = collection;
+                data.saved_positions = positions;
+            },
+        ))
 }
 
 fn main_widget() -> impl Widget<AppState> {
+    // both rows keep their bars aligned
+    let positions: SharedPositions = Rc::new(RefCell::new(Vec::new()));
     Flex::column()
         .main_axis_alignment(druid::widget::MainAxisAlignment::Start)
         .cross_axis_alignment(druid::widget::CrossAxisAlignment::Start)
-        .with_child(Container::new(
-            Scroll::new(
-                Splits::new(|| {
-                    Label::new(|text: &String, _: &Env| format!("Collection: {text}"))
-                        .fix_height(120.)
-                })
-                .horizontal()
-                .min_size(180.)
-                .draggable(true)
-                .bar_size(6.),
-            )
-            .horizontal()
-            .lens(AppState::collection),
-        ))
+        .with_child(splits(&positions))
+        .with_child(splits(&positions))
+        .with_child(persisted_splits())
+        .with_child(Label::new(|data: &AppState, _: &Env| {
+            format!("Saved positions: {:?}", data.saved_positions)
+        }))
 }
 
 pub fn main() {
     let main_window = WindowDesc::new(main_widget())
         .title("Dropdown")
-        .window_size((500., 140.));
+        .window_size((500., 240.));
 
     let mut collection = Vector::new();
     collection.push_back("Column 1".to_string());
     collection.push_back("Column 2".to_string());
     collection.push_back("Column 3".to_string());
 
-    let initial_state = AppState { collection };
+    // restore a previously saved layout
+    let saved_positions = vector![200., 400., 600.];
+
+    let initial_state = AppState {
+        collection,
+        saved_positions,
+    };
 
     AppLauncher::with_window(main_window)
         .log_to_console()
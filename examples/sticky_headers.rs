@@ -0,0 +1,62 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use druid::im::Vector;
+use druid::widget::{Label, List, Scroll};
+use druid::{theme, AppLauncher, Data, Lens, Widget, WidgetExt, WindowDesc};
+use druid_widget_nursery::StickyHeaders;
+
+#[derive(Clone, Data, Lens)]
+struct Section {
+    letter: char,
+    names: Vector<String>,
+}
+
+#[derive(Clone, Data, Lens)]
+struct AppData {
+    sections: Vector<Section>,
+}
+
+fn main_widget() -> impl Widget<AppData> {
+    let contacts = StickyHeaders::new(
+        || {
+            Label::dynamic(|section: &Section, _| section.letter.to_string())
+                .with_text_size(18.)
+                .padding((10., 4.))
+                .expand_width()
+                .background(theme::BACKGROUND_LIGHT)
+        },
+        || {
+            List::new(|| Label::dynamic(|name: &String, _| name.clone()).padding((20., 6.)))
+                .lens(Section::names)
+        },
+    );
+    Scroll::new(contacts.lens(AppData::sections)).vertical()
+}
+
+fn main() {
+    let names = [
+        "Ada", "Alan", "Alice", "Barbara", "Bob", "Brian", "Carol", "Charles", "Claude", "Dave",
+        "Dennis", "Donald", "Edsger", "Emma", "Eve", "Frances", "Frank", "Grace", "Guido", "Hedy",
+        "John", "Judy", "Ken", "Linus", "Margaret",
+    ];
+    let mut sections: Vector<Section> = Vector::new();
+    for name in names {
+        let letter = name.chars().next().unwrap();
+        match sections.back_mut() {
+            Some(section) if section.letter == letter => section.names.push_back(name.into()),
+            _ => sections.push_back(Section {
+                letter,
+                names: Vector::unit(name.into()),
+            }),
+        }
+    }
+
+    let main_window = WindowDesc::new(main_widget())
+        .title("Sticky Headers")
+        .window_size((300., 400.));
+    AppLauncher::with_window(main_window)
+        .log_to_console()
+        .launch(AppData { sections })
+        .expect("launch failed");
+}
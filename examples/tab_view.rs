@@ -0,0 +1,55 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use druid::widget::{Button, Flex, Label, TextBox};
+use druid::{AppLauncher, Data, Lens, Widget, WidgetExt, WindowDesc};
+use druid_widget_nursery::{TabView, TabsState};
+
+#[derive(Clone, Data, Lens)]
+struct AppData {
+    tabs: TabsState<usize>,
+    next: usize,
+    notes: String,
+}
+
+fn main_widget() -> impl Widget<AppData> {
+    let new_tab = Button::new("New tab").on_click(|_, data: &mut AppData, _| {
+        data.tabs.open(data.next);
+        data.next += 1;
+    });
+
+    let tabs = TabView::new(
+        AppData::tabs,
+        |key: &usize, _: &AppData| format!("Document {key}"),
+        |key: &usize| {
+            Flex::column()
+                .with_child(Label::new(format!("This is document {key}.")))
+                .with_default_spacer()
+                .with_child(TextBox::multiline().lens(AppData::notes).fix_width(300.))
+                .padding(10.)
+        },
+    );
+
+    Flex::column()
+        .with_child(new_tab.padding(5.))
+        .with_flex_child(tabs, 1.)
+}
+
+fn main() {
+    let mut tabs = TabsState::new();
+    for key in 0..5 {
+        tabs.open(key);
+    }
+
+    let main_window = WindowDesc::new(main_widget())
+        .title("Tab View")
+        .window_size((500., 300.));
+    AppLauncher::with_window(main_window)
+        .log_to_console()
+        .launch(AppData {
+            tabs,
+            next: 5,
+            notes: String::new(),
+        })
+        .expect("launch failed");
+}
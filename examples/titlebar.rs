@@ -3,9 +3,7 @@
 
 use druid::widget::{Button, Flex, Label};
 use druid::{AppLauncher, Application, Widget, WidgetExt, WindowDesc};
-use druid_widget_nursery::TitleBar;
-
-// TitleBar only works on windows.
+use druid_widget_nursery::{ResizeFrame, TitleBar};
 
 fn build_titlebar() -> impl Widget<u32> {
     // Make a row of buttons and title for the titlebar.
@@ -31,11 +29,13 @@ fn build_titlebar() -> impl Widget<u32> {
 
 fn build_ui() -> impl Widget<u32> {
     // A column with a titlebar and then whatever body the app has beneath it.
-    Flex::column()
+    let column = Flex::column()
         .with_child(build_titlebar())
         .with_child(Label::new(
-            "Whatever is beneath the titlebar.\nYou can also drag the window around by the title! Try it"
-        ))
+            "Whatever is beneath the titlebar.\nYou can also drag the window around by the title, and double-click it to maximize! Try it"
+        ));
+    // Without the normal window frame, this resizes the window when dragging its edges.
+    ResizeFrame::new(column).with_min_size((300.0, 150.0))
 }
 
 fn main() {
@@ -1,12 +1,14 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::time::Duration;
+
 use druid::{
-    widget::{Flex, Label, TextBox},
+    widget::{CrossAxisAlignment, Flex, Label, TextBox},
     AppLauncher, Data, Env, Lens, Widget, WidgetExt, WindowDesc,
 };
 
-use druid_widget_nursery::WidgetExt as _;
+use druid_widget_nursery::{TooltipController, WidgetExt as _};
 
 fn main() {
     let window = WindowDesc::new(ui());
@@ -25,9 +27,32 @@ fn ui() -> impl Widget<AppState> {
     let label = Label::new("Hover me for a secret message!")
         .tooltip(|data: &AppState, _env: &Env| data.message.clone());
     let text_box = TextBox::new().lens(AppState::message);
+    let widget_label = Label::new("This one shows a widget").tooltip_widget(|| {
+        Flex::row()
+            .with_child(Label::new("ℹ").with_text_size(24.0))
+            .with_default_spacer()
+            .with_child(
+                Flex::column()
+                    .cross_axis_alignment(CrossAxisAlignment::Start)
+                    .with_child(Label::new("The secret message is"))
+                    .with_child(Label::new(|data: &AppState, _env: &Env| {
+                        data.message.clone()
+                    })),
+            )
+            .padding(4.0)
+    });
+    let slow_label = Label::new("This one takes a second and follows the mouse").controller(
+        TooltipController::new("Patience!")
+            .with_delay(Duration::from_secs(1))
+            .follow_cursor(true),
+    );
 
     Flex::column()
         .with_child(label)
         .with_default_spacer()
+        .with_child(slow_label)
+        .with_default_spacer()
+        .with_child(widget_label)
+        .with_default_spacer()
         .with_child(text_box)
 }
@@ -3,36 +3,63 @@
 
 use ::std::time::Instant;
 
+use druid::keyboard_types::Key;
 use druid::kurbo::{Point, Rect, RoundedRect};
 use druid::widget::prelude::*;
 use druid::{Color, RenderContext, TextLayout, Widget};
 
+crate::selectors! {
+    /// Notification sent by an [`AdvancedSlider`] with its new value, whenever the
+    /// user changed it by dragging, typing or with the arrow keys.
+    ADVANCED_SLIDER_CHANGED: f64,
+}
+
+/// The space around the track.
+const MARGIN: f64 = 2.0;
+
+type FormatFn = Box<dyn Fn(f64) -> String>;
+type ParseFn = Box<dyn Fn(&str) -> Option<f64>>;
+
 /// An advanced version of the slider, allowing interactive update of a numeric
 /// value.
 ///
 /// This slider implements `Widget<f64>`, and works on values clamped
 /// in the range `min_val..max_val`. Additionally double clicks allows to enter
-/// the precise value over the keyboard.
+/// the precise value over the keyboard. While typing, the border turns red if
+/// the value isn't a number within the range.
+///
+/// Once clicked, or focused with Tab, the arrow keys step the value by the step
+/// size, or by a hundredth of the range without stepping. Changes made by the
+/// user are also sent as an [`ADVANCED_SLIDER_CHANGED`] notification.
+///
+/// The value is shown with the significant digits set by `with_significant`,
+/// unless a formatter is given with `with_formatter`. Keyboard input is then
+/// read back with the parser given with `with_parser`.
 pub struct AdvancedSlider {
     min_val: f64,
     max_val: f64,
     start_val: f64,
     step_size: Option<f64>,
     signif_dig: usize,
+    formatter: Option<FormatFn>,
+    parser: Option<ParseFn>,
     val_text: TextLayout<String>,
     last_click: Instant,
     input_mode: bool,
     input_string: String,
+    input_invalid: bool,
     keyboard_input_origin: bool,
     text_offset: f64,
+    track_width: f64,
+    height: f64,
 }
 
 impl AdvancedSlider {
     /// Takes a mouse event and returns the slider value at the specified
     /// x position.
     fn x_from_mouse(&self, mouse_event: &druid::MouseEvent) -> f64 {
-        // Determines percentage regarding the slider size 120.0
-        let mut perc_attempt: f64 = (mouse_event.pos.x - 2.0) / 120.0;
+        // Determines percentage regarding the track width
+        let mut perc_attempt: f64 = (mouse_event.pos.x - MARGIN) / self.track_width;
         // Make sure percentage is bounded between 0 and 1
         if perc_attempt < 0.0 {
             perc_attempt = 0.0;
@@ -70,27 +97,94 @@ impl AdvancedSlider {
                 // Apply stepping
                 data =
                     ((data_attempt - self.min_val) / step_size).round() * step_size + self.min_val;
-                string = format!("{:.*}", self.signif_dig, data);
+                string = self.format(data);
             }
             None => {
                 data = data_attempt;
                 if self.keyboard_input_origin {
                     if modified {
                         // Correct String when data was corrected
-                        string = format!("{:.*}", self.signif_dig, data);
+                        string = self.format(data);
                     } else {
                         // Otherwise take input string from the keyboard
                         string = self.input_string.clone();
                     }
                 } else {
                     // Format String when the origin wasn't keyboard input
-                    string = format!("{:.*}", self.signif_dig, data);
+                    string = self.format(data);
                 }
             }
         }
         (data, string)
     }
 
+    /// Returns the value after stepping `value` with an arrow key, or `None` if
+    /// `key` isn't an arrow key.
+    fn stepped(&self, value: f64, key: &Key) -> Option<f64> {
+        let step = self
+            .step_size
+            .unwrap_or((self.max_val - self.min_val) / 100.0);
+        let attempt = match key {
+            Key::ArrowRight | Key::ArrowUp => value + step,
+            Key::ArrowLeft | Key::ArrowDown => value - step,
+            _ => return None,
+        };
+        Some(self.data_from_attempt(attempt).0)
+    }
+
+    /// Sets the data to a value chosen by the user, notifying if it changed.
+    fn set_value(&self, ctx: &mut EventCtx, data: &mut f64, value: f64) {
+        if value != *data {
+            *data = value;
+            ctx.submit_notification(ADVANCED_SLIDER_CHANGED.with(value));
+        }
+    }
+
+    /// Formats `value` for the label.
+    fn format(&self, value: f64) -> String {
+        match &self.formatter {
+            Some(formatter) => formatter(value),
+            None => format!("{:.*}", self.signif_dig, value),
+        }
+    }
+
+    /// Parses the keyboard input, returning `None` if it isn't a value.
+    fn parse(&self, input: &str) -> Option<f64> {
+        match &self.parser {
+            Some(parser) => parser(input),
+            None => input.parse().ok(),
+        }
+    }
+
+    /// Handles a character typed in input mode. A minus toggles the sign of the
+    /// input, a second dot is ignored.
+    fn push_input_char(&mut self, string: &str) {
+        match string {
+            "-" => {
+                if self.input_string.starts_with('-') {
+                    self.input_string.remove(0);
+                } else {
+                    self.input_string.insert(0, '-');
+                }
+            }
+            "." if self.input_string.contains('.') => {}
+            _ => self.input_string.push_str(string),
+        }
+        self.input_invalid = !self.input_is_valid();
+    }
+
+    /// Checks whether the current keyboard input is a number within the range
+    /// of the slider. An empty (or sign only) input is not considered invalid yet.
+    fn input_is_valid(&self) -> bool {
+        if self.input_string.is_empty() || self.input_string == "-" {
+            return true;
+        }
+        match self.parse(&self.input_string) {
+            Some(value) => value >= self.min_val && value <= self.max_val,
+            None => false,
+        }
+    }
+
     /// Builder style method for constructing a new slider
     pub fn new() -> AdvancedSlider {
         AdvancedSlider {
@@ -99,12 +193,17 @@ impl AdvancedSlider {
             start_val: 50.0,
             step_size: None,
             signif_dig: 0,
+            formatter: None,
+            parser: None,
             val_text: TextLayout::from_text("50"),
             last_click: Instant::now(),
             input_mode: false,
             input_string: String::from(""),
+            input_invalid: false,
             keyboard_input_origin: false,
             text_offset: 0.0,
+            track_width: 120.0,
+            height: 24.0,
         }
     }
 
@@ -123,7 +222,7 @@ impl AdvancedSlider {
     /// Builder style method for setting the start value.
     pub fn with_start_val(mut self, start_val: f64) -> AdvancedSlider {
         self.start_val = start_val;
-        self.val_text = TextLayout::from_text(format!("{:.*}", self.signif_dig, start_val));
+        self.val_text = TextLayout::from_text(self.format(start_val));
         self
     }
 
@@ -144,12 +243,51 @@ impl AdvancedSlider {
         self
     }
 
+    /// Builder style method to set a formatter for the label, like
+    /// `|value| format!("{} %", value)`, replacing the significant digits.
+    pub fn with_formatter(mut self, formatter: impl Fn(f64) -> String + 'static) -> AdvancedSlider {
+        self.formatter = Some(Box::new(formatter));
+        self.val_text = TextLayout::from_text(self.format(self.start_val));
+        self
+    }
+
+    /// Builder style method to set a parser for the keyboard input, returning
+    /// `None` for invalid input. Any character can be typed with a parser, so
+    /// that units are accepted.
+    pub fn with_parser(mut self, parser: impl Fn(&str) -> Option<f64> + 'static) -> AdvancedSlider {
+        self.parser = Some(Box::new(parser));
+        self
+    }
+
     /// Builder style method to give the label and offset. Sometimes necessary
     /// on different operating systems with different fonts ? <- Not sure about that
     pub fn with_text_offset(mut self, offset: f64) -> AdvancedSlider {
         self.text_offset = offset;
         self
     }
+
+    /// Builder style method to set the width of the track. The default is 120.
+    pub fn with_track_width(mut self, track_width: f64) -> AdvancedSlider {
+        self.track_width = track_width.max(1.0);
+        self
+    }
+
+    /// Builder style method to set the height of the slider, including a margin
+    /// of 2 around the track. The default is 24.
+    pub fn with_height(mut self, height: f64) -> AdvancedSlider {
+        self.height = height.max(2.0 * MARGIN + 1.0);
+        self
+    }
+
+    /// The rectangle of the track, within the margin.
+    fn track_rect(&self) -> Rect {
+        Rect::new(
+            MARGIN,
+            MARGIN,
+            MARGIN + self.track_width,
+            self.height - MARGIN,
+        )
+    }
 }
 
 impl Default for AdvancedSlider {
@@ -176,6 +314,7 @@ impl Widget<f64> for AdvancedSlider {
                         // Enter input mode
                         self.input_mode = true;
                         self.input_string = String::from("");
+                        self.input_invalid = false;
                         self.val_text = TextLayout::from_text(self.input_string.to_string());
                         self.val_text.rebuild_if_needed(ctx.text(), env);
                         ctx.request_focus();
@@ -183,9 +322,10 @@ impl Widget<f64> for AdvancedSlider {
                     } else {
                         // Handle simple click
                         ctx.set_active(true);
+                        ctx.request_focus();
                         let data_attempt = self.x_from_mouse(mouse_event);
                         let data_tuple = self.data_from_attempt(data_attempt);
-                        *data = data_tuple.0;
+                        self.set_value(ctx, data, data_tuple.0);
                     }
                 }
             }
@@ -204,31 +344,39 @@ impl Widget<f64> for AdvancedSlider {
                     if ctx.is_active() {
                         let data_attempt = self.x_from_mouse(mouse_event);
                         let data_tuple = self.data_from_attempt(data_attempt);
-                        *data = data_tuple.0;
+                        self.set_value(ctx, data, data_tuple.0);
                     }
                 }
             }
 
+            // Step the value with the arrow keys
+            Event::KeyDown(key_event) if !self.input_mode => {
+                if let Some(value) = self.stepped(*data, &key_event.key) {
+                    self.set_value(ctx, data, value);
+                    ctx.set_handled();
+                }
+            }
+
             Event::KeyDown(key_event) => match &key_event.key {
                 // Enter to confirm keyboard input
                 druid::keyboard_types::Key::Enter => {
                     ctx.resign_focus();
-                    let try_parse = self.input_string.parse::<f64>();
+                    self.input_invalid = false;
+                    let try_parse = self.parse(&self.input_string);
                     match try_parse {
                         // When parsable -> specify keyboard origin and convert to data
-                        Ok(parsed_input) => {
+                        Some(parsed_input) => {
                             self.keyboard_input_origin = true;
                             let data_tuple = self.data_from_attempt(parsed_input);
-                            *data = data_tuple.0;
+                            self.set_value(ctx, data, data_tuple.0);
                             self.val_text = TextLayout::from_text(data_tuple.1);
                             self.val_text.rebuild_if_needed(ctx.text(), env);
                             self.input_mode = false;
                         }
 
                         // When not parsable -> revert to old data
-                        Err(_) => {
-                            self.val_text =
-                                TextLayout::from_text(format!("{:.*}", self.signif_dig, data));
+                        None => {
+                            self.val_text = TextLayout::from_text(self.format(*data));
                             self.val_text.rebuild_if_needed(ctx.text(), env);
                             self.input_mode = false;
                             ctx.request_paint();
@@ -239,7 +387,14 @@ impl Widget<f64> for AdvancedSlider {
                 // Handle allowed input characters
                 druid::keyboard_types::Key::Character(string) => match string.as_str() {
                     "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" | "." | "-" => {
-                        self.input_string.push_str(string);
+                        self.push_input_char(string);
+                        self.val_text = TextLayout::from_text(self.input_string.to_string());
+                        self.val_text.rebuild_if_needed(ctx.text(), env);
+                        ctx.request_paint();
+                    }
+                    // Units are left to the custom parser
+                    _ if self.parser.is_some() => {
+                        self.push_input_char(string);
                         self.val_text = TextLayout::from_text(self.input_string.to_string());
                         self.val_text.rebuild_if_needed(ctx.text(), env);
                         ctx.request_paint();
@@ -250,6 +405,7 @@ impl Widget<f64> for AdvancedSlider {
                 // Handle deleting chararcters of the input sting
                 druid::keyboard_types::Key::Backspace => {
                     self.input_string.pop();
+                    self.input_invalid = !self.input_is_valid();
                     self.val_text = TextLayout::from_text(self.input_string.to_string());
                     self.val_text.rebuild_if_needed(ctx.text(), env);
                     ctx.request_paint();
@@ -265,6 +421,7 @@ impl Widget<f64> for AdvancedSlider {
     // Handle initialisation
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, _data: &f64, _env: &Env) {
         if let LifeCycle::WidgetAdded = event {
+            ctx.register_for_focus();
             ctx.request_layout();
             ctx.request_paint();
         }
@@ -278,7 +435,7 @@ impl Widget<f64> for AdvancedSlider {
             if self.keyboard_input_origin {
                 self.keyboard_input_origin = false;
             } else {
-                self.val_text = TextLayout::from_text(format!("{:.*}", self.signif_dig, data));
+                self.val_text = TextLayout::from_text(self.format(*data));
                 self.val_text.rebuild_if_needed(ctx.text(), env);
             }
             ctx.request_layout();
@@ -293,18 +450,29 @@ impl Widget<f64> for AdvancedSlider {
         _data: &f64,
         _env: &Env,
     ) -> Size {
-        Size::new(124.0, 24.0)
+        Size::new(self.track_width + 2.0 * MARGIN, self.height)
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, data: &f64, _env: &Env) {
-        let rounded_box = RoundedRect::new(2.0, 2.0, 122.0, 22.0, 2.0);
+        let track = self.track_rect();
+        let rounded_box = RoundedRect::from_rect(track, 2.0);
         // Handle in which mode to draw the widget
         if self.input_mode {
             ctx.fill(rounded_box, &Color::rgb8(50, 50, 50));
-            ctx.stroke(rounded_box, &Color::rgb8(80, 80, 80), 1.0);
+            // Show a red border while the typed value can't be accepted as is
+            if self.input_invalid {
+                ctx.stroke(rounded_box, &Color::rgb8(212, 32, 35), 1.5);
+            } else {
+                ctx.stroke(rounded_box, &Color::rgb8(80, 80, 80), 1.0);
+            }
         } else {
-            let percentage = (data - self.min_val) / (self.max_val - self.min_val) * 100.0;
-            let blocker = Rect::new(percentage * 1.2 + 2.0, 2.0, 122.0, 22.0);
+            let fraction = (data - self.min_val) / (self.max_val - self.min_val);
+            let blocker = Rect::new(
+                track.x0 + fraction * track.width(),
+                track.y0,
+                track.x1,
+                track.y1,
+            );
 
             // Constrain blocker to within the slider. A blocker is used to make
             // sure the slider is flat on one side and rounded on the other side.
@@ -317,10 +485,54 @@ impl Widget<f64> for AdvancedSlider {
             ctx.stroke(rounded_box, &Color::rgb8(30, 30, 30), 1.0);
         }
         // Center Text and draw it
-        let text_width = self.val_text.layout_metrics().size.width;
-        self.val_text.draw(
-            ctx,
-            Point::new(62.0 - (text_width / 2.0), 2.0 + self.text_offset),
+        let text_size = self.val_text.layout_metrics().size;
+        let text_origin = Point::new(
+            track.center().x - text_size.width / 2.0,
+            track.center().y - text_size.height / 2.0 + self.text_offset,
         );
+        self.val_text.draw(ctx, text_origin);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrow_keys() {
+        let slider = AdvancedSlider::new()
+            .with_range(0.0, 10.0)
+            .with_step_size(2.0);
+        assert_eq!(slider.stepped(4.0, &Key::ArrowRight), Some(6.0));
+        assert_eq!(slider.stepped(4.0, &Key::ArrowDown), Some(2.0));
+        // clamped to the range
+        assert_eq!(slider.stepped(10.0, &Key::ArrowUp), Some(10.0));
+        assert_eq!(slider.stepped(4.0, &Key::Enter), None);
+
+        // without stepping, a hundredth of the range
+        let slider = AdvancedSlider::new().with_range(0.0, 10.0);
+        assert_eq!(slider.stepped(5.0, &Key::ArrowLeft), Some(4.9));
+    }
+
+    #[test]
+    fn percent_formatter() {
+        let mut slider = AdvancedSlider::new()
+            .with_formatter(|value| format!("{} %", value))
+            .with_parser(|input| input.trim_end_matches('%').trim().parse().ok());
+        assert_eq!(slider.format(50.0), "50 %");
+        assert_eq!(slider.parse("50%"), Some(50.0));
+        assert_eq!(slider.parse(&slider.format(12.5)), Some(12.5));
+
+        for c in ["5", "0", "%"] {
+            slider.push_input_char(c);
+        }
+        assert!(slider.input_is_valid());
+        slider.push_input_char("0");
+        assert!(!slider.input_is_valid());
+
+        // the numeric default
+        let slider = AdvancedSlider::new().with_significant(2);
+        assert_eq!(slider.format(0.5), "0.50");
+        assert_eq!(slider.parse("50%"), None);
     }
 }
@@ -0,0 +1,195 @@
+// Copyright 2022 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A padding widget animating between insets.
+
+use druid::widget::prelude::*;
+use druid::{Insets, Point, WidgetPod};
+
+use crate::animation::{Animated, AnimationCurve};
+use crate::RequestCtx;
+
+type PaddingFn<T> = Box<dyn Fn(&T, &Env) -> Insets>;
+
+enum Padding<T> {
+    Fixed,
+    Dynamic(PaddingFn<T>),
+}
+
+/// A widget that adds padding around its child, animating the padding when it
+/// changes.
+///
+/// The padding is either set with [`set_padding`], or computed from the data with
+/// [`dynamic`], in which case it is animated whenever the computed insets change.
+///
+/// [`set_padding`]: AnimatedPadding::set_padding
+/// [`dynamic`]: AnimatedPadding::dynamic
+pub struct AnimatedPadding<T, W> {
+    padding: Padding<T>,
+    insets: Animated<Insets>,
+    child: WidgetPod<T, W>,
+}
+
+impl<T, W: Widget<T>> AnimatedPadding<T, W> {
+    /// Create a widget with a fixed padding, which can be changed with
+    /// [`set_padding`](AnimatedPadding::set_padding).
+    pub fn new(insets: impl Into<Insets>, child: W) -> Self {
+        Self::with_padding(Padding::Fixed, insets.into(), child)
+    }
+
+    /// Create a widget whose padding is computed from the data by `padding`.
+    ///
+    /// The insets are computed again on every update, and the padding is animated
+    /// towards them when they changed.
+    pub fn dynamic(padding: impl Fn(&T, &Env) -> Insets + 'static, child: W) -> Self {
+        Self::with_padding(Padding::Dynamic(Box::new(padding)), Insets::ZERO, child)
+    }
+
+    fn with_padding(padding: Padding<T>, insets: Insets, child: W) -> Self {
+        Self {
+            padding,
+            insets: Animated::new(insets)
+                .curve(AnimationCurve::EASE_OUT)
+                .duration(0.3)
+                .layout(true),
+            child: WidgetPod::new(child),
+        }
+    }
+
+    /// Builder-style method for specifying the [`AnimationCurve`].
+    ///
+    /// For the non-builder varient, see [`set_curve`].
+    ///
+    /// [`set_curve`]: #method.set_curve
+    pub fn curve(mut self, curve: impl Into<AnimationCurve>) -> Self {
+        self.set_curve(curve);
+        self
+    }
+
+    /// Set the [`AnimationCurve`].
+    pub fn set_curve(&mut self, curve: impl Into<AnimationCurve>) {
+        self.insets.set_curve(curve.into());
+    }
+
+    /// Builder-style method for specifying the animation duration.
+    ///
+    /// For the non-builder varient, see [`set_duration`].
+    ///
+    /// [`set_duration`]: #method.set_duration
+    pub fn duration(mut self, duration: f64) -> Self {
+        self.set_duration(duration);
+        self
+    }
+
+    /// Set the animation duration in seconds.
+    pub fn set_duration(&mut self, duration: f64) {
+        self.insets.set_duration(duration);
+    }
+
+    /// Animate the padding to `insets`.
+    ///
+    /// A padding computed from the data is replaced by this fixed padding.
+    pub fn set_padding(&mut self, ctx: &mut impl RequestCtx, insets: impl Into<Insets>) {
+        self.padding = Padding::Fixed;
+        self.insets.animate(ctx, insets.into());
+    }
+
+    /// The current, possibly animating, padding.
+    pub fn padding(&self) -> Insets {
+        self.insets.get()
+    }
+
+    /// Animates a padding computed from the data towards its new insets.
+    fn update_padding(&mut self, ctx: &mut impl RequestCtx, data: &T, env: &Env) {
+        if let Padding::Dynamic(padding) = &self.padding {
+            let insets = padding(data, env);
+            if insets != self.insets.end() {
+                self.insets.animate(ctx, insets);
+            }
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for AnimatedPadding<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::AnimFrame(nanos) = event {
+            self.insets.update(ctx, *nanos);
+        }
+        self.child.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            if let Padding::Dynamic(padding) = &self.padding {
+                self.insets.jump_to_value(padding(data, env));
+            }
+        }
+        self.child.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.update_padding(ctx, data, env);
+        self.child.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let insets = self.insets.get();
+        let child_bc = bc.shrink(insets.size());
+        let child_size = self.child.layout(ctx, &child_bc, data, env);
+        self.child.set_origin(ctx, Point::new(insets.x0, insets.y0));
+
+        let size = bc.constrain(child_size + insets.size());
+        let baseline = self.child.baseline_offset() + insets.y1;
+        ctx.set_baseline_offset(baseline);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.child.paint(ctx, data, env);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::test::TestCtx;
+    use druid::widget::SizedBox;
+
+    const FRAME: u64 = 100_000_000;
+
+    fn assert_insets(actual: Insets, expected: f64) {
+        let Insets { x0, y0, x1, y1 } = actual;
+        for side in [x0, y0, x1, y1] {
+            assert!(
+                (side - expected).abs() < 1e-9,
+                "{:?} != {}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn animate_data_change() {
+        let env = Env::empty();
+        let mut padding = AnimatedPadding::dynamic(
+            |padding: &f64, _env| Insets::uniform(*padding),
+            SizedBox::empty(),
+        )
+        .curve(AnimationCurve::LINEAR);
+        padding.insets.jump_to_value(Insets::ZERO);
+
+        padding.update_padding(&mut TestCtx, &30.0, &env);
+        assert_insets(padding.padding(), 0.0);
+        padding.insets.update(&mut TestCtx, FRAME);
+        assert_insets(padding.padding(), 10.0);
+        padding.insets.update(&mut TestCtx, FRAME);
+        assert_insets(padding.padding(), 20.0);
+
+        // an unchanged padding doesn't restart the animation
+        padding.update_padding(&mut TestCtx, &30.0, &env);
+        padding.insets.update(&mut TestCtx, FRAME);
+        assert_insets(padding.padding(), 30.0);
+        assert!(!padding.insets.animating());
+    }
+}
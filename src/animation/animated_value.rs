@@ -7,13 +7,71 @@ use crate::animation::{AnimationController, AnimationCurve, Interpolate};
 
 use crate::RequestCtx;
 
+/// The time step used to integrate the spring motion, in seconds.
+const SPRING_STEP: f64 = 0.001;
+/// The distance to the end and the velocity below which a spring is at rest.
+const SPRING_REST: f64 = 0.001;
+
+/// A spring pulling the animation progress from 0.0 towards 1.0.
+#[derive(Debug, Clone, Copy)]
+struct Spring {
+    stiffness: f64,
+    damping: f64,
+    position: f64,
+    velocity: f64,
+    running: bool,
+}
+
+impl Spring {
+    fn new(stiffness: f64, damping: f64) -> Self {
+        Spring {
+            stiffness,
+            damping,
+            position: 1.0,
+            velocity: 0.0,
+            running: false,
+        }
+    }
+
+    fn start(&mut self) {
+        self.position = 0.0;
+        self.velocity = 0.0;
+        self.running = true;
+    }
+
+    fn stop(&mut self) {
+        self.position = 1.0;
+        self.velocity = 0.0;
+        self.running = false;
+    }
+
+    /// Moves the spring forward by `seconds`, and stops it once it came to rest.
+    fn advance(&mut self, seconds: f64) {
+        // long pauses between frames would take many steps without changing much
+        let seconds = seconds.min(1.0);
+        let steps = (seconds / SPRING_STEP).ceil().max(1.0);
+        let dt = seconds / steps;
+        for _ in 0..steps as usize {
+            let acceleration =
+                -self.stiffness * (self.position - 1.0) - self.damping * self.velocity;
+            self.velocity += acceleration * dt;
+            self.position += self.velocity * dt;
+        }
+        if (self.position - 1.0).abs() < SPRING_REST && self.velocity.abs() < SPRING_REST {
+            self.stop();
+        }
+    }
+}
+
 /// Animated provides simple transition-animations for single values or tuples of values that implement
 /// [`Interpolate`].
+#[derive(Debug)]
 pub struct Animated<T> {
     start: T,
     end: T,
     controller: AnimationController,
     curve: AnimationCurve,
+    spring: Option<Spring>,
 
     current: T,
 }
@@ -36,6 +94,7 @@ impl<T: Interpolate> Animated<T> {
             end: value.clone(),
             controller,
             curve: Default::default(),
+            spring: None,
             current: value,
         }
     }
@@ -50,6 +109,7 @@ impl<T: Interpolate> Animated<T> {
             end: value.clone(),
             controller,
             curve: Default::default(),
+            spring: None,
             current: value,
         }
     }
@@ -84,6 +144,29 @@ impl<T: Interpolate> Animated<T> {
         self.controller.set_duration(duration);
     }
 
+    /// Builder-style method to animate with a spring instead of a curve.
+    ///
+    /// For the non-builder varient, see [`set_spring`].
+    ///
+    /// [`set_spring`]: #method.set_spring
+    pub fn spring(mut self, stiffness: f64, damping: f64) -> Self {
+        self.set_spring(stiffness, damping);
+        self
+    }
+
+    /// Animate with a spring of the given `stiffness` and `damping` (for a unit mass)
+    /// pulling the value towards the end value.
+    ///
+    /// The animation lasts until the spring comes to rest, so the duration and the
+    /// curve are ignored. With a damping below `2 * stiffness.sqrt()`, the value
+    /// overshoots the end value and oscillates around it before settling.
+    ///
+    /// When the end value changes during an animation, the spring starts again from
+    /// the current value, without keeping its velocity.
+    pub fn set_spring(&mut self, stiffness: f64, damping: f64) {
+        self.spring = Some(Spring::new(stiffness, damping));
+    }
+
     /// Builder-style method for specifying the layout flag.
     ///
     /// For the non-builder varient, see [`set_layout`].
@@ -114,14 +197,21 @@ impl<T: Interpolate> Animated<T> {
         self.end.clone()
     }
 
-    /// Returns the animation progress (between 0.0 and 1.0)
+    /// Returns the animation progress (between 0.0 and 1.0, except for a spring
+    /// overshooting the end value)
     pub fn progress(&self) -> f64 {
-        self.controller.fraction()
+        match &self.spring {
+            Some(spring) => spring.position,
+            None => self.controller.fraction(),
+        }
     }
 
     /// Returns true if the animation is running.
     pub fn animating(&self) -> bool {
-        self.controller.animating()
+        match &self.spring {
+            Some(spring) => spring.running,
+            None => self.controller.animating(),
+        }
     }
 
     /// Set the new end value.
@@ -132,6 +222,11 @@ impl<T: Interpolate> Animated<T> {
         if value != self.end {
             self.start = self.current.clone();
             self.end = value;
+            if let Some(spring) = &mut self.spring {
+                spring.start();
+                ctx.request_anim_frame();
+                return;
+            }
             self.controller.reset();
             self.controller.start(ctx);
             if !self.controller.animating() {
@@ -159,6 +254,9 @@ impl<T: Interpolate> Animated<T> {
     /// Stop the animation and set the value.
     pub fn jump_to_value(&mut self, value: T) {
         self.controller.reset();
+        if let Some(spring) = &mut self.spring {
+            spring.stop();
+        }
         self.start = value.clone();
         self.end = value.clone();
         self.current = value;
@@ -167,6 +265,9 @@ impl<T: Interpolate> Animated<T> {
     /// Stop the animation at the current value
     pub fn end_animation(&mut self) {
         self.controller.reset();
+        if let Some(spring) = &mut self.spring {
+            spring.stop();
+        }
         self.start = self.current.clone();
         self.end = self.current.clone();
     }
@@ -181,6 +282,23 @@ impl<T: Interpolate> Animated<T> {
     ///
     /// Note: This must be called to drive the animation.
     pub fn update(&mut self, ctx: &mut impl RequestCtx, nanos: u64) {
+        if let Some(spring) = &mut self.spring {
+            if spring.running {
+                spring.advance(nanos as f64 * 0.000000001);
+                if spring.running {
+                    self.current = self.start.interpolate(&self.end, spring.position);
+                    ctx.request_anim_frame();
+                } else {
+                    self.current = self.end.clone();
+                }
+                if self.controller.requests_layout() {
+                    ctx.request_layout();
+                } else {
+                    ctx.request_paint();
+                }
+            }
+            return;
+        }
         self.controller.update(ctx, nanos);
         if self.animating() {
             let fraction = self.controller.fraction();
@@ -200,3 +318,30 @@ impl<T> Deref for Animated<T> {
         &self.current
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::test::TestCtx;
+    use crate::StackChildPosition;
+
+    #[test]
+    fn spring_overshoots_and_settles() {
+        let start = StackChildPosition::new().left(Some(0.0));
+        let end = StackChildPosition::new().left(Some(100.0));
+        let mut animated = Animated::new(start).spring(200.0, 10.0);
+        animated.animate(&mut TestCtx, end.clone());
+        assert!(animated.animating());
+
+        let mut max_left: f64 = 0.0;
+        let mut frames = 0;
+        while animated.animating() && frames < 1000 {
+            animated.update(&mut TestCtx, 16_000_000);
+            max_left = max_left.max(animated.get().left.unwrap());
+            frames += 1;
+        }
+        assert!(max_left > 100.0);
+        assert!(frames < 1000);
+        assert_eq!(animated.get(), end);
+    }
+}
@@ -8,6 +8,7 @@ use crate::RequestCtx;
 /// Control animations. An Animation controller produces values between 0.0 and 1.0 during
 /// the given duration. You can run this animation forward, backwards,
 /// altered and repeated.
+#[derive(Debug)]
 pub struct AnimationController {
     duration: f64,
     direction: AnimationDirection,
@@ -87,6 +88,11 @@ impl AnimationController {
         self.layout = layout;
     }
 
+    /// Whether a layout is requested after each update.
+    pub(in crate::animation) fn requests_layout(&self) -> bool {
+        self.layout
+    }
+
     /// Builder-style method for specifying the duration.
     ///
     /// For the non-builder varient, see [`set_duration`].
@@ -137,6 +143,52 @@ impl AnimationController {
         }
     }
 
+    /// Jump to the point of the current repetition where the animation value is
+    /// `fraction` (between 0.0 and 1.0), without changing the status.
+    ///
+    /// The value is updated immediately; the widget has to request a paint or layout
+    /// itself if the animation is not running.
+    pub fn seek(&mut self, fraction: f64) {
+        let fraction = super::clamp_fraction(fraction);
+        if self.duration > 0.0 {
+            let repeat_count = (self.since_start / self.duration).floor();
+            let even_repeat = repeat_count % 2.0 == 0.0;
+            // translate is its own inverse
+            let local = self.direction.translate(fraction, even_repeat);
+            self.since_start = (repeat_count + local) * self.duration;
+        }
+        self.fraction = fraction;
+    }
+
+    /// Flip the [`AnimationDirection`] while keeping the current value, so that a
+    /// running animation goes back to where it came from.
+    ///
+    /// [`Forward`] and [`Reverse`] are swapped, as are [`Alternate`] and
+    /// [`AlternateReverse`]. Unlike [`set_direction`], this doesn't reset the
+    /// animation: the number of repetitions already run is kept, and the current
+    /// repetition finishes after the time that already elapsed in it.
+    ///
+    /// [`Forward`]: AnimationDirection::Forward
+    /// [`Reverse`]: AnimationDirection::Reverse
+    /// [`Alternate`]: AnimationDirection::Alternate
+    /// [`AlternateReverse`]: AnimationDirection::AlternateReverse
+    /// [`set_direction`]: #method.set_direction
+    pub fn reverse(&mut self) {
+        use AnimationDirection::*;
+
+        self.direction = match self.direction {
+            Forward => Reverse,
+            Reverse => Forward,
+            Alternate => AlternateReverse,
+            AlternateReverse => Alternate,
+        };
+        if self.duration > 0.0 {
+            let factor = self.since_start / self.duration;
+            let repeat_count = factor.floor();
+            self.since_start = (repeat_count + 1.0 - factor.fract()) * self.duration;
+        }
+    }
+
     /// Start the animation.
     pub fn start(&mut self, ctx: &mut impl RequestCtx) {
         self.since_start = 0.0;
@@ -196,3 +248,52 @@ impl AnimationController {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::test::TestCtx;
+
+    const FRAME: u64 = 100_000_000;
+
+    #[test]
+    fn seek_and_reverse() {
+        let mut controller = AnimationController::new();
+        controller.start(&mut TestCtx);
+        controller.seek(0.5);
+        controller.update(&mut TestCtx, FRAME);
+        assert!((controller.fraction() - 0.6).abs() < 1e-9);
+
+        controller.reverse();
+        assert!((controller.fraction() - 0.6).abs() < 1e-9);
+        controller.update(&mut TestCtx, FRAME);
+        assert!((controller.fraction() - 0.5).abs() < 1e-9);
+        controller.update(&mut TestCtx, FRAME);
+        assert!((controller.fraction() - 0.4).abs() < 1e-9);
+
+        // runs out at the start
+        controller.update(&mut TestCtx, 10 * FRAME);
+        assert_eq!(controller.fraction(), 0.0);
+        assert_eq!(controller.status(), AnimationStatus::Retiring);
+    }
+
+    #[test]
+    fn reverse_alternating() {
+        let mut controller = AnimationController::new()
+            .direction(AnimationDirection::Alternate)
+            .repeat_limit(Some(2));
+        controller.start(&mut TestCtx);
+        // in the second repetition, going back
+        controller.update(&mut TestCtx, 13 * FRAME);
+        assert!((controller.fraction() - 0.7).abs() < 1e-9);
+
+        controller.reverse();
+        controller.update(&mut TestCtx, FRAME);
+        assert!((controller.fraction() - 0.8).abs() < 1e-9);
+
+        controller.seek(0.25);
+        controller.update(&mut TestCtx, FRAME);
+        assert!((controller.fraction() - 0.35).abs() < 1e-9);
+        assert_eq!(controller.status(), AnimationStatus::Running);
+    }
+}
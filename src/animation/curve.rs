@@ -152,24 +152,60 @@ impl CubicBezierAnimationCurve {
         3.0 * a * (1.0 - m) * (1.0 - m) * m + 3.0 * b * (1.0 - m) * m * m + m * m * m
     }
 
-    /// Returns the value of the curve at point `t`.
-    pub fn translate(&self, t: f64) -> f64 {
-        let mut start = 0.0;
-        let mut end = 1.0;
+    fn evaluate_cubic_derivative(a: f64, b: f64, m: f64) -> f64 {
+        3.0 * a * (1.0 - m) * (1.0 - m) + 6.0 * (b - a) * (1.0 - m) * m + 3.0 * (1.0 - b) * m * m
+    }
+
+    /// Finds the parameter of the point of the curve whose x coordinate is `t`.
+    ///
+    /// Like CSS, this uses Newton-Raphson iterations, and falls back to bisection
+    /// where the slope is too flat for them to converge.
+    fn solve_parameter(&self, t: f64) -> f64 {
+        const NEWTON_ITERATIONS: usize = 8;
+        const BISECTION_ITERATIONS: usize = 64;
+        const EPSILON: f64 = 1e-7;
 
-        const CUBIC_ERROR_BOUND: f64 = 0.001;
+        let mut m = t;
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = Self::evaluate_cubic(self.x1, self.x2, m) - t;
+            if error.abs() < EPSILON {
+                return m;
+            }
+            let slope = Self::evaluate_cubic_derivative(self.x1, self.x2, m);
+            if slope.abs() < EPSILON {
+                break;
+            }
+            m -= error / slope;
+        }
 
-        loop {
-            let midpoint = (start + end) / 2.0;
-            let estimate = Self::evaluate_cubic(self.x1, self.x2, midpoint);
-            if (t - estimate).abs() < CUBIC_ERROR_BOUND {
-                return Self::evaluate_cubic(self.y1, self.y2, midpoint);
+        let mut start = 0.0;
+        let mut end = 1.0;
+        m = t;
+        for _ in 0..BISECTION_ITERATIONS {
+            let estimate = Self::evaluate_cubic(self.x1, self.x2, m);
+            if (estimate - t).abs() < EPSILON {
+                break;
             }
             if estimate < t {
-                start = midpoint;
+                start = m;
             } else {
-                end = midpoint;
+                end = m;
             }
+            m = (start + end) / 2.0;
+        }
+        m
+    }
+
+    /// Returns the value of the curve at point `t`.
+    ///
+    /// The curve starts exactly at 0.0 and ends exactly at 1.0.
+    pub fn translate(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else {
+            Self::evaluate_cubic(self.y1, self.y2, self.solve_parameter(t))
         }
     }
 }
@@ -229,3 +265,43 @@ fn combine_in_out_rev(f: fn(f64) -> f64, t: f64) -> f64 {
         0.5 * f(t * 2.0 - 1.0) + 0.5
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoints() {
+        let curves = [
+            AnimationCurve::LINEAR,
+            AnimationCurve::EASE_IN,
+            AnimationCurve::EASE_OUT,
+            AnimationCurve::EASE_IN_OUT,
+            AnimationCurve::EASE_OUT_ELASTIC,
+            AnimationCurve::BOUNCE_OUT,
+            AnimationCurve::EASE_IN_BACK,
+            AnimationCurve::EASE_OUT_BACK,
+            AnimationCurve::EASE_IN_OUT_BACK,
+            AnimationCurve::cubic(0.25, 0.1, 0.25, 1.0),
+        ];
+        for curve in &curves {
+            assert_eq!(curve.translate(0.0), 0.0, "{:?}", curve);
+            assert_eq!(curve.translate(1.0), 1.0, "{:?}", curve);
+            assert!(curve.translate(0.5).is_finite());
+        }
+    }
+
+    #[test]
+    fn cubic_matches_css() {
+        // css `ease`
+        let ease = AnimationCurve::cubic(0.25, 0.1, 0.25, 1.0);
+        assert!((ease.translate(0.25) - 0.4085).abs() < 1e-3);
+        assert!((ease.translate(0.5) - 0.8024).abs() < 1e-3);
+        // a symmetric curve goes through the center
+        let ease_in_out = AnimationCurve::cubic(0.42, 0.0, 0.58, 1.0);
+        assert!((ease_in_out.translate(0.5) - 0.5).abs() < 1e-6);
+        // steep control points, where the slope is almost flat at the start
+        let steep = AnimationCurve::cubic(1.0, 0.0, 1.0, 0.0);
+        assert!(steep.translate(0.5) < 0.5);
+    }
+}
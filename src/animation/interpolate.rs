@@ -18,21 +18,42 @@ impl Interpolate for f64 {
     }
 }
 
-//TODO: make this more efficient
+/// Colors are interpolated in linear RGB, as interpolating the gamma-encoded sRGB
+/// components darkens the colors in between. Alpha is interpolated linearly.
 impl Interpolate for Color {
     fn interpolate(&self, other: &Self, value: f64) -> Self {
         let (r1, g1, b1, a1) = self.as_rgba();
         let (r2, g2, b2, a2) = other.as_rgba();
+        let lerp = |c1: f64, c2: f64| {
+            let linear = srgb_to_linear(c1).interpolate(&srgb_to_linear(c2), value);
+            linear_to_srgb(super::clamp_fraction(linear))
+        };
 
         Color::rgba(
-            r1.interpolate(&r2, value),
-            g1.interpolate(&g2, value),
-            b1.interpolate(&b2, value),
+            lerp(r1, r2),
+            lerp(g1, g2),
+            lerp(b1, b2),
             a1.interpolate(&a2, value),
         )
     }
 }
 
+fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 impl Interpolate for Vec2 {
     fn interpolate(&self, other: &Self, value: f64) -> Self {
         Vec2::new(
@@ -144,3 +165,68 @@ impl<
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::test::TestCtx;
+    use crate::animation::{Animated, AnimationCurve};
+
+    #[test]
+    fn color_midpoint_is_linear() {
+        let mid = Color::BLACK.interpolate(&Color::WHITE, 0.5);
+        // half the light intensity, rather than the sRGB value 128
+        assert_eq!(mid.as_rgba8(), (188, 188, 188, 255));
+
+        assert_eq!(Color::RED.interpolate(&Color::BLUE, 0.0), Color::RED);
+        assert_eq!(Color::RED.interpolate(&Color::BLUE, 1.0), Color::BLUE);
+        let transparent = Color::RED.with_alpha(0.0);
+        assert_eq!(Color::RED.interpolate(&transparent, 0.5).as_rgba8().3, 128);
+    }
+
+    #[test]
+    fn animated_background_color() {
+        let mut background = Animated::new(Color::BLACK)
+            .duration(0.2)
+            .curve(AnimationCurve::LINEAR);
+        background.animate(&mut TestCtx, Color::WHITE);
+        assert_eq!(background.get(), Color::BLACK);
+
+        background.update(&mut TestCtx, 100_000_000);
+        assert_eq!(background.get().as_rgba8(), (188, 188, 188, 255));
+        background.update(&mut TestCtx, 100_000_000);
+        assert_eq!(background.get(), Color::WHITE);
+        assert!(!background.animating());
+    }
+
+    #[test]
+    fn geometry_endpoints() {
+        fn check<T: Interpolate + std::fmt::Debug>(start: T, end: T, mid: T) {
+            assert_eq!(start.interpolate(&end, 0.0), start);
+            assert_eq!(start.interpolate(&end, 0.5), mid);
+            assert_eq!(start.interpolate(&end, 1.0), end);
+        }
+
+        check(
+            Point::new(0.0, 10.0),
+            Point::new(20.0, 30.0),
+            Point::new(10.0, 20.0),
+        );
+        check(
+            Size::new(10.0, 0.0),
+            Size::new(30.0, 40.0),
+            Size::new(20.0, 20.0),
+        );
+        // the origin and the size are interpolated, rather than the corners
+        check(
+            Rect::new(0.0, 0.0, 10.0, 10.0),
+            Rect::new(20.0, 40.0, 50.0, 60.0),
+            Rect::new(10.0, 20.0, 30.0, 35.0),
+        );
+        check(
+            Insets::new(0.0, 2.0, 4.0, 6.0),
+            Insets::uniform(10.0),
+            Insets::new(5.0, 6.0, 7.0, 8.0),
+        );
+    }
+}
@@ -12,7 +12,7 @@ mod interpolate;
 mod state;
 mod storage;
 #[cfg(test)]
-mod test;
+pub(crate) mod test;
 
 pub use animated_value::Animated;
 pub use animator::Animator;
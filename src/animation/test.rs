@@ -2,6 +2,28 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use super::*;
+use crate::RequestCtx;
+use druid::{Data, Env, Rect, Widget, WindowConfig, WindowId};
+
+/// A context ignoring all requests.
+pub(crate) struct TestCtx;
+
+impl RequestCtx for TestCtx {
+    fn request_paint(&mut self) {}
+    fn request_paint_rect(&mut self, _rect: Rect) {}
+    fn request_layout(&mut self) {}
+    fn request_anim_frame(&mut self) {}
+    fn children_changed(&mut self) {}
+    fn new_sub_window<W: Widget<U> + 'static, U: Data>(
+        &mut self,
+        _window_config: WindowConfig,
+        _widget: W,
+        _data: U,
+        _env: Env,
+    ) -> WindowId {
+        unimplemented!()
+    }
+}
 
 #[test]
 fn test_animator() {
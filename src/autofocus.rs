@@ -6,8 +6,29 @@ use druid::{widget::Controller, Command, Selector};
 
 const TAKE_FOCUS: Selector<()> = Selector::new("auto_focus.take_focus");
 
+type FocusWhenFn<T> = Box<dyn Fn(&T) -> bool>;
+
+/// A controller giving focus to its widget when it is added.
+///
+/// With [`AutoFocus::when`] or [`AutoFocus::to_child`], the focus is also taken each
+/// time a predicate on the data becomes true, or given to a descendant, see
+/// [`ConditionalAutoFocus`].
 pub struct AutoFocus;
 
+impl AutoFocus {
+    /// Create a controller focusing its widget when it is added, and again whenever
+    /// `when` becomes true, for example to focus a search box each time a panel opens.
+    pub fn when<T>(when: impl Fn(&T) -> bool + 'static) -> ConditionalAutoFocus<T> {
+        ConditionalAutoFocus::new().when(when)
+    }
+
+    /// Create a controller giving the focus to the descendant with the id `target`
+    /// when the widget is added.
+    pub fn to_child<T>(target: WidgetId) -> ConditionalAutoFocus<T> {
+        ConditionalAutoFocus::new().to_child(target)
+    }
+}
+
 impl<W: Widget<T>, T> Controller<T, W> for AutoFocus {
     fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
         if let Event::Command(cmd) = event {
@@ -38,3 +59,99 @@ impl<W: Widget<T>, T> Controller<T, W> for AutoFocus {
         child.lifecycle(ctx, event, data, env)
     }
 }
+
+/// An [`AutoFocus`] taking the focus again when a predicate on the data becomes true,
+/// or giving it to a descendant, created with [`AutoFocus::when`] or
+/// [`AutoFocus::to_child`].
+pub struct ConditionalAutoFocus<T> {
+    when: Option<FocusWhenFn<T>>,
+    target: Option<WidgetId>,
+}
+
+impl<T> ConditionalAutoFocus<T> {
+    fn new() -> Self {
+        ConditionalAutoFocus {
+            when: None,
+            target: None,
+        }
+    }
+
+    /// Builder-style method to take the focus again whenever `when` becomes true.
+    pub fn when(mut self, when: impl Fn(&T) -> bool + 'static) -> Self {
+        self.when = Some(Box::new(when));
+        self
+    }
+
+    /// Builder-style method to give the focus to the descendant with the id `target`,
+    /// rather than to the wrapped widget.
+    pub fn to_child(mut self, target: WidgetId) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Returns whether the focus is taken again, as the predicate became true.
+    fn refocus(&self, old_data: &T, data: &T) -> bool {
+        matches!(&self.when, Some(when) if !when(old_data) && when(data))
+    }
+}
+
+impl<T, W: Widget<T>> Controller<T, W> for ConditionalAutoFocus<T> {
+    fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::Command(cmd) = event {
+            if cmd.is(TAKE_FOCUS) {
+                match self.target {
+                    Some(target) => ctx.set_focus(target),
+                    None => ctx.request_focus(),
+                }
+            }
+        }
+
+        child.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(
+        &mut self,
+        child: &mut W,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &T,
+        env: &Env,
+    ) {
+        match event {
+            LifeCycle::BuildFocusChain if self.target.is_none() => ctx.register_for_focus(),
+            LifeCycle::WidgetAdded => {
+                ctx.submit_command(Command::new(TAKE_FOCUS, (), ctx.widget_id()))
+            }
+            _ => (),
+        }
+
+        child.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, child: &mut W, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if self.refocus(old_data, data) {
+            ctx.submit_command(Command::new(TAKE_FOCUS, (), ctx.widget_id()));
+        }
+
+        child.update(ctx, old_data, data, env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refocus_when_opened() {
+        let focus = AutoFocus::when(|open: &bool| *open);
+
+        assert!(focus.refocus(&false, &true));
+        assert!(!focus.refocus(&true, &true));
+        assert!(!focus.refocus(&true, &false));
+        // closed and opened again
+        assert!(focus.refocus(&false, &true));
+
+        // without a predicate, the focus is only taken when added
+        assert!(!AutoFocus::to_child::<bool>(WidgetId::next()).refocus(&false, &true));
+    }
+}
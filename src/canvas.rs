@@ -2,22 +2,38 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 //! A widget that allows for arbitrary layout of it's children.
-use druid::kurbo::Rect;
+use druid::kurbo::{Affine, Rect};
 use druid::{
-    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, PaintCtx,
-    Point, Size, UpdateCtx, Widget, WidgetPod,
+    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, Lens, LifeCycle, LifeCycleCtx,
+    MouseButton, MouseEvent, PaintCtx, Point, RenderContext, Size, UpdateCtx, ViewContext, Widget,
+    WidgetPod,
 };
 
+use crate::dyn_lens::DynLens;
+
+/// How much a step of the mouse wheel zooms the canvas.
+const ZOOM_SPEED: f64 = 0.002;
+
 ///A container that allows for arbitrary layout.
 ///
 ///This widget allows you to lay widgets out at any point, and to allow that positioning to be dependent on the data.
 ///This is facilitated by the [`CanvasLayout`] trait, and will most typically be used by wrapping your desired widgets
 ///in a [`CanvasWrap`] wrapper.
 ///
+///
+///With [`with_transform`], the content can be panned and zoomed by an affine transform
+///stored in the data.
+///
 ///[`CanvasLayout`]: trait.CanvasLayout.html
 ///[`CanvasWrap`]: struct.CanvasWrap.html
+///[`with_transform`]: Canvas::with_transform
 pub struct Canvas<T: Data> {
     children: Vec<(Rect, Box<dyn CanvasLayout<T>>)>,
+    transform: Option<Box<dyn DynLens<T, Affine>>>,
+    wheel_zoom: bool,
+    drag_pan: bool,
+    /// The last mouse position while panning with the middle button.
+    pan_pos: Option<Point>,
 }
 
 impl<T: Data> Default for Canvas<T> {
@@ -28,7 +44,97 @@ impl<T: Data> Default for Canvas<T> {
 
 impl<T: Data> Canvas<T> {
     pub fn new() -> Self {
-        Self { children: vec![] }
+        Self {
+            children: vec![],
+            transform: None,
+            wheel_zoom: false,
+            drag_pan: false,
+            pan_pos: None,
+        }
+    }
+
+    /// Builder-style method to transform the content of the canvas by the affine
+    /// transform focused by `transform`.
+    ///
+    /// The transform maps the coordinate space of the children to the one of the canvas,
+    /// and mouse events are mapped back through its inverse. While the content is
+    /// transformed, the whole canvas is repainted whenever the children may have
+    /// requested a paint.
+    pub fn with_transform(mut self, transform: impl Lens<T, Affine> + 'static) -> Self {
+        self.transform = Some(Box::new(transform));
+        self
+    }
+
+    /// Builder-style method to zoom the content around the cursor with the mouse wheel.
+    ///
+    /// This has no effect without [`with_transform`](Canvas::with_transform).
+    pub fn wheel_zoom(mut self, wheel_zoom: bool) -> Self {
+        self.wheel_zoom = wheel_zoom;
+        self
+    }
+
+    /// Builder-style method to pan the content by dragging with the middle mouse button.
+    ///
+    /// This has no effect without [`with_transform`](Canvas::with_transform).
+    pub fn middle_drag_pan(mut self, drag_pan: bool) -> Self {
+        self.drag_pan = drag_pan;
+        self
+    }
+
+    /// Returns the transform of the content, or the identity without a transform lens.
+    fn transform(&self, data: &T) -> Affine {
+        match &self.transform {
+            Some(transform) => transform.with(data, |transform| *transform),
+            None => Affine::IDENTITY,
+        }
+    }
+
+    /// Returns true if the content is transformed by something other than the identity.
+    ///
+    /// The children invalidate rects in their own coordinate space, which don't match
+    /// the painted area anymore, so the whole canvas is repainted instead.
+    fn is_transformed(&self, data: &T) -> bool {
+        !self.transform(data).same(&Affine::IDENTITY)
+    }
+
+    /// Replaces the transform of the content with `f` applied to it.
+    fn update_transform(&self, ctx: &mut EventCtx, data: &mut T, f: impl FnOnce(Affine) -> Affine) {
+        if let Some(transform) = &self.transform {
+            transform.with_mut(data, |transform| *transform = f(*transform));
+            ctx.request_paint();
+        }
+    }
+
+    /// Zoom or pan the content, for the events which the children didn't handle.
+    fn pan_and_zoom(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T) {
+        match event {
+            Event::Wheel(mouse) if self.wheel_zoom && !ctx.is_handled() => {
+                let factor = (-mouse.wheel_delta.y * ZOOM_SPEED).exp();
+                self.update_transform(ctx, data, |t| zoom_at(t, mouse.pos, factor));
+                ctx.set_handled();
+            }
+            Event::MouseDown(mouse)
+                if self.drag_pan && mouse.button == MouseButton::Middle && !ctx.is_handled() =>
+            {
+                self.pan_pos = Some(mouse.pos);
+                ctx.set_active(true);
+                ctx.set_handled();
+            }
+            Event::MouseMove(mouse) => {
+                if let Some(pan_pos) = self.pan_pos {
+                    let delta = mouse.pos - pan_pos;
+                    self.update_transform(ctx, data, |t| Affine::translate(delta) * t);
+                    self.pan_pos = Some(mouse.pos);
+                }
+            }
+            Event::MouseUp(mouse)
+                if mouse.button == MouseButton::Middle && self.pan_pos.is_some() =>
+            {
+                self.pan_pos = None;
+                ctx.set_active(false);
+            }
+            _ => (),
+        }
     }
     pub fn with_child(mut self, child: impl CanvasLayout<T> + 'static) -> Self {
         self.children.push((Rect::ZERO, Box::new(child)));
@@ -39,20 +145,69 @@ impl<T: Data> Canvas<T> {
         self.children.push((Rect::ZERO, Box::new(child)));
         ctx.children_changed();
     }
+
+    /// Returns the index of the topmost child whose layout rect contains `pos`, in the
+    /// coordinate space of the canvas.
+    ///
+    /// `pos` is mapped through the inverse of the transform of the content, as mouse
+    /// events are. Children are painted in order, so later children are on top of
+    /// earlier ones.
+    pub fn child_at(&self, data: &T, pos: Point) -> Option<usize> {
+        let pos = self.transform(data).inverse() * pos;
+        self.children
+            .iter()
+            .rposition(|(rect, _)| rect.contains(pos))
+    }
+
+    /// Moves the child at `index` on top of the other children.
+    ///
+    /// The indices of the children after `index` are shifted down by one.
+    pub fn raise_child(&mut self, ctx: &mut EventCtx, index: usize) {
+        if index < self.children.len() {
+            let child = self.children.remove(index);
+            self.children.push(child);
+            ctx.request_paint();
+        }
+    }
+
+    /// Moves the child at `index` below the other children.
+    ///
+    /// The indices of the children before `index` are shifted up by one.
+    pub fn lower_child(&mut self, ctx: &mut EventCtx, index: usize) {
+        if index < self.children.len() {
+            let child = self.children.remove(index);
+            self.children.insert(0, child);
+            ctx.request_paint();
+        }
+    }
 }
 
 impl<T: Data> Widget<T> for Canvas<T> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
         //we're letting their own filtering handle event filtering
         //we may want to revisit that decision
-        for (_, child) in &mut self.children {
-            child.event(ctx, event, data, env);
+        let inverse = self.transform(data).inverse();
+        let child_event = transform_mouse(event, inverse);
+        // the children on top get the events first, like they get the clicks
+        for (_, child) in self.children.iter_mut().rev() {
+            if ctx.is_handled() {
+                break;
+            }
+            child.event(ctx, &child_event, data, env);
         }
+        if self.is_transformed(data) {
+            ctx.request_paint();
+        }
+        self.pan_and_zoom(ctx, event, data);
     }
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        let child_event = transform_view_context(event, self.transform(data).inverse());
         for (_, child) in &mut self.children {
-            child.lifecycle(ctx, event, data, env);
+            child.lifecycle(ctx, &child_event, data, env);
+        }
+        if self.is_transformed(data) {
+            ctx.request_paint();
         }
     }
 
@@ -60,6 +215,9 @@ impl<T: Data> Widget<T> for Canvas<T> {
         for (_, child) in &mut self.children {
             child.update(ctx, old_data, data, env);
         }
+        if !self.transform(old_data).same(&self.transform(data)) || self.is_transformed(data) {
+            ctx.request_paint();
+        }
     }
 
     fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
@@ -82,9 +240,57 @@ impl<T: Data> Widget<T> for Canvas<T> {
     fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
         //TODO: filter painting based on our extents? (don't draw widgets entirely outside our bounds?)
         //It's the main reason we keep and update the rect
-        for (_, child) in &mut self.children {
-            child.paint(ctx, data, env);
-        }
+        let transform = self.transform(data);
+        // the region to paint in the coordinate space of the children
+        let region = transform
+            .inverse()
+            .transform_rect_bbox(ctx.region().bounding_box());
+        let children = &mut self.children;
+        ctx.with_save(|ctx| {
+            ctx.transform(transform);
+            ctx.with_child_ctx(region, |ctx| {
+                for (_, child) in children {
+                    child.paint(ctx, data, env);
+                }
+            });
+        });
+    }
+}
+
+/// Returns `transform` zoomed by `factor`, keeping the point at `pos` in place.
+fn zoom_at(transform: Affine, pos: Point, factor: f64) -> Affine {
+    Affine::translate(pos.to_vec2())
+        * Affine::scale(factor)
+        * Affine::translate(-pos.to_vec2())
+        * transform
+}
+
+/// Returns the event with the positions of mouse events mapped by `transform`.
+fn transform_mouse(event: &Event, transform: Affine) -> Event {
+    let map = |mouse: &MouseEvent| {
+        let mut mouse = mouse.clone();
+        mouse.pos = transform * mouse.pos;
+        mouse
+    };
+    match event {
+        Event::MouseDown(mouse) => Event::MouseDown(map(mouse)),
+        Event::MouseUp(mouse) => Event::MouseUp(map(mouse)),
+        Event::MouseMove(mouse) => Event::MouseMove(map(mouse)),
+        Event::Wheel(mouse) => Event::Wheel(map(mouse)),
+        _ => event.clone(),
+    }
+}
+
+/// Returns the event with the view context mapped by `transform`, the mouse position
+/// and the clip of the children being in the coordinate space of the content.
+fn transform_view_context(event: &LifeCycle, transform: Affine) -> LifeCycle {
+    match event {
+        LifeCycle::ViewContextChanged(view) => LifeCycle::ViewContextChanged(ViewContext {
+            last_mouse_position: view.last_mouse_position.map(|pos| transform * pos),
+            clip: transform.transform_rect_bbox(view.clip),
+            ..*view
+        }),
+        _ => event.clone(),
     }
 }
 
@@ -147,3 +353,143 @@ impl<W: Widget<T>, T: Data, F: Fn(&T) -> Point> Widget<T> for CanvasWrap<W, T, F
 pub trait CanvasLayout<T: Data>: Widget<T> {
     fn canvas_layout(&mut self, ctx: &mut LayoutCtx, data: &T, env: &Env) -> (Point, Size);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::ModularWidget;
+    use druid::widget::SizedBox;
+    use druid::Selector;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn mouse_down(pos: Point) -> Event {
+        Event::MouseDown(MouseEvent {
+            pos,
+            window_pos: pos,
+            buttons: Default::default(),
+            mods: Default::default(),
+            count: 1,
+            focus: false,
+            button: MouseButton::Left,
+            wheel_delta: Default::default(),
+        })
+    }
+
+    #[test]
+    fn child_at_respects_paint_order() {
+        let mut canvas = Canvas::<()>::new();
+        for origin in [(0.0, 0.0), (10.0, 10.0), (20.0, 20.0)] {
+            canvas = canvas.with_child(CanvasWrap::new(SizedBox::empty(), move |_| {
+                Point::from(origin)
+            }));
+            let rect = Rect::from_origin_size(origin, (30.0, 30.0));
+            canvas.children.last_mut().unwrap().0 = rect;
+        }
+
+        assert_eq!(canvas.child_at(&(), Point::new(5.0, 5.0)), Some(0));
+        assert_eq!(canvas.child_at(&(), Point::new(15.0, 15.0)), Some(1));
+        assert_eq!(canvas.child_at(&(), Point::new(25.0, 25.0)), Some(2));
+        assert_eq!(canvas.child_at(&(), Point::new(45.0, 45.0)), Some(2));
+        assert_eq!(canvas.child_at(&(), Point::new(55.0, 55.0)), None);
+    }
+
+    #[test]
+    fn events_reach_top_child_first() {
+        let clicked = Rc::new(RefCell::new(Vec::new()));
+        let child = |name: &'static str, origin: Point| {
+            let clicked = clicked.clone();
+            let widget = ModularWidget::new(())
+                .event_fn(move |_, ctx, event, _, _| {
+                    if let Event::MouseDown(_) = event {
+                        clicked.borrow_mut().push(name);
+                        ctx.set_handled();
+                    }
+                })
+                .layout_fn(|_, _, _, _, _| Size::new(30.0, 30.0));
+            CanvasWrap::new(widget, move |_| origin)
+        };
+        let canvas = Canvas::<()>::new()
+            .with_child(child("bottom", Point::ZERO))
+            .with_child(child("top", Point::new(10.0, 10.0)));
+
+        Harness::create_simple((), canvas, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+
+            // where the children overlap, only the top one gets the click
+            harness.event(mouse_down(Point::new(20.0, 20.0)));
+            assert_eq!(*clicked.borrow(), ["top"]);
+
+            harness.event(mouse_down(Point::new(5.0, 5.0)));
+            assert_eq!(*clicked.borrow(), ["top", "bottom"]);
+        });
+    }
+
+    #[test]
+    fn transformed_content() {
+        let mut canvas = Canvas::<Affine>::new().with_transform(druid::lens::Identity);
+        canvas = canvas.with_child(CanvasWrap::new(SizedBox::empty(), |_| {
+            Point::new(10.0, 10.0)
+        }));
+        canvas.children[0].0 = Rect::from_origin_size((10.0, 10.0), (30.0, 30.0));
+        let transform = Affine::scale(2.0);
+
+        // the child is painted from (20, 20) to (80, 80)
+        assert_eq!(canvas.child_at(&transform, Point::new(70.0, 70.0)), Some(0));
+        assert_eq!(canvas.child_at(&transform, Point::new(15.0, 15.0)), None);
+        assert_eq!(canvas.child_at(&transform, Point::new(90.0, 90.0)), None);
+        assert_eq!(
+            canvas.child_at(&Affine::IDENTITY, Point::new(15.0, 15.0)),
+            Some(0)
+        );
+
+        // mouse events are mapped back into the coordinate space of the children
+        match transform_mouse(&mouse_down(Point::new(70.0, 70.0)), transform.inverse()) {
+            Event::MouseDown(mouse) => assert_eq!(mouse.pos, Point::new(35.0, 35.0)),
+            _ => unreachable!(),
+        }
+
+        // zooming keeps the point under the cursor in place
+        let pos = Point::new(50.0, 30.0);
+        let zoomed = zoom_at(transform, pos, 1.5);
+        assert_eq!(zoomed * (transform.inverse() * pos), pos);
+    }
+
+    #[test]
+    fn child_repaint_under_transform() {
+        const REPAINT: Selector = Selector::new("druid-widget-nursery.test.repaint");
+
+        let painted_at = Rc::new(RefCell::new(Vec::new()));
+        let child = ModularWidget::new(painted_at.clone())
+            .event_fn(|_, ctx, event, _, _| {
+                if matches!(event, Event::Command(cmd) if cmd.is(REPAINT)) {
+                    ctx.request_paint();
+                }
+            })
+            .layout_fn(|_, _, _, _, _| Size::new(20.0, 20.0))
+            .paint_fn(|painted_at, ctx, _, _| {
+                painted_at.borrow_mut().push(ctx.current_transform());
+            });
+        let canvas = Canvas::new()
+            .with_transform(druid::lens::Identity)
+            .with_child(CanvasWrap::new(child, |_| Point::new(100.0, 100.0)));
+
+        Harness::create_simple(Affine::scale(2.0), canvas, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            harness.paint_invalid();
+            painted_at.borrow_mut().clear();
+
+            // the child invalidates its rect before the transform, from (100, 100) to
+            // (120, 120), while it is painted from (200, 200) to (240, 240)
+            harness.submit_command(REPAINT);
+            harness.paint_invalid();
+            assert_eq!(
+                *painted_at.borrow(),
+                [Affine::scale(2.0) * Affine::translate((100.0, 100.0))]
+            );
+        });
+    }
+}
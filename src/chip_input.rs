@@ -0,0 +1,242 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A text input turning what the user typed into removable chips.
+
+use druid::im::Vector;
+use druid::keyboard_types::Key;
+use druid::text::TextComponent;
+use druid::widget::prelude::*;
+use druid::widget::{CrossAxisAlignment, Flex, Label, TextBox};
+use druid::{theme, Lens, Point, WidgetExt as _, WidgetPod};
+
+use crate::wrap::{Wrap, WrapCrossAlignment};
+use crate::AutoFocus;
+
+const INPUT_WIDTH: f64 = 100.0;
+
+#[derive(Clone, Data, Lens)]
+struct ChipInputState {
+    chips: Vector<String>,
+    input: String,
+}
+
+/// An input for a list of short texts, such as tags or recipients, shown as chips.
+///
+/// The chips are laid out in a [`Wrap`], each with a button to remove it, followed
+/// by a text box for adding new chips. The typed text becomes a chip when the user
+/// presses Enter or types a comma, and Backspace in the empty text box removes the
+/// last chip.
+///
+/// By default, a text which is already a chip is ignored, see
+/// [`with_allow_duplicates`](ChipInput::with_allow_duplicates).
+pub struct ChipInput {
+    inner: WidgetPod<ChipInputState, Wrap<ChipInputState>>,
+    input: String,
+    chip_count: usize,
+    placeholder: String,
+    allow_duplicates: bool,
+}
+
+impl ChipInput {
+    pub fn new() -> Self {
+        ChipInput {
+            inner: WidgetPod::new(Wrap::new()),
+            input: String::new(),
+            chip_count: 0,
+            placeholder: String::new(),
+            allow_duplicates: false,
+        }
+    }
+
+    /// Builder-style method for setting the placeholder of the text box.
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Builder-style method for setting whether the same text can be added twice.
+    pub fn with_allow_duplicates(mut self, allow_duplicates: bool) -> Self {
+        self.set_allow_duplicates(allow_duplicates);
+        self
+    }
+
+    /// Set whether the same text can be added twice.
+    pub fn set_allow_duplicates(&mut self, allow_duplicates: bool) {
+        self.allow_duplicates = allow_duplicates;
+    }
+
+    fn state(&self, chips: &Vector<String>) -> ChipInputState {
+        ChipInputState {
+            chips: chips.clone(),
+            input: self.input.clone(),
+        }
+    }
+
+    /// Rebuilds the chips. The text box takes the focus unless this is the first build.
+    fn rebuild(&mut self, chip_count: usize, focus: bool) {
+        let mut wrap = Wrap::new()
+            .spacing(4.0)
+            .run_spacing(4.0)
+            .cross_alignment(WrapCrossAlignment::Center);
+        for index in 0..chip_count {
+            wrap.add_child(Box::new(chip(index)));
+        }
+
+        let mut textbox = TextBox::new().with_placeholder(self.placeholder.clone());
+        textbox.text_mut().borrow_mut().send_notification_on_return = true;
+        let textbox = textbox.fix_width(INPUT_WIDTH).lens(ChipInputState::input);
+        if focus {
+            wrap.add_child(Box::new(textbox.controller(AutoFocus)));
+        } else {
+            wrap.add_child(Box::new(textbox));
+        }
+
+        self.inner = WidgetPod::new(wrap);
+        self.chip_count = chip_count;
+    }
+}
+
+impl Default for ChipInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn chip(index: usize) -> impl Widget<ChipInputState> {
+    let text = Label::dynamic(move |state: &ChipInputState, _| {
+        state.chips.get(index).cloned().unwrap_or_default()
+    });
+    let remove = Label::new("\u{00d7}").on_click(move |_, state: &mut ChipInputState, _| {
+        if index < state.chips.len() {
+            state.chips.remove(index);
+        }
+    });
+    Flex::row()
+        .cross_axis_alignment(CrossAxisAlignment::Center)
+        .with_child(text)
+        .with_spacer(2.0)
+        .with_child(remove)
+        .padding((8.0, 2.0, 4.0, 2.0))
+        .background(theme::BACKGROUND_LIGHT)
+        .border(theme::BORDER_LIGHT, 1.0)
+        .rounded(10.0)
+}
+
+/// Adds the comma separated texts in `input` to `chips`, skipping empty texts
+/// and, unless `allow_duplicates` is set, texts which are already chips.
+fn add_chips(chips: &mut Vector<String>, input: &str, allow_duplicates: bool) {
+    for text in input.split(',').map(str::trim) {
+        if !text.is_empty() && (allow_duplicates || !chips.iter().any(|chip| chip == text)) {
+            chips.push_back(text.to_string());
+        }
+    }
+}
+
+impl Widget<Vector<String>> for ChipInput {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut Vector<String>, env: &Env) {
+        let mut state = self.state(data);
+        match event {
+            Event::Notification(notification) => {
+                if notification.is(TextComponent::RETURN) {
+                    add_chips(&mut state.chips, &state.input, self.allow_duplicates);
+                    state.input.clear();
+                    ctx.set_handled();
+                }
+            }
+            Event::KeyDown(key) if key.key == Key::Backspace && state.input.is_empty() => {
+                if ctx.has_focus() && state.chips.pop_back().is_some() {
+                    ctx.set_handled();
+                } else {
+                    self.inner.event(ctx, event, &mut state, env);
+                }
+            }
+            _ => self.inner.event(ctx, event, &mut state, env),
+        }
+
+        // a comma commits everything typed before it
+        if let Some(comma) = state.input.rfind(',') {
+            add_chips(
+                &mut state.chips,
+                &state.input[..comma],
+                self.allow_duplicates,
+            );
+            state.input = state.input[comma + 1..].trim_start().to_string();
+        }
+
+        if !state.input.same(&self.input) {
+            self.input = state.input;
+            ctx.request_update();
+        }
+        if !state.chips.same(data) {
+            *data = state.chips;
+        }
+    }
+
+    fn lifecycle(
+        &mut self,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &Vector<String>,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            self.rebuild(data.len(), false);
+        }
+        let state = self.state(data);
+        self.inner.lifecycle(ctx, event, &state, env);
+    }
+
+    fn update(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _old_data: &Vector<String>,
+        data: &Vector<String>,
+        env: &Env,
+    ) {
+        if data.len() != self.chip_count {
+            self.rebuild(data.len(), true);
+            ctx.children_changed();
+            return;
+        }
+        let state = self.state(data);
+        self.inner.update(ctx, &state, env);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &Vector<String>,
+        env: &Env,
+    ) -> Size {
+        let state = self.state(data);
+        let size = self.inner.layout(ctx, bc, &state, env);
+        self.inner.set_origin(ctx, Point::ORIGIN);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &Vector<String>, env: &Env) {
+        let state = self.state(data);
+        self.inner.paint(ctx, &state, env);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adding_chips() {
+        let mut chips = Vector::new();
+        add_chips(&mut chips, "rust, druid,, ", false);
+        assert_eq!(
+            chips,
+            Vector::from(vec!["rust".to_string(), "druid".into()])
+        );
+        add_chips(&mut chips, "druid", false);
+        assert_eq!(chips.len(), 2);
+        add_chips(&mut chips, "druid", true);
+        assert_eq!(chips.len(), 3);
+    }
+}
@@ -6,10 +6,14 @@
 use druid::widget::prelude::*;
 use druid::{Point, Widget, WidgetPod};
 
+type KeyChangedFn<T> = Box<dyn FnMut(&T) -> bool>;
+
 pub struct ComputedWidget<T, U> {
     child: WidgetPod<U, Box<dyn Widget<U>>>,
     data: Option<U>,
     computer: Box<dyn FnMut(&T) -> U>,
+    /// Returns whether the key the computation depends on changed since the last call.
+    key_changed: Option<KeyChangedFn<T>>,
 }
 
 impl<T, U> ComputedWidget<T, U> {
@@ -18,6 +22,34 @@ impl<T, U> ComputedWidget<T, U> {
             child: WidgetPod::new(Box::new(child)),
             data: None,
             computer: Box::new(computer),
+            key_changed: None,
+        }
+    }
+
+    /// Builder-style method to only recompute the data when the value returned by
+    /// `key` changes, instead of on every update.
+    ///
+    /// This is useful when the computation is expensive and only depends on a part of
+    /// the data.
+    pub fn when_changed<K: PartialEq + 'static>(mut self, key: impl Fn(&T) -> K + 'static) -> Self {
+        let mut last_key = None;
+        self.key_changed = Some(Box::new(move |data| {
+            let key = key(data);
+            let changed = last_key.as_ref() != Some(&key);
+            last_key = Some(key);
+            changed
+        }));
+        self
+    }
+
+    /// Computes the data again if it is missing or its key changed.
+    fn recompute(&mut self, data: &T) {
+        let key_changed = match &mut self.key_changed {
+            Some(key_changed) => key_changed(data),
+            None => true,
+        };
+        if key_changed || self.data.is_none() {
+            self.data = Some((self.computer)(data));
         }
     }
 }
@@ -36,14 +68,14 @@ impl<T, U: Data> Widget<T> for ComputedWidget<T, U> {
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
         if let LifeCycle::WidgetAdded = event {
-            self.data = Some((self.computer)(data));
+            self.recompute(data);
         }
         self.child
             .lifecycle(ctx, event, self.data.as_ref().unwrap(), env);
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
-        self.data = Some((self.computer)(data));
+        self.recompute(data);
         self.child.update(ctx, self.data.as_ref().unwrap(), env);
     }
 
@@ -57,3 +89,32 @@ impl<T, U: Data> Widget<T> for ComputedWidget<T, U> {
         self.child.paint(ctx, self.data.as_ref().unwrap(), env);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::widget::Label;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn unrelated_changes_are_not_recomputed() {
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        let mut widget = ComputedWidget::new(Label::dynamic(|s: &String, _| s.clone()), {
+            move |data: &(u32, u32)| {
+                counter.set(counter.get() + 1);
+                data.0.to_string()
+            }
+        })
+        .when_changed(|data: &(u32, u32)| data.0);
+
+        widget.recompute(&(1, 1));
+        assert_eq!(calls.get(), 1);
+        widget.recompute(&(1, 2));
+        assert_eq!(calls.get(), 1);
+        widget.recompute(&(2, 2));
+        assert_eq!(calls.get(), 2);
+        assert_eq!(widget.data.as_deref(), Some("2"));
+    }
+}
@@ -4,6 +4,86 @@
 use crate::multi_value::INDENT;
 use druid::Env;
 
+type ConfigureFn<T> = Box<dyn Fn(&mut Env, &T)>;
+
 pub fn configure_env<T>(env: &mut Env, _: &T) {
     env.set(INDENT, 30.0);
 }
+
+/// Several env configuration functions, applied in the order they were added.
+///
+/// This lets the theme, custom keys and the setup of this crate be registered
+/// independently, and given to [`AppLauncher::configure_env`] at once:
+///
+/// ```
+/// # use druid::{theme, AppLauncher};
+/// use druid_widget_nursery::{configure_env, EnvConfig};
+///
+/// # fn launch(launcher: AppLauncher<()>) {
+/// let config = EnvConfig::new()
+///     .with(configure_env)
+///     .with(|env, _| env.set(theme::TEXT_SIZE_NORMAL, 16.0));
+/// launcher.configure_env(config.build());
+/// # }
+/// ```
+///
+/// [`AppLauncher::configure_env`]: druid::AppLauncher::configure_env
+pub struct EnvConfig<T> {
+    configure: Vec<ConfigureFn<T>>,
+}
+
+impl<T> Default for EnvConfig<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> EnvConfig<T> {
+    /// Create a configuration which doesn't change the env.
+    pub fn new() -> Self {
+        EnvConfig {
+            configure: Vec::new(),
+        }
+    }
+
+    /// Builder-style method to add a configuration function, applied after the previous ones.
+    pub fn with(mut self, configure: impl Fn(&mut Env, &T) + 'static) -> Self {
+        self.configure.push(Box::new(configure));
+        self
+    }
+
+    /// Apply the configuration functions in order.
+    pub fn apply(&self, env: &mut Env, data: &T) {
+        for configure in &self.configure {
+            configure(env, data);
+        }
+    }
+
+    /// Returns a function applying the configuration functions in order.
+    pub fn build(self) -> impl Fn(&mut Env, &T) + 'static
+    where
+        T: 'static,
+    {
+        move |env, data| self.apply(env, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::theme;
+
+    #[test]
+    fn apply_in_order() {
+        let config = EnvConfig::new()
+            .with(configure_env)
+            .with(|env, _| env.set(theme::TEXT_SIZE_NORMAL, 16.0))
+            .with(|env, size: &f64| env.set(theme::TEXT_SIZE_LARGE, *size));
+        let mut env = Env::empty();
+        config.build()(&mut env, &24.0);
+
+        assert_eq!(env.get(INDENT), 30.0);
+        assert_eq!(env.get(theme::TEXT_SIZE_NORMAL), 16.0);
+        assert_eq!(env.get(theme::TEXT_SIZE_LARGE), 24.0);
+    }
+}
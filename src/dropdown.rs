@@ -3,46 +3,75 @@
 
 use druid::commands::CLOSE_WINDOW;
 use druid::widget::prelude::*;
-use druid::widget::Controller;
 use druid::widget::WidgetExt;
-use druid::Target;
-use druid::WindowSizePolicy;
-use druid::{Point, WindowConfig};
+use druid::widget::{Controller, Scroll};
+use druid::{Point, Rect, WindowConfig};
+use druid::{Target, WidgetPod};
+use druid::{Vec2, WindowSizePolicy};
 use druid::{WindowId, WindowLevel};
 
+use crate::on_monitor::screen_bounds;
+
 type DropFn<T> = Box<dyn Fn(&T, &Env) -> Box<dyn Widget<T>>>;
 
 pub struct Dropdown<T> {
     drop: DropFn<T>,
     window: Option<WindowId>,
+    max_height: Option<f64>,
 }
 
 crate::selectors! {
     DROPDOWN_SHOW,
     DROPDOWN_HIDE,
     DROPDOWN_CLOSED,
+    /// Sent to the header when the dropdown was placed, with whether it opened upward
+    /// because it didn't fit below the header.
+    DROPDOWN_PLACED: bool,
 }
 
 impl<T: Data> Dropdown<T> {
     pub fn new<W: 'static + Widget<T>, DW: Widget<T> + 'static>(
         header: W,
         make_drop: impl Fn(&T, &Env) -> DW + 'static,
+    ) -> impl Widget<T> {
+        Self::new_inner(header, make_drop, None)
+    }
+
+    /// Like [`new`], with the dropdown scrolling when it is higher than `max_height`.
+    ///
+    /// [`new`]: Dropdown::new
+    pub fn new_with_max_height<W: 'static + Widget<T>, DW: Widget<T> + 'static>(
+        header: W,
+        make_drop: impl Fn(&T, &Env) -> DW + 'static,
+        max_height: f64,
+    ) -> impl Widget<T> {
+        Self::new_inner(header, make_drop, Some(max_height))
+    }
+
+    fn new_inner<W: 'static + Widget<T>, DW: Widget<T> + 'static>(
+        header: W,
+        make_drop: impl Fn(&T, &Env) -> DW + 'static,
+        max_height: Option<f64>,
     ) -> impl Widget<T> {
         // padding for putting header in separate WidgetPod
         // because notifications from same WidgetPod are not sent
         header.padding(0.).controller(Dropdown {
             drop: Box::new(move |d, e| make_drop(d, e).boxed()),
             window: None,
+            max_height,
         })
     }
 
     fn show_dropdown(&mut self, data: &mut T, env: &Env, ctx: &mut EventCtx) {
-        let widget = (self.drop)(data, env);
-        let mut origin = ctx.to_window(Point::new(0., ctx.size().height));
-
+        let widget = limit_height((self.drop)(data, env), self.max_height);
         let insets = ctx.window().content_insets();
-        origin.x += insets.x0;
-        origin.y += insets.y0;
+        let header = Rect::from_origin_size(ctx.to_window(Point::ORIGIN), ctx.size())
+            + Vec2::new(insets.x0, insets.y0);
+        let origin = Point::new(header.x0, header.y1);
+
+        // the dropdown is positioned relative to the window of the header
+        let window_origin = ctx.window().get_position().to_vec2();
+        let bounds = screen_bounds(ctx.window()) - window_origin;
 
         self.window = Some(
             ctx.new_sub_window(
@@ -54,6 +83,8 @@ impl<T: Data> Dropdown<T> {
                     .show_titlebar(false),
                 widget.controller(DropedCtrl {
                     parent: ctx.widget_id(),
+                    header,
+                    bounds,
                 }),
                 data.clone(),
                 env.clone(),
@@ -63,8 +94,77 @@ impl<T: Data> Dropdown<T> {
     }
 }
 
+/// Limits the height of its child, a [`Scroll`] for long dropdowns.
+struct MaxHeight<T> {
+    child: WidgetPod<T, Box<dyn Widget<T>>>,
+    max_height: f64,
+}
+
+/// Makes `widget` scroll when it is higher than `max_height`, if any.
+pub(crate) fn limit_height<T: Data>(
+    widget: Box<dyn Widget<T>>,
+    max_height: Option<f64>,
+) -> Box<dyn Widget<T>> {
+    match max_height {
+        Some(max_height) => MaxHeight {
+            child: WidgetPod::new(Scroll::new(widget).vertical().boxed()),
+            max_height,
+        }
+        .boxed(),
+        None => widget,
+    }
+}
+
+/// Returns `bc` with a maximum height of at most `max_height`.
+fn max_height_constraints(bc: &BoxConstraints, max_height: f64) -> BoxConstraints {
+    let min = Size::new(bc.min().width, bc.min().height.min(max_height));
+    let max = Size::new(bc.max().width, bc.max().height.min(max_height));
+    BoxConstraints::new(min, max)
+}
+
+impl<T: Data> Widget<T> for MaxHeight<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.child.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.child.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.child.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let bc = max_height_constraints(bc, self.max_height);
+        let size = self.child.layout(ctx, &bc, data, env);
+        self.child.set_origin(ctx, Point::ORIGIN);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.child.paint(ctx, data, env);
+    }
+}
+
 struct DropedCtrl {
     parent: WidgetId,
+    /// The header of the dropdown, relative to the window of the header.
+    header: Rect,
+    /// The screen bounds, relative to the window of the header.
+    bounds: Rect,
+}
+
+/// Returns the origin of a dropdown of `height` opened from `header`: below the header,
+/// or above it when it doesn't fit below within `bounds` and there is more room above.
+fn drop_origin(header: Rect, height: f64, bounds: Rect) -> Point {
+    let below = bounds.y1 - header.y1;
+    let above = header.y0 - bounds.y0;
+    if height > below && above > below {
+        Point::new(header.x0, header.y0 - height)
+    } else {
+        Point::new(header.x0, header.y1)
+    }
 }
 
 impl<T, W: Widget<T>> Controller<T, W> for DropedCtrl {
@@ -74,6 +174,25 @@ impl<T, W: Widget<T>> Controller<T, W> for DropedCtrl {
         }
         child.event(ctx, event, data, env);
     }
+
+    fn lifecycle(
+        &mut self,
+        child: &mut W,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &T,
+        env: &Env,
+    ) {
+        if let LifeCycle::Size(size) = event {
+            let origin = drop_origin(self.header, size.height, self.bounds);
+            let upward = origin.y < self.header.y1;
+            if upward {
+                ctx.window().set_position(origin);
+            }
+            ctx.submit_command(DROPDOWN_PLACED.with(upward).to(self.parent));
+        }
+        child.lifecycle(ctx, event, data, env)
+    }
 }
 
 impl<T: Data, W: Widget<T>> Controller<T, W> for Dropdown<T> {
@@ -87,6 +206,12 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for Dropdown<T> {
                 self.show_dropdown(data, env, ctx);
                 ctx.set_handled();
             }
+            Event::Command(cmd) if cmd.is(DROPDOWN_PLACED) => {
+                // send DROPDOWN_PLACED to header
+                let inner_cmd = cmd.clone().to(Target::Global);
+                child.event(ctx, &Event::Command(inner_cmd), data, env);
+                ctx.set_handled();
+            }
             Event::Command(cmd) if cmd.is(DROPDOWN_CLOSED) => {
                 ctx.set_active(false);
                 self.window = None;
@@ -133,3 +258,47 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for Dropdown<T> {
         child.lifecycle(ctx, event, data, env)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_upward_near_bottom() {
+        let bounds = Rect::new(0.0, 0.0, 800.0, 600.0);
+        let header = |y| Rect::new(10.0, y, 110.0, y + 20.0);
+
+        // room below the header
+        assert_eq!(
+            drop_origin(header(100.0), 200.0, bounds),
+            Point::new(10.0, 120.0)
+        );
+        // near the bottom, the dropdown is above the header
+        let origin = drop_origin(header(500.0), 200.0, bounds);
+        assert_eq!(origin, Point::new(10.0, 300.0));
+        assert!(origin.y + 200.0 <= header(500.0).y0);
+        // it doesn't fit either way, so it stays on the side with more room
+        assert_eq!(
+            drop_origin(header(200.0), 500.0, bounds),
+            Point::new(10.0, 220.0)
+        );
+    }
+
+    #[test]
+    fn clamp_dropdown_height() {
+        // the dropdown window sizes itself to its content
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(f64::INFINITY, f64::INFINITY));
+        let clamped = max_height_constraints(&bc, 300.0);
+        assert_eq!(clamped.max(), Size::new(f64::INFINITY, 300.0));
+        // 100 items of a list scrolled in the dropdown
+        assert_eq!(clamped.constrain((120.0, 100.0 * 24.0)).height, 300.0);
+        assert_eq!(clamped.constrain((120.0, 5.0 * 24.0)).height, 120.0);
+
+        // a fixed size higher than the maximum height
+        let fixed = BoxConstraints::tight(Size::new(200.0, 500.0));
+        assert_eq!(
+            max_height_constraints(&fixed, 300.0).constrain((200.0, 500.0)),
+            Size::new(200.0, 300.0)
+        );
+    }
+}
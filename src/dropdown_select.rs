@@ -3,20 +3,30 @@
 
 //! A simple list selection widget, for selecting a single value out of a list.
 
-use crate::dropdown::{DROPDOWN_CLOSED, DROPDOWN_HIDE, DROPDOWN_SHOW};
-use crate::{AutoFocus, Dropdown, ListSelect, Wedge, WidgetExt as _};
+use crate::dropdown::{
+    limit_height, DROPDOWN_CLOSED, DROPDOWN_HIDE, DROPDOWN_PLACED, DROPDOWN_SHOW,
+};
+use crate::list_select::{flatten_groups, flatten_values, ListGroup};
+use crate::{AutoFocus, Dropdown, ListFilter, ListSelect, Wedge, WidgetExt as _};
 use druid::commands::CLOSE_WINDOW;
+use druid::im::Vector;
 use druid::kurbo::{BezPath, TranslateScale};
-use druid::widget::{Controller, DefaultScopePolicy, Label, LabelText, LineBreaking, Scope};
+use druid::lens::Map;
+use druid::widget::{
+    Controller, CrossAxisAlignment, DefaultScopePolicy, Flex, Label, LabelText, LineBreaking, List,
+    Painter, Scope, Scroll, TextBox,
+};
 use druid::{
-    theme, Affine, BoxConstraints, Data, Env, Event, EventCtx, Insets, LayoutCtx, Lens, LifeCycle,
-    LifeCycleCtx, LinearGradient, PaintCtx, Point, RenderContext, Size, UnitPoint, UpdateCtx,
-    Widget, WidgetExt, WidgetPod,
+    theme, Affine, ArcStr, BoxConstraints, Data, Env, Event, EventCtx, Insets, LayoutCtx, Lens,
+    LifeCycle, LifeCycleCtx, LinearGradient, PaintCtx, Point, RenderContext, Size, UnitPoint,
+    UpdateCtx, Vec2, Widget, WidgetExt, WidgetPod,
 };
 use std::marker::PhantomData;
 
 // NOTE: This is copied from Button. Should those be generic, or maybe set in the environment?
 const LABEL_INSETS: Insets = Insets::uniform_xy(8., 2.);
+// height of the scrolled list of a searchable dropdown, unless a size is given.
+const SEARCH_LIST_HEIGHT: f64 = 240.;
 
 /// Builds a list selection widget, showed as a button, for which the different possible values appear as a dropdown.
 pub struct DropdownSelect<T> {
@@ -30,24 +40,169 @@ impl<T: Data> DropdownSelect<T> {
     pub fn new(
         values: impl IntoIterator<Item = (impl Into<LabelText<T>> + 'static, T)> + Clone + 'static,
     ) -> impl Widget<T> {
-        Self::new_inner(values, None)
+        Self::new_inner(vec![(None, flatten_values(values))], None, None)
     }
 
     pub fn new_sized(
         values: impl IntoIterator<Item = (impl Into<LabelText<T>> + 'static, T)> + Clone + 'static,
         size: Size,
     ) -> impl Widget<T> {
-        Self::new_inner(values, Some(size))
+        Self::new_inner(vec![(None, flatten_values(values))], Some(size), None)
     }
 
-    fn new_inner(
+    /// Like [`new`], with the list scrolling when it is higher than `max_height`,
+    /// instead of overflowing the screen.
+    ///
+    /// [`new`]: DropdownSelect::new
+    pub fn new_with_max_height(
         values: impl IntoIterator<Item = (impl Into<LabelText<T>> + 'static, T)> + Clone + 'static,
+        max_height: f64,
+    ) -> impl Widget<T> {
+        Self::new_inner(vec![(None, flatten_values(values))], None, Some(max_height))
+    }
+
+    /// Like [`new_sized`], with the list scrolling when it is higher than `max_height`.
+    ///
+    /// [`new_sized`]: DropdownSelect::new_sized
+    pub fn new_sized_with_max_height(
+        values: impl IntoIterator<Item = (impl Into<LabelText<T>> + 'static, T)> + Clone + 'static,
+        size: Size,
+        max_height: f64,
+    ) -> impl Widget<T> {
+        Self::new_inner(
+            vec![(None, flatten_values(values))],
+            Some(size),
+            Some(max_height),
+        )
+    }
+
+    /// Given a vector of `(group_label, items)` tuples, where each of the items is a
+    /// `(label_text, enum_variant)` tuple, create a dropdown select widget whose list
+    /// shows a non-selectable header before each group.
+    pub fn new_grouped(
+        groups: impl IntoIterator<
+            Item = (
+                impl Into<LabelText<T>> + 'static,
+                impl IntoIterator<Item = (impl Into<LabelText<T>> + 'static, T)>,
+            ),
+        >,
+    ) -> impl Widget<T> {
+        Self::new_inner(flatten_groups(groups), None, None)
+    }
+
+    /// Given a vector of `(label_text, enum_variant)` tuples, create a dropdown select widget
+    /// with a text box above the list, which only shows the values whose label contains the
+    /// typed text, ignoring case.
+    pub fn new_searchable(
+        values: impl IntoIterator<Item = (impl Into<String>, T)>,
+    ) -> impl Widget<T> {
+        Self::new_searchable_inner(values, None)
+    }
+
+    /// Like [`new_searchable`], with a fixed size for the dropdown.
+    ///
+    /// [`new_searchable`]: DropdownSelect::new_searchable
+    pub fn new_searchable_sized(
+        values: impl IntoIterator<Item = (impl Into<String>, T)>,
+        size: Size,
+    ) -> impl Widget<T> {
+        Self::new_searchable_inner(values, Some(size))
+    }
+
+    fn new_searchable_inner(
+        values: impl IntoIterator<Item = (impl Into<String>, T)>,
+        size: Option<Size>,
+    ) -> impl Widget<T> {
+        let items: Vector<SearchItem<T>> = values
+            .into_iter()
+            .map(|(label, variant)| SearchItem {
+                label: label.into().into(),
+                variant,
+            })
+            .collect();
+        let groups = vec![(
+            None,
+            items
+                .iter()
+                .map(|item| (item.label.to_string().into(), item.variant.clone()))
+                .collect(),
+        )];
+
+        let make_drop = move |_t: &DropdownState<T>, env: &Env| {
+            let items = items.clone();
+            let list = ListFilter::new(
+                List::new(search_item),
+                |(_, item): &(T, SearchItem<T>), query: &String| matches_query(&item.label, query),
+            )
+            .lens(Map::new(
+                move |state: &DropdownState<T>| {
+                    ((state.data.clone(), items.clone()), state.query.clone())
+                },
+                |state: &mut DropdownState<T>, ((data, _), query): ((T, _), String)| {
+                    state.data = data;
+                    state.query = query;
+                },
+            ));
+            let list = Scroll::new(list).vertical();
+            let list = match size {
+                Some(size) => list.fix_size(size.width, size.height),
+                None => list.fix_height(SEARCH_LIST_HEIGHT),
+            };
+            Flex::column()
+                .cross_axis_alignment(CrossAxisAlignment::Fill)
+                .with_child(
+                    TextBox::new()
+                        .with_placeholder("Search")
+                        .lens(DropdownState::<T>::query)
+                        .controller(AutoFocus),
+                )
+                .with_child(list)
+                .border(env.get(theme::BORDER_DARK), 1.0)
+                .controller(DropdownSelectCtrl)
+        };
+        Self::with_dropdown(&groups, make_drop)
+    }
+
+    fn new_inner(
+        groups: Vec<ListGroup<T>>,
         size: Option<Size>,
+        max_height: Option<f64>,
     ) -> impl Widget<T> {
-        let mut variants = Vec::new();
-        for (label, variant) in values.clone().into_iter() {
-            variants.push((label.into(), variant));
+        let make_drop = Self::list_drop(groups.clone(), size, max_height);
+        Self::with_dropdown(&groups, make_drop)
+    }
+
+    /// Returns the function building the list of the dropdown, with a fixed `size`, and
+    /// scrolling when it is higher than `max_height`.
+    fn list_drop(
+        groups: Vec<ListGroup<T>>,
+        size: Option<Size>,
+        max_height: Option<f64>,
+    ) -> impl Fn(&DropdownState<T>, &Env) -> Box<dyn Widget<DropdownState<T>>> {
+        move |_t: &DropdownState<T>, env: &Env| {
+            let w = ListSelect::from_groups(groups.clone())
+                .lens(DropdownState::<T>::data)
+                .border(env.get(theme::BORDER_DARK), 1.0)
+                .controller(DropdownSelectCtrl)
+                .controller(AutoFocus);
+            let w = if let Some(size) = size {
+                w.fix_size(size.width, size.height).boxed()
+            } else {
+                w.boxed()
+            };
+            limit_height(w, max_height)
         }
+    }
+
+    /// Builds the button showing the selected value, opening the widget built by `make_drop`.
+    fn with_dropdown<W: Widget<DropdownState<T>> + 'static>(
+        groups: &[ListGroup<T>],
+        make_drop: impl Fn(&DropdownState<T>, &Env) -> W + 'static,
+    ) -> impl Widget<T> {
+        let variants: Vec<_> = groups
+            .iter()
+            .flat_map(|(_, values)| values.iter().cloned())
+            .collect();
         let header = DropdownButton::new(move |t: &T, env: &Env| {
             let mut var = variants
                 .clone()
@@ -64,25 +219,17 @@ impl<T: Data> DropdownSelect<T> {
                 ctx.submit_notification(DROPDOWN_HIDE)
             } else {
                 t.expanded = true;
+                t.query.clear();
                 ctx.submit_notification(DROPDOWN_SHOW)
             }
         })
+        .on_command(DROPDOWN_PLACED, |_ctx, upward, t: &mut DropdownState<T>| {
+            t.upward = *upward;
+        })
         .on_command(DROPDOWN_CLOSED, |_ctx, &(), t: &mut DropdownState<T>| {
             t.expanded = false;
         });
 
-        let make_drop = move |_t: &DropdownState<T>, env: &Env| {
-            let w = ListSelect::new(values.clone())
-                .lens(DropdownState::<T>::data)
-                .border(env.get(theme::BORDER_DARK), 1.0)
-                .controller(DropdownSelectCtrl)
-                .controller(AutoFocus);
-            if let Some(size) = size {
-                w.fix_size(size.width, size.height).boxed()
-            } else {
-                w.boxed()
-            }
-        };
         // A `Scope` is used here to add internal data shared within the children widgets,
         // namely whether or not the dropdown is expanded. See `DropdownState`.
         Scope::new(
@@ -96,9 +243,17 @@ impl<T: Data> DropdownSelect<T> {
 // reacts to it by updating its expanded state
 struct DropdownSelectCtrl;
 
-impl<T: Data, W: Widget<T>> Controller<T, W> for DropdownSelectCtrl {
-    fn update(&mut self, child: &mut W, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
-        if !old_data.same(data) {
+impl<T: Data, W: Widget<DropdownState<T>>> Controller<DropdownState<T>, W> for DropdownSelectCtrl {
+    fn update(
+        &mut self,
+        child: &mut W,
+        ctx: &mut UpdateCtx,
+        old_data: &DropdownState<T>,
+        data: &DropdownState<T>,
+        env: &Env,
+    ) {
+        // the search query may change without selecting a value
+        if !old_data.data.same(&data.data) {
             // workaround for https://github.com/linebender/druid/issues/1939
             let ext = ctx.get_external_handle();
             ext.submit_command(CLOSE_WINDOW, (), ctx.window_id())
@@ -112,6 +267,10 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for DropdownSelectCtrl {
 struct DropdownState<T> {
     data: T,
     expanded: bool,
+    /// Whether the dropdown opened above the button.
+    upward: bool,
+    /// The text typed in the search box of a searchable dropdown.
+    query: String,
 }
 
 impl<T> DropdownState<T> {
@@ -119,10 +278,45 @@ impl<T> DropdownState<T> {
         DropdownState {
             data,
             expanded: false,
+            upward: false,
+            query: String::new(),
         }
     }
 }
 
+/// A value of a searchable dropdown, with its label.
+#[derive(Clone, Data)]
+struct SearchItem<T> {
+    label: ArcStr,
+    variant: T,
+}
+
+fn matches_query(label: &str, query: &str) -> bool {
+    label.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// An item of the list of a searchable dropdown, along with the selected value.
+fn search_item<T: Data>() -> impl Widget<(T, SearchItem<T>)> {
+    Label::new(|(_, item): &(T, SearchItem<T>), _env: &Env| item.label.to_string())
+        .padding(LABEL_INSETS)
+        .background(Painter::new(
+            |ctx, (selected, item): &(T, SearchItem<T>), env| {
+                if selected.same(&item.variant) {
+                    let background_gradient = LinearGradient::new(
+                        UnitPoint::TOP,
+                        UnitPoint::BOTTOM,
+                        (env.get(theme::PRIMARY_LIGHT), env.get(theme::PRIMARY_DARK)),
+                    );
+                    let rect = ctx.size().to_rect();
+                    ctx.fill(rect, &background_gradient);
+                }
+            },
+        ))
+        .on_click(|_ctx, (selected, item): &mut (T, SearchItem<T>), _env| {
+            *selected = item.variant.clone();
+        })
+}
+
 /// A button with a left or down arrow, changing shape when opened.
 struct DropdownButton<T> {
     wedge: WidgetPod<bool, Wedge>,
@@ -188,7 +382,7 @@ impl<T: Data> Widget<DropdownState<T>> for DropdownButton<T> {
         data: &DropdownState<T>,
         env: &Env,
     ) {
-        if old_data.expanded != data.expanded {
+        if old_data.expanded != data.expanded || old_data.upward != data.upward {
             ctx.request_paint();
         }
         self.wedge.update(ctx, &data.expanded, env);
@@ -253,10 +447,13 @@ impl<T: Data> Widget<DropdownState<T>> for DropdownButton<T> {
 
         let radius = TranslateScale::scale(1.5) * env.get(theme::BUTTON_BORDER_RADIUS);
         if data.expanded {
-            let rounded_rect = half_rounded_rect(
-                size - Size::new(stroke_width, stroke_width),
-                radius.top_left,
-            );
+            let inner_size = size - Size::new(stroke_width, stroke_width);
+            let mut rounded_rect = half_rounded_rect(inner_size, radius.top_left);
+            if data.upward {
+                // the corners next to the dropdown are square
+                let flip = Affine::FLIP_Y.then_translate(Vec2::new(0.0, inner_size.height));
+                rounded_rect.apply_affine(flip);
+            }
             ctx.with_save(|ctx| {
                 ctx.transform(Affine::translate((stroke_width / 2.0, stroke_width / 2.0)));
                 ctx.fill(rounded_rect.clone(), &bg_gradient);
@@ -301,3 +498,59 @@ fn half_rounded_rect(size: Size, r: f64) -> BezPath {
     path.close_path();
     path
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::{Record, Recording, TestWidgetExt};
+    use druid::widget::ViewSwitcher;
+
+    /// The size of the list of a dropdown built by `list_drop`, laid out with loose
+    /// constraints.
+    fn list_size(size: Option<Size>, max_height: Option<f64>) -> Size {
+        let values: Vec<_> = (0..100).map(|i| (i.to_string().into(), i)).collect();
+        let make_drop = DropdownSelect::list_drop(vec![(None, values)], size, max_height);
+        let recording = Recording::default();
+        let widget = ViewSwitcher::new(|_, _| (), move |_, data, env| make_drop(data, env))
+            .record(&recording)
+            .align_left();
+
+        let mut list_size = Size::ZERO;
+        Harness::create_simple(DropdownState::new(0), widget, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            for record in recording.drain() {
+                if let Record::Layout(size) = record {
+                    list_size = size;
+                }
+            }
+        });
+        list_size
+    }
+
+    #[test]
+    fn list_max_height() {
+        // the harness window is 400 high
+        assert_eq!(list_size(None, None).height, 400.0);
+        assert_eq!(list_size(None, Some(150.0)).height, 150.0);
+
+        let size = Size::new(200.0, 300.0);
+        assert_eq!(list_size(Some(size), None), size);
+        assert_eq!(list_size(Some(size), Some(150.0)).height, 150.0);
+    }
+
+    #[test]
+    fn search() {
+        let labels: Vec<_> = (1..=200).map(|i| format!("Number {i}")).collect();
+        let matching = labels
+            .iter()
+            .filter(|label| matches_query(label, "ber 19"))
+            .count();
+        // 19 and 190 to 199
+        assert_eq!(matching, 11);
+        assert!(matches_query("September", "BER"));
+        assert!(matches_query("March", ""));
+        assert!(!matches_query("March", "ber"));
+    }
+}
@@ -5,6 +5,8 @@
 
 use druid::Lens;
 
+use crate::prism::Prism;
+
 /// A version of Lens that can be made into a trait object.
 pub trait DynLens<T, U> {
     fn with_raw(&self, t: &T, f: &mut dyn FnMut(&U));
@@ -21,6 +23,44 @@ impl<T, U, L: Lens<T, U>> DynLens<T, U> for L {
     }
 }
 
+/// A lens focusing on the data of a [`Prism`], for example an enum variant, which
+/// uses a default value when the prism's data is not present.
+///
+/// When the data is not present, changes to the default value are discarded. Like
+/// any lens, it can be used as a [`DynLens`].
+pub struct PrismOrDefault<P, U> {
+    prism: P,
+    default: U,
+}
+
+impl<P, U> PrismOrDefault<P, U> {
+    /// Create a lens focusing on the data of `prism`, or on `default` when the data
+    /// is not present.
+    pub fn new(prism: P, default: U) -> Self {
+        Self { prism, default }
+    }
+}
+
+impl<T, U: Clone, P: Prism<T, U>> Lens<T, U> for PrismOrDefault<P, U> {
+    fn with<V, F: FnOnce(&U) -> V>(&self, data: &T, f: F) -> V {
+        match self.prism.get(data) {
+            Some(inner) => f(&inner),
+            None => f(&self.default),
+        }
+    }
+
+    fn with_mut<V, F: FnOnce(&mut U) -> V>(&self, data: &mut T, f: F) -> V {
+        match self.prism.get(data) {
+            Some(mut inner) => {
+                let value = f(&mut inner);
+                self.prism.put(data, inner);
+                value
+            }
+            None => f(&mut self.default.clone()),
+        }
+    }
+}
+
 impl<T, U> dyn DynLens<T, U> {
     pub fn with<R>(&self, data: &T, f: impl FnOnce(&U) -> R) -> R {
         let mut f = Some(f);
@@ -44,3 +84,25 @@ impl<T, U> dyn DynLens<T, U> {
         r.unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prism::ResultOk;
+
+    #[test]
+    fn prism_or_default() {
+        let lens: Box<dyn DynLens<Result<u32, String>, u32>> =
+            Box::new(PrismOrDefault::new(ResultOk, 7));
+
+        let mut data = Ok(1);
+        assert_eq!(lens.with(&data, |value| *value), 1);
+        lens.with_mut(&mut data, |value| *value = 2);
+        assert_eq!(data, Ok(2));
+
+        let mut data = Err("error".to_string());
+        assert_eq!(lens.with(&data, |value| *value), 7);
+        lens.with_mut(&mut data, |value| *value = 2);
+        assert_eq!(data, Err("error".to_string()));
+    }
+}
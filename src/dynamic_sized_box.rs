@@ -11,11 +11,20 @@ use druid::Data;
 /// If not given a child, The box will try to size itself as a fraction of the parent's
 /// box constraints. If height or width is not set, it will be treated as zero.
 ///
+/// The size can be clamped with [`with_min_size`] and [`with_max_size`], and locked to an
+/// aspect ratio with [`with_aspect_ratio`].
+///
 /// [`SizedBox`]: druid::widget::SizedBox
+/// [`with_min_size`]: DynamicSizedBox::with_min_size
+/// [`with_max_size`]: DynamicSizedBox::with_max_size
+/// [`with_aspect_ratio`]: DynamicSizedBox::with_aspect_ratio
 pub struct DynamicSizedBox<T> {
     inner: Option<Box<dyn Widget<T>>>,
     width: Option<f64>,
     height: Option<f64>,
+    min_size: Size,
+    max_size: Size,
+    aspect_ratio: Option<f64>,
 }
 
 impl<T> DynamicSizedBox<T> {
@@ -25,6 +34,9 @@ impl<T> DynamicSizedBox<T> {
             inner: Some(Box::new(inner)),
             width: None,
             height: None,
+            min_size: Size::ZERO,
+            max_size: Size::new(f64::INFINITY, f64::INFINITY),
+            aspect_ratio: None,
         }
     }
 
@@ -34,6 +46,9 @@ impl<T> DynamicSizedBox<T> {
             inner: None,
             width: None,
             height: None,
+            min_size: Size::ZERO,
+            max_size: Size::new(f64::INFINITY, f64::INFINITY),
+            aspect_ratio: None,
         }
     }
 
@@ -103,24 +118,70 @@ impl<T> DynamicSizedBox<T> {
         self
     }
 
+    /// Builder-style method for setting the minimum size of the box.
+    ///
+    /// The fractional width and height are clamped to be at least this size.
+    pub fn with_min_size(mut self, min_size: impl Into<Size>) -> Self {
+        self.min_size = min_size.into();
+        self
+    }
+
+    /// Builder-style method for setting the maximum size of the box.
+    ///
+    /// The fractional width and height are clamped to be at most this size.
+    pub fn with_max_size(mut self, max_size: impl Into<Size>) -> Self {
+        self.max_size = max_size.into();
+        self
+    }
+
+    /// Builder-style method for locking the ratio of the width to the height.
+    ///
+    /// If only one of width and height is set, the other one is derived from it.
+    /// If both are set, the dimension which is too large for the ratio is reduced.
+    /// The ratio is applied after clamping, so it is kept over the minimum size.
+    pub fn with_aspect_ratio(mut self, ratio: f64) -> Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Determine the width and height of the box, if set, for the given maximum size.
+    fn target_size(&self, max: Size) -> (Option<f64>, Option<f64>) {
+        let mut width = self.width.map(|width| {
+            (width * max.width)
+                .max(self.min_size.width)
+                .min(self.max_size.width)
+        });
+        let mut height = self.height.map(|height| {
+            (height * max.height)
+                .max(self.min_size.height)
+                .min(self.max_size.height)
+        });
+
+        if let Some(ratio) = self.aspect_ratio {
+            match (width, height) {
+                (Some(w), Some(h)) if w > h * ratio => width = Some(h * ratio),
+                (Some(w), _) => height = Some(w / ratio),
+                (None, Some(h)) => width = Some(h * ratio),
+                (None, None) => (),
+            }
+        }
+
+        (width, height)
+    }
+
     /// Determine the constraints that will be used for inner widget.
     fn inner_constraints(&self, bc: &BoxConstraints) -> BoxConstraints {
         // if we have a width/height, multiply it by bc.max to get new width/height
         // of widget and clamp on that value
         // if we don't have width/height, box constraints stay the same
-        let (min_width, max_width) = match self.width {
-            Some(width) => {
-                let w = width * bc.max().width;
-                (w, w)
-            }
+        let (width, height) = self.target_size(bc.max());
+        let (min_width, max_width) = match width {
+            Some(w) => (w, w),
             None => (bc.min().width, bc.max().width),
         };
 
-        let (min_height, max_height) = match self.height {
-            Some(height) => {
-                let h = height * bc.max().height;
-                (h, h)
-            }
+        let (min_height, max_height) = match height {
+            Some(h) => (h, h),
             None => (bc.min().height, bc.max().height),
         };
 
@@ -158,10 +219,10 @@ impl<T: Data> Widget<T> for DynamicSizedBox<T> {
         let inner_bc = self.inner_constraints(&bc);
         let size = match self.inner.as_mut() {
             Some(inner) => inner.layout(ctx, &inner_bc, data, env),
-            None => bc.constrain((
-                self.width.unwrap_or(0.0) * bc.max().width,
-                self.height.unwrap_or(0.0) * bc.max().height,
-            )),
+            None => {
+                let (width, height) = self.target_size(bc.max());
+                bc.constrain((width.unwrap_or(0.0), height.unwrap_or(0.0)))
+            }
         };
 
         if size.width.is_infinite() {
@@ -185,3 +246,31 @@ impl<T: Data> Widget<T> for DynamicSizedBox<T> {
         self.inner.as_ref().and_then(|inner| inner.id())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_and_aspect_ratio() {
+        let max = Size::new(1_000_000.0, 50.0);
+        let sized_box = DynamicSizedBox::<()>::empty()
+            .expand()
+            .with_min_size((0.0, 100.0))
+            .with_max_size((300.0, 200.0));
+        assert_eq!(sized_box.target_size(max), (Some(300.0), Some(100.0)));
+
+        // the width is reduced to keep the ratio
+        let sized_box = sized_box.with_aspect_ratio(2.0);
+        assert_eq!(sized_box.target_size(max), (Some(200.0), Some(100.0)));
+
+        // the height is derived from the width
+        let sized_box = DynamicSizedBox::<()>::empty()
+            .with_width(0.5)
+            .with_aspect_ratio(4.0 / 3.0);
+        assert_eq!(
+            sized_box.target_size(Size::new(800.0, 0.0)),
+            (Some(400.0), Some(300.0))
+        );
+    }
+}
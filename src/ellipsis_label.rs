@@ -0,0 +1,238 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A label which truncates its text with an ellipsis when it doesn't fit.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use druid::piet::TextStorage;
+use druid::text::RichText;
+use druid::widget::prelude::*;
+use druid::widget::LabelText;
+use druid::{Point, TextLayout};
+
+use crate::stack_tooltip::{RichTextCell, StackTooltip};
+
+const ELLIPSIS: &str = "\u{2026}";
+
+/// A single line label which truncates its text with "…" when it is wider than
+/// the available space.
+///
+/// By default, hovering a truncated label shows the full text in a [`StackTooltip`].
+pub struct EllipsisLabel<T: Data> {
+    inner: StackTooltip<T>,
+    tooltip: Rc<Cell<bool>>,
+}
+
+impl<T: Data> EllipsisLabel<T> {
+    pub fn new(text: impl Into<LabelText<T>>) -> Self {
+        let tooltip = Rc::new(Cell::new(true));
+        let tooltip_text: RichTextCell =
+            Rc::new(RefCell::new((RichText::new("".into()), Vec::new())));
+        let show_tooltip = Rc::new(Cell::new(false));
+        let truncated = TruncatedText {
+            text: text.into(),
+            layout: TextLayout::new(),
+            measure: TextLayout::new(),
+            tooltip: tooltip.clone(),
+            tooltip_text: tooltip_text.clone(),
+            show_tooltip: show_tooltip.clone(),
+        };
+        EllipsisLabel {
+            inner: StackTooltip::from_shared(truncated, tooltip_text, show_tooltip),
+            tooltip,
+        }
+    }
+
+    /// Builder-style method for setting whether the full text is shown in a
+    /// tooltip when the label is truncated.
+    pub fn with_tooltip(self, tooltip: bool) -> Self {
+        self.set_tooltip(tooltip);
+        self
+    }
+
+    /// Set whether the full text is shown in a tooltip when the label is truncated.
+    pub fn set_tooltip(&self, tooltip: bool) {
+        self.tooltip.set(tooltip);
+    }
+}
+
+impl<T: Data> Widget<T> for EllipsisLabel<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.inner.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.inner.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env)
+    }
+}
+
+/// The text of an [`EllipsisLabel`], which updates the tooltip when it is truncated.
+struct TruncatedText<T> {
+    text: LabelText<T>,
+    layout: TextLayout<String>,
+    measure: TextLayout<String>,
+    tooltip: Rc<Cell<bool>>,
+    tooltip_text: RichTextCell,
+    show_tooltip: Rc<Cell<bool>>,
+}
+
+impl<T: Data> TruncatedText<T> {
+    fn measure(&mut self, ctx: &mut LayoutCtx, text: String, env: &Env) -> f64 {
+        self.measure.set_text(text);
+        self.measure.rebuild_if_needed(ctx.text(), env);
+        self.measure.size().width
+    }
+
+    /// Returns the longest prefix of `full`, followed by an ellipsis, which fits in `max_width`.
+    fn truncate(&mut self, ctx: &mut LayoutCtx, full: &str, max_width: f64, env: &Env) -> String {
+        let bounds: Vec<usize> = full
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(Some(full.len()))
+            .collect();
+        // binary search for the number of characters which still fit
+        let (mut low, mut high) = (0, bounds.len() - 1);
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            let candidate = format!("{}{}", full[..bounds[mid]].trim_end(), ELLIPSIS);
+            if self.measure(ctx, candidate, env) <= max_width {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        format!("{}{}", full[..bounds[low]].trim_end(), ELLIPSIS)
+    }
+}
+
+impl<T: Data> Widget<T> for TruncatedText<T> {
+    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut T, _env: &Env) {}
+
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            self.text.resolve(data, env);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        if self.text.resolve(data, env) || self.layout.needs_rebuild_after_update(ctx) {
+            ctx.request_layout();
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, env: &Env) -> Size {
+        let full = self.text.display_text().to_string();
+        let max_width = bc.max().width;
+        let truncated = self.measure(ctx, full.clone(), env) > max_width;
+        let shown = if truncated {
+            self.truncate(ctx, &full, max_width, env)
+        } else {
+            full.clone()
+        };
+
+        if self.tooltip_text.borrow().0.as_str() != full {
+            self.tooltip_text.borrow_mut().0 = RichText::new(full.into());
+        }
+        self.show_tooltip.set(truncated && self.tooltip.get());
+
+        self.layout.set_text(shown);
+        self.layout.rebuild_if_needed(ctx.text(), env);
+        bc.constrain(self.layout.size())
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, _env: &Env) {
+        self.layout.draw(ctx, Point::ORIGIN);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::WidgetExt as _;
+
+    const TEXT: &str = "A label with a rather long text";
+
+    /// A text shared with the test, to read what it shows.
+    struct Shared(Rc<RefCell<TruncatedText<()>>>);
+
+    impl Widget<()> for Shared {
+        fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut (), env: &Env) {
+            self.0.borrow_mut().event(ctx, event, data, env)
+        }
+
+        fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &(), env: &Env) {
+            self.0.borrow_mut().lifecycle(ctx, event, data, env)
+        }
+
+        fn update(&mut self, ctx: &mut UpdateCtx, old_data: &(), data: &(), env: &Env) {
+            self.0.borrow_mut().update(ctx, old_data, data, env)
+        }
+
+        fn layout(
+            &mut self,
+            ctx: &mut LayoutCtx,
+            bc: &BoxConstraints,
+            data: &(),
+            env: &Env,
+        ) -> Size {
+            self.0.borrow_mut().layout(ctx, bc, data, env)
+        }
+
+        fn paint(&mut self, ctx: &mut PaintCtx, data: &(), env: &Env) {
+            self.0.borrow_mut().paint(ctx, data, env)
+        }
+    }
+
+    /// Returns the text shown within `width`, the text of the tooltip and whether
+    /// the tooltip is shown.
+    fn shown_within(width: f64) -> (String, String, bool) {
+        let text = Rc::new(RefCell::new(TruncatedText {
+            text: TEXT.into(),
+            layout: TextLayout::new(),
+            measure: TextLayout::new(),
+            tooltip: Rc::new(Cell::new(true)),
+            tooltip_text: Rc::new(RefCell::new((RichText::new("".into()), Vec::new()))),
+            show_tooltip: Rc::new(Cell::new(false)),
+        }));
+        let widget = Shared(text.clone()).fix_width(width).align_left();
+        Harness::create_simple((), widget, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+        });
+        let text = text.borrow();
+        let shown = text.layout.text().cloned().unwrap_or_default();
+        let tooltip = text.tooltip_text.borrow().0.as_str().to_string();
+        (shown, tooltip, text.show_tooltip.get())
+    }
+
+    #[test]
+    fn truncated_text() {
+        let (shown, tooltip, show_tooltip) = shown_within(60.0);
+        assert!(shown.ends_with(ELLIPSIS), "{}", shown);
+        assert!(TEXT.starts_with(shown.trim_end_matches(ELLIPSIS)));
+        assert!(shown.len() < TEXT.len());
+        // the tooltip has the full text
+        assert_eq!(tooltip, TEXT);
+        assert!(show_tooltip);
+
+        let (shown, tooltip, show_tooltip) = shown_within(400.0);
+        assert_eq!(shown, TEXT);
+        assert_eq!(tooltip, TEXT);
+        assert!(!show_tooltip);
+    }
+}
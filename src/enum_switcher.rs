@@ -1,10 +1,13 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::animation::{Animated, AnimationCurve};
 use crate::prism::{Prism, PrismWidget, PrismWrap};
+use crate::RequestCtx;
+use druid::kurbo::Affine;
 use druid::{
-    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, PaintCtx, Size,
-    UpdateCtx, Widget,
+    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, PaintCtx,
+    RenderContext, Size, UpdateCtx, Widget,
 };
 
 type WidgetBuilder<T> = Box<dyn Fn(&T) -> Option<Box<dyn PrismWidget<T>>>>;
@@ -113,6 +116,66 @@ impl<T: Data> Widget<T> for LazySwitcher<T> {
 pub struct Switcher<T: Data> {
     widgets: Vec<Box<dyn PrismWidget<T>>>,
     current: Option<usize>,
+    transition: Option<Transition>,
+}
+
+/// The slide from the previous variant to the current one.
+struct Transition {
+    progress: Animated<f64>,
+    /// The index of the variant sliding out, while the transition runs.
+    previous: Option<usize>,
+    /// 1.0 when the current variant comes from the right, -1.0 from the left.
+    direction: f64,
+}
+
+impl Transition {
+    fn new(duration: f64, curve: AnimationCurve) -> Self {
+        Transition {
+            progress: Animated::new(1.0).duration(duration).curve(curve),
+            previous: None,
+            direction: 1.0,
+        }
+    }
+
+    fn switch(&mut self, ctx: &mut impl RequestCtx, old: Option<usize>, new: Option<usize>) {
+        let progress = self.progress.get();
+        match (old, new) {
+            // back to the variant sliding out: reverse the slide from where it is
+            (_, Some(new)) if self.previous == Some(new) => {
+                self.previous = old;
+                self.direction = -self.direction;
+                self.progress.jump_to_value(1.0 - progress);
+            }
+            // variants after the current one come from the right
+            (Some(old), Some(new)) => {
+                self.previous = Some(old);
+                self.direction = if new > old { 1.0 } else { -1.0 };
+                self.progress.jump_to_value(0.0);
+            }
+            _ => {
+                self.previous = None;
+                self.progress.jump_to_value(1.0);
+            }
+        }
+        self.progress.animate(ctx, 1.0);
+    }
+
+    fn update(&mut self, ctx: &mut impl RequestCtx, nanos: u64) {
+        self.progress.update(ctx, nanos);
+        if !self.progress.animating() {
+            self.previous = None;
+        }
+    }
+
+    /// The horizontal offsets of the current and the previous variant, as fractions of the
+    /// width of the switcher.
+    fn offsets(&self) -> (f64, f64) {
+        let progress = self.progress.get();
+        (
+            self.direction * (1.0 - progress),
+            -self.direction * progress,
+        )
+    }
 }
 
 impl<T: Data> Switcher<T> {
@@ -120,9 +183,20 @@ impl<T: Data> Switcher<T> {
         Switcher {
             widgets: vec![],
             current: None,
+            transition: None,
         }
     }
 
+    /// Builder-style method to slide between the variants when the current variant changes,
+    /// instead of switching instantly.
+    ///
+    /// The following variants come from the right, and the preceding ones from the left,
+    /// while the previous variant slides out with the data it had last.
+    pub fn with_transition(mut self, duration: f64, curve: impl Into<AnimationCurve>) -> Self {
+        self.transition = Some(Transition::new(duration, curve.into()));
+        self
+    }
+
     /// Adds a new variant to the widget. This variant is show as long as the prism returns `Some()`
     /// for the current data.
     pub fn with_variant<U: Data, P: Prism<T, U> + 'static>(
@@ -161,6 +235,9 @@ impl<T: Data> Default for Switcher<T> {
 
 impl<T: Data> Widget<T> for Switcher<T> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let (Event::AnimFrame(nanos), Some(transition)) = (event, &mut self.transition) {
+            transition.update(ctx, *nanos);
+        }
         for (index, child) in self.widgets.iter_mut().enumerate() {
             if event.should_propagate_to_hidden() || self.current == Some(index) {
                 child.event(ctx, event, data, env);
@@ -180,7 +257,11 @@ impl<T: Data> Widget<T> for Switcher<T> {
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        let old = self.current;
         if self.rebuild_if_needed(data) {
+            if let Some(transition) = &mut self.transition {
+                transition.switch(ctx, old, self.current);
+            }
             ctx.request_layout();
             ctx.children_changed();
         }
@@ -191,6 +272,9 @@ impl<T: Data> Widget<T> for Switcher<T> {
     }
 
     fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        if let Some(previous) = self.transition.as_ref().and_then(|t| t.previous) {
+            self.widgets[previous].layout(ctx, bc, data, env);
+        }
         if let Some(index) = self.current {
             self.widgets[index].layout(ctx, bc, data, env)
         } else {
@@ -199,8 +283,52 @@ impl<T: Data> Widget<T> for Switcher<T> {
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
-        if let Some(index) = self.current {
+        let transition = self.transition.as_ref().filter(|t| t.previous.is_some());
+        if let (Some(transition), Some(index)) = (transition, self.current) {
+            let (current_offset, previous_offset) = transition.offsets();
+            let previous = transition.previous.unwrap();
+            let size = ctx.size();
+            let widgets = &mut self.widgets;
+            ctx.with_save(|ctx| {
+                ctx.clip(size.to_rect());
+                for (index, offset) in [(previous, previous_offset), (index, current_offset)] {
+                    ctx.with_save(|ctx| {
+                        ctx.transform(Affine::translate((offset * size.width, 0.0)));
+                        widgets[index].paint(ctx, data, env);
+                    });
+                }
+            });
+        } else if let Some(index) = self.current {
             self.widgets[index].paint(ctx, data, env);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::test::TestCtx;
+
+    #[test]
+    fn retarget_transition() {
+        let mut transition = Transition::new(1.0, AnimationCurve::LINEAR);
+        transition.switch(&mut TestCtx, Some(0), Some(2));
+        assert_eq!(transition.offsets(), (1.0, -0.0));
+        transition.update(&mut TestCtx, 250_000_000);
+        assert_eq!(transition.offsets(), (0.75, -0.25));
+
+        // switching back continues from where the variants are
+        transition.switch(&mut TestCtx, Some(2), Some(0));
+        assert_eq!(transition.previous, Some(2));
+        assert_eq!(transition.offsets(), (-0.25, 0.75));
+        transition.update(&mut TestCtx, 500_000_000);
+        assert_eq!(transition.offsets(), (-0.125, 0.875));
+        transition.update(&mut TestCtx, 500_000_000);
+        assert_eq!(transition.offsets(), (-0.0, 1.0));
+        assert_eq!(transition.previous, None);
+
+        // a variant before the current one comes from the left
+        transition.switch(&mut TestCtx, Some(1), Some(0));
+        assert_eq!(transition.offsets(), (-1.0, 0.0));
+    }
+}
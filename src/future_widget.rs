@@ -8,76 +8,161 @@
 use std::{any::Any, future::Future, pin::Pin};
 
 use druid::widget::prelude::*;
-use druid::{Data, Selector, SingleUse, Target, WidgetPod};
+use druid::{Data, ExtEventSink, Selector, SingleUse, Target, WidgetPod};
 
 struct Response {
     value: Box<dyn Any + Send>,
+    /// The generation of the widget when the future was spawned.
+    generation: u64,
 }
 
 const ASYNC_RESPONSE: Selector<SingleUse<Response>> = Selector::new("druid-async.async-response");
 
 #[cfg(target_arch = "wasm32")]
 pub type FutureWidgetAction<T> =
-    Box<dyn FnOnce(&T, &Env) -> Pin<Box<dyn Future<Output = Box<dyn Any + Send>>>>>;
+    Box<dyn Fn(&T, &Env) -> Pin<Box<dyn Future<Output = Box<dyn Any + Send>>>>>;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub type FutureWidgetAction<T> =
-    Box<dyn FnOnce(&T, &Env) -> Pin<Box<dyn Send + Future<Output = Box<dyn Any + Send>>>>>;
+    Box<dyn Fn(&T, &Env) -> Pin<Box<dyn Send + Future<Output = Box<dyn Any + Send>>>>>;
 
-pub type FutureWidgetDone<T, U> = Box<dyn FnOnce(Box<U>, &mut T, &Env) -> Box<dyn Widget<T>>>;
+pub type FutureWidgetDone<T, U> = Box<dyn Fn(Box<U>, &mut T, &Env) -> Box<dyn Widget<T>>>;
 
+type RestartFn<T> = Box<dyn Fn(&T, &T) -> bool>;
+
+/// A widget showing a loading widget while a future runs, and then the widget built
+/// from the result of the future.
+///
+/// The loading widget, for example a [`Spinner`] or an indeterminate [`ProgressBar`],
+/// gets the same data as the widget built from the result.
+///
+/// With [`restart_on_change`], the future is spawned again whenever some part of the
+/// data changes, and the results of the previous futures are ignored, even when they
+/// resolve after the latest one.
+///
+/// [`Spinner`]: druid::widget::Spinner
+/// [`ProgressBar`]: crate::ProgressBar
+///
+/// [`restart_on_change`]: FutureWidget::restart_on_change
 pub struct FutureWidget<T, U> {
-    future: Option<FutureWidgetAction<T>>,
-    inner: WidgetPod<T, Box<dyn Widget<T>>>,
-    on_done: Option<FutureWidgetDone<T, U>>,
+    future: FutureWidgetAction<T>,
+    loading: WidgetPod<T, Box<dyn Widget<T>>>,
+    /// The widget built from the result of the latest future, once it resolved.
+    result: Option<WidgetPod<T, Box<dyn Widget<T>>>>,
+    on_done: FutureWidgetDone<T, U>,
+    restart: Option<RestartFn<T>>,
+    /// Incremented whenever the future is spawned.
+    generation: u64,
 }
 
 #[cfg(target_arch = "wasm32")]
 impl<T, U> FutureWidget<T, U> {
+    /// Create a widget running the future made by `future_maker`, showing `loading`
+    /// until it resolves, and then the widget built by `on_done` from its result.
     pub fn new<FMaker, Fut, Done>(
         future_maker: FMaker,
-        pending: impl Widget<T> + 'static,
+        loading: impl Widget<T> + 'static,
         on_done: Done,
     ) -> Self
     where
         U: Send + 'static,
-        FMaker: FnOnce(&T, &Env) -> Fut + 'static,
+        FMaker: Fn(&T, &Env) -> Fut + 'static,
         Fut: Future<Output = U> + 'static,
-        Done: FnOnce(Box<U>, &mut T, &Env) -> Box<dyn Widget<T>> + 'static,
+        Done: Fn(Box<U>, &mut T, &Env) -> Box<dyn Widget<T>> + 'static,
     {
         Self {
-            future: Some(Box::new(move |data, env| {
+            future: Box::new(move |data, env| {
                 let fut = future_maker(data, env);
                 Box::pin(async move { Box::new(fut.await) as _ })
-            })),
-            inner: WidgetPod::new(Box::new(pending)),
-            on_done: Some(Box::new(on_done)),
+            }),
+            loading: WidgetPod::new(Box::new(loading)),
+            result: None,
+            on_done: Box::new(on_done),
+            restart: None,
+            generation: 0,
         }
     }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 impl<T, U> FutureWidget<T, U> {
+    /// Create a widget running the future made by `future_maker`, showing `loading`
+    /// until it resolves, and then the widget built by `on_done` from its result.
     pub fn new<FMaker, Fut, Done>(
         future_maker: FMaker,
-        pending: impl Widget<T> + 'static,
+        loading: impl Widget<T> + 'static,
         on_done: Done,
     ) -> Self
     where
         U: Send + 'static,
-        FMaker: FnOnce(&T, &Env) -> Fut + 'static,
+        FMaker: Fn(&T, &Env) -> Fut + 'static,
         Fut: Future<Output = U> + 'static + Send,
-        Done: FnOnce(Box<U>, &mut T, &Env) -> Box<dyn Widget<T>> + 'static,
+        Done: Fn(Box<U>, &mut T, &Env) -> Box<dyn Widget<T>> + 'static,
     {
         Self {
-            future: Some(Box::new(move |data, env| {
+            future: Box::new(move |data, env| {
                 let fut = future_maker(data, env);
                 Box::pin(async move { Box::new(fut.await) as _ })
-            })),
-            inner: WidgetPod::new(Box::new(pending)),
-            on_done: Some(Box::new(on_done)),
+            }),
+            loading: WidgetPod::new(Box::new(loading)),
+            result: None,
+            on_done: Box::new(on_done),
+            restart: None,
+            generation: 0,
+        }
+    }
+}
+
+impl<T: Data, U: 'static> FutureWidget<T, U> {
+    /// Builder-style method to spawn the future again whenever the value returned by
+    /// `key` changes, for example a search query.
+    ///
+    /// The loading widget is shown again until the new future resolves.
+    pub fn restart_on_change<K: Data>(mut self, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.restart = Some(Box::new(move |old, new| !key(old).same(&key(new))));
+        self
+    }
+
+    /// Makes the future for `data`, resolving to the response of a new generation.
+    fn start(&mut self, data: &T, env: &Env) -> impl Future<Output = Response> {
+        self.generation += 1;
+        let generation = self.generation;
+        let future = (self.future)(data, env);
+        async move {
+            let value = future.await;
+            Response { value, generation }
         }
     }
+
+    fn spawn(&mut self, sink: ExtEventSink, widget_id: WidgetId, data: &T, env: &Env) {
+        let response = self.start(data, env);
+        let task = async move {
+            let response = response.await;
+            sink.submit_command(
+                ASYNC_RESPONSE,
+                SingleUse::new(response),
+                Target::Widget(widget_id),
+            )
+            .unwrap();
+        };
+
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(task);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        tokio::spawn(task);
+    }
+
+    /// The widget which is shown, the result widget once the future resolved and the
+    /// loading widget before.
+    fn current(&mut self) -> &mut WidgetPod<T, Box<dyn Widget<T>>> {
+        self.result.as_mut().unwrap_or(&mut self.loading)
+    }
+
+    /// Whether the response comes from the latest spawned future.
+    fn is_current(&self, response: &Response) -> bool {
+        response.generation == self.generation
+    }
 }
 
 impl<T: Data, U: 'static> Widget<T> for FutureWidget<T, U> {
@@ -85,43 +170,40 @@ impl<T: Data, U: 'static> Widget<T> for FutureWidget<T, U> {
         if let Event::Command(cmd) = event {
             if let Some(res) = cmd.get(ASYNC_RESPONSE) {
                 let res = res.take().unwrap();
-                let value = res.value.downcast::<U>().unwrap();
-                let on_done = self.on_done.take().unwrap();
-                self.inner = WidgetPod::new((on_done)(value, data, env));
-                ctx.children_changed();
+                if self.is_current(&res) {
+                    let value = res.value.downcast::<U>().unwrap();
+                    self.result = Some(WidgetPod::new((self.on_done)(value, data, env)));
+                    ctx.children_changed();
+                }
+                ctx.set_handled();
                 return;
             }
         }
-        self.inner.event(ctx, event, data, env);
+        self.current().event(ctx, event, data, env);
     }
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
         if let LifeCycle::WidgetAdded = event {
-            let sink = ctx.get_external_handle();
-            let widget_id = ctx.widget_id();
-            let future = self.future.take().unwrap()(data, env);
-            let task = async move {
-                let data = future.await;
-                sink.submit_command(
-                    ASYNC_RESPONSE,
-                    SingleUse::new(Response { value: data }),
-                    Target::Widget(widget_id),
-                )
-                .unwrap();
-            };
-
-            #[cfg(target_arch = "wasm32")]
-            wasm_bindgen_futures::spawn_local(task);
-
-            #[cfg(not(target_arch = "wasm32"))]
-            tokio::spawn(task);
+            self.spawn(ctx.get_external_handle(), ctx.widget_id(), data, env);
         }
 
-        self.inner.lifecycle(ctx, event, data, env)
+        self.loading.lifecycle(ctx, event, data, env);
+        if let Some(result) = &mut self.result {
+            result.lifecycle(ctx, event, data, env);
+        }
     }
 
-    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
-        self.inner.update(ctx, data, env)
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if matches!(&self.restart, Some(restart) if restart(old_data, data)) {
+            self.spawn(ctx.get_external_handle(), ctx.widget_id(), data, env);
+            if self.result.take().is_some() {
+                ctx.children_changed();
+            }
+        }
+        self.loading.update(ctx, data, env);
+        if let Some(result) = &mut self.result {
+            result.update(ctx, data, env);
+        }
     }
 
     fn layout(
@@ -131,10 +213,137 @@ impl<T: Data, U: 'static> Widget<T> for FutureWidget<T, U> {
         data: &T,
         env: &Env,
     ) -> druid::Size {
-        self.inner.layout(ctx, bc, data, env)
+        let current = self.current();
+        let size = current.layout(ctx, bc, data, env);
+        current.set_origin(ctx, druid::Point::ORIGIN);
+        size
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
-        self.inner.paint(ctx, data, env)
+        self.current().paint(ctx, data, env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::{Record, Recording, TestWidgetExt};
+    use druid::widget::SizedBox;
+    use druid::Point;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type QueryWidget = FutureWidget<String, usize>;
+
+    /// A future widget shared with the test, to start its futures.
+    struct Shared(Rc<RefCell<WidgetPod<String, QueryWidget>>>);
+
+    impl Widget<String> for Shared {
+        fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut String, env: &Env) {
+            self.0.borrow_mut().event(ctx, event, data, env)
+        }
+
+        fn lifecycle(
+            &mut self,
+            ctx: &mut LifeCycleCtx,
+            event: &LifeCycle,
+            data: &String,
+            env: &Env,
+        ) {
+            self.0.borrow_mut().lifecycle(ctx, event, data, env)
+        }
+
+        fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &String, data: &String, env: &Env) {
+            self.0.borrow_mut().update(ctx, data, env)
+        }
+
+        fn layout(
+            &mut self,
+            ctx: &mut LayoutCtx,
+            bc: &BoxConstraints,
+            data: &String,
+            env: &Env,
+        ) -> Size {
+            let size = self.0.borrow_mut().layout(ctx, bc, data, env);
+            self.0.borrow_mut().set_origin(ctx, Point::ORIGIN);
+            size
+        }
+
+        fn paint(&mut self, ctx: &mut PaintCtx, data: &String, env: &Env) {
+            self.0.borrow_mut().paint(ctx, data, env)
+        }
+    }
+
+    /// A runtime to spawn the futures on, which never polls them.
+    fn idle_runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn stale_results_are_ignored() {
+        let shown = Rc::new(RefCell::new(Vec::new()));
+        let widget: QueryWidget = FutureWidget::new(
+            |query: &String, _env| {
+                let len = query.len();
+                async move { len }
+            },
+            SizedBox::empty(),
+            {
+                let shown = shown.clone();
+                move |value, _data, _env| {
+                    shown.borrow_mut().push(*value);
+                    Box::new(SizedBox::empty())
+                }
+            },
+        );
+        let widget = Rc::new(RefCell::new(WidgetPod::new(widget)));
+        let id = widget.borrow().id();
+        let respond = |response| ASYNC_RESPONSE.with(SingleUse::new(response)).to(id);
+
+        let runtime = idle_runtime();
+        let _guard = runtime.enter();
+
+        Harness::create_simple(String::new(), Shared(widget.clone()), |harness| {
+            harness.send_initial_events();
+
+            // the futures for two queries are queued, the first one resolves last
+            let env = Env::empty();
+            let first = widget.borrow_mut().widget_mut().start(&"a".into(), &env);
+            let second = widget.borrow_mut().widget_mut().start(&"ab".into(), &env);
+            harness.submit_command(respond(futures::executor::block_on(second)));
+            harness.submit_command(respond(futures::executor::block_on(first)));
+
+            assert_eq!(*shown.borrow(), vec![2]);
+        });
+    }
+
+    #[test]
+    fn loading_widget_while_pending() {
+        let recording = Recording::default();
+        let widget: QueryWidget = FutureWidget::new(
+            |_query: &String, _env| std::future::pending(),
+            SizedBox::empty().record(&recording),
+            |_value, _data, _env| Box::new(SizedBox::empty()),
+        );
+
+        let runtime = idle_runtime();
+        let _guard = runtime.enter();
+
+        Harness::create_simple(String::new(), widget, |harness| {
+            harness.send_initial_events();
+            harness.paint();
+            assert!(matches!(
+                recording.next(),
+                Record::L(LifeCycle::WidgetAdded)
+            ));
+            let records: Vec<_> = recording.drain().collect();
+            assert!(records.iter().any(
+                |record| matches!(record, Record::Layout(size) if *size == Size::new(400., 400.))
+            ));
+            assert!(records.iter().any(|record| matches!(record, Record::Paint)));
+        });
     }
 }
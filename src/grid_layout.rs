@@ -0,0 +1,488 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A dashboard-like grid of tiles, which can be dragged around and resized.
+
+use druid::im::Vector;
+use druid::kurbo::Line;
+use druid::widget::prelude::*;
+use druid::{theme, Affine, Lens, Point, Rect, Vec2, WidgetPod};
+
+// the distance the mouse has to move before a tile starts being dragged
+const DRAG_THRESHOLD: f64 = 4.0;
+// the size of the resize grip in the bottom-right corner of every tile
+const GRIP_SIZE: f64 = 12.0;
+// the width used for each cell when the width of the grid is unbounded
+const DEFAULT_CELL_WIDTH: f64 = 100.0;
+
+/// The position and size of a tile in a [`GridLayout`], in grid cells.
+#[derive(Clone, Copy, Data, Debug, PartialEq, Eq, Lens)]
+pub struct GridTile {
+    pub row: usize,
+    pub col: usize,
+    pub row_span: usize,
+    pub col_span: usize,
+}
+
+impl GridTile {
+    pub fn new(row: usize, col: usize, row_span: usize, col_span: usize) -> Self {
+        GridTile {
+            row,
+            col,
+            row_span: row_span.max(1),
+            col_span: col_span.max(1),
+        }
+    }
+
+    /// Returns true if both tiles share at least one cell.
+    pub fn overlaps(&self, other: &GridTile) -> bool {
+        self.row < other.row + other.row_span
+            && other.row < self.row + self.row_span
+            && self.col < other.col + other.col_span
+            && other.col < self.col + self.col_span
+    }
+}
+
+/// Pushes the tiles overlapping the tile at `moved` downwards, and then the tiles
+/// overlapping those, until no tiles overlap anymore.
+fn resolve_collisions(tiles: &mut Vector<GridTile>, moved: usize) {
+    let mut pending = vec![moved];
+    while let Some(pusher) = pending.pop() {
+        let pushing = tiles[pusher];
+        for index in 0..tiles.len() {
+            if index != pusher && index != moved && tiles[index].overlaps(&pushing) {
+                tiles[index].row = pushing.row + pushing.row_span;
+                pending.push(index);
+            }
+        }
+    }
+}
+
+enum GridDrag {
+    Move {
+        index: usize,
+        start: Point,
+        grab: Vec2,
+        pos: Point,
+        moving: bool,
+        target: GridTile,
+    },
+    Resize {
+        index: usize,
+        target: GridTile,
+    },
+}
+
+/// A grid of tiles, for building dashboards.
+///
+/// The `i`th child is placed in the cells described by the `i`th [`GridTile`] of the
+/// data the lens points to. Tiles can be dragged to other cells and resized in cell
+/// increments with the grip in their bottom-right corner. When a tile is dropped on
+/// other tiles, those are pushed downwards to make room for it.
+pub struct GridLayout<T, L> {
+    children: Vec<WidgetPod<T, Box<dyn Widget<T>>>>,
+    tiles: L,
+    columns: usize,
+    row_height: f64,
+    spacing: f64,
+    cell_width: f64,
+    drag: Option<GridDrag>,
+}
+
+impl<T: Data, L: Lens<T, Vector<GridTile>>> GridLayout<T, L> {
+    /// Create a new `GridLayout` with the given number of columns, using `tiles`
+    /// to access the tile positions.
+    pub fn new(tiles: L, columns: usize) -> Self {
+        GridLayout {
+            children: Vec::new(),
+            tiles,
+            columns: columns.max(1),
+            row_height: 100.0,
+            spacing: 8.0,
+            cell_width: DEFAULT_CELL_WIDTH,
+            drag: None,
+        }
+    }
+
+    /// Builder-style method to add a tile.
+    pub fn with_child(mut self, child: impl Widget<T> + 'static) -> Self {
+        self.add_child(child);
+        self
+    }
+
+    /// Add a tile.
+    pub fn add_child(&mut self, child: impl Widget<T> + 'static) {
+        self.children.push(WidgetPod::new(Box::new(child)));
+    }
+
+    /// Builder-style method to set the height of a grid row.
+    pub fn row_height(mut self, row_height: f64) -> Self {
+        self.set_row_height(row_height);
+        self
+    }
+
+    /// Set the height of a grid row.
+    pub fn set_row_height(&mut self, row_height: f64) {
+        self.row_height = row_height;
+    }
+
+    /// Builder-style method to set the spacing between tiles.
+    pub fn spacing(mut self, spacing: f64) -> Self {
+        self.set_spacing(spacing);
+        self
+    }
+
+    /// Set the spacing between tiles.
+    pub fn set_spacing(&mut self, spacing: f64) {
+        self.spacing = spacing;
+    }
+
+    fn tile_rect(&self, tile: &GridTile) -> Rect {
+        let x = self.spacing + tile.col as f64 * (self.cell_width + self.spacing);
+        let y = self.spacing + tile.row as f64 * (self.row_height + self.spacing);
+        let width = tile.col_span as f64 * (self.cell_width + self.spacing) - self.spacing;
+        let height = tile.row_span as f64 * (self.row_height + self.spacing) - self.spacing;
+        Rect::new(x, y, x + width, y + height)
+    }
+
+    fn grip_rect(&self, tile: &GridTile) -> Rect {
+        let rect = self.tile_rect(tile);
+        Rect::new(rect.x1 - GRIP_SIZE, rect.y1 - GRIP_SIZE, rect.x1, rect.y1)
+    }
+
+    /// The tile under `pos`, and whether `pos` is on its resize grip.
+    fn hit_test(&self, pos: Point, data: &T) -> Option<(usize, GridTile, bool)> {
+        self.tiles.with(data, |tiles| {
+            tiles
+                .iter()
+                .take(self.children.len())
+                .enumerate()
+                .rev()
+                .find(|(_, tile)| self.tile_rect(tile).contains(pos))
+                .map(|(index, tile)| (index, *tile, self.grip_rect(tile).contains(pos)))
+        })
+    }
+
+    /// Where a tile whose top left corner is at `origin` snaps to.
+    fn snap_origin(&self, tile: &GridTile, origin: Point) -> GridTile {
+        let col = ((origin.x - self.spacing) / (self.cell_width + self.spacing)).round();
+        let row = ((origin.y - self.spacing) / (self.row_height + self.spacing)).round();
+        let max_col = self.columns.saturating_sub(tile.col_span);
+        GridTile {
+            row: row.max(0.) as usize,
+            col: (col.max(0.) as usize).min(max_col),
+            ..*tile
+        }
+    }
+
+    /// The spans of a tile whose bottom right corner is dragged to `corner`. A tile
+    /// starting past the last column, in data made for a wider grid, keeps one column.
+    fn snap_corner(&self, tile: &GridTile, corner: Point) -> GridTile {
+        let rect = self.tile_rect(tile);
+        let col_span = ((corner.x - rect.x0 + self.spacing) / (self.cell_width + self.spacing))
+            .round()
+            .max(1.) as usize;
+        let row_span = ((corner.y - rect.y0 + self.spacing) / (self.row_height + self.spacing))
+            .round()
+            .max(1.) as usize;
+        GridTile {
+            col_span: col_span.min(self.columns.saturating_sub(tile.col).max(1)),
+            row_span,
+            ..*tile
+        }
+    }
+}
+
+impl<T: Data, L: Lens<T, Vector<GridTile>>> Widget<T> for GridLayout<T, L> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::MouseDown(mouse) = event {
+            match self.hit_test(mouse.pos, data) {
+                Some((index, tile, true)) if mouse.button.is_left() => {
+                    self.drag = Some(GridDrag::Resize {
+                        index,
+                        target: tile,
+                    });
+                    ctx.set_active(true);
+                    ctx.set_handled();
+                    ctx.request_paint();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        for child in &mut self.children {
+            child.event(ctx, event, data, env);
+        }
+
+        match event {
+            Event::MouseDown(mouse) if mouse.button.is_left() && !ctx.is_handled() => {
+                if let Some((index, tile, _)) = self.hit_test(mouse.pos, data) {
+                    self.drag = Some(GridDrag::Move {
+                        index,
+                        start: mouse.pos,
+                        grab: mouse.pos - self.tile_rect(&tile).origin(),
+                        pos: mouse.pos,
+                        moving: false,
+                        target: tile,
+                    });
+                    ctx.set_active(true);
+                }
+            }
+            Event::MouseMove(mouse) => {
+                let new_target = match &self.drag {
+                    Some(GridDrag::Move {
+                        start,
+                        grab,
+                        moving,
+                        target,
+                        ..
+                    }) => {
+                        let moving = *moving || (mouse.pos - *start).hypot() > DRAG_THRESHOLD;
+                        moving.then(|| self.snap_origin(target, mouse.pos - *grab))
+                    }
+                    Some(GridDrag::Resize { target, .. }) => {
+                        Some(self.snap_corner(target, mouse.pos))
+                    }
+                    None => None,
+                };
+                match &mut self.drag {
+                    Some(GridDrag::Move {
+                        pos,
+                        moving,
+                        target,
+                        ..
+                    }) => {
+                        *pos = mouse.pos;
+                        if let Some(new_target) = new_target {
+                            *moving = true;
+                            *target = new_target;
+                        }
+                        ctx.request_paint();
+                    }
+                    Some(GridDrag::Resize { target, .. }) => {
+                        if let Some(new_target) = new_target {
+                            *target = new_target;
+                        }
+                        ctx.request_paint();
+                    }
+                    None => {}
+                }
+            }
+            Event::MouseUp(_) => {
+                if let Some(drag) = self.drag.take() {
+                    let (index, target) = match drag {
+                        GridDrag::Move { index, target, .. } => (index, target),
+                        GridDrag::Resize { index, target } => (index, target),
+                    };
+                    self.tiles
+                        .with_mut(data, |tiles| match tiles.get_mut(index) {
+                            Some(tile) if *tile != target => {
+                                *tile = target;
+                                resolve_collisions(tiles, index);
+                            }
+                            _ => {}
+                        });
+                    ctx.set_active(false);
+                    ctx.request_paint();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        for child in &mut self.children {
+            child.lifecycle(ctx, event, data, env);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        let (tiles_changed, count_changed) = self.tiles.with(old_data, |old| {
+            self.tiles
+                .with(data, |new| (!old.same(new), old.len() != new.len()))
+        });
+        if tiles_changed {
+            ctx.request_layout();
+        }
+        // the index of the dragged tile may not point to the same tile anymore
+        if count_changed && self.drag.take().is_some() {
+            ctx.request_paint();
+        }
+        for child in &mut self.children {
+            child.update(ctx, data, env);
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let columns = self.columns as f64;
+        self.cell_width = if bc.max().width.is_finite() {
+            ((bc.max().width - self.spacing * (columns + 1.)) / columns).max(0.)
+        } else {
+            DEFAULT_CELL_WIDTH
+        };
+
+        let (rects, rows) = self.tiles.with(data, |tiles| {
+            let rects: Vec<Rect> = (0..self.children.len())
+                .map(|index| match tiles.get(index) {
+                    Some(tile) => self.tile_rect(tile),
+                    None => Rect::ZERO,
+                })
+                .collect();
+            let rows = tiles
+                .iter()
+                .take(self.children.len())
+                .map(|tile| tile.row + tile.row_span)
+                .max()
+                .unwrap_or(0);
+            (rects, rows)
+        });
+        for (child, rect) in self.children.iter_mut().zip(rects) {
+            child.layout(ctx, &BoxConstraints::tight(rect.size()), data, env);
+            child.set_origin(ctx, rect.origin());
+        }
+
+        let width = self.spacing + columns * (self.cell_width + self.spacing);
+        let height = self.spacing + rows as f64 * (self.row_height + self.spacing);
+        bc.constrain(Size::new(width, height))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let (dragged, offset) = match &self.drag {
+            Some(GridDrag::Move {
+                index,
+                pos,
+                grab,
+                moving: true,
+                target,
+                ..
+            }) => {
+                let placeholder = self.tile_rect(target);
+                let color = env.get(theme::PRIMARY_DARK).with_alpha(0.3);
+                ctx.fill(placeholder, &color);
+                let origin = self.children[*index].layout_rect().origin();
+                (Some(*index), *pos - *grab - origin)
+            }
+            Some(GridDrag::Resize { target, .. }) => {
+                let placeholder = self.tile_rect(target);
+                let color = env.get(theme::PRIMARY_DARK).with_alpha(0.3);
+                ctx.fill(placeholder, &color);
+                (None, Vec2::ZERO)
+            }
+            _ => (None, Vec2::ZERO),
+        };
+
+        let grip_color = env.get(theme::BORDER_LIGHT);
+        for (index, child) in self.children.iter_mut().enumerate() {
+            if Some(index) != dragged {
+                child.paint(ctx, data, env);
+                let rect = child.layout_rect();
+                let corner = Point::new(rect.x1, rect.y1);
+                for step in 1..=3 {
+                    let inset = step as f64 * GRIP_SIZE / 4.;
+                    let line = Line::new(
+                        (corner.x - inset, corner.y - 2.),
+                        (corner.x - 2., corner.y - inset),
+                    );
+                    ctx.stroke(line, &grip_color, 1.);
+                }
+            }
+        }
+
+        // the dragged tile is painted last, above the other tiles
+        if let Some(index) = dragged {
+            ctx.with_save(|ctx| {
+                ctx.transform(Affine::translate(offset));
+                self.children[index].paint(ctx, data, env);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WidgetExt as _;
+    use druid::tests::harness::Harness;
+    use druid::widget::SizedBox;
+    use druid::{MouseButton, MouseEvent, Selector};
+
+    fn mouse(pos: Point) -> MouseEvent {
+        MouseEvent {
+            pos,
+            window_pos: pos,
+            buttons: Default::default(),
+            mods: Default::default(),
+            count: 1,
+            focus: false,
+            button: MouseButton::Left,
+            wheel_delta: Default::default(),
+        }
+    }
+
+    #[test]
+    fn dropped_tile_pushes_others_down() {
+        let mut tiles = Vector::from(vec![
+            GridTile::new(0, 0, 1, 2),
+            GridTile::new(1, 0, 1, 1),
+            GridTile::new(2, 0, 2, 1),
+        ]);
+        // drop the first tile onto the second one
+        tiles[0] = GridTile::new(1, 0, 1, 2);
+        resolve_collisions(&mut tiles, 0);
+
+        assert_eq!(tiles[0], GridTile::new(1, 0, 1, 2));
+        assert_eq!(tiles[1], GridTile::new(2, 0, 1, 1));
+        assert_eq!(tiles[2], GridTile::new(3, 0, 2, 1));
+        for (i, a) in tiles.iter().enumerate() {
+            for b in tiles.iter().skip(i + 1) {
+                assert!(!a.overlaps(b));
+            }
+        }
+    }
+
+    #[test]
+    fn resize_within_columns() {
+        let grid = GridLayout::<Vector<GridTile>, _>::new(druid::lens::Identity, 4);
+        let corner =
+            |cols: f64| Point::new(grid.spacing + cols * (grid.cell_width + grid.spacing), 50.0);
+
+        let tile = GridTile::new(0, 1, 1, 1);
+        assert_eq!(grid.snap_corner(&tile, corner(3.0)).col_span, 2);
+        // the span stops at the last column
+        assert_eq!(grid.snap_corner(&tile, corner(10.0)).col_span, 3);
+        // a tile past the last column
+        let tile = GridTile::new(0, 6, 1, 1);
+        assert_eq!(grid.snap_corner(&tile, corner(10.0)).col_span, 1);
+    }
+
+    #[test]
+    fn tiles_removed_while_dragging() {
+        const REMOVE_LAST: Selector = Selector::new("druid-widget-nursery.test.remove-last");
+
+        let grid = GridLayout::new(druid::lens::Identity, 4)
+            .with_child(SizedBox::empty())
+            .with_child(SizedBox::empty())
+            .on_command(REMOVE_LAST, |_, _, tiles: &mut Vector<GridTile>| {
+                tiles.pop_back();
+            });
+        let tiles = Vector::from(vec![GridTile::new(0, 0, 1, 1), GridTile::new(0, 1, 1, 1)]);
+
+        Harness::create_simple(tiles, grid, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+
+            // drag the second tile, which is removed before it is dropped
+            harness.event(Event::MouseDown(mouse(Point::new(120.0, 20.0))));
+            harness.event(Event::MouseMove(mouse(Point::new(300.0, 20.0))));
+            harness.submit_command(REMOVE_LAST);
+            harness.event(Event::MouseMove(mouse(Point::new(320.0, 20.0))));
+            harness.event(Event::MouseUp(mouse(Point::new(320.0, 20.0))));
+
+            assert_eq!(
+                harness.data(),
+                &Vector::from(vec![GridTile::new(0, 0, 1, 1)])
+            );
+        });
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A label which emphasizes parts of its text, for example to show search matches.
+
+use std::ops::Range;
+
+use druid::text::{Attribute, RichText};
+use druid::widget::prelude::*;
+use druid::{theme, Color, FontWeight, KeyOrValue, Point, TextLayout};
+
+type MatchFn<T> = Box<dyn Fn(&T) -> (String, Vec<Range<usize>>)>;
+
+/// A label which renders some ranges of its text in a highlight color, and
+/// optionally on a highlight background.
+///
+/// The text and the highlighted ranges are computed from the data, either
+/// directly or by matching a query against the text with [`substring_matches`]
+/// or [`fuzzy_matches`].
+pub struct HighlightLabel<T> {
+    matcher: MatchFn<T>,
+    text: String,
+    ranges: Vec<Range<usize>>,
+    layout: TextLayout<RichText>,
+    highlight_color: KeyOrValue<Color>,
+    highlight_background: Option<KeyOrValue<Color>>,
+}
+
+impl<T: Data> HighlightLabel<T> {
+    /// Create a new `HighlightLabel` from closures returning the text and the
+    /// byte ranges of the text to highlight.
+    pub fn new(
+        text: impl Fn(&T) -> String + 'static,
+        ranges: impl Fn(&T) -> Vec<Range<usize>> + 'static,
+    ) -> Self {
+        Self::from_matcher(Box::new(move |data| (text(data), ranges(data))))
+    }
+
+    /// Create a new `HighlightLabel` highlighting every case-insensitive occurrence
+    /// of the query in the text.
+    pub fn substring(
+        text: impl Fn(&T) -> String + 'static,
+        query: impl Fn(&T) -> String + 'static,
+    ) -> Self {
+        Self::from_matcher(Box::new(move |data| {
+            let text = text(data);
+            let ranges = substring_matches(&text, &query(data));
+            (text, ranges)
+        }))
+    }
+
+    /// Create a new `HighlightLabel` highlighting the characters of the text
+    /// matched by the query with [`fuzzy_matches`].
+    pub fn fuzzy(
+        text: impl Fn(&T) -> String + 'static,
+        query: impl Fn(&T) -> String + 'static,
+    ) -> Self {
+        Self::from_matcher(Box::new(move |data| {
+            let text = text(data);
+            let ranges = fuzzy_matches(&text, &query(data)).unwrap_or_default();
+            (text, ranges)
+        }))
+    }
+
+    fn from_matcher(matcher: MatchFn<T>) -> Self {
+        HighlightLabel {
+            matcher,
+            text: String::new(),
+            ranges: Vec::new(),
+            layout: TextLayout::new(),
+            highlight_color: theme::PRIMARY_LIGHT.into(),
+            highlight_background: None,
+        }
+    }
+
+    /// Builder-style method for setting the color of highlighted text.
+    pub fn with_highlight_color(mut self, color: impl Into<KeyOrValue<Color>>) -> Self {
+        self.set_highlight_color(color);
+        self
+    }
+
+    /// Set the color of highlighted text.
+    pub fn set_highlight_color(&mut self, color: impl Into<KeyOrValue<Color>>) {
+        self.highlight_color = color.into();
+        self.rebuild_text();
+    }
+
+    /// Builder-style method for setting the background painted behind highlighted text.
+    pub fn with_highlight_background(mut self, color: impl Into<KeyOrValue<Color>>) -> Self {
+        self.set_highlight_background(color);
+        self
+    }
+
+    /// Set the background painted behind highlighted text.
+    pub fn set_highlight_background(&mut self, color: impl Into<KeyOrValue<Color>>) {
+        self.highlight_background = Some(color.into());
+    }
+
+    /// Recomputes the text and the ranges, returns true if they changed.
+    fn update_matches(&mut self, data: &T) -> bool {
+        let (text, ranges) = (self.matcher)(data);
+        if text == self.text && ranges == self.ranges {
+            return false;
+        }
+        self.text = text;
+        self.ranges = ranges;
+        self.rebuild_text();
+        true
+    }
+
+    fn rebuild_text(&mut self) {
+        let mut rich_text = RichText::new(self.text.as_str().into());
+        for range in &self.ranges {
+            rich_text.add_attribute(
+                range.clone(),
+                Attribute::text_color(self.highlight_color.clone()),
+            );
+            rich_text.add_attribute(range.clone(), Attribute::weight(FontWeight::BOLD));
+        }
+        self.layout.set_text(rich_text);
+    }
+}
+
+impl<T: Data> Widget<T> for HighlightLabel<T> {
+    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut T, _env: &Env) {}
+
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, _env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            self.update_matches(data);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, _env: &Env) {
+        if (!old_data.same(data) && self.update_matches(data))
+            || self.layout.needs_rebuild_after_update(ctx)
+        {
+            ctx.request_layout();
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, env: &Env) -> Size {
+        self.layout.set_wrap_width(bc.max().width);
+        self.layout.rebuild_if_needed(ctx.text(), env);
+        bc.constrain(self.layout.size())
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, env: &Env) {
+        if let Some(background) = &self.highlight_background {
+            let background = background.resolve(env);
+            for range in &self.ranges {
+                for rect in self.layout.rects_for_range(range.clone()) {
+                    ctx.fill(rect.to_rounded_rect(2.), &background);
+                }
+            }
+        }
+        self.layout.draw(ctx, Point::ORIGIN);
+    }
+}
+
+/// Returns the byte ranges of all non-overlapping, case-insensitive occurrences
+/// of `query` in `text`.
+pub fn substring_matches(text: &str, query: &str) -> Vec<Range<usize>> {
+    let mut matches = Vec::new();
+    if query.is_empty() {
+        return matches;
+    }
+    let mut start = 0;
+    while start < text.len() {
+        let rest = &text[start..];
+        match match_prefix(rest, query) {
+            Some(len) => {
+                matches.push(start..start + len);
+                start += len;
+            }
+            None => start += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    matches
+}
+
+/// Returns the byte length of the prefix of `text` matching `query`, ignoring case.
+fn match_prefix(text: &str, query: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+    for query_char in query.chars() {
+        let (_, text_char) = chars.next()?;
+        if !text_char.to_lowercase().eq(query_char.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(text.len(), |(index, _)| index))
+}
+
+/// Matches the characters of `query`, in order and ignoring case, against `text`.
+///
+/// Returns the byte ranges of the matched characters, with consecutive characters
+/// merged into one range, or `None` if not every character of the query was found.
+pub fn fuzzy_matches(text: &str, query: &str) -> Option<Vec<Range<usize>>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut chars = text.char_indices();
+    for query_char in query.chars() {
+        let (index, text_char) = chars
+            .by_ref()
+            .find(|(_, c)| c.to_lowercase().eq(query_char.to_lowercase()))?;
+        let end = index + text_char.len_utf8();
+        match ranges.last_mut() {
+            Some(last) if last.end == index => last.end = end,
+            _ => ranges.push(index..end),
+        }
+    }
+    Some(ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substring() {
+        assert_eq!(substring_matches("Foo bar foo", "foo"), vec![0..3, 8..11]);
+        assert_eq!(substring_matches("Grüße", "SSE"), vec![]);
+        assert_eq!(substring_matches("Grüße", "üß"), vec![2..6]);
+        assert_eq!(substring_matches("abc", ""), vec![]);
+    }
+
+    #[test]
+    fn fuzzy() {
+        assert_eq!(
+            fuzzy_matches("TreeNode", "tnd"),
+            Some(vec![0..1, 4..5, 6..7])
+        );
+        assert_eq!(fuzzy_matches("TreeNode", "rnode"), Some(vec![1..2, 4..8]));
+        assert_eq!(fuzzy_matches("TreeNode", "x"), None);
+    }
+}
@@ -4,19 +4,74 @@
 use libloading::{Library, Symbol};
 use notify5::{Event, RecommendedWatcher, RecursiveMode, Watcher};
 use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
 
+/// A type for errors that occur when watching or loading the reloaded library, or when
+/// serializing the app data given to it.
+#[derive(Debug)]
+pub enum HotReloadError {
+    IoError(io::Error),
+    WatchError(notify5::Error),
+    LoadError(libloading::Error),
+    MissingSymbol(&'static str),
+    SerializeError(serde_json::Error),
+}
+
+impl std::fmt::Display for HotReloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::IoError(err) => write!(f, "io error loading library: '{err}'"),
+            Self::WatchError(err) => write!(f, "failed to watch library: '{err}'"),
+            Self::LoadError(err) => write!(f, "failed to load library: '{err}'"),
+            Self::MissingSymbol(name) => write!(f, "library has no function '{name}'"),
+            Self::SerializeError(err) => write!(f, "failed to serialize app data: '{err}'"),
+        }
+    }
+}
+
+impl std::error::Error for HotReloadError {}
+
+impl From<io::Error> for HotReloadError {
+    fn from(src: io::Error) -> HotReloadError {
+        HotReloadError::IoError(src)
+    }
+}
+
+impl From<notify5::Error> for HotReloadError {
+    fn from(src: notify5::Error) -> HotReloadError {
+        HotReloadError::WatchError(src)
+    }
+}
+
+impl From<serde_json::Error> for HotReloadError {
+    fn from(src: serde_json::Error) -> HotReloadError {
+        HotReloadError::SerializeError(src)
+    }
+}
+
+impl From<libloading::Error> for HotReloadError {
+    fn from(src: libloading::Error) -> HotReloadError {
+        HotReloadError::LoadError(src)
+    }
+}
+
 pub struct HotReloadLib {
     lib_path: PathBuf,
-    temp_path: PathBuf,
+    temp_path: Option<PathBuf>,
     library: Option<Library>,
     _watcher: RecommendedWatcher,
 }
 
 impl HotReloadLib {
-    pub fn new(path: impl AsRef<Path>, on_reload: impl Fn() + Send + Sync + 'static) -> Self {
-        let lib_path = path.as_ref().canonicalize().unwrap();
-        let (lib, temp_path) = copy_and_load_library(&lib_path);
+    /// Watch the library at `path`, calling `on_reload` when it is rebuilt.
+    ///
+    /// The library is loaded by [`update`](HotReloadLib::update).
+    pub fn new(
+        path: impl AsRef<Path>,
+        on_reload: impl Fn() + Send + Sync + 'static,
+    ) -> Result<Self, HotReloadError> {
+        let lib_path = path.as_ref().canonicalize()?;
         let mut watcher = notify5::recommended_watcher({
             let lib_path = lib_path.clone();
             move |ev| {
@@ -29,49 +84,74 @@ impl HotReloadLib {
                     on_reload();
                 }
             }
-        })
-        .unwrap();
-        watcher
-            .watch(lib_path.parent().unwrap(), RecursiveMode::NonRecursive)
-            .unwrap();
+        })?;
+        // a canonical path to a file has a parent
+        watcher.watch(lib_path.parent().unwrap(), RecursiveMode::NonRecursive)?;
 
-        HotReloadLib {
-            temp_path,
+        Ok(HotReloadLib {
+            temp_path: None,
             lib_path,
-            library: Some(lib),
+            library: None,
             _watcher: watcher,
-        }
+        })
     }
 
     pub unsafe fn load_symbol<Signature>(&self, symbol_name: &str) -> Option<Symbol<Signature>> {
-        let lib = self.library.as_ref().unwrap();
+        let lib = self.library.as_ref()?;
         lib.get(symbol_name.as_bytes()).ok()
     }
 
-    pub fn update(&mut self) {
-        self.library = None;
-        fs::remove_file(&self.temp_path).unwrap();
-        let (library, path) = copy_and_load_library(&self.lib_path);
+    /// Load the current version of the library, unloading the previous one.
+    pub fn update(&mut self) -> Result<(), HotReloadError> {
+        self.unload();
+        let (library, path) = copy_and_load_library(&self.lib_path)?;
         self.library = Some(library);
-        self.temp_path = path;
+        self.temp_path = Some(path);
+        Ok(())
+    }
+
+    fn unload(&mut self) {
+        self.library = None;
+        if let Some(temp_path) = self.temp_path.take() {
+            if let Err(e) = fs::remove_file(&temp_path) {
+                log::warn!("failed to remove '{}': {}", temp_path.display(), e);
+            }
+        }
     }
 }
 
 impl Drop for HotReloadLib {
     fn drop(&mut self) {
-        fs::remove_file(&self.temp_path).unwrap();
+        self.unload();
     }
 }
 
-fn copy_and_load_library(lib_path: &Path) -> (Library, PathBuf) {
+fn copy_and_load_library(lib_path: &Path) -> Result<(Library, PathBuf), HotReloadError> {
     let unique_path = {
         let mut path = std::env::temp_dir();
         path.push(rand::random::<u64>().to_string());
-        path.set_extension(lib_path.extension().unwrap());
+        if let Some(extension) = lib_path.extension() {
+            path.set_extension(extension);
+        }
         path
     };
-    fs::copy(lib_path, &unique_path).expect("Failed to copy lib to unique path");
-    let lib = Library::new(unique_path.as_os_str()).expect("Failed to load library");
+    fs::copy(lib_path, &unique_path)?;
+    match Library::new(unique_path.as_os_str()) {
+        Ok(lib) => Ok((lib, unique_path)),
+        Err(e) => {
+            let _ = fs::remove_file(&unique_path);
+            Err(e.into())
+        }
+    }
+}
 
-    (lib, unique_path)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_library() {
+        let result = HotReloadLib::new("target/no-such-dir/libmissing.so", || ());
+        assert!(matches!(result, Err(HotReloadError::IoError(_))));
+    }
 }
@@ -1,15 +1,42 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-// TODO: survive a change in AppData.
+//! Reloading the widgets of an app from a dynamic library while it runs.
+//!
+//! The app data crosses the boundary with the library serialized as JSON, so the
+//! app data must implement `Serialize` and `Deserialize`. The view function of the
+//! library has the signature `fn() -> Box<dyn Widget<SerializedData>>`, and builds
+//! its widget with [`serialized`]:
+//!
+//! ```
+//! use druid::widget::{Label, Widget};
+//! use druid_widget_nursery::hot_reload::{serialized, SerializedData};
+//!
+//! #[derive(Clone, druid::Data, serde::Serialize, serde::Deserialize)]
+//! pub struct AppData {
+//!     name: String,
+//! }
+//!
+//! #[no_mangle]
+//! pub fn view() -> Box<dyn Widget<SerializedData>> {
+//!     serialized(Label::new(|data: &AppData, _: &_| data.name.clone()))
+//! }
+//! ```
+//!
+//! When the app data is changed in the library, the data of the app is deserialized
+//! into the new version, and nothing is shown while this fails.
 
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex};
 
+mod serialized;
 mod widget;
 
-use druid::{Data, Env, ExtEventSink, PlatformError, Selector, Target};
+use druid::{AppDelegate, Data, Env, ExtEventSink, PlatformError, Selector, Target};
+pub use hot_reload_lib::HotReloadError;
 use hot_reload_lib::HotReloadLib;
+use serde::{de::DeserializeOwned, Serialize};
+pub use serialized::{serialized, SerializedData};
 use widget::HotReloaderWidget;
 
 mod hot_reload_lib;
@@ -27,7 +54,7 @@ pub struct WindowDesc<T> {
     _ty: PhantomData<*const T>,
 }
 
-impl<T: Data> WindowDesc<T> {
+impl<T: Data + Serialize + DeserializeOwned> WindowDesc<T> {
     pub fn new(lib_path: &'static str, view: &'static str) -> Self {
         Self {
             lib_path,
@@ -36,33 +63,44 @@ impl<T: Data> WindowDesc<T> {
         }
     }
 
-    fn build(self, sink: Arc<Mutex<Option<ExtEventSink>>>) -> druid::WindowDesc<T> {
+    fn build(
+        self,
+        sink: Arc<Mutex<Option<ExtEventSink>>>,
+    ) -> Result<druid::WindowDesc<T>, HotReloadError> {
         let lib_path = self.lib_path;
         let view_fn_name = self.view;
         let hot_reloader_widget = HotReloaderWidget {
             lib: HotReloadLib::new(lib_path, move || {
                 let sink = sink.lock().unwrap();
-                let sink = sink.as_ref().unwrap();
-                sink.submit_command(RELOAD, (), Target::Global).unwrap();
-            }),
+                if let Some(sink) = sink.as_ref() {
+                    if let Err(e) = sink.submit_command(RELOAD, (), Target::Global) {
+                        log::error!("failed to request a reload: {}", e);
+                    }
+                }
+            })?,
             inner: None,
             view_fn_name,
+            serialized: None,
+            retry: None,
         };
-        druid::WindowDesc::new(hot_reloader_widget)
+        Ok(druid::WindowDesc::new(hot_reloader_widget))
     }
 }
 
-impl<T: Data> AppLauncher<T> {
+impl<T: Data + Serialize + DeserializeOwned> AppLauncher<T> {
     /// Create a new `AppLauncher` with the provided window.
-    pub fn with_window(window: WindowDesc<T>) -> Self {
+    ///
+    /// Returns an error if the library of the window can't be watched, for example when
+    /// it doesn't exist. Loading the library is retried while the app runs.
+    pub fn with_window(window: WindowDesc<T>) -> Result<Self, HotReloadError> {
         let sink = Arc::default();
-        let window = window.build(Arc::clone(&sink));
+        let window = window.build(Arc::clone(&sink))?;
         let inner = druid::AppLauncher::with_window(window);
 
         let mut sink = sink.lock().unwrap();
         *sink = Some(inner.get_external_handle());
 
-        Self { inner }
+        Ok(Self { inner })
     }
 
     /// Provide an optional closure that will be given mutable access to
@@ -74,14 +112,16 @@ impl<T: Data> AppLauncher<T> {
         self
     }
 
-    // TODO: add delegate
-    // /// Set the [`AppDelegate`].
-    // ///
-    // /// [`AppDelegate`]: trait.AppDelegate.html
-    // pub fn delegate(mut self, delegate: impl AppDelegate<T> + 'static) -> Self {
-    //     self.inner = self.inner.delegate(delegate);
-    //     self
-    // }
+    /// Set the [`AppDelegate`].
+    ///
+    /// The delegate is part of the app rather than of the reloaded library, so it keeps
+    /// its state when the library is reloaded.
+    ///
+    /// [`AppDelegate`]: druid::AppDelegate
+    pub fn delegate(mut self, delegate: impl AppDelegate<T> + 'static) -> Self {
+        self.inner = self.inner.delegate(delegate);
+        self
+    }
 
     /// Initialize a minimal logger for printing logs out to stderr.
     ///
@@ -0,0 +1,190 @@
+// Copyright 2021 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use druid::{widget::prelude::*, ArcStr, WidgetPod};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// The app data given to the widget of the reloaded library, serialized as JSON.
+///
+/// The library deserializes it into its own version of the app data, so a change of
+/// the app data only makes the data fail to deserialize, rather than reinterpreting
+/// the data of the app with another layout.
+#[derive(Debug, Clone, Data)]
+pub struct SerializedData(ArcStr);
+
+impl SerializedData {
+    pub(super) fn new<T: Serialize>(data: &T) -> Result<Self, serde_json::Error> {
+        serde_json::to_string(data).map(|json| SerializedData(json.into()))
+    }
+
+    pub(super) fn get<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.0)
+    }
+}
+
+/// Wraps the widget of the view function of the reloaded library, which is given the
+/// app data as [`SerializedData`].
+///
+/// Nothing is shown while the data can't be deserialized into `T`, and the error is
+/// logged. Fields added to the app data should have a `#[serde(default)]` attribute, so
+/// that the data of the app still deserializes into the new version.
+///
+/// ```
+/// use druid::widget::{Label, Widget};
+/// use druid_widget_nursery::hot_reload::{serialized, SerializedData};
+///
+/// #[derive(Clone, druid::Data, serde::Serialize, serde::Deserialize)]
+/// pub struct AppData {
+///     name: String,
+/// }
+///
+/// #[no_mangle]
+/// pub fn view() -> Box<dyn Widget<SerializedData>> {
+///     serialized(Label::new(|data: &AppData, _: &_| data.name.clone()))
+/// }
+/// ```
+pub fn serialized<T: Data + Serialize + DeserializeOwned>(
+    widget: impl Widget<T> + 'static,
+) -> Box<dyn Widget<SerializedData>> {
+    Box::new(SerializedView {
+        inner: WidgetPod::new(Box::new(widget)),
+        data: None,
+    })
+}
+
+struct SerializedView<T> {
+    inner: WidgetPod<T, Box<dyn Widget<T>>>,
+    /// The deserialized data, if it could be deserialized.
+    data: Option<T>,
+}
+
+impl<T: DeserializeOwned> SerializedView<T> {
+    fn deserialize(&mut self, data: &SerializedData) {
+        self.data = data
+            .get()
+            .map_err(|e| log::error!("failed to deserialize the app data: {}", e))
+            .ok();
+    }
+}
+
+impl<T: Data + Serialize + DeserializeOwned> Widget<SerializedData> for SerializedView<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut SerializedData, env: &Env) {
+        if let Some(inner_data) = &mut self.data {
+            let old_data = inner_data.clone();
+            self.inner.event(ctx, event, inner_data, env);
+            if !old_data.same(inner_data) {
+                match SerializedData::new(inner_data) {
+                    Ok(new_data) => *data = new_data,
+                    Err(e) => log::error!("failed to serialize the app data: {}", e),
+                }
+            }
+        }
+    }
+
+    fn lifecycle(
+        &mut self,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &SerializedData,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            self.deserialize(data);
+        }
+        if let Some(inner_data) = &self.data {
+            self.inner.lifecycle(ctx, event, inner_data, env);
+        }
+    }
+
+    fn update(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        old_data: &SerializedData,
+        data: &SerializedData,
+        env: &Env,
+    ) {
+        if !old_data.same(data) {
+            let was_shown = self.data.is_some();
+            self.deserialize(data);
+            if was_shown != self.data.is_some() {
+                ctx.children_changed();
+            }
+        }
+        if let Some(inner_data) = &self.data {
+            if self.inner.is_initialized() {
+                self.inner.update(ctx, inner_data, env);
+            }
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        _data: &SerializedData,
+        env: &Env,
+    ) -> Size {
+        match &self.data {
+            Some(inner_data) if self.inner.is_initialized() => {
+                let size = self.inner.layout(ctx, bc, inner_data, env);
+                self.inner.set_origin(ctx, (0.0, 0.0).into());
+                size
+            }
+            _ => bc.min(),
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _data: &SerializedData, env: &Env) {
+        if let Some(inner_data) = &self.data {
+            if self.inner.is_initialized() {
+                self.inner.paint(ctx, inner_data, env);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct AppData {
+        name: String,
+    }
+
+    /// The app data after adding a field in the library.
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NewAppData {
+        name: String,
+        #[serde(default)]
+        checked: bool,
+    }
+
+    /// The app data after changing the type of a field in the library.
+    #[derive(Debug, Deserialize)]
+    struct ChangedAppData {
+        #[allow(dead_code)]
+        name: u32,
+    }
+
+    #[test]
+    fn changed_app_data() {
+        let name = "druid".to_string();
+        let data = SerializedData::new(&AppData { name: name.clone() }).unwrap();
+
+        let new_data: NewAppData = data.get().unwrap();
+        assert_eq!(
+            new_data,
+            NewAppData {
+                name: name.clone(),
+                checked: false
+            }
+        );
+        // the app gets the data back from the library
+        let data = SerializedData::new(&new_data).unwrap();
+        assert_eq!(data.get::<AppData>().unwrap(), AppData { name });
+
+        assert!(data.get::<ChangedAppData>().is_err());
+    }
+}
@@ -1,40 +1,117 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use super::{hot_reload_lib::HotReloadLib, RELOAD};
-use druid::{widget::prelude::*, WidgetPod};
+use super::{hot_reload_lib::HotReloadLib, HotReloadError, SerializedData, RELOAD};
+use druid::{widget::prelude::*, TimerToken, WidgetPod};
+use serde::{de::DeserializeOwned, Serialize};
+use std::time::Duration;
+
+/// How many times loading the library is tried, as it may still be written by cargo.
+const LOAD_ATTEMPTS: u32 = 5;
+/// The wait before the first retry, doubled for each following one.
+const LOAD_BACKOFF: Duration = Duration::from_millis(50);
+
+type ViewFn = fn() -> Box<dyn Widget<SerializedData>>;
 
 pub struct HotReloaderWidget<T> {
     pub(super) lib: HotReloadLib,
-    pub(super) inner: Option<WidgetPod<T, Box<dyn Widget<T>>>>,
+    pub(super) inner: Option<WidgetPod<SerializedData, Box<dyn Widget<SerializedData>>>>,
     pub(super) view_fn_name: &'static str,
+    /// The app data and its serialization given to the widget of the library.
+    pub(super) serialized: Option<(T, SerializedData)>,
+    /// The timer of the next attempt to load the library, and its number.
+    pub(super) retry: Option<(TimerToken, u32)>,
 }
 
 impl<T> HotReloaderWidget<T> {
-    fn update_lib(&mut self) {
+    /// Load the library again and build its widget. If it fails, returns the delay
+    /// before the next attempt, which is retried a few times with a short backoff.
+    /// Nothing is shown if it still fails.
+    fn update_lib(&mut self, attempt: u32) -> Option<Duration> {
         // droping it before unloading the library
         drop(self.inner.take());
-        self.lib.update();
-        let load = unsafe {
-            self.lib
-                .load_symbol::<fn() -> Box<dyn Widget<T>>>(self.view_fn_name)
-                .unwrap()
-        };
-        let returned_widget = load();
-        self.inner = Some(WidgetPod::new(returned_widget));
+        match self.load_view() {
+            Ok(view) => {
+                self.inner = Some(WidgetPod::new(view()));
+                None
+            }
+            Err(e) if attempt < LOAD_ATTEMPTS => {
+                log::warn!("{} (attempt {}/{})", e, attempt, LOAD_ATTEMPTS);
+                Some(LOAD_BACKOFF * 2u32.pow(attempt - 1))
+            }
+            Err(e) => {
+                log::error!("{}, giving up", e);
+                None
+            }
+        }
+    }
+
+    fn load_view(&mut self) -> Result<ViewFn, HotReloadError> {
+        self.lib.update()?;
+        let view = unsafe { self.lib.load_symbol::<ViewFn>(self.view_fn_name) };
+        view.map(|view| *view)
+            .ok_or(HotReloadError::MissingSymbol(self.view_fn_name))
     }
 }
 
-impl<T: Data> Widget<T> for HotReloaderWidget<T> {
+impl<T: Data + Serialize + DeserializeOwned> HotReloaderWidget<T> {
+    /// Returns the serialized app data, serializing it again only when it changed.
+    ///
+    /// Fails if the app data can't be serialized as JSON, for example a map with keys
+    /// other than strings. The widget of the library is then skipped.
+    fn serialize(&mut self, data: &T) -> Result<SerializedData, HotReloadError> {
+        match &self.serialized {
+            Some((old_data, serialized)) if old_data.same(data) => Ok(serialized.clone()),
+            _ => {
+                let serialized = SerializedData::new(data)?;
+                self.serialized = Some((data.clone(), serialized.clone()));
+                Ok(serialized)
+            }
+        }
+    }
+}
+
+impl<T: Data + Serialize + DeserializeOwned> Widget<T> for HotReloaderWidget<T> {
     fn event(&mut self, ctx: &mut druid::EventCtx, event: &Event, data: &mut T, env: &druid::Env) {
-        if let Event::Command(cmd) = event {
-            if cmd.is(RELOAD) {
-                self.update_lib();
+        match event {
+            Event::Command(cmd) if cmd.is(RELOAD) => {
+                self.retry = self
+                    .update_lib(1)
+                    .map(|backoff| (ctx.request_timer(backoff), 2));
+                ctx.children_changed();
+                return;
+            }
+            Event::Timer(token) if matches!(self.retry, Some((timer, _)) if timer == *token) => {
+                let (_, attempt) = self.retry.take().unwrap();
+                self.retry = self
+                    .update_lib(attempt)
+                    .map(|backoff| (ctx.request_timer(backoff), attempt + 1));
                 ctx.children_changed();
+                ctx.request_layout();
+                return;
+            }
+            _ => (),
+        }
+        let serialized = match self.serialize(data) {
+            Ok(serialized) => serialized,
+            Err(e) => {
+                log::error!("{}", e);
                 return;
             }
+        };
+        let mut new_serialized = serialized.clone();
+        if let Some(inner) = &mut self.inner {
+            inner.event(ctx, event, &mut new_serialized, env);
+        }
+        if !serialized.same(&new_serialized) {
+            match new_serialized.get::<T>() {
+                Ok(new_data) => {
+                    self.serialized = Some((new_data.clone(), new_serialized));
+                    *data = new_data;
+                }
+                Err(e) => log::error!("failed to deserialize the app data of the library: {}", e),
+            }
         }
-        self.inner.as_mut().unwrap().event(ctx, event, data, env)
     }
 
     fn lifecycle(
@@ -47,19 +124,27 @@ impl<T: Data> Widget<T> for HotReloaderWidget<T> {
         if let LifeCycle::WidgetAdded = event {
             // just update library first time
             if self.inner.is_none() {
-                self.update_lib();
+                self.retry = self
+                    .update_lib(1)
+                    .map(|backoff| (ctx.request_timer(backoff), 2));
                 ctx.children_changed();
                 ctx.request_layout();
             }
         }
-        self.inner
-            .as_mut()
-            .unwrap()
-            .lifecycle(ctx, event, data, env)
+        if let (Ok(serialized), Some(inner)) = (self.serialize(data), &mut self.inner) {
+            inner.lifecycle(ctx, event, &serialized, env)
+        }
     }
 
     fn update(&mut self, ctx: &mut druid::UpdateCtx, _old_data: &T, data: &T, env: &druid::Env) {
-        self.inner.as_mut().unwrap().update(ctx, data, env)
+        match self.serialize(data) {
+            Ok(serialized) => {
+                if let Some(inner) = &mut self.inner {
+                    inner.update(ctx, &serialized, env)
+                }
+            }
+            Err(e) => log::error!("{}", e),
+        }
     }
 
     fn layout(
@@ -69,14 +154,19 @@ impl<T: Data> Widget<T> for HotReloaderWidget<T> {
         data: &T,
         env: &druid::Env,
     ) -> druid::Size {
-        self.inner
-            .as_mut()
-            .unwrap()
-            .set_origin(ctx, (0.0, 0.0).into());
-        self.inner.as_mut().unwrap().layout(ctx, bc, data, env)
+        match (self.serialize(data), &mut self.inner) {
+            (Ok(serialized), Some(inner)) => {
+                let size = inner.layout(ctx, bc, &serialized, env);
+                inner.set_origin(ctx, (0.0, 0.0).into());
+                size
+            }
+            _ => bc.min(),
+        }
     }
 
     fn paint(&mut self, ctx: &mut druid::PaintCtx, data: &T, env: &druid::Env) {
-        self.inner.as_mut().unwrap().paint(ctx, data, env)
+        if let (Ok(serialized), Some(inner)) = (self.serialize(data), &mut self.inner) {
+            inner.paint(ctx, &serialized, env)
+        }
     }
 }
@@ -5,9 +5,11 @@
 
 #![allow(clippy::new_ret_no_self)]
 
+mod animated_padding;
 pub mod animation;
 mod autofocus;
 mod canvas;
+mod chip_input;
 mod computed;
 mod configure_env;
 mod context_traits;
@@ -15,23 +17,35 @@ pub mod dropdown;
 mod dropdown_select;
 mod dyn_lens;
 mod dynamic_sized_box;
+mod ellipsis_label;
 pub mod enum_switcher;
+mod grid_layout;
+pub mod highlight_label;
 mod list_select;
 #[macro_use]
 mod macros;
 mod advanced_slider;
 mod mask;
+mod menu_bar;
+mod minimap;
 mod multi_value;
 pub mod navigator;
 mod on_change;
 mod on_cmd;
+mod on_lifecycle;
 mod on_monitor;
+mod password_field;
 pub mod prism;
 mod progress_bar;
+mod ruler;
+mod scroll_observer;
 mod separator;
 pub mod splits;
 mod stack;
 pub mod stack_tooltip;
+mod state_view;
+mod sticky_headers;
+mod tab_view;
 pub mod table;
 pub mod theme_loader;
 mod titlebar;
@@ -52,31 +66,48 @@ mod future_widget;
 pub mod hot_reload;
 mod list_filter;
 
-pub use advanced_slider::AdvancedSlider;
-pub use autofocus::AutoFocus;
+pub use advanced_slider::{AdvancedSlider, ADVANCED_SLIDER_CHANGED};
+pub use animated_padding::AnimatedPadding;
+pub use autofocus::{AutoFocus, ConditionalAutoFocus};
 pub use canvas::{Canvas, CanvasLayout, CanvasWrap};
+pub use chip_input::ChipInput;
 pub use computed::ComputedWidget;
-pub use configure_env::configure_env;
+pub use configure_env::{configure_env, EnvConfig};
 pub use context_traits::{AnyCtx, CommandCtx, CursorCtx, LaidOutCtx, RequestCtx};
 pub use dropdown::Dropdown;
 pub use dropdown_select::DropdownSelect;
-pub use dyn_lens::DynLens;
+pub use dyn_lens::{DynLens, PrismOrDefault};
 pub use dynamic_sized_box::DynamicSizedBox;
-pub use list_filter::{FilterIter, ListFilter};
+pub use ellipsis_label::EllipsisLabel;
+pub use grid_layout::{GridLayout, GridTile};
+pub use highlight_label::HighlightLabel;
+pub use list_filter::{FilterIter, ListFilter, LIST_FILTER_COUNT_CHANGED};
 pub use list_select::ListSelect;
-pub use mask::Mask;
+pub use mask::{Mask, MaskShape};
+pub use menu_bar::{BarMenu, BarMenuItem, MenuBar};
+pub use minimap::{Minimap, MinimapState, MinimapViewport};
 pub use multi_value::{MultiCheckbox, MultiRadio};
 pub use on_change::OnChange;
 pub use on_cmd::OnCmd;
+pub use on_lifecycle::{OnAdded, OnRemoved};
 pub use on_monitor::OnMonitor;
-pub use progress_bar::ProgressBar;
-pub use separator::{Orientation, Separator};
+pub use password_field::PasswordField;
+pub use progress_bar::{Progress, ProgressBar};
+pub use ruler::Ruler;
+pub use scroll_observer::{ScrollObserver, Viewport, VIEWPORT_CHANGED};
+pub use separator::{LabeledSeparator, Orientation, Separator, SeparatorStyle};
 pub use stack::{Stack, StackChildParams, StackChildPosition};
-pub use titlebar::TitleBar;
+pub use state_view::{LoadState, StateView};
+pub use sticky_headers::StickyHeaders;
+pub use tab_view::{TabView, TabsState};
+pub use titlebar::{ResizeFrame, TitleBar};
 pub use tooltip::TooltipController;
-pub use tree::{Tree, TreeNode, TREE_NODE_REMOVE};
-pub use versioned::Versioned;
-pub use wedge::Wedge;
+pub use tree::{
+    Tree, TreeNode, TREE_NODE_COLLAPSED, TREE_NODE_EXPANDED, TREE_NODE_REMOVE,
+    TREE_SELECTION_CHANGED,
+};
+pub use versioned::{OnVersionBump, Versioned};
+pub use wedge::{Wedge, WedgeOrientation};
 pub use widget_ext::WidgetExt;
 
 #[cfg(feature = "async")]
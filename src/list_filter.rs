@@ -4,12 +4,22 @@
 use druid::im::Vector;
 use druid::widget::ListIter;
 use druid::{
-    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, PaintCtx, Size,
-    UpdateCtx, Widget,
+    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, PaintCtx,
+    Point, Selector, Size, UpdateCtx, Widget, WidgetPod,
 };
+use std::cmp::Ordering;
 use std::marker::PhantomData;
 use std::ops::Range;
 
+crate::selectors! {
+    /// Notification sent by a [`ListFilter`] when the number of accepted elements changed,
+    /// with the new number.
+    LIST_FILTER_COUNT_CHANGED: usize,
+}
+
+// Command sent by a `ListFilter` to itself from update, as notifications need an `EventCtx`.
+const COUNT_CHANGED: Selector<usize> = Selector::new("list-filter.count-changed");
+
 #[derive(Data, Clone)]
 pub struct FilterIter<I> {
     data: I,
@@ -20,10 +30,44 @@ impl<I> FilterIter<I> {
     pub fn new(data: I, indices: Vector<usize>) -> Self {
         FilterIter { data, indices }
     }
+
+    /// Whether the elements are iterated in the order of the data, which doesn't require
+    /// to clone them.
+    fn in_data_order(&self) -> bool {
+        self.indices
+            .iter()
+            .zip(self.indices.iter().skip(1))
+            .all(|(a, b)| a < b)
+    }
+
+    /// Clones the elements, in the order of the indices.
+    fn sorted_elements<T: Data>(&self) -> Vec<T>
+    where
+        I: ListIter<T>,
+    {
+        let mut positions = vec![None; self.data.data_len()];
+        for (position, index) in self.indices.iter().enumerate() {
+            positions[*index] = Some(position);
+        }
+        let mut elements = vec![None; self.indices.len()];
+        self.data.for_each(|element, index| {
+            if let Some(position) = positions[index] {
+                elements[position] = Some(element.clone());
+            }
+        });
+        elements.into_iter().map(Option::unwrap).collect()
+    }
 }
 
 impl<T: Data, I: ListIter<T>> ListIter<T> for FilterIter<I> {
     fn for_each(&self, mut cb: impl FnMut(&T, usize)) {
+        if !self.in_data_order() {
+            for (position, element) in self.sorted_elements().iter().enumerate() {
+                cb(element, position);
+            }
+            return;
+        }
+
         let mut indices = self.indices.iter();
         let mut next = indices.next();
         let mut counter = 0;
@@ -39,6 +83,25 @@ impl<T: Data, I: ListIter<T>> ListIter<T> for FilterIter<I> {
     }
 
     fn for_each_mut(&mut self, mut cb: impl FnMut(&mut T, usize)) {
+        if !self.in_data_order() {
+            let mut elements = self.sorted_elements();
+            for (position, element) in elements.iter_mut().enumerate() {
+                cb(element, position);
+            }
+            // write the elements back, leaving the unchanged ones untouched
+            let mut elements: Vec<_> = self.indices.iter().zip(elements).collect();
+            elements.sort_by_key(|(index, _)| **index);
+            let mut elements = elements.into_iter().peekable();
+            self.data.for_each_mut(|element, index| {
+                if let Some((_, new)) = elements.next_if(|(next, _)| **next == index) {
+                    if !element.same(&new) {
+                        *element = new;
+                    }
+                }
+            });
+            return;
+        }
+
         let mut indices = self.indices.iter();
         let mut next = indices.next();
         let mut counter = 0;
@@ -60,11 +123,18 @@ impl<T: Data, I: ListIter<T>> ListIter<T> for FilterIter<I> {
 
 type FilterUpdate<I, D> = dyn Fn(&mut Vector<usize>, usize, &I, Range<usize>, &D);
 
+type SortFn<T> = Box<dyn Fn(&T, &T) -> Ordering>;
+
 /// A widget which filters a list for its inner widget.
+///
+/// With [`with_sort`](ListFilter::with_sort), the inner widget gets the accepted elements
+/// in another order than the one of the data.
 pub struct ListFilter<D, T, I> {
     accepted: Vector<usize>,
     filter_update: Box<FilterUpdate<I, D>>,
+    sort: Option<SortFn<T>>,
     inner: Box<dyn Widget<FilterIter<I>>>,
+    empty: Option<WidgetPod<D, Box<dyn Widget<D>>>>,
     phantom: PhantomData<T>,
 }
 
@@ -88,14 +158,87 @@ impl<D: Data, T: Data, I: ListIter<T>> ListFilter<D, T, I> {
                     })
                 },
             ),
+            sort: None,
             inner: Box::new(inner),
+            empty: None,
             phantom: PhantomData,
         }
     }
+
+    /// Builder-style method to show the accepted elements sorted by `compare`.
+    ///
+    /// The sort is stable, so equal elements keep the order of the data. The data itself
+    /// isn't reordered.
+    pub fn with_sort(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.sort = Some(Box::new(compare));
+        self
+    }
+
+    /// Builder-style method to show `widget` instead of the inner widget when no element is
+    /// accepted. It gets the filter data, for example to show the search query.
+    pub fn empty(mut self, widget: impl Widget<D> + 'static) -> Self {
+        self.empty = Some(WidgetPod::new(Box::new(widget)));
+        self
+    }
+
+    /// The number of accepted elements.
+    pub fn matched_len(&self) -> usize {
+        self.accepted.len()
+    }
+
+    /// The widget shown instead of the inner widget, when no element is accepted.
+    fn shown_empty(&mut self) -> Option<&mut WidgetPod<D, Box<dyn Widget<D>>>> {
+        match self.accepted.is_empty() {
+            true => self.empty.as_mut(),
+            false => None,
+        }
+    }
+
+    /// Computes the accepted indices, in the order they are shown.
+    fn refilter(&mut self, data: &(I, D)) {
+        self.accepted.clear();
+        (self.filter_update)(
+            &mut self.accepted,
+            0,
+            &data.0,
+            0..(data.0.data_len()),
+            &data.1,
+        );
+
+        if let Some(sort) = &self.sort {
+            let mut accepted = self.accepted.iter().copied().peekable();
+            let mut elements = Vec::with_capacity(self.accepted.len());
+            data.0.for_each(|element, index| {
+                if accepted.next_if_eq(&index).is_some() {
+                    elements.push((index, element.clone()));
+                }
+            });
+            elements.sort_by(|(_, a), (_, b)| sort(a, b));
+            self.accepted = elements.into_iter().map(|(index, _)| index).collect();
+        }
+    }
 }
 
 impl<T: Data, D: Data, I: ListIter<T>> Widget<(I, D)> for ListFilter<D, T, I> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut (I, D), env: &Env) {
+        if let Event::Command(cmd) = event {
+            if let Some(count) = cmd.get(COUNT_CHANGED) {
+                ctx.submit_notification(LIST_FILTER_COUNT_CHANGED.with(*count));
+                ctx.set_handled();
+                return;
+            }
+        }
+
+        if let Some(empty) = self.shown_empty() {
+            empty.event(ctx, event, &mut data.1, env);
+            if !event.should_propagate_to_hidden() {
+                return;
+            }
+        } else if let Some(empty) = &mut self.empty {
+            if event.should_propagate_to_hidden() {
+                empty.event(ctx, event, &mut data.1, env);
+            }
+        }
         let mut inner_data = FilterIter::new(data.0.clone(), self.accepted.clone());
         self.inner.event(ctx, event, &mut inner_data, env);
         data.0 = inner_data.data;
@@ -103,13 +246,10 @@ impl<T: Data, D: Data, I: ListIter<T>> Widget<(I, D)> for ListFilter<D, T, I> {
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &(I, D), env: &Env) {
         if let LifeCycle::WidgetAdded = event {
-            (self.filter_update)(
-                &mut self.accepted,
-                0,
-                &data.0,
-                0..(data.0.data_len()),
-                &data.1,
-            );
+            self.refilter(data);
+        }
+        if let Some(empty) = &mut self.empty {
+            empty.lifecycle(ctx, event, &data.1, env);
         }
         let inner_data = FilterIter::new(data.0.clone(), self.accepted.clone());
         self.inner.lifecycle(ctx, event, &inner_data, env);
@@ -120,14 +260,17 @@ impl<T: Data, D: Data, I: ListIter<T>> Widget<(I, D)> for ListFilter<D, T, I> {
 
         if !old_data.same(data) {
             //TODO: do real diffing here
-            self.accepted.clear();
-            (self.filter_update)(
-                &mut self.accepted,
-                0,
-                &data.0,
-                0..(data.0.data_len()),
-                &data.1,
-            );
+            let old_len = self.matched_len();
+            self.refilter(data);
+            if self.matched_len() != old_len {
+                ctx.submit_command(COUNT_CHANGED.with(self.matched_len()).to(ctx.widget_id()));
+                if old_len == 0 || self.matched_len() == 0 {
+                    ctx.request_layout();
+                }
+            }
+        }
+        if let Some(empty) = &mut self.empty {
+            empty.update(ctx, &data.1, env);
         }
         let inner_data = FilterIter::new(data.0.clone(), self.accepted.clone());
         self.inner.update(ctx, &old_inner, &inner_data, env);
@@ -140,12 +283,71 @@ impl<T: Data, D: Data, I: ListIter<T>> Widget<(I, D)> for ListFilter<D, T, I> {
         data: &(I, D),
         env: &Env,
     ) -> Size {
+        if let Some(empty) = self.shown_empty() {
+            let size = empty.layout(ctx, bc, &data.1, env);
+            empty.set_origin(ctx, Point::ORIGIN);
+            return size;
+        }
         let inner_data = FilterIter::new(data.0.clone(), self.accepted.clone());
         self.inner.layout(ctx, bc, &inner_data, env)
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, data: &(I, D), env: &Env) {
+        if let Some(empty) = self.shown_empty() {
+            empty.paint(ctx, &data.1, env);
+            return;
+        }
         let inner_data = FilterIter::new(data.0.clone(), self.accepted.clone());
         self.inner.paint(ctx, &inner_data, env);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::im::vector;
+    use druid::widget::SizedBox;
+
+    fn elements(iter: &FilterIter<Vector<i32>>) -> Vec<i32> {
+        let mut elements = Vec::new();
+        iter.for_each(|element, position| {
+            assert_eq!(position, elements.len());
+            elements.push(*element);
+        });
+        elements
+    }
+
+    #[test]
+    fn sorted_filter() {
+        let data = vector![3, 1, 4, 1, 5, 9, 2, 6];
+        let mut filter = ListFilter::new(SizedBox::empty(), |x: &i32, min: &i32| x >= min)
+            .with_sort(|a: &i32, b: &i32| b.cmp(a));
+        filter.refilter(&(data.clone(), 3));
+
+        let mut iter = FilterIter::new(data.clone(), filter.accepted.clone());
+        assert_eq!(elements(&iter), [9, 6, 5, 4, 3]);
+
+        iter.for_each_mut(|element, position| {
+            if position == 0 {
+                *element = 0;
+            }
+        });
+        // the data keeps its order
+        assert_eq!(iter.data, vector![3, 1, 4, 1, 5, 0, 2, 6]);
+    }
+
+    #[test]
+    fn empty_widget() {
+        let mut filter = ListFilter::new(SizedBox::empty(), |x: &i32, min: &i32| x >= min);
+        filter.refilter(&(vector![1, 2], 3));
+        assert_eq!(filter.matched_len(), 0);
+        // without an empty widget, the inner widget is shown
+        assert!(filter.shown_empty().is_none());
+
+        let mut filter = filter.empty(SizedBox::empty());
+        assert!(filter.shown_empty().is_some());
+        filter.refilter(&(vector![1, 2], 2));
+        assert_eq!(filter.matched_len(), 1);
+        assert!(filter.shown_empty().is_none());
+    }
+}
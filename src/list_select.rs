@@ -7,13 +7,18 @@ use druid::keyboard_types::Key;
 use druid::widget::{Controller, CrossAxisAlignment, Flex, Label, LabelText};
 use druid::{
     theme, BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx,
-    LinearGradient, PaintCtx, RenderContext, Size, UnitPoint, UpdateCtx, Widget,
+    LinearGradient, PaintCtx, RenderContext, Size, UnitPoint, UpdateCtx, Widget, WidgetExt,
 };
 
 // added padding between the edges of the widget and the text.
 const LABEL_X_PADDING: f64 = 8.0;
+// added padding above and below group headers.
+const GROUP_HEADER_Y_PADDING: f64 = 4.0;
 
 /// Builds a simple list selection widget, for selecting a single value out of a list.
+///
+/// When focused, the up and down arrow keys select the previous and next item, and
+/// `Home` and `End` select the first and last item.
 pub struct ListSelect<T> {
     /// Internal widget data.
     widget: Flex<T>,
@@ -21,16 +26,48 @@ pub struct ListSelect<T> {
     controller: ListSelectController<T>,
 }
 
+/// A group of items, with an optional non-selectable header label.
+pub(crate) type ListGroup<T> = (Option<LabelText<T>>, Vec<(LabelText<T>, T)>);
+
 impl<T: Data> ListSelect<T> {
     /// Given a vector of `(label_text, enum_variant)` tuples, create a list of items to select from
     pub fn new(
         values: impl IntoIterator<Item = (impl Into<LabelText<T>> + 'static, T)>,
     ) -> ListSelect<T> {
+        Self::from_groups(vec![(None, flatten_values(values))])
+    }
+
+    /// Given a vector of `(group_label, items)` tuples, where each of the items is a
+    /// `(label_text, enum_variant)` tuple, create a list of items to select from, with
+    /// a non-selectable header before each group.
+    ///
+    /// Keyboard navigation skips the group headers.
+    pub fn new_grouped(
+        groups: impl IntoIterator<
+            Item = (
+                impl Into<LabelText<T>> + 'static,
+                impl IntoIterator<Item = (impl Into<LabelText<T>> + 'static, T)>,
+            ),
+        >,
+    ) -> ListSelect<T> {
+        Self::from_groups(flatten_groups(groups))
+    }
+
+    pub(crate) fn from_groups(groups: impl IntoIterator<Item = ListGroup<T>>) -> ListSelect<T> {
         let mut col = Flex::column().cross_axis_alignment(CrossAxisAlignment::Fill);
         let mut variants = Vec::new();
-        for (index, (label, variant)) in values.into_iter().enumerate() {
-            variants.insert(index, variant.clone());
-            col.add_child(ListItem::new(label, variant));
+        for (header, values) in groups {
+            if let Some(header) = header {
+                col.add_child(
+                    Label::new(header)
+                        .with_text_color(theme::DISABLED_TEXT_COLOR)
+                        .padding((LABEL_X_PADDING, GROUP_HEADER_Y_PADDING)),
+                );
+            }
+            for (label, variant) in values {
+                variants.push(variant.clone());
+                col.add_child(ListItem::new(label, variant));
+            }
         }
 
         ListSelect {
@@ -38,22 +75,22 @@ impl<T: Data> ListSelect<T> {
             controller: ListSelectController {
                 variants,
                 action: None,
+                wrap: false,
             },
         }
     }
 
-    /// Provide a closure to be called when an item is selected.
-    pub fn on_select(self, f: impl Fn(&mut EventCtx, &mut T, &Env) + 'static) -> ListSelect<T> {
-        let widget = self.widget;
-        let ListSelectController { variants, .. } = self.controller;
+    /// Builder-style method to make the arrow keys wrap around, from the last item to
+    /// the first one and back.
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.controller.wrap = wrap;
+        self
+    }
 
-        ListSelect {
-            widget,
-            controller: ListSelectController {
-                variants,
-                action: Some(Box::new(f)),
-            },
-        }
+    /// Provide a closure to be called when an item is selected.
+    pub fn on_select(mut self, f: impl Fn(&mut EventCtx, &mut T, &Env) + 'static) -> ListSelect<T> {
+        self.controller.action = Some(Box::new(f));
+        self
     }
 }
 
@@ -82,29 +119,69 @@ impl<T: Data> Widget<T> for ListSelect<T> {
     }
 }
 
+pub(crate) fn flatten_values<T>(
+    values: impl IntoIterator<Item = (impl Into<LabelText<T>> + 'static, T)>,
+) -> Vec<(LabelText<T>, T)> {
+    values
+        .into_iter()
+        .map(|(label, variant)| (label.into(), variant))
+        .collect()
+}
+
+pub(crate) fn flatten_groups<T>(
+    groups: impl IntoIterator<
+        Item = (
+            impl Into<LabelText<T>> + 'static,
+            impl IntoIterator<Item = (impl Into<LabelText<T>> + 'static, T)>,
+        ),
+    >,
+) -> Vec<ListGroup<T>> {
+    groups
+        .into_iter()
+        .map(|(header, values)| (Some(header.into()), flatten_values(values)))
+        .collect()
+}
+
 type ListSelectAction<T> = Box<dyn Fn(&mut EventCtx, &mut T, &Env) + 'static>;
 
 // A Controller to handle arrow key in the list selection widget.
 struct ListSelectController<T> {
     variants: Vec<T>,
     action: Option<ListSelectAction<T>>,
+    wrap: bool,
 }
 
 impl<T: Data> ListSelectController<T> {
-    fn change_index(&self, data: &mut T, next_else_previous: bool) {
-        if let Some(mut index) = self.variants.iter().position(|variant| variant.same(data)) {
-            if next_else_previous {
-                index += 1
-            } else {
-                index = index.saturating_sub(1);
-            }
-            if let Some(new_data) = self.variants.get(index) {
-                *data = (*new_data).clone();
+    /// Selects the variant reached with `key`, returning whether the key was handled.
+    fn change_index(&self, data: &mut T, key: &Key) -> bool {
+        let current = self.variants.iter().position(|variant| variant.same(data));
+        match target_index(current, self.variants.len(), key, self.wrap) {
+            Some(index) => {
+                *data = self.variants[index].clone();
+                true
             }
+            None => false,
         }
     }
 }
 
+/// The index selected by pressing `key` with `current` selected, out of `len` items,
+/// or `None` if the key doesn't navigate the list.
+fn target_index(current: Option<usize>, len: usize, key: &Key, wrap: bool) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    match (key, current) {
+        (Key::Home, _) => Some(0),
+        (Key::End, _) => Some(last),
+        (Key::ArrowUp, None) => Some(last),
+        (Key::ArrowDown, None) => Some(0),
+        (Key::ArrowUp, Some(0)) => Some(if wrap { last } else { 0 }),
+        (Key::ArrowUp, Some(index)) => Some(index - 1),
+        (Key::ArrowDown, Some(index)) if index >= last => Some(if wrap { 0 } else { last }),
+        (Key::ArrowDown, Some(index)) => Some(index + 1),
+        _ => None,
+    }
+}
+
 impl<T: Data> Controller<T, Flex<T>> for ListSelectController<T> {
     fn event(
         &mut self,
@@ -123,18 +200,11 @@ impl<T: Data> Controller<T, Flex<T>> for ListSelectController<T> {
             selected = ctx.is_hot() && ctx.has_focus();
         }
         if let Event::KeyDown(key_event) = event {
-            match key_event.key {
-                Key::ArrowUp => {
-                    selected = true;
-                    self.change_index(data, false);
-                    ctx.request_update();
-                }
-                Key::ArrowDown => {
-                    selected = true;
-                    self.change_index(data, true);
-                    ctx.request_update();
-                }
-                _ => {}
+            if self.change_index(data, &key_event.key) {
+                selected = true;
+                ctx.request_focus();
+                ctx.request_paint();
+                ctx.set_handled();
             }
         } else {
             child.event(ctx, event, data, env)
@@ -245,3 +315,55 @@ impl<T: Data> Widget<T> for ListItem<T> {
             .draw_at(ctx, (LABEL_X_PADDING, self.label_y));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrow_keys() {
+        let controller = ListSelectController {
+            variants: vec![1, 2, 3],
+            action: None,
+            wrap: false,
+        };
+        let mut data = 1;
+        assert!(controller.change_index(&mut data, &Key::ArrowDown));
+        assert_eq!(data, 2);
+        controller.change_index(&mut data, &Key::ArrowDown);
+        controller.change_index(&mut data, &Key::ArrowDown);
+        assert_eq!(data, 3);
+        controller.change_index(&mut data, &Key::Home);
+        assert_eq!(data, 1);
+        controller.change_index(&mut data, &Key::ArrowUp);
+        assert_eq!(data, 1);
+        controller.change_index(&mut data, &Key::End);
+        assert_eq!(data, 3);
+        assert!(!controller.change_index(&mut data, &Key::Enter));
+    }
+
+    #[test]
+    fn grouped_headers() {
+        let mut list = ListSelect::new_grouped(vec![
+            ("Odd", vec![("One", 1), ("Three", 3)]),
+            ("Even", vec![("Two", 2)]),
+        ]);
+        // only the items are selectable, not the headers
+        assert_eq!(list.controller.variants, vec![1, 3, 2]);
+
+        let mut data = 3;
+        list.controller.change_index(&mut data, &Key::ArrowDown);
+        assert_eq!(data, 2);
+        list = list.with_wrap(true);
+        list.controller.change_index(&mut data, &Key::ArrowDown);
+        assert_eq!(data, 1);
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(target_index(Some(2), 3, &Key::ArrowDown, true), Some(0));
+        assert_eq!(target_index(Some(0), 3, &Key::ArrowUp, true), Some(2));
+        assert_eq!(target_index(None, 3, &Key::ArrowDown, false), Some(0));
+        assert_eq!(target_index(None, 0, &Key::Home, false), None);
+    }
+}
@@ -1,22 +1,57 @@
 // Copyright 2022 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::animation::{Animated, AnimationCurve};
+use druid::kurbo::{BezPath, Circle, Shape};
 use druid::theme;
 use druid::widget::{Align, BackgroundBrush, Flex, Label, LabelText, Spinner};
 use druid::{
     BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, PaintCtx,
-    Point, Size, UpdateCtx, Widget, WidgetExt, WidgetPod,
+    Point, RenderContext, Size, UpdateCtx, Widget, WidgetExt, WidgetPod,
 };
 
 type ShowMaskFn<T> = Box<dyn Fn(&T, &Env) -> bool>;
 
+/// The shape the paint of a [`Mask`] is clipped to, see [`Mask::with_shape`].
+#[derive(Debug, Clone)]
+pub enum MaskShape {
+    /// The bounds of the widget.
+    Rect,
+    /// The largest circle fitting in the center of the widget, for example for avatars.
+    Circle,
+    /// The bounds of the widget, with corners rounded by the given radius.
+    RoundedRect(f64),
+    /// A path in the coordinates of the widget.
+    Custom(BezPath),
+}
+
+impl MaskShape {
+    /// The outline of the shape for a widget of the given size.
+    fn path(&self, size: Size) -> BezPath {
+        let rect = size.to_rect();
+        match self {
+            MaskShape::Rect => rect.to_path(0.1),
+            MaskShape::Circle => Circle::new(rect.center(), size.min_side() / 2.0).to_path(0.1),
+            MaskShape::RoundedRect(radius) => rect.to_rounded_rect(*radius).to_path(0.1),
+            MaskShape::Custom(path) => path.clone(),
+        }
+    }
+}
+
 /// A widget that conditionally masks the child content and displays
 /// other content instead (the mask).
+///
+/// When the mask is shown or hidden, the overlay fades in or out and the
+/// mask is revealed by a circle growing from the center, or hidden by the
+/// circle shrinking back.
 pub struct Mask<T> {
     child: WidgetPod<T, Box<dyn Widget<T>>>,
     mask: WidgetPod<T, Box<dyn Widget<T>>>,
     show_mask_cb: Option<ShowMaskFn<T>>,
     show_mask: bool,
+    /// 1.0 when the mask is fully shown, 0.0 when it is hidden.
+    reveal: Animated<f64>,
+    shape: Option<MaskShape>,
 }
 
 impl<T: Data> Mask<T> {
@@ -35,6 +70,10 @@ impl<T: Data> Mask<T> {
             mask: WidgetPod::new(mask.boxed()),
             show_mask_cb: None,
             show_mask: false,
+            reveal: Animated::jump(0.0)
+                .duration(0.2)
+                .curve(AnimationCurve::EASE_OUT),
+            shape: None,
         }
     }
 
@@ -48,6 +87,7 @@ impl<T: Data> Mask<T> {
     pub fn set_show_mask(&mut self, show_mask: bool) {
         self.show_mask_cb = None;
         self.show_mask = show_mask;
+        self.reveal.jump_to_value(if show_mask { 1.0 } else { 0.0 });
     }
 
     /// Builder-style method to dynamically compute the `show_mask`
@@ -57,6 +97,30 @@ impl<T: Data> Mask<T> {
         self
     }
 
+    /// Builder-style method to set the duration in seconds of the transition
+    /// between shown and hidden.
+    pub fn with_transition_duration(mut self, duration: f64) -> Self {
+        self.set_transition_duration(duration);
+        self
+    }
+
+    /// Set the duration in seconds of the transition between shown and hidden.
+    pub fn set_transition_duration(&mut self, duration: f64) {
+        self.reveal.set_duration(duration);
+    }
+
+    /// Builder-style method to set the curve of the transition between shown
+    /// and hidden.
+    pub fn with_transition_curve(mut self, curve: impl Into<AnimationCurve>) -> Self {
+        self.set_transition_curve(curve);
+        self
+    }
+
+    /// Set the curve of the transition between shown and hidden.
+    pub fn set_transition_curve(&mut self, curve: impl Into<AnimationCurve>) {
+        self.reveal.set_curve(curve.into());
+    }
+
     /// Builder-style method for setting the mask widget.
     pub fn with_mask(mut self, mask: impl Widget<T> + 'static) -> Self {
         self.set_mask(mask);
@@ -68,6 +132,19 @@ impl<T: Data> Mask<T> {
         self.mask = WidgetPod::new(mask.boxed());
     }
 
+    /// Builder-style method to clip the child and the mask to `shape`.
+    ///
+    /// By default, nothing is clipped.
+    pub fn with_shape(mut self, shape: MaskShape) -> Self {
+        self.set_shape(shape);
+        self
+    }
+
+    /// Set the shape the child and the mask are clipped to.
+    pub fn set_shape(&mut self, shape: MaskShape) {
+        self.shape = Some(shape);
+    }
+
     /// Builder-style method to create a mask with a spinner and a text.
     pub fn with_text_mask(mut self, text: impl Into<LabelText<T>>) -> Self {
         self.set_text_mask(text);
@@ -88,6 +165,9 @@ impl<T: Data> Mask<T> {
 
 impl<T: Data> Widget<T> for Mask<T> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::AnimFrame(nanos) = event {
+            self.reveal.update(ctx, *nanos);
+        }
         if self.show_mask {
             self.mask.event(ctx, event, data, env);
         } else {
@@ -99,6 +179,8 @@ impl<T: Data> Widget<T> for Mask<T> {
         if let LifeCycle::WidgetAdded = event {
             if let Some(show_mask_cb) = &self.show_mask_cb {
                 self.show_mask = (show_mask_cb)(data, env);
+                self.reveal
+                    .jump_to_value(if self.show_mask { 1.0 } else { 0.0 });
             }
         }
         self.child.lifecycle(ctx, event, data, env);
@@ -110,6 +192,8 @@ impl<T: Data> Widget<T> for Mask<T> {
             let new_show_mask = (show_mask_cb)(data, env);
             if new_show_mask != self.show_mask {
                 ctx.request_paint();
+                self.reveal
+                    .animate(ctx, if new_show_mask { 1.0 } else { 0.0 });
             }
             self.show_mask = new_show_mask;
         }
@@ -140,15 +224,58 @@ impl<T: Data> Widget<T> for Mask<T> {
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        match &self.shape {
+            Some(shape) => {
+                let path = shape.path(ctx.size());
+                ctx.with_save(|ctx| {
+                    ctx.clip(path);
+                    self.paint_clipped(ctx, data, env);
+                });
+            }
+            None => self.paint_clipped(ctx, data, env),
+        }
+    }
+}
+
+impl<T: Data> Mask<T> {
+    /// Paint the child, and the mask over it while it is shown.
+    fn paint_clipped(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
         self.child.paint(ctx, data, env);
 
-        if self.show_mask {
-            let bg_color = env.get(theme::WINDOW_BACKGROUND_COLOR).with_alpha(0.5);
+        let reveal = self.reveal.get();
+        if reveal > 0.0 {
+            let bg_color = env
+                .get(theme::WINDOW_BACKGROUND_COLOR)
+                .with_alpha(0.5 * reveal);
             let mut brush = BackgroundBrush::Color(bg_color);
 
             brush.paint(ctx, data, env);
 
-            self.mask.paint(ctx, data, env);
+            let size = ctx.size();
+            let center = size.to_rect().center();
+            let radius = reveal * size.to_vec2().hypot() / 2.0;
+            ctx.with_save(|ctx| {
+                ctx.clip(Circle::new(center, radius));
+                self.mask.paint(ctx, data, env);
+            });
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_shape() {
+        let path = MaskShape::Circle.path(Size::new(100.0, 60.0));
+
+        assert!(path.contains(Point::new(50.0, 30.0)));
+        assert!(path.contains(Point::new(75.0, 30.0)));
+        // the corners are outside of the circle
+        assert!(!path.contains(Point::new(2.0, 2.0)));
+        assert!(!path.contains(Point::new(98.0, 58.0)));
+        // as are the sides of the wider axis
+        assert!(!path.contains(Point::new(85.0, 30.0)));
+    }
+}
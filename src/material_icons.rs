@@ -1,6 +1,8 @@
 // Copyright 2022 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+mod names;
+
 pub use druid_material_icons::{normal, IconPaths};
 
 use druid::{
@@ -9,6 +11,35 @@ use druid::{
     Color, KeyOrValue,
 };
 
+type IconFn<T> = Box<dyn Fn(&T) -> IconPaths>;
+
+/// Returns the icon named `name`, like `alarm_add` for [`normal::action::ALARM_ADD`].
+///
+/// The names are the lowercase names of the constants, without their leading
+/// underscore, which are the names of the material design icons.
+pub fn by_name(name: &str) -> Option<IconPaths> {
+    names::ICONS
+        .binary_search_by_key(&name, |(name, _)| name)
+        .ok()
+        .map(|index| names::ICONS[index].1)
+}
+
+/// Returns the names of all the icons, in alphabetical order, for example to build an
+/// icon picker.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    names::ICONS.iter().map(|(name, _)| *name)
+}
+
+/// Returns whether `a` and `b` are the same icon, comparing their paths.
+fn same_icon(a: &IconPaths, b: &IconPaths) -> bool {
+    a.size == b.size
+        && a.paths.len() == b.paths.len()
+        && a.paths
+            .iter()
+            .zip(b.paths)
+            .all(|(a, b)| a.els == b.els && a.opacity == b.opacity)
+}
+
 /// A widget that draws one of the material icons.
 ///
 /// # Examples
@@ -18,13 +49,16 @@ use druid::{
 /// use druid_widget_nursery::material_icons::{Icon, normal::action::ALARM_ADD};
 /// let icon = Icon::new(ALARM_ADD)
 ///     // optional - defaults to text color
-///     .with_color(Color::WHITE);
+///     .with_color(Color::WHITE)
+///     // optional - defaults to the size of the icon
+///     .with_size(32.0);
 /// // use `icon` as you would any widget...
 /// ```
 #[derive(Debug, Clone)]
 pub struct Icon {
     paths: IconPaths,
     color: KeyOrValue<Color>,
+    size: Option<f64>,
 }
 
 impl Icon {
@@ -33,6 +67,16 @@ impl Icon {
         Self {
             paths,
             color: KeyOrValue::from(druid::theme::TEXT_COLOR),
+            size: None,
+        }
+    }
+
+    /// Create an icon showing the icon returned by `icon` for the data.
+    pub fn dynamic<T>(icon: impl Fn(&T) -> IconPaths + 'static) -> DynamicIcon<T> {
+        DynamicIcon {
+            // the icon is chosen when the widget is added
+            icon: Icon::new(normal::action::ABC),
+            select: Box::new(icon),
         }
     }
 
@@ -40,6 +84,19 @@ impl Icon {
         self.color = color.into();
         self
     }
+
+    /// Builder-style method to set the preferred width of the icon, its height following
+    /// the aspect ratio of the icon.
+    pub fn with_size(mut self, size: f64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    fn preferred_size(&self, bc: &BoxConstraints) -> Size {
+        let width = self.size.unwrap_or(self.paths.size.width);
+        // Try to preserve aspect ratio if possible, but if not then allow non-uniform scaling.
+        bc.constrain_aspect_ratio(self.paths.size.aspect_ratio(), width)
+    }
 }
 
 impl<T: Data> Widget<T> for Icon {
@@ -53,8 +110,7 @@ impl<T: Data> Widget<T> for Icon {
         // no update
     }
     fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, _env: &Env) -> Size {
-        // Try to preserve aspect ratio if possible, but if not then allow non-uniform scaling.
-        bc.constrain_aspect_ratio(self.paths.size.aspect_ratio(), self.paths.size.width)
+        self.preferred_size(bc)
     }
     fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, env: &Env) {
         let color = self.color.resolve(env);
@@ -72,3 +128,106 @@ impl<T: Data> Widget<T> for Icon {
         }
     }
 }
+
+/// An [`Icon`] choosing the icon it shows from the data, created with [`Icon::dynamic`].
+pub struct DynamicIcon<T> {
+    icon: Icon,
+    select: IconFn<T>,
+}
+
+impl<T> DynamicIcon<T> {
+    /// Show the icon chosen for `data`, returning whether it changed.
+    fn select(&mut self, data: &T) -> bool {
+        let paths = (self.select)(data);
+        let changed = !same_icon(&paths, &self.icon.paths);
+        self.icon.paths = paths;
+        changed
+    }
+
+    pub fn with_color(mut self, color: impl Into<KeyOrValue<Color>>) -> Self {
+        self.icon = self.icon.with_color(color);
+        self
+    }
+
+    /// Builder-style method to set the preferred width of the icon, see [`Icon::with_size`].
+    pub fn with_size(mut self, size: f64) -> Self {
+        self.icon = self.icon.with_size(size);
+        self
+    }
+}
+
+impl<T: Data> Widget<T> for DynamicIcon<T> {
+    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut T, _env: &Env) {
+        // no events
+    }
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, _env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            self.select(data);
+        }
+    }
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, _env: &Env) {
+        if self.select(data) {
+            ctx.request_layout();
+        }
+    }
+    fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, _env: &Env) -> Size {
+        self.icon.preferred_size(bc)
+    }
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.icon.paint(ctx, data, env);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use normal::action::{ALARM_ADD, DONE};
+
+    #[test]
+    fn icons_by_name() {
+        assert!(same_icon(&by_name("alarm_add").unwrap(), &ALARM_ADD));
+        assert!(!same_icon(&by_name("done").unwrap(), &ALARM_ADD));
+        assert!(by_name("3d_rotation").is_some());
+        assert!(by_name("not_an_icon").is_none());
+        assert!(by_name("ALARM_ADD").is_none());
+
+        let names: Vec<_> = names().collect();
+        assert_eq!(names.len(), names::ICONS.len());
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn switch_dynamic_icon() {
+        let mut icon = Icon::dynamic(|done: &bool| if *done { DONE } else { ALARM_ADD });
+
+        // the icon chosen when the widget is added
+        assert!(icon.select(&false));
+        assert!(same_icon(&icon.icon.paths, &ALARM_ADD));
+        assert!(!icon.select(&false));
+
+        assert!(icon.select(&true));
+        assert!(same_icon(&icon.icon.paths, &DONE));
+        assert!(!icon.select(&true));
+        assert!(icon.select(&false));
+    }
+
+    #[test]
+    fn icon_sizes() {
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(100.0, 100.0));
+
+        assert_eq!(Icon::new(ALARM_ADD).preferred_size(&bc), ALARM_ADD.size);
+        assert_eq!(
+            Icon::new(ALARM_ADD).with_size(16.0).preferred_size(&bc),
+            Size::new(16.0, 16.0)
+        );
+        assert_eq!(
+            Icon::new(DONE).with_size(48.0).preferred_size(&bc),
+            Size::new(48.0, 48.0)
+        );
+        // the constraints win over the preferred size
+        assert_eq!(
+            Icon::new(DONE).with_size(200.0).preferred_size(&bc),
+            Size::new(100.0, 100.0)
+        );
+    }
+}
@@ -0,0 +1,2089 @@
+// Copyright 2022 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The names of the material icons, generated from the constants of `druid-material-icons`.
+
+use super::normal::*;
+use super::IconPaths;
+
+/// The icons by name, sorted by name.
+#[rustfmt::skip]
+pub(super) static ICONS: &[(&str, IconPaths)] = &[
+    ("10k", av::_10K),
+    ("10mp", image::_10MP),
+    ("11mp", image::_11MP),
+    ("123", action::_123),
+    ("12mp", image::_12MP),
+    ("13mp", image::_13MP),
+    ("14mp", image::_14MP),
+    ("15mp", image::_15MP),
+    ("16mp", image::_16MP),
+    ("17mp", image::_17MP),
+    ("18mp", image::_18MP),
+    ("19mp", image::_19MP),
+    ("1k", av::_1K),
+    ("1k_plus", av::_1K_PLUS),
+    ("1x_mobiledata", device::_1X_MOBILEDATA),
+    ("20mp", image::_20MP),
+    ("21mp", image::_21MP),
+    ("22mp", image::_22MP),
+    ("23mp", image::_23MP),
+    ("24mp", image::_24MP),
+    ("2k", av::_2K),
+    ("2k_plus", av::_2K_PLUS),
+    ("2mp", image::_2MP),
+    ("30fps", device::_30FPS),
+    ("30fps_select", image::_30FPS_SELECT),
+    ("360", maps::_360),
+    ("3d_rotation", action::_3D_ROTATION),
+    ("3g_mobiledata", device::_3G_MOBILEDATA),
+    ("3k", av::_3K),
+    ("3k_plus", av::_3K_PLUS),
+    ("3mp", image::_3MP),
+    ("3p", communication::_3P),
+    ("4g_mobiledata", device::_4G_MOBILEDATA),
+    ("4g_plus_mobiledata", device::_4G_PLUS_MOBILEDATA),
+    ("4k", av::_4K),
+    ("4k_plus", av::_4K_PLUS),
+    ("4mp", image::_4MP),
+    ("5g", av::_5G),
+    ("5k", av::_5K),
+    ("5k_plus", av::_5K_PLUS),
+    ("5mp", image::_5MP),
+    ("60fps", device::_60FPS),
+    ("60fps_select", image::_60FPS_SELECT),
+    ("6_ft_apart", social::_6_FT_APART),
+    ("6k", av::_6K),
+    ("6k_plus", av::_6K_PLUS),
+    ("6mp", image::_6MP),
+    ("7k", av::_7K),
+    ("7k_plus", av::_7K_PLUS),
+    ("7mp", image::_7MP),
+    ("8k", av::_8K),
+    ("8k_plus", av::_8K_PLUS),
+    ("8mp", image::_8MP),
+    ("9k", av::_9K),
+    ("9k_plus", av::_9K_PLUS),
+    ("9mp", image::_9MP),
+    ("abc", action::ABC),
+    ("ac_unit", places::AC_UNIT),
+    ("access_alarm", device::ACCESS_ALARM),
+    ("access_alarms", device::ACCESS_ALARMS),
+    ("access_time", device::ACCESS_TIME),
+    ("access_time_filled", device::ACCESS_TIME_FILLED),
+    ("accessibility", action::ACCESSIBILITY),
+    ("accessibility_new", action::ACCESSIBILITY_NEW),
+    ("accessible", action::ACCESSIBLE),
+    ("accessible_forward", action::ACCESSIBLE_FORWARD),
+    ("account_balance", action::ACCOUNT_BALANCE),
+    ("account_balance_wallet", action::ACCOUNT_BALANCE_WALLET),
+    ("account_box", action::ACCOUNT_BOX),
+    ("account_circle", action::ACCOUNT_CIRCLE),
+    ("account_tree", notification::ACCOUNT_TREE),
+    ("ad_units", device::AD_UNITS),
+    ("adb", notification::ADB),
+    ("add", content::ADD),
+    ("add_a_photo", image::ADD_A_PHOTO),
+    ("add_alarm", device::ADD_ALARM),
+    ("add_alert", alert::ADD_ALERT),
+    ("add_box", content::ADD_BOX),
+    ("add_business", maps::ADD_BUSINESS),
+    ("add_call", notification::ADD_CALL),
+    ("add_card", action::ADD_CARD),
+    ("add_chart", editor::ADD_CHART),
+    ("add_circle", content::ADD_CIRCLE),
+    ("add_circle_outline", content::ADD_CIRCLE_OUTLINE),
+    ("add_comment", editor::ADD_COMMENT),
+    ("add_ic_call", communication::ADD_IC_CALL),
+    ("add_link", content::ADD_LINK),
+    ("add_location", maps::ADD_LOCATION),
+    ("add_location_alt", maps::ADD_LOCATION_ALT),
+    ("add_moderator", social::ADD_MODERATOR),
+    ("add_photo_alternate", image::ADD_PHOTO_ALTERNATE),
+    ("add_reaction", social::ADD_REACTION),
+    ("add_road", maps::ADD_ROAD),
+    ("add_shopping_cart", action::ADD_SHOPPING_CART),
+    ("add_task", action::ADD_TASK),
+    ("add_to_drive", action::ADD_TO_DRIVE),
+    ("add_to_home_screen", device::ADD_TO_HOME_SCREEN),
+    ("add_to_photos", image::ADD_TO_PHOTOS),
+    ("add_to_queue", av::ADD_TO_QUEUE),
+    ("addchart", action::ADDCHART),
+    ("adf_scanner", hardware::ADF_SCANNER),
+    ("adjust", image::ADJUST),
+    ("admin_panel_settings", action::ADMIN_PANEL_SETTINGS),
+    ("ads_click", action::ADS_CLICK),
+    ("agriculture", maps::AGRICULTURE),
+    ("air", device::AIR),
+    ("airline_seat_flat", notification::AIRLINE_SEAT_FLAT),
+    ("airline_seat_flat_angled", notification::AIRLINE_SEAT_FLAT_ANGLED),
+    ("airline_seat_individual_suite", notification::AIRLINE_SEAT_INDIVIDUAL_SUITE),
+    ("airline_seat_legroom_extra", notification::AIRLINE_SEAT_LEGROOM_EXTRA),
+    ("airline_seat_legroom_normal", notification::AIRLINE_SEAT_LEGROOM_NORMAL),
+    ("airline_seat_legroom_reduced", notification::AIRLINE_SEAT_LEGROOM_REDUCED),
+    ("airline_seat_recline_extra", notification::AIRLINE_SEAT_RECLINE_EXTRA),
+    ("airline_seat_recline_normal", notification::AIRLINE_SEAT_RECLINE_NORMAL),
+    ("airline_stops", maps::AIRLINE_STOPS),
+    ("airlines", maps::AIRLINES),
+    ("airplane_ticket", device::AIRPLANE_TICKET),
+    ("airplanemode_active", device::AIRPLANEMODE_ACTIVE),
+    ("airplanemode_inactive", device::AIRPLANEMODE_INACTIVE),
+    ("airplay", av::AIRPLAY),
+    ("airport_shuttle", places::AIRPORT_SHUTTLE),
+    ("alarm", action::ALARM),
+    ("alarm_add", action::ALARM_ADD),
+    ("alarm_off", action::ALARM_OFF),
+    ("alarm_on", action::ALARM_ON),
+    ("album", av::ALBUM),
+    ("align_horizontal_center", editor::ALIGN_HORIZONTAL_CENTER),
+    ("align_horizontal_left", editor::ALIGN_HORIZONTAL_LEFT),
+    ("align_horizontal_right", editor::ALIGN_HORIZONTAL_RIGHT),
+    ("align_vertical_bottom", editor::ALIGN_VERTICAL_BOTTOM),
+    ("align_vertical_center", editor::ALIGN_VERTICAL_CENTER),
+    ("align_vertical_top", editor::ALIGN_VERTICAL_TOP),
+    ("all_inbox", action::ALL_INBOX),
+    ("all_inclusive", places::ALL_INCLUSIVE),
+    ("all_out", action::ALL_OUT),
+    ("alt_route", maps::ALT_ROUTE),
+    ("alternate_email", communication::ALTERNATE_EMAIL),
+    ("amp_stories", content::AMP_STORIES),
+    ("analytics", action::ANALYTICS),
+    ("anchor", action::ANCHOR),
+    ("android", action::ANDROID),
+    ("animation", image::ANIMATION),
+    ("announcement", action::ANNOUNCEMENT),
+    ("aod", device::AOD),
+    ("apartment", places::APARTMENT),
+    ("api", action::API),
+    ("app_blocking", action::APP_BLOCKING),
+    ("app_registration", communication::APP_REGISTRATION),
+    ("app_settings_alt", navigation::APP_SETTINGS_ALT),
+    ("app_shortcut", action::APP_SHORTCUT),
+    ("approval", file::APPROVAL),
+    ("apps", navigation::APPS),
+    ("apps_outage", navigation::APPS_OUTAGE),
+    ("architecture", social::ARCHITECTURE),
+    ("archive", content::ARCHIVE),
+    ("area_chart", editor::AREA_CHART),
+    ("arrow_back", navigation::ARROW_BACK),
+    ("arrow_back_ios", navigation::ARROW_BACK_IOS),
+    ("arrow_back_ios_new", navigation::ARROW_BACK_IOS_NEW),
+    ("arrow_circle_down", action::ARROW_CIRCLE_DOWN),
+    ("arrow_circle_left", action::ARROW_CIRCLE_LEFT),
+    ("arrow_circle_right", action::ARROW_CIRCLE_RIGHT),
+    ("arrow_circle_up", action::ARROW_CIRCLE_UP),
+    ("arrow_downward", navigation::ARROW_DOWNWARD),
+    ("arrow_drop_down", navigation::ARROW_DROP_DOWN),
+    ("arrow_drop_down_circle", navigation::ARROW_DROP_DOWN_CIRCLE),
+    ("arrow_drop_up", navigation::ARROW_DROP_UP),
+    ("arrow_forward", navigation::ARROW_FORWARD),
+    ("arrow_forward_ios", navigation::ARROW_FORWARD_IOS),
+    ("arrow_left", navigation::ARROW_LEFT),
+    ("arrow_right", navigation::ARROW_RIGHT),
+    ("arrow_right_alt", action::ARROW_RIGHT_ALT),
+    ("arrow_upward", navigation::ARROW_UPWARD),
+    ("art_track", av::ART_TRACK),
+    ("article", action::ARTICLE),
+    ("aspect_ratio", action::ASPECT_RATIO),
+    ("assessment", action::ASSESSMENT),
+    ("assignment", action::ASSIGNMENT),
+    ("assignment_ind", action::ASSIGNMENT_IND),
+    ("assignment_late", action::ASSIGNMENT_LATE),
+    ("assignment_return", action::ASSIGNMENT_RETURN),
+    ("assignment_returned", action::ASSIGNMENT_RETURNED),
+    ("assignment_turned_in", action::ASSIGNMENT_TURNED_IN),
+    ("assistant", image::ASSISTANT),
+    ("assistant_direction", navigation::ASSISTANT_DIRECTION),
+    ("assistant_navigation", navigation::ASSISTANT_NAVIGATION),
+    ("assistant_photo", image::ASSISTANT_PHOTO),
+    ("assured_workload", action::ASSURED_WORKLOAD),
+    ("atm", maps::ATM),
+    ("attach_email", file::ATTACH_EMAIL),
+    ("attach_file", editor::ATTACH_FILE),
+    ("attach_money", editor::ATTACH_MONEY),
+    ("attachment", file::ATTACHMENT),
+    ("attractions", maps::ATTRACTIONS),
+    ("attribution", content::ATTRIBUTION),
+    ("audio_file", av::AUDIO_FILE),
+    ("audiotrack", image::AUDIOTRACK),
+    ("auto_awesome", image::AUTO_AWESOME),
+    ("auto_awesome_mosaic", image::AUTO_AWESOME_MOSAIC),
+    ("auto_awesome_motion", image::AUTO_AWESOME_MOTION),
+    ("auto_delete", alert::AUTO_DELETE),
+    ("auto_fix_high", image::AUTO_FIX_HIGH),
+    ("auto_fix_normal", image::AUTO_FIX_NORMAL),
+    ("auto_fix_off", image::AUTO_FIX_OFF),
+    ("auto_graph", editor::AUTO_GRAPH),
+    ("auto_stories", image::AUTO_STORIES),
+    ("autofps_select", image::AUTOFPS_SELECT),
+    ("autorenew", action::AUTORENEW),
+    ("av_timer", av::AV_TIMER),
+    ("baby_changing_station", places::BABY_CHANGING_STATION),
+    ("back_hand", social::BACK_HAND),
+    ("backpack", places::BACKPACK),
+    ("backspace", content::BACKSPACE),
+    ("backup", action::BACKUP),
+    ("backup_table", action::BACKUP_TABLE),
+    ("badge", maps::BADGE),
+    ("bakery_dining", maps::BAKERY_DINING),
+    ("balance", action::BALANCE),
+    ("balcony", places::BALCONY),
+    ("ballot", content::BALLOT),
+    ("bar_chart", editor::BAR_CHART),
+    ("batch_prediction", action::BATCH_PREDICTION),
+    ("bathroom", search::BATHROOM),
+    ("bathtub", places::BATHTUB),
+    ("battery_0_bar", device::BATTERY_0_BAR),
+    ("battery_1_bar", device::BATTERY_1_BAR),
+    ("battery_2_bar", device::BATTERY_2_BAR),
+    ("battery_3_bar", device::BATTERY_3_BAR),
+    ("battery_4_bar", device::BATTERY_4_BAR),
+    ("battery_5_bar", device::BATTERY_5_BAR),
+    ("battery_6_bar", device::BATTERY_6_BAR),
+    ("battery_alert", device::BATTERY_ALERT),
+    ("battery_charging_full", device::BATTERY_CHARGING_FULL),
+    ("battery_full", device::BATTERY_FULL),
+    ("battery_saver", device::BATTERY_SAVER),
+    ("battery_std", device::BATTERY_STD),
+    ("battery_unknown", device::BATTERY_UNKNOWN),
+    ("beach_access", places::BEACH_ACCESS),
+    ("bed", search::BED),
+    ("bedroom_baby", search::BEDROOM_BABY),
+    ("bedroom_child", search::BEDROOM_CHILD),
+    ("bedroom_parent", search::BEDROOM_PARENT),
+    ("bedtime", image::BEDTIME),
+    ("bedtime_off", image::BEDTIME_OFF),
+    ("beenhere", maps::BEENHERE),
+    ("bento", places::BENTO),
+    ("bike_scooter", maps::BIKE_SCOOTER),
+    ("biotech", content::BIOTECH),
+    ("blender", search::BLENDER),
+    ("block", content::BLOCK),
+    ("block_flipped", content::BLOCK_FLIPPED),
+    ("bloodtype", device::BLOODTYPE),
+    ("bluetooth", device::BLUETOOTH),
+    ("bluetooth_audio", notification::BLUETOOTH_AUDIO),
+    ("bluetooth_connected", device::BLUETOOTH_CONNECTED),
+    ("bluetooth_disabled", device::BLUETOOTH_DISABLED),
+    ("bluetooth_drive", device::BLUETOOTH_DRIVE),
+    ("bluetooth_searching", device::BLUETOOTH_SEARCHING),
+    ("blur_circular", image::BLUR_CIRCULAR),
+    ("blur_linear", image::BLUR_LINEAR),
+    ("blur_off", image::BLUR_OFF),
+    ("blur_on", image::BLUR_ON),
+    ("bolt", content::BOLT),
+    ("book", action::BOOK),
+    ("book_online", action::BOOK_ONLINE),
+    ("bookmark", action::BOOKMARK),
+    ("bookmark_add", action::BOOKMARK_ADD),
+    ("bookmark_added", action::BOOKMARK_ADDED),
+    ("bookmark_border", action::BOOKMARK_BORDER),
+    ("bookmark_remove", action::BOOKMARK_REMOVE),
+    ("bookmarks", action::BOOKMARKS),
+    ("border_all", editor::BORDER_ALL),
+    ("border_bottom", editor::BORDER_BOTTOM),
+    ("border_clear", editor::BORDER_CLEAR),
+    ("border_color", editor::BORDER_COLOR),
+    ("border_horizontal", editor::BORDER_HORIZONTAL),
+    ("border_inner", editor::BORDER_INNER),
+    ("border_left", editor::BORDER_LEFT),
+    ("border_outer", editor::BORDER_OUTER),
+    ("border_right", editor::BORDER_RIGHT),
+    ("border_style", editor::BORDER_STYLE),
+    ("border_top", editor::BORDER_TOP),
+    ("border_vertical", editor::BORDER_VERTICAL),
+    ("boy", social::BOY),
+    ("branding_watermark", av::BRANDING_WATERMARK),
+    ("breakfast_dining", maps::BREAKFAST_DINING),
+    ("brightness_1", image::BRIGHTNESS_1),
+    ("brightness_2", image::BRIGHTNESS_2),
+    ("brightness_3", image::BRIGHTNESS_3),
+    ("brightness_4", image::BRIGHTNESS_4),
+    ("brightness_5", image::BRIGHTNESS_5),
+    ("brightness_6", image::BRIGHTNESS_6),
+    ("brightness_7", image::BRIGHTNESS_7),
+    ("brightness_auto", device::BRIGHTNESS_AUTO),
+    ("brightness_high", device::BRIGHTNESS_HIGH),
+    ("brightness_low", device::BRIGHTNESS_LOW),
+    ("brightness_medium", device::BRIGHTNESS_MEDIUM),
+    ("broken_image", image::BROKEN_IMAGE),
+    ("browse_gallery", action::BROWSE_GALLERY),
+    ("browser_not_supported", hardware::BROWSER_NOT_SUPPORTED),
+    ("browser_updated", hardware::BROWSER_UPDATED),
+    ("brunch_dining", maps::BRUNCH_DINING),
+    ("brush", image::BRUSH),
+    ("bubble_chart", editor::BUBBLE_CHART),
+    ("bug_report", action::BUG_REPORT),
+    ("build", action::BUILD),
+    ("build_circle", action::BUILD_CIRCLE),
+    ("bungalow", places::BUNGALOW),
+    ("burst_mode", image::BURST_MODE),
+    ("bus_alert", maps::BUS_ALERT),
+    ("business", communication::BUSINESS),
+    ("business_center", places::BUSINESS_CENTER),
+    ("cabin", places::CABIN),
+    ("cable", device::CABLE),
+    ("cached", action::CACHED),
+    ("cake", social::CAKE),
+    ("calculate", content::CALCULATE),
+    ("calendar_month", action::CALENDAR_MONTH),
+    ("calendar_today", action::CALENDAR_TODAY),
+    ("calendar_view_day", action::CALENDAR_VIEW_DAY),
+    ("calendar_view_month", action::CALENDAR_VIEW_MONTH),
+    ("calendar_view_week", action::CALENDAR_VIEW_WEEK),
+    ("call", communication::CALL),
+    ("call_end", communication::CALL_END),
+    ("call_made", communication::CALL_MADE),
+    ("call_merge", communication::CALL_MERGE),
+    ("call_missed", communication::CALL_MISSED),
+    ("call_missed_outgoing", communication::CALL_MISSED_OUTGOING),
+    ("call_received", communication::CALL_RECEIVED),
+    ("call_split", communication::CALL_SPLIT),
+    ("call_to_action", av::CALL_TO_ACTION),
+    ("camera", image::CAMERA),
+    ("camera_alt", image::CAMERA_ALT),
+    ("camera_enhance", action::CAMERA_ENHANCE),
+    ("camera_front", image::CAMERA_FRONT),
+    ("camera_indoor", search::CAMERA_INDOOR),
+    ("camera_outdoor", search::CAMERA_OUTDOOR),
+    ("camera_rear", image::CAMERA_REAR),
+    ("camera_roll", image::CAMERA_ROLL),
+    ("cameraswitch", device::CAMERASWITCH),
+    ("campaign", navigation::CAMPAIGN),
+    ("cancel", navigation::CANCEL),
+    ("cancel_presentation", communication::CANCEL_PRESENTATION),
+    ("cancel_schedule_send", action::CANCEL_SCHEDULE_SEND),
+    ("candlestick_chart", editor::CANDLESTICK_CHART),
+    ("car_crash", maps::CAR_CRASH),
+    ("car_rental", maps::CAR_RENTAL),
+    ("car_repair", maps::CAR_REPAIR),
+    ("card_giftcard", action::CARD_GIFTCARD),
+    ("card_membership", action::CARD_MEMBERSHIP),
+    ("card_travel", action::CARD_TRAVEL),
+    ("carpenter", places::CARPENTER),
+    ("cases", image::CASES),
+    ("casino", places::CASINO),
+    ("cast", hardware::CAST),
+    ("cast_connected", hardware::CAST_CONNECTED),
+    ("cast_for_education", hardware::CAST_FOR_EDUCATION),
+    ("castle", maps::CASTLE),
+    ("catching_pokemon", social::CATCHING_POKEMON),
+    ("category", maps::CATEGORY),
+    ("celebration", maps::CELEBRATION),
+    ("cell_tower", communication::CELL_TOWER),
+    ("cell_wifi", communication::CELL_WIFI),
+    ("center_focus_strong", image::CENTER_FOCUS_STRONG),
+    ("center_focus_weak", image::CENTER_FOCUS_WEAK),
+    ("chair", search::CHAIR),
+    ("chair_alt", search::CHAIR_ALT),
+    ("chalet", places::CHALET),
+    ("change_circle", content::CHANGE_CIRCLE),
+    ("change_history", action::CHANGE_HISTORY),
+    ("charging_station", places::CHARGING_STATION),
+    ("chat", communication::CHAT),
+    ("chat_bubble", communication::CHAT_BUBBLE),
+    ("chat_bubble_outline", communication::CHAT_BUBBLE_OUTLINE),
+    ("check", navigation::CHECK),
+    ("check_box", toggle::CHECK_BOX),
+    ("check_box_outline_blank", toggle::CHECK_BOX_OUTLINE_BLANK),
+    ("check_circle", action::CHECK_CIRCLE),
+    ("check_circle_outline", action::CHECK_CIRCLE_OUTLINE),
+    ("checklist", editor::CHECKLIST),
+    ("checklist_rtl", editor::CHECKLIST_RTL),
+    ("checkroom", places::CHECKROOM),
+    ("chevron_left", navigation::CHEVRON_LEFT),
+    ("chevron_right", navigation::CHEVRON_RIGHT),
+    ("child_care", places::CHILD_CARE),
+    ("child_friendly", places::CHILD_FRIENDLY),
+    ("chrome_reader_mode", action::CHROME_READER_MODE),
+    ("church", maps::CHURCH),
+    ("circle", image::CIRCLE),
+    ("circle_notifications", action::CIRCLE_NOTIFICATIONS),
+    ("class", action::CLASS),
+    ("clean_hands", social::CLEAN_HANDS),
+    ("cleaning_services", maps::CLEANING_SERVICES),
+    ("clear", content::CLEAR),
+    ("clear_all", communication::CLEAR_ALL),
+    ("close", navigation::CLOSE),
+    ("close_fullscreen", action::CLOSE_FULLSCREEN),
+    ("closed_caption", av::CLOSED_CAPTION),
+    ("closed_caption_disabled", av::CLOSED_CAPTION_DISABLED),
+    ("closed_caption_off", av::CLOSED_CAPTION_OFF),
+    ("cloud", file::CLOUD),
+    ("cloud_circle", file::CLOUD_CIRCLE),
+    ("cloud_done", file::CLOUD_DONE),
+    ("cloud_download", file::CLOUD_DOWNLOAD),
+    ("cloud_off", file::CLOUD_OFF),
+    ("cloud_queue", file::CLOUD_QUEUE),
+    ("cloud_sync", file::CLOUD_SYNC),
+    ("cloud_upload", file::CLOUD_UPLOAD),
+    ("cloudy_snowing", home::CLOUDY_SNOWING),
+    ("co2", social::CO2),
+    ("co_present", communication::CO_PRESENT),
+    ("code", action::CODE),
+    ("code_off", action::CODE_OFF),
+    ("coffee", search::COFFEE),
+    ("coffee_maker", search::COFFEE_MAKER),
+    ("collections", image::COLLECTIONS),
+    ("collections_bookmark", image::COLLECTIONS_BOOKMARK),
+    ("color_lens", image::COLOR_LENS),
+    ("colorize", image::COLORIZE),
+    ("comment", communication::COMMENT),
+    ("comment_bank", action::COMMENT_BANK),
+    ("comments_disabled", communication::COMMENTS_DISABLED),
+    ("commit", action::COMMIT),
+    ("commute", action::COMMUTE),
+    ("compare", image::COMPARE),
+    ("compare_arrows", action::COMPARE_ARROWS),
+    ("compass_calibration", maps::COMPASS_CALIBRATION),
+    ("compost", social::COMPOST),
+    ("compress", action::COMPRESS),
+    ("computer", hardware::COMPUTER),
+    ("confirmation_number", notification::CONFIRMATION_NUMBER),
+    ("connect_without_contact", social::CONNECT_WITHOUT_CONTACT),
+    ("connected_tv", hardware::CONNECTED_TV),
+    ("connecting_airports", maps::CONNECTING_AIRPORTS),
+    ("construction", social::CONSTRUCTION),
+    ("contact_mail", communication::CONTACT_MAIL),
+    ("contact_page", action::CONTACT_PAGE),
+    ("contact_phone", communication::CONTACT_PHONE),
+    ("contact_support", action::CONTACT_SUPPORT),
+    ("contactless", action::CONTACTLESS),
+    ("contacts", communication::CONTACTS),
+    ("content_copy", content::CONTENT_COPY),
+    ("content_cut", content::CONTENT_CUT),
+    ("content_paste", content::CONTENT_PASTE),
+    ("content_paste_go", content::CONTENT_PASTE_GO),
+    ("content_paste_off", content::CONTENT_PASTE_OFF),
+    ("content_paste_search", content::CONTENT_PASTE_SEARCH),
+    ("contrast", image::CONTRAST),
+    ("control_camera", av::CONTROL_CAMERA),
+    ("control_point", image::CONTROL_POINT),
+    ("control_point_duplicate", image::CONTROL_POINT_DUPLICATE),
+    ("cookie", social::COOKIE),
+    ("copy_all", content::COPY_ALL),
+    ("copyright", action::COPYRIGHT),
+    ("coronavirus", social::CORONAVIRUS),
+    ("corporate_fare", places::CORPORATE_FARE),
+    ("cottage", places::COTTAGE),
+    ("countertops", places::COUNTERTOPS),
+    ("create", content::CREATE),
+    ("create_new_folder", file::CREATE_NEW_FOLDER),
+    ("credit_card", action::CREDIT_CARD),
+    ("credit_card_off", action::CREDIT_CARD_OFF),
+    ("credit_score", device::CREDIT_SCORE),
+    ("crib", places::CRIB),
+    ("crisis_alert", maps::CRISIS_ALERT),
+    ("crop", image::CROP),
+    ("crop_16_9", image::CROP_16_9),
+    ("crop_3_2", image::CROP_3_2),
+    ("crop_5_4", image::CROP_5_4),
+    ("crop_7_5", image::CROP_7_5),
+    ("crop_din", image::CROP_DIN),
+    ("crop_free", image::CROP_FREE),
+    ("crop_landscape", image::CROP_LANDSCAPE),
+    ("crop_original", image::CROP_ORIGINAL),
+    ("crop_portrait", image::CROP_PORTRAIT),
+    ("crop_rotate", image::CROP_ROTATE),
+    ("crop_square", image::CROP_SQUARE),
+    ("cruelty_free", social::CRUELTY_FREE),
+    ("css", action::CSS),
+    ("currency_bitcoin", image::CURRENCY_BITCOIN),
+    ("currency_exchange", action::CURRENCY_EXCHANGE),
+    ("currency_franc", image::CURRENCY_FRANC),
+    ("currency_lira", image::CURRENCY_LIRA),
+    ("currency_pound", image::CURRENCY_POUND),
+    ("currency_ruble", image::CURRENCY_RUBLE),
+    ("currency_rupee", image::CURRENCY_RUPEE),
+    ("currency_yen", image::CURRENCY_YEN),
+    ("currency_yuan", image::CURRENCY_YUAN),
+    ("cyclone", social::CYCLONE),
+    ("dangerous", action::DANGEROUS),
+    ("dark_mode", device::DARK_MODE),
+    ("dashboard", action::DASHBOARD),
+    ("dashboard_customize", action::DASHBOARD_CUSTOMIZE),
+    ("data_array", editor::DATA_ARRAY),
+    ("data_exploration", action::DATA_EXPLORATION),
+    ("data_object", editor::DATA_OBJECT),
+    ("data_saver_off", device::DATA_SAVER_OFF),
+    ("data_saver_on", device::DATA_SAVER_ON),
+    ("data_thresholding", action::DATA_THRESHOLDING),
+    ("data_usage", device::DATA_USAGE),
+    ("date_range", action::DATE_RANGE),
+    ("deblur", image::DEBLUR),
+    ("deck", social::DECK),
+    ("dehaze", image::DEHAZE),
+    ("delete", action::DELETE),
+    ("delete_forever", action::DELETE_FOREVER),
+    ("delete_outline", action::DELETE_OUTLINE),
+    ("delete_sweep", content::DELETE_SWEEP),
+    ("delivery_dining", maps::DELIVERY_DINING),
+    ("density_large", action::DENSITY_LARGE),
+    ("density_medium", action::DENSITY_MEDIUM),
+    ("density_small", action::DENSITY_SMALL),
+    ("departure_board", maps::DEPARTURE_BOARD),
+    ("description", action::DESCRIPTION),
+    ("deselect", content::DESELECT),
+    ("design_services", maps::DESIGN_SERVICES),
+    ("desktop_access_disabled", communication::DESKTOP_ACCESS_DISABLED),
+    ("desktop_mac", hardware::DESKTOP_MAC),
+    ("desktop_windows", hardware::DESKTOP_WINDOWS),
+    ("details", image::DETAILS),
+    ("developer_board", hardware::DEVELOPER_BOARD),
+    ("developer_board_off", hardware::DEVELOPER_BOARD_OFF),
+    ("developer_mode", device::DEVELOPER_MODE),
+    ("device_hub", hardware::DEVICE_HUB),
+    ("device_thermostat", device::DEVICE_THERMOSTAT),
+    ("device_unknown", hardware::DEVICE_UNKNOWN),
+    ("devices", device::DEVICES),
+    ("devices_fold", device::DEVICES_FOLD),
+    ("devices_other", hardware::DEVICES_OTHER),
+    ("dialer_sip", communication::DIALER_SIP),
+    ("dialpad", communication::DIALPAD),
+    ("diamond", maps::DIAMOND),
+    ("difference", file::DIFFERENCE),
+    ("dining", search::DINING),
+    ("dinner_dining", maps::DINNER_DINING),
+    ("directions", maps::DIRECTIONS),
+    ("directions_bike", maps::DIRECTIONS_BIKE),
+    ("directions_boat", maps::DIRECTIONS_BOAT),
+    ("directions_boat_filled", maps::DIRECTIONS_BOAT_FILLED),
+    ("directions_bus", maps::DIRECTIONS_BUS),
+    ("directions_bus_filled", maps::DIRECTIONS_BUS_FILLED),
+    ("directions_car", maps::DIRECTIONS_CAR),
+    ("directions_car_filled", maps::DIRECTIONS_CAR_FILLED),
+    ("directions_off", notification::DIRECTIONS_OFF),
+    ("directions_railway", maps::DIRECTIONS_RAILWAY),
+    ("directions_railway_filled", maps::DIRECTIONS_RAILWAY_FILLED),
+    ("directions_run", maps::DIRECTIONS_RUN),
+    ("directions_subway", maps::DIRECTIONS_SUBWAY),
+    ("directions_subway_filled", maps::DIRECTIONS_SUBWAY_FILLED),
+    ("directions_transit", maps::DIRECTIONS_TRANSIT),
+    ("directions_transit_filled", maps::DIRECTIONS_TRANSIT_FILLED),
+    ("directions_walk", maps::DIRECTIONS_WALK),
+    ("dirty_lens", image::DIRTY_LENS),
+    ("disabled_by_default", action::DISABLED_BY_DEFAULT),
+    ("disabled_visible", action::DISABLED_VISIBLE),
+    ("disc_full", notification::DISC_FULL),
+    ("discount", device::DISCOUNT),
+    ("display_settings", action::DISPLAY_SETTINGS),
+    ("dns", action::DNS),
+    ("do_disturb", notification::DO_DISTURB),
+    ("do_disturb_alt", notification::DO_DISTURB_ALT),
+    ("do_disturb_off", notification::DO_DISTURB_OFF),
+    ("do_disturb_on", notification::DO_DISTURB_ON),
+    ("do_not_disturb", notification::DO_NOT_DISTURB),
+    ("do_not_disturb_alt", notification::DO_NOT_DISTURB_ALT),
+    ("do_not_disturb_off", notification::DO_NOT_DISTURB_OFF),
+    ("do_not_disturb_on", notification::DO_NOT_DISTURB_ON),
+    ("do_not_disturb_on_total_silence", device::DO_NOT_DISTURB_ON_TOTAL_SILENCE),
+    ("do_not_step", places::DO_NOT_STEP),
+    ("do_not_touch", places::DO_NOT_TOUCH),
+    ("dock", hardware::DOCK),
+    ("document_scanner", communication::DOCUMENT_SCANNER),
+    ("domain", social::DOMAIN),
+    ("domain_add", social::DOMAIN_ADD),
+    ("domain_disabled", communication::DOMAIN_DISABLED),
+    ("domain_verification", communication::DOMAIN_VERIFICATION),
+    ("done", action::DONE),
+    ("done_all", action::DONE_ALL),
+    ("done_outline", action::DONE_OUTLINE),
+    ("donut_large", action::DONUT_LARGE),
+    ("donut_small", action::DONUT_SMALL),
+    ("door_back", search::DOOR_BACK),
+    ("door_front", search::DOOR_FRONT),
+    ("door_sliding", search::DOOR_SLIDING),
+    ("doorbell", search::DOORBELL),
+    ("double_arrow", navigation::DOUBLE_ARROW),
+    ("downhill_skiing", social::DOWNHILL_SKIING),
+    ("download", file::DOWNLOAD),
+    ("download_done", file::DOWNLOAD_DONE),
+    ("download_for_offline", file::DOWNLOAD_FOR_OFFLINE),
+    ("downloading", file::DOWNLOADING),
+    ("drafts", content::DRAFTS),
+    ("drag_handle", editor::DRAG_HANDLE),
+    ("drag_indicator", action::DRAG_INDICATOR),
+    ("draw", editor::DRAW),
+    ("drive_eta", notification::DRIVE_ETA),
+    ("drive_file_move", file::DRIVE_FILE_MOVE),
+    ("drive_file_move_outline", file::DRIVE_FILE_MOVE_OUTLINE),
+    ("drive_file_move_rtl", file::DRIVE_FILE_MOVE_RTL),
+    ("drive_file_rename_outline", file::DRIVE_FILE_RENAME_OUTLINE),
+    ("drive_folder_upload", file::DRIVE_FOLDER_UPLOAD),
+    ("dry", places::DRY),
+    ("dry_cleaning", maps::DRY_CLEANING),
+    ("duo", communication::DUO),
+    ("dvr", device::DVR),
+    ("dynamic_feed", content::DYNAMIC_FEED),
+    ("dynamic_form", action::DYNAMIC_FORM),
+    ("e_mobiledata", device::E_MOBILEDATA),
+    ("earbuds", hardware::EARBUDS),
+    ("earbuds_battery", hardware::EARBUDS_BATTERY),
+    ("east", navigation::EAST),
+    ("eco", action::ECO),
+    ("edgesensor_high", device::EDGESENSOR_HIGH),
+    ("edgesensor_low", device::EDGESENSOR_LOW),
+    ("edit", image::EDIT),
+    ("edit_attributes", maps::EDIT_ATTRIBUTES),
+    ("edit_calendar", action::EDIT_CALENDAR),
+    ("edit_location", maps::EDIT_LOCATION),
+    ("edit_location_alt", maps::EDIT_LOCATION_ALT),
+    ("edit_note", editor::EDIT_NOTE),
+    ("edit_notifications", social::EDIT_NOTIFICATIONS),
+    ("edit_off", action::EDIT_OFF),
+    ("edit_road", maps::EDIT_ROAD),
+    ("egg", maps::EGG),
+    ("egg_alt", maps::EGG_ALT),
+    ("eject", action::EJECT),
+    ("elderly", social::ELDERLY),
+    ("elderly_woman", social::ELDERLY_WOMAN),
+    ("electric_bike", maps::ELECTRIC_BIKE),
+    ("electric_car", maps::ELECTRIC_CAR),
+    ("electric_moped", maps::ELECTRIC_MOPED),
+    ("electric_rickshaw", maps::ELECTRIC_RICKSHAW),
+    ("electric_scooter", maps::ELECTRIC_SCOOTER),
+    ("electrical_services", maps::ELECTRICAL_SERVICES),
+    ("elevator", places::ELEVATOR),
+    ("email", communication::EMAIL),
+    ("emergency", maps::EMERGENCY),
+    ("emergency_recording", maps::EMERGENCY_RECORDING),
+    ("emergency_share", maps::EMERGENCY_SHARE),
+    ("emoji_emotions", social::EMOJI_EMOTIONS),
+    ("emoji_events", social::EMOJI_EVENTS),
+    ("emoji_flags", social::EMOJI_FLAGS),
+    ("emoji_food_beverage", social::EMOJI_FOOD_BEVERAGE),
+    ("emoji_nature", social::EMOJI_NATURE),
+    ("emoji_objects", social::EMOJI_OBJECTS),
+    ("emoji_people", social::EMOJI_PEOPLE),
+    ("emoji_symbols", social::EMOJI_SYMBOLS),
+    ("emoji_transportation", social::EMOJI_TRANSPORTATION),
+    ("engineering", social::ENGINEERING),
+    ("enhanced_encryption", notification::ENHANCED_ENCRYPTION),
+    ("equalizer", av::EQUALIZER),
+    ("error", alert::ERROR),
+    ("error_outline", alert::ERROR_OUTLINE),
+    ("escalator", places::ESCALATOR),
+    ("escalator_warning", places::ESCALATOR_WARNING),
+    ("euro", image::EURO),
+    ("euro_symbol", action::EURO_SYMBOL),
+    ("ev_station", maps::EV_STATION),
+    ("event", action::EVENT),
+    ("event_available", notification::EVENT_AVAILABLE),
+    ("event_busy", notification::EVENT_BUSY),
+    ("event_note", notification::EVENT_NOTE),
+    ("event_repeat", action::EVENT_REPEAT),
+    ("event_seat", action::EVENT_SEAT),
+    ("exit_to_app", action::EXIT_TO_APP),
+    ("expand", action::EXPAND),
+    ("expand_circle_down", navigation::EXPAND_CIRCLE_DOWN),
+    ("expand_less", navigation::EXPAND_LESS),
+    ("expand_more", navigation::EXPAND_MORE),
+    ("explicit", av::EXPLICIT),
+    ("explore", action::EXPLORE),
+    ("explore_off", action::EXPLORE_OFF),
+    ("exposure", image::EXPOSURE),
+    ("exposure_neg_1", image::EXPOSURE_NEG_1),
+    ("exposure_neg_2", image::EXPOSURE_NEG_2),
+    ("exposure_plus_1", image::EXPOSURE_PLUS_1),
+    ("exposure_plus_2", image::EXPOSURE_PLUS_2),
+    ("exposure_zero", image::EXPOSURE_ZERO),
+    ("extension", action::EXTENSION),
+    ("extension_off", action::EXTENSION_OFF),
+    ("face", action::FACE),
+    ("face_retouching_natural", image::FACE_RETOUCHING_NATURAL),
+    ("face_retouching_off", image::FACE_RETOUCHING_OFF),
+    ("facebook", social::FACEBOOK),
+    ("fact_check", action::FACT_CHECK),
+    ("factory", maps::FACTORY),
+    ("family_restroom", places::FAMILY_RESTROOM),
+    ("fast_forward", av::FAST_FORWARD),
+    ("fast_rewind", av::FAST_REWIND),
+    ("fastfood", maps::FASTFOOD),
+    ("favorite", action::FAVORITE),
+    ("favorite_border", action::FAVORITE_BORDER),
+    ("fax", action::FAX),
+    ("featured_play_list", av::FEATURED_PLAY_LIST),
+    ("featured_video", av::FEATURED_VIDEO),
+    ("feed", search::FEED),
+    ("feedback", action::FEEDBACK),
+    ("female", social::FEMALE),
+    ("fence", places::FENCE),
+    ("festival", maps::FESTIVAL),
+    ("fiber_dvr", av::FIBER_DVR),
+    ("fiber_manual_record", av::FIBER_MANUAL_RECORD),
+    ("fiber_new", av::FIBER_NEW),
+    ("fiber_pin", av::FIBER_PIN),
+    ("fiber_smart_record", av::FIBER_SMART_RECORD),
+    ("file_copy", content::FILE_COPY),
+    ("file_download", file::FILE_DOWNLOAD),
+    ("file_download_done", file::FILE_DOWNLOAD_DONE),
+    ("file_download_off", file::FILE_DOWNLOAD_OFF),
+    ("file_open", file::FILE_OPEN),
+    ("file_present", action::FILE_PRESENT),
+    ("file_upload", file::FILE_UPLOAD),
+    ("filter", image::FILTER),
+    ("filter_1", image::FILTER_1),
+    ("filter_2", image::FILTER_2),
+    ("filter_3", image::FILTER_3),
+    ("filter_4", image::FILTER_4),
+    ("filter_5", image::FILTER_5),
+    ("filter_6", image::FILTER_6),
+    ("filter_7", image::FILTER_7),
+    ("filter_8", image::FILTER_8),
+    ("filter_9", image::FILTER_9),
+    ("filter_9_plus", image::FILTER_9_PLUS),
+    ("filter_alt", action::FILTER_ALT),
+    ("filter_alt_off", action::FILTER_ALT_OFF),
+    ("filter_b_and_w", image::FILTER_B_AND_W),
+    ("filter_center_focus", image::FILTER_CENTER_FOCUS),
+    ("filter_drama", image::FILTER_DRAMA),
+    ("filter_frames", image::FILTER_FRAMES),
+    ("filter_hdr", image::FILTER_HDR),
+    ("filter_list", content::FILTER_LIST),
+    ("filter_list_alt", action::FILTER_LIST_ALT),
+    ("filter_list_off", content::FILTER_LIST_OFF),
+    ("filter_none", image::FILTER_NONE),
+    ("filter_tilt_shift", image::FILTER_TILT_SHIFT),
+    ("filter_vintage", image::FILTER_VINTAGE),
+    ("find_in_page", action::FIND_IN_PAGE),
+    ("find_replace", action::FIND_REPLACE),
+    ("fingerprint", action::FINGERPRINT),
+    ("fire_extinguisher", places::FIRE_EXTINGUISHER),
+    ("fireplace", social::FIREPLACE),
+    ("first_page", navigation::FIRST_PAGE),
+    ("fit_screen", action::FIT_SCREEN),
+    ("fitbit", social::FITBIT),
+    ("fitness_center", places::FITNESS_CENTER),
+    ("flag", content::FLAG),
+    ("flag_circle", content::FLAG_CIRCLE),
+    ("flaky", action::FLAKY),
+    ("flare", image::FLARE),
+    ("flash_auto", image::FLASH_AUTO),
+    ("flash_off", image::FLASH_OFF),
+    ("flash_on", image::FLASH_ON),
+    ("flashlight_off", device::FLASHLIGHT_OFF),
+    ("flashlight_on", device::FLASHLIGHT_ON),
+    ("flatware", search::FLATWARE),
+    ("flight", maps::FLIGHT),
+    ("flight_class", maps::FLIGHT_CLASS),
+    ("flight_land", action::FLIGHT_LAND),
+    ("flight_takeoff", action::FLIGHT_TAKEOFF),
+    ("flip", image::FLIP),
+    ("flip_camera_android", image::FLIP_CAMERA_ANDROID),
+    ("flip_camera_ios", image::FLIP_CAMERA_IOS),
+    ("flip_to_back", action::FLIP_TO_BACK),
+    ("flip_to_front", action::FLIP_TO_FRONT),
+    ("flood", social::FLOOD),
+    ("flourescent", device::FLOURESCENT),
+    ("flutter_dash", action::FLUTTER_DASH),
+    ("fmd_bad", device::FMD_BAD),
+    ("fmd_good", device::FMD_GOOD),
+    ("foggy", home::FOGGY),
+    ("folder", file::FOLDER),
+    ("folder_copy", file::FOLDER_COPY),
+    ("folder_delete", file::FOLDER_DELETE),
+    ("folder_off", file::FOLDER_OFF),
+    ("folder_open", file::FOLDER_OPEN),
+    ("folder_shared", file::FOLDER_SHARED),
+    ("folder_special", notification::FOLDER_SPECIAL),
+    ("folder_zip", file::FOLDER_ZIP),
+    ("follow_the_signs", social::FOLLOW_THE_SIGNS),
+    ("font_download", content::FONT_DOWNLOAD),
+    ("font_download_off", content::FONT_DOWNLOAD_OFF),
+    ("food_bank", places::FOOD_BANK),
+    ("forest", maps::FOREST),
+    ("fork_left", maps::FORK_LEFT),
+    ("fork_right", maps::FORK_RIGHT),
+    ("format_align_center", editor::FORMAT_ALIGN_CENTER),
+    ("format_align_justify", editor::FORMAT_ALIGN_JUSTIFY),
+    ("format_align_left", editor::FORMAT_ALIGN_LEFT),
+    ("format_align_right", editor::FORMAT_ALIGN_RIGHT),
+    ("format_bold", editor::FORMAT_BOLD),
+    ("format_clear", editor::FORMAT_CLEAR),
+    ("format_color_fill", editor::FORMAT_COLOR_FILL),
+    ("format_color_reset", editor::FORMAT_COLOR_RESET),
+    ("format_color_text", editor::FORMAT_COLOR_TEXT),
+    ("format_indent_decrease", editor::FORMAT_INDENT_DECREASE),
+    ("format_indent_increase", editor::FORMAT_INDENT_INCREASE),
+    ("format_italic", editor::FORMAT_ITALIC),
+    ("format_line_spacing", editor::FORMAT_LINE_SPACING),
+    ("format_list_bulleted", editor::FORMAT_LIST_BULLETED),
+    ("format_list_numbered", editor::FORMAT_LIST_NUMBERED),
+    ("format_list_numbered_rtl", editor::FORMAT_LIST_NUMBERED_RTL),
+    ("format_overline", file::FORMAT_OVERLINE),
+    ("format_paint", editor::FORMAT_PAINT),
+    ("format_quote", editor::FORMAT_QUOTE),
+    ("format_shapes", editor::FORMAT_SHAPES),
+    ("format_size", editor::FORMAT_SIZE),
+    ("format_strikethrough", editor::FORMAT_STRIKETHROUGH),
+    ("format_textdirection_l_to_r", editor::FORMAT_TEXTDIRECTION_L_TO_R),
+    ("format_textdirection_r_to_l", editor::FORMAT_TEXTDIRECTION_R_TO_L),
+    ("format_underlined", editor::FORMAT_UNDERLINED),
+    ("fort", maps::FORT),
+    ("forum", communication::FORUM),
+    ("forward", content::FORWARD),
+    ("forward_10", av::FORWARD_10),
+    ("forward_30", av::FORWARD_30),
+    ("forward_5", av::FORWARD_5),
+    ("forward_to_inbox", communication::FORWARD_TO_INBOX),
+    ("foundation", places::FOUNDATION),
+    ("free_breakfast", places::FREE_BREAKFAST),
+    ("free_cancellation", action::FREE_CANCELLATION),
+    ("front_hand", social::FRONT_HAND),
+    ("fullscreen", navigation::FULLSCREEN),
+    ("fullscreen_exit", navigation::FULLSCREEN_EXIT),
+    ("functions", editor::FUNCTIONS),
+    ("g_mobiledata", device::G_MOBILEDATA),
+    ("g_translate", action::G_TRANSLATE),
+    ("gamepad", hardware::GAMEPAD),
+    ("games", av::GAMES),
+    ("garage", search::GARAGE),
+    ("gavel", action::GAVEL),
+    ("generating_tokens", action::GENERATING_TOKENS),
+    ("gesture", content::GESTURE),
+    ("get_app", action::GET_APP),
+    ("gif", action::GIF),
+    ("gif_box", action::GIF_BOX),
+    ("girl", social::GIRL),
+    ("gite", places::GITE),
+    ("golf_course", places::GOLF_COURSE),
+    ("gpp_bad", device::GPP_BAD),
+    ("gpp_good", device::GPP_GOOD),
+    ("gpp_maybe", device::GPP_MAYBE),
+    ("gps_fixed", device::GPS_FIXED),
+    ("gps_not_fixed", device::GPS_NOT_FIXED),
+    ("gps_off", device::GPS_OFF),
+    ("grade", action::GRADE),
+    ("gradient", image::GRADIENT),
+    ("grading", action::GRADING),
+    ("grain", image::GRAIN),
+    ("graphic_eq", device::GRAPHIC_EQ),
+    ("grass", places::GRASS),
+    ("grid_3x3", device::GRID_3X3),
+    ("grid_4x4", device::GRID_4X4),
+    ("grid_goldenratio", device::GRID_GOLDENRATIO),
+    ("grid_off", image::GRID_OFF),
+    ("grid_on", image::GRID_ON),
+    ("grid_view", file::GRID_VIEW),
+    ("group", social::GROUP),
+    ("group_add", social::GROUP_ADD),
+    ("group_off", social::GROUP_OFF),
+    ("group_remove", social::GROUP_REMOVE),
+    ("group_work", action::GROUP_WORK),
+    ("groups", social::GROUPS),
+    ("h_mobiledata", device::H_MOBILEDATA),
+    ("h_plus_mobiledata", device::H_PLUS_MOBILEDATA),
+    ("hail", maps::HAIL),
+    ("handshake", social::HANDSHAKE),
+    ("handyman", maps::HANDYMAN),
+    ("hardware", maps::HARDWARE),
+    ("hd", av::HD),
+    ("hdr_auto", device::HDR_AUTO),
+    ("hdr_auto_select", device::HDR_AUTO_SELECT),
+    ("hdr_enhanced_select", image::HDR_ENHANCED_SELECT),
+    ("hdr_off", image::HDR_OFF),
+    ("hdr_off_select", device::HDR_OFF_SELECT),
+    ("hdr_on", image::HDR_ON),
+    ("hdr_on_select", device::HDR_ON_SELECT),
+    ("hdr_plus", image::HDR_PLUS),
+    ("hdr_strong", image::HDR_STRONG),
+    ("hdr_weak", image::HDR_WEAK),
+    ("headphones", hardware::HEADPHONES),
+    ("headphones_battery", hardware::HEADPHONES_BATTERY),
+    ("headset", hardware::HEADSET),
+    ("headset_mic", hardware::HEADSET_MIC),
+    ("headset_off", hardware::HEADSET_OFF),
+    ("healing", image::HEALING),
+    ("health_and_safety", social::HEALTH_AND_SAFETY),
+    ("hearing", av::HEARING),
+    ("hearing_disabled", av::HEARING_DISABLED),
+    ("heart_broken", social::HEART_BROKEN),
+    ("height", editor::HEIGHT),
+    ("help", action::HELP),
+    ("help_center", action::HELP_CENTER),
+    ("help_outline", action::HELP_OUTLINE),
+    ("hevc", image::HEVC),
+    ("hexagon", editor::HEXAGON),
+    ("hide_image", image::HIDE_IMAGE),
+    ("hide_source", action::HIDE_SOURCE),
+    ("high_quality", av::HIGH_QUALITY),
+    ("highlight", editor::HIGHLIGHT),
+    ("highlight_alt", action::HIGHLIGHT_ALT),
+    ("highlight_off", action::HIGHLIGHT_OFF),
+    ("hiking", social::HIKING),
+    ("history", action::HISTORY),
+    ("history_edu", social::HISTORY_EDU),
+    ("history_toggle_off", action::HISTORY_TOGGLE_OFF),
+    ("hive", social::HIVE),
+    ("hls", action::HLS),
+    ("hls_off", action::HLS_OFF),
+    ("holiday_village", places::HOLIDAY_VILLAGE),
+    ("home", action::HOME),
+    ("home_filled", action::HOME_FILLED),
+    ("home_max", hardware::HOME_MAX),
+    ("home_mini", hardware::HOME_MINI),
+    ("home_repair_service", maps::HOME_REPAIR_SERVICE),
+    ("home_work", navigation::HOME_WORK),
+    ("horizontal_distribute", editor::HORIZONTAL_DISTRIBUTE),
+    ("horizontal_rule", editor::HORIZONTAL_RULE),
+    ("horizontal_split", action::HORIZONTAL_SPLIT),
+    ("hot_tub", places::HOT_TUB),
+    ("hotel", maps::HOTEL),
+    ("hotel_class", action::HOTEL_CLASS),
+    ("hourglass_bottom", communication::HOURGLASS_BOTTOM),
+    ("hourglass_disabled", action::HOURGLASS_DISABLED),
+    ("hourglass_empty", action::HOURGLASS_EMPTY),
+    ("hourglass_full", action::HOURGLASS_FULL),
+    ("hourglass_top", communication::HOURGLASS_TOP),
+    ("house", places::HOUSE),
+    ("house_siding", places::HOUSE_SIDING),
+    ("houseboat", places::HOUSEBOAT),
+    ("how_to_reg", content::HOW_TO_REG),
+    ("how_to_vote", content::HOW_TO_VOTE),
+    ("html", action::HTML),
+    ("http", action::HTTP),
+    ("https", action::HTTPS),
+    ("hub", communication::HUB),
+    ("hvac", maps::HVAC),
+    ("ice_skating", social::ICE_SKATING),
+    ("icecream", maps::ICECREAM),
+    ("image", image::IMAGE),
+    ("image_aspect_ratio", image::IMAGE_ASPECT_RATIO),
+    ("image_not_supported", image::IMAGE_NOT_SUPPORTED),
+    ("image_search", image::IMAGE_SEARCH),
+    ("imagesearch_roller", notification::IMAGESEARCH_ROLLER),
+    ("import_contacts", communication::IMPORT_CONTACTS),
+    ("import_export", communication::IMPORT_EXPORT),
+    ("important_devices", action::IMPORTANT_DEVICES),
+    ("inbox", content::INBOX),
+    ("incomplete_circle", image::INCOMPLETE_CIRCLE),
+    ("indeterminate_check_box", toggle::INDETERMINATE_CHECK_BOX),
+    ("info", action::INFO),
+    ("info_outline", action::INFO_OUTLINE),
+    ("input", action::INPUT),
+    ("insert_chart", editor::INSERT_CHART),
+    ("insert_chart_outlined", editor::INSERT_CHART_OUTLINED),
+    ("insert_comment", editor::INSERT_COMMENT),
+    ("insert_drive_file", editor::INSERT_DRIVE_FILE),
+    ("insert_emoticon", editor::INSERT_EMOTICON),
+    ("insert_invitation", editor::INSERT_INVITATION),
+    ("insert_link", editor::INSERT_LINK),
+    ("insert_page_break", editor::INSERT_PAGE_BREAK),
+    ("insert_photo", editor::INSERT_PHOTO),
+    ("insights", content::INSIGHTS),
+    ("install_desktop", action::INSTALL_DESKTOP),
+    ("install_mobile", action::INSTALL_MOBILE),
+    ("integration_instructions", action::INTEGRATION_INSTRUCTIONS),
+    ("interests", social::INTERESTS),
+    ("interpreter_mode", av::INTERPRETER_MODE),
+    ("inventory", content::INVENTORY),
+    ("inventory_2", content::INVENTORY_2),
+    ("invert_colors", action::INVERT_COLORS),
+    ("invert_colors_off", communication::INVERT_COLORS_OFF),
+    ("ios_share", social::IOS_SHARE),
+    ("iron", places::IRON),
+    ("iso", image::ISO),
+    ("javascript", action::JAVASCRIPT),
+    ("join_full", action::JOIN_FULL),
+    ("join_inner", action::JOIN_INNER),
+    ("join_left", action::JOIN_LEFT),
+    ("join_right", action::JOIN_RIGHT),
+    ("kayaking", social::KAYAKING),
+    ("kebab_dining", maps::KEBAB_DINING),
+    ("key", communication::KEY),
+    ("key_off", communication::KEY_OFF),
+    ("keyboard", hardware::KEYBOARD),
+    ("keyboard_alt", hardware::KEYBOARD_ALT),
+    ("keyboard_arrow_down", hardware::KEYBOARD_ARROW_DOWN),
+    ("keyboard_arrow_left", hardware::KEYBOARD_ARROW_LEFT),
+    ("keyboard_arrow_right", hardware::KEYBOARD_ARROW_RIGHT),
+    ("keyboard_arrow_up", hardware::KEYBOARD_ARROW_UP),
+    ("keyboard_backspace", hardware::KEYBOARD_BACKSPACE),
+    ("keyboard_capslock", hardware::KEYBOARD_CAPSLOCK),
+    ("keyboard_command_key", hardware::KEYBOARD_COMMAND_KEY),
+    ("keyboard_control_key", hardware::KEYBOARD_CONTROL_KEY),
+    ("keyboard_double_arrow_down", hardware::KEYBOARD_DOUBLE_ARROW_DOWN),
+    ("keyboard_double_arrow_left", hardware::KEYBOARD_DOUBLE_ARROW_LEFT),
+    ("keyboard_double_arrow_right", hardware::KEYBOARD_DOUBLE_ARROW_RIGHT),
+    ("keyboard_double_arrow_up", hardware::KEYBOARD_DOUBLE_ARROW_UP),
+    ("keyboard_hide", hardware::KEYBOARD_HIDE),
+    ("keyboard_option_key", hardware::KEYBOARD_OPTION_KEY),
+    ("keyboard_return", hardware::KEYBOARD_RETURN),
+    ("keyboard_tab", hardware::KEYBOARD_TAB),
+    ("keyboard_voice", hardware::KEYBOARD_VOICE),
+    ("king_bed", social::KING_BED),
+    ("kitchen", places::KITCHEN),
+    ("kitesurfing", social::KITESURFING),
+    ("label", action::LABEL),
+    ("label_important", action::LABEL_IMPORTANT),
+    ("label_important_outline", action::LABEL_IMPORTANT_OUTLINE),
+    ("label_off", action::LABEL_OFF),
+    ("label_outline", action::LABEL_OUTLINE),
+    ("lan", device::LAN),
+    ("landscape", image::LANDSCAPE),
+    ("landslide", social::LANDSLIDE),
+    ("language", action::LANGUAGE),
+    ("laptop", hardware::LAPTOP),
+    ("laptop_chromebook", hardware::LAPTOP_CHROMEBOOK),
+    ("laptop_mac", hardware::LAPTOP_MAC),
+    ("laptop_windows", hardware::LAPTOP_WINDOWS),
+    ("last_page", navigation::LAST_PAGE),
+    ("launch", action::LAUNCH),
+    ("layers", maps::LAYERS),
+    ("layers_clear", maps::LAYERS_CLEAR),
+    ("leaderboard", action::LEADERBOARD),
+    ("leak_add", image::LEAK_ADD),
+    ("leak_remove", image::LEAK_REMOVE),
+    ("legend_toggle", navigation::LEGEND_TOGGLE),
+    ("lens", image::LENS),
+    ("lens_blur", device::LENS_BLUR),
+    ("library_add", av::LIBRARY_ADD),
+    ("library_add_check", av::LIBRARY_ADD_CHECK),
+    ("library_books", av::LIBRARY_BOOKS),
+    ("library_music", av::LIBRARY_MUSIC),
+    ("light", search::LIGHT),
+    ("light_mode", device::LIGHT_MODE),
+    ("lightbulb", action::LIGHTBULB),
+    ("lightbulb_outline", action::LIGHTBULB_OUTLINE),
+    ("line_axis", editor::LINE_AXIS),
+    ("line_style", action::LINE_STYLE),
+    ("line_weight", action::LINE_WEIGHT),
+    ("linear_scale", editor::LINEAR_SCALE),
+    ("link", content::LINK),
+    ("link_off", content::LINK_OFF),
+    ("linked_camera", image::LINKED_CAMERA),
+    ("liquor", maps::LIQUOR),
+    ("list", action::LIST),
+    ("list_alt", communication::LIST_ALT),
+    ("live_help", communication::LIVE_HELP),
+    ("live_tv", notification::LIVE_TV),
+    ("living", search::LIVING),
+    ("local_activity", maps::LOCAL_ACTIVITY),
+    ("local_airport", maps::LOCAL_AIRPORT),
+    ("local_atm", maps::LOCAL_ATM),
+    ("local_bar", maps::LOCAL_BAR),
+    ("local_cafe", maps::LOCAL_CAFE),
+    ("local_car_wash", maps::LOCAL_CAR_WASH),
+    ("local_convenience_store", maps::LOCAL_CONVENIENCE_STORE),
+    ("local_dining", maps::LOCAL_DINING),
+    ("local_drink", maps::LOCAL_DRINK),
+    ("local_fire_department", maps::LOCAL_FIRE_DEPARTMENT),
+    ("local_florist", maps::LOCAL_FLORIST),
+    ("local_gas_station", maps::LOCAL_GAS_STATION),
+    ("local_grocery_store", maps::LOCAL_GROCERY_STORE),
+    ("local_hospital", maps::LOCAL_HOSPITAL),
+    ("local_hotel", maps::LOCAL_HOTEL),
+    ("local_laundry_service", maps::LOCAL_LAUNDRY_SERVICE),
+    ("local_library", maps::LOCAL_LIBRARY),
+    ("local_mall", maps::LOCAL_MALL),
+    ("local_movies", maps::LOCAL_MOVIES),
+    ("local_offer", maps::LOCAL_OFFER),
+    ("local_parking", maps::LOCAL_PARKING),
+    ("local_pharmacy", maps::LOCAL_PHARMACY),
+    ("local_phone", maps::LOCAL_PHONE),
+    ("local_pizza", maps::LOCAL_PIZZA),
+    ("local_play", maps::LOCAL_PLAY),
+    ("local_police", maps::LOCAL_POLICE),
+    ("local_post_office", maps::LOCAL_POST_OFFICE),
+    ("local_printshop", maps::LOCAL_PRINTSHOP),
+    ("local_see", maps::LOCAL_SEE),
+    ("local_shipping", maps::LOCAL_SHIPPING),
+    ("local_taxi", maps::LOCAL_TAXI),
+    ("location_city", social::LOCATION_CITY),
+    ("location_disabled", device::LOCATION_DISABLED),
+    ("location_off", communication::LOCATION_OFF),
+    ("location_on", communication::LOCATION_ON),
+    ("location_pin", maps::LOCATION_PIN),
+    ("location_searching", device::LOCATION_SEARCHING),
+    ("lock", action::LOCK),
+    ("lock_clock", action::LOCK_CLOCK),
+    ("lock_open", action::LOCK_OPEN),
+    ("lock_outline", action::LOCK_OUTLINE),
+    ("lock_reset", action::LOCK_RESET),
+    ("login", action::LOGIN),
+    ("logo_dev", image::LOGO_DEV),
+    ("logout", action::LOGOUT),
+    ("looks", image::LOOKS),
+    ("looks_3", image::LOOKS_3),
+    ("looks_4", image::LOOKS_4),
+    ("looks_5", image::LOOKS_5),
+    ("looks_6", image::LOOKS_6),
+    ("looks_one", image::LOOKS_ONE),
+    ("looks_two", image::LOOKS_TWO),
+    ("loop", av::LOOP),
+    ("loupe", image::LOUPE),
+    ("low_priority", content::LOW_PRIORITY),
+    ("loyalty", action::LOYALTY),
+    ("lte_mobiledata", device::LTE_MOBILEDATA),
+    ("lte_plus_mobiledata", device::LTE_PLUS_MOBILEDATA),
+    ("luggage", social::LUGGAGE),
+    ("lunch_dining", maps::LUNCH_DINING),
+    ("mail", content::MAIL),
+    ("mail_outline", communication::MAIL_OUTLINE),
+    ("male", social::MALE),
+    ("man", social::MAN),
+    ("manage_accounts", action::MANAGE_ACCOUNTS),
+    ("manage_history", action::MANAGE_HISTORY),
+    ("manage_search", search::MANAGE_SEARCH),
+    ("map", maps::MAP),
+    ("maps_home_work", navigation::MAPS_HOME_WORK),
+    ("maps_ugc", maps::MAPS_UGC),
+    ("margin", editor::MARGIN),
+    ("mark_as_unread", action::MARK_AS_UNREAD),
+    ("mark_chat_read", communication::MARK_CHAT_READ),
+    ("mark_chat_unread", communication::MARK_CHAT_UNREAD),
+    ("mark_email_read", communication::MARK_EMAIL_READ),
+    ("mark_email_unread", communication::MARK_EMAIL_UNREAD),
+    ("mark_unread_chat_alt", communication::MARK_UNREAD_CHAT_ALT),
+    ("markunread", content::MARKUNREAD),
+    ("markunread_mailbox", action::MARKUNREAD_MAILBOX),
+    ("masks", social::MASKS),
+    ("maximize", action::MAXIMIZE),
+    ("media_bluetooth_off", device::MEDIA_BLUETOOTH_OFF),
+    ("media_bluetooth_on", device::MEDIA_BLUETOOTH_ON),
+    ("mediation", action::MEDIATION),
+    ("medical_information", maps::MEDICAL_INFORMATION),
+    ("medical_services", maps::MEDICAL_SERVICES),
+    ("medication", device::MEDICATION),
+    ("medication_liquid", device::MEDICATION_LIQUID),
+    ("meeting_room", places::MEETING_ROOM),
+    ("memory", hardware::MEMORY),
+    ("menu", navigation::MENU),
+    ("menu_book", maps::MENU_BOOK),
+    ("menu_open", navigation::MENU_OPEN),
+    ("merge", maps::MERGE),
+    ("merge_type", editor::MERGE_TYPE),
+    ("message", communication::MESSAGE),
+    ("mic", av::MIC),
+    ("mic_external_off", image::MIC_EXTERNAL_OFF),
+    ("mic_external_on", image::MIC_EXTERNAL_ON),
+    ("mic_none", av::MIC_NONE),
+    ("mic_off", av::MIC_OFF),
+    ("microwave", places::MICROWAVE),
+    ("military_tech", social::MILITARY_TECH),
+    ("minimize", action::MINIMIZE),
+    ("minor_crash", maps::MINOR_CRASH),
+    ("miscellaneous_services", maps::MISCELLANEOUS_SERVICES),
+    ("missed_video_call", av::MISSED_VIDEO_CALL),
+    ("mms", notification::MMS),
+    ("mobile_friendly", device::MOBILE_FRIENDLY),
+    ("mobile_off", device::MOBILE_OFF),
+    ("mobile_screen_share", communication::MOBILE_SCREEN_SHARE),
+    ("mobiledata_off", device::MOBILEDATA_OFF),
+    ("mode", editor::MODE),
+    ("mode_comment", editor::MODE_COMMENT),
+    ("mode_edit", editor::MODE_EDIT),
+    ("mode_edit_outline", editor::MODE_EDIT_OUTLINE),
+    ("mode_night", device::MODE_NIGHT),
+    ("mode_of_travel", maps::MODE_OF_TRAVEL),
+    ("mode_standby", device::MODE_STANDBY),
+    ("model_training", action::MODEL_TRAINING),
+    ("monetization_on", editor::MONETIZATION_ON),
+    ("money", maps::MONEY),
+    ("money_off", editor::MONEY_OFF),
+    ("money_off_csred", editor::MONEY_OFF_CSRED),
+    ("monitor", hardware::MONITOR),
+    ("monitor_heart", device::MONITOR_HEART),
+    ("monitor_weight", device::MONITOR_WEIGHT),
+    ("monochrome_photos", image::MONOCHROME_PHOTOS),
+    ("mood", social::MOOD),
+    ("mood_bad", social::MOOD_BAD),
+    ("moped", maps::MOPED),
+    ("more", notification::MORE),
+    ("more_horiz", navigation::MORE_HORIZ),
+    ("more_time", communication::MORE_TIME),
+    ("more_vert", navigation::MORE_VERT),
+    ("mosque", maps::MOSQUE),
+    ("motion_photos_auto", image::MOTION_PHOTOS_AUTO),
+    ("motion_photos_off", image::MOTION_PHOTOS_OFF),
+    ("motion_photos_on", image::MOTION_PHOTOS_ON),
+    ("motion_photos_pause", image::MOTION_PHOTOS_PAUSE),
+    ("motion_photos_paused", image::MOTION_PHOTOS_PAUSED),
+    ("mouse", hardware::MOUSE),
+    ("move_down", editor::MOVE_DOWN),
+    ("move_to_inbox", content::MOVE_TO_INBOX),
+    ("move_up", editor::MOVE_UP),
+    ("movie", av::MOVIE),
+    ("movie_creation", image::MOVIE_CREATION),
+    ("movie_filter", image::MOVIE_FILTER),
+    ("moving", maps::MOVING),
+    ("mp", image::MP),
+    ("multiline_chart", editor::MULTILINE_CHART),
+    ("multiple_stop", maps::MULTIPLE_STOP),
+    ("museum", maps::MUSEUM),
+    ("music_note", image::MUSIC_NOTE),
+    ("music_off", image::MUSIC_OFF),
+    ("music_video", av::MUSIC_VIDEO),
+    ("my_location", maps::MY_LOCATION),
+    ("nat", communication::NAT),
+    ("nature", image::NATURE),
+    ("nature_people", image::NATURE_PEOPLE),
+    ("navigate_before", image::NAVIGATE_BEFORE),
+    ("navigate_next", image::NAVIGATE_NEXT),
+    ("navigation", maps::NAVIGATION),
+    ("near_me", maps::NEAR_ME),
+    ("near_me_disabled", maps::NEAR_ME_DISABLED),
+    ("nearby_error", device::NEARBY_ERROR),
+    ("nearby_off", device::NEARBY_OFF),
+    ("network_cell", device::NETWORK_CELL),
+    ("network_check", notification::NETWORK_CHECK),
+    ("network_locked", notification::NETWORK_LOCKED),
+    ("network_ping", action::NETWORK_PING),
+    ("network_wifi", device::NETWORK_WIFI),
+    ("network_wifi_1_bar", device::NETWORK_WIFI_1_BAR),
+    ("network_wifi_2_bar", device::NETWORK_WIFI_2_BAR),
+    ("network_wifi_3_bar", device::NETWORK_WIFI_3_BAR),
+    ("new_label", action::NEW_LABEL),
+    ("new_releases", av::NEW_RELEASES),
+    ("newspaper", file::NEWSPAPER),
+    ("next_plan", action::NEXT_PLAN),
+    ("next_week", content::NEXT_WEEK),
+    ("nfc", device::NFC),
+    ("night_shelter", places::NIGHT_SHELTER),
+    ("nightlife", maps::NIGHTLIFE),
+    ("nightlight", device::NIGHTLIGHT),
+    ("nightlight_round", action::NIGHTLIGHT_ROUND),
+    ("nights_stay", social::NIGHTS_STAY),
+    ("no_accounts", action::NO_ACCOUNTS),
+    ("no_backpack", places::NO_BACKPACK),
+    ("no_cell", places::NO_CELL),
+    ("no_crash", maps::NO_CRASH),
+    ("no_drinks", places::NO_DRINKS),
+    ("no_encryption", notification::NO_ENCRYPTION),
+    ("no_encryption_gmailerrorred", notification::NO_ENCRYPTION_GMAILERRORRED),
+    ("no_flash", places::NO_FLASH),
+    ("no_food", places::NO_FOOD),
+    ("no_luggage", social::NO_LUGGAGE),
+    ("no_meals", maps::NO_MEALS),
+    ("no_meals_ouline", maps::NO_MEALS_OULINE),
+    ("no_meeting_room", places::NO_MEETING_ROOM),
+    ("no_photography", places::NO_PHOTOGRAPHY),
+    ("no_sim", communication::NO_SIM),
+    ("no_stroller", places::NO_STROLLER),
+    ("no_transfer", maps::NO_TRANSFER),
+    ("noise_aware", action::NOISE_AWARE),
+    ("noise_control_off", action::NOISE_CONTROL_OFF),
+    ("nordic_walking", social::NORDIC_WALKING),
+    ("north", navigation::NORTH),
+    ("north_east", navigation::NORTH_EAST),
+    ("north_west", navigation::NORTH_WEST),
+    ("not_accessible", action::NOT_ACCESSIBLE),
+    ("not_interested", av::NOT_INTERESTED),
+    ("not_listed_location", maps::NOT_LISTED_LOCATION),
+    ("not_started", action::NOT_STARTED),
+    ("note", av::NOTE),
+    ("note_add", action::NOTE_ADD),
+    ("note_alt", device::NOTE_ALT),
+    ("notes", editor::NOTES),
+    ("notification_add", social::NOTIFICATION_ADD),
+    ("notification_important", alert::NOTIFICATION_IMPORTANT),
+    ("notifications", social::NOTIFICATIONS),
+    ("notifications_active", social::NOTIFICATIONS_ACTIVE),
+    ("notifications_none", social::NOTIFICATIONS_NONE),
+    ("notifications_off", social::NOTIFICATIONS_OFF),
+    ("notifications_paused", social::NOTIFICATIONS_PAUSED),
+    ("numbers", editor::NUMBERS),
+    ("offline_bolt", action::OFFLINE_BOLT),
+    ("offline_pin", action::OFFLINE_PIN),
+    ("offline_share", navigation::OFFLINE_SHARE),
+    ("ondemand_video", notification::ONDEMAND_VIDEO),
+    ("online_prediction", action::ONLINE_PREDICTION),
+    ("opacity", action::OPACITY),
+    ("open_in_browser", action::OPEN_IN_BROWSER),
+    ("open_in_full", action::OPEN_IN_FULL),
+    ("open_in_new", action::OPEN_IN_NEW),
+    ("open_in_new_off", action::OPEN_IN_NEW_OFF),
+    ("open_with", action::OPEN_WITH),
+    ("other_houses", places::OTHER_HOUSES),
+    ("outbond", action::OUTBOND),
+    ("outbound", action::OUTBOUND),
+    ("outbox", action::OUTBOX),
+    ("outdoor_grill", social::OUTDOOR_GRILL),
+    ("outgoing_mail", action::OUTGOING_MAIL),
+    ("outlet", action::OUTLET),
+    ("outlined_flag", content::OUTLINED_FLAG),
+    ("output", action::OUTPUT),
+    ("padding", editor::PADDING),
+    ("pages", social::PAGES),
+    ("pageview", action::PAGEVIEW),
+    ("paid", action::PAID),
+    ("palette", image::PALETTE),
+    ("pan_tool", action::PAN_TOOL),
+    ("pan_tool_alt", action::PAN_TOOL_ALT),
+    ("panorama", image::PANORAMA),
+    ("panorama_fish_eye", image::PANORAMA_FISH_EYE),
+    ("panorama_horizontal", image::PANORAMA_HORIZONTAL),
+    ("panorama_horizontal_select", image::PANORAMA_HORIZONTAL_SELECT),
+    ("panorama_photosphere", image::PANORAMA_PHOTOSPHERE),
+    ("panorama_photosphere_select", image::PANORAMA_PHOTOSPHERE_SELECT),
+    ("panorama_vertical", image::PANORAMA_VERTICAL),
+    ("panorama_vertical_select", image::PANORAMA_VERTICAL_SELECT),
+    ("panorama_wide_angle", image::PANORAMA_WIDE_ANGLE),
+    ("panorama_wide_angle_select", image::PANORAMA_WIDE_ANGLE_SELECT),
+    ("paragliding", social::PARAGLIDING),
+    ("park", maps::PARK),
+    ("party_mode", social::PARTY_MODE),
+    ("password", device::PASSWORD),
+    ("pattern", device::PATTERN),
+    ("pause", av::PAUSE),
+    ("pause_circle", av::PAUSE_CIRCLE),
+    ("pause_circle_filled", av::PAUSE_CIRCLE_FILLED),
+    ("pause_circle_outline", av::PAUSE_CIRCLE_OUTLINE),
+    ("pause_presentation", communication::PAUSE_PRESENTATION),
+    ("payment", action::PAYMENT),
+    ("payments", navigation::PAYMENTS),
+    ("pedal_bike", maps::PEDAL_BIKE),
+    ("pending", action::PENDING),
+    ("pending_actions", action::PENDING_ACTIONS),
+    ("pentagon", editor::PENTAGON),
+    ("people", social::PEOPLE),
+    ("people_alt", social::PEOPLE_ALT),
+    ("people_outline", social::PEOPLE_OUTLINE),
+    ("percent", action::PERCENT),
+    ("perm_camera_mic", action::PERM_CAMERA_MIC),
+    ("perm_contact_calendar", action::PERM_CONTACT_CALENDAR),
+    ("perm_data_setting", action::PERM_DATA_SETTING),
+    ("perm_device_information", action::PERM_DEVICE_INFORMATION),
+    ("perm_identity", action::PERM_IDENTITY),
+    ("perm_media", action::PERM_MEDIA),
+    ("perm_phone_msg", action::PERM_PHONE_MSG),
+    ("perm_scan_wifi", action::PERM_SCAN_WIFI),
+    ("person", social::PERSON),
+    ("person_add", social::PERSON_ADD),
+    ("person_add_alt", social::PERSON_ADD_ALT),
+    ("person_add_alt_1", social::PERSON_ADD_ALT_1),
+    ("person_add_disabled", communication::PERSON_ADD_DISABLED),
+    ("person_off", social::PERSON_OFF),
+    ("person_outline", social::PERSON_OUTLINE),
+    ("person_pin", maps::PERSON_PIN),
+    ("person_pin_circle", maps::PERSON_PIN_CIRCLE),
+    ("person_remove", social::PERSON_REMOVE),
+    ("person_remove_alt_1", social::PERSON_REMOVE_ALT_1),
+    ("person_search", communication::PERSON_SEARCH),
+    ("personal_injury", social::PERSONAL_INJURY),
+    ("personal_video", notification::PERSONAL_VIDEO),
+    ("pest_control", maps::PEST_CONTROL),
+    ("pest_control_rodent", maps::PEST_CONTROL_RODENT),
+    ("pets", action::PETS),
+    ("phishing", device::PHISHING),
+    ("phone", communication::PHONE),
+    ("phone_android", hardware::PHONE_ANDROID),
+    ("phone_bluetooth_speaker", notification::PHONE_BLUETOOTH_SPEAKER),
+    ("phone_callback", notification::PHONE_CALLBACK),
+    ("phone_disabled", communication::PHONE_DISABLED),
+    ("phone_enabled", communication::PHONE_ENABLED),
+    ("phone_forwarded", notification::PHONE_FORWARDED),
+    ("phone_in_talk", notification::PHONE_IN_TALK),
+    ("phone_iphone", hardware::PHONE_IPHONE),
+    ("phone_locked", notification::PHONE_LOCKED),
+    ("phone_missed", notification::PHONE_MISSED),
+    ("phone_paused", notification::PHONE_PAUSED),
+    ("phonelink", hardware::PHONELINK),
+    ("phonelink_erase", communication::PHONELINK_ERASE),
+    ("phonelink_lock", communication::PHONELINK_LOCK),
+    ("phonelink_off", hardware::PHONELINK_OFF),
+    ("phonelink_ring", communication::PHONELINK_RING),
+    ("phonelink_setup", communication::PHONELINK_SETUP),
+    ("photo", image::PHOTO),
+    ("photo_album", image::PHOTO_ALBUM),
+    ("photo_camera", image::PHOTO_CAMERA),
+    ("photo_camera_back", image::PHOTO_CAMERA_BACK),
+    ("photo_camera_front", image::PHOTO_CAMERA_FRONT),
+    ("photo_filter", image::PHOTO_FILTER),
+    ("photo_library", image::PHOTO_LIBRARY),
+    ("photo_size_select_actual", image::PHOTO_SIZE_SELECT_ACTUAL),
+    ("photo_size_select_large", image::PHOTO_SIZE_SELECT_LARGE),
+    ("photo_size_select_small", image::PHOTO_SIZE_SELECT_SMALL),
+    ("php", action::PHP),
+    ("piano", social::PIANO),
+    ("piano_off", social::PIANO_OFF),
+    ("picture_as_pdf", image::PICTURE_AS_PDF),
+    ("picture_in_picture", action::PICTURE_IN_PICTURE),
+    ("picture_in_picture_alt", action::PICTURE_IN_PICTURE_ALT),
+    ("pie_chart", editor::PIE_CHART),
+    ("pie_chart_outline", editor::PIE_CHART_OUTLINE),
+    ("pie_chart_outlined", editor::PIE_CHART_OUTLINED),
+    ("pin", device::PIN),
+    ("pin_drop", maps::PIN_DROP),
+    ("pin_end", action::PIN_END),
+    ("pin_invoke", action::PIN_INVOKE),
+    ("pinch", action::PINCH),
+    ("pivot_table_chart", navigation::PIVOT_TABLE_CHART),
+    ("pix", social::PIX),
+    ("place", maps::PLACE),
+    ("plagiarism", action::PLAGIARISM),
+    ("play_arrow", av::PLAY_ARROW),
+    ("play_circle", av::PLAY_CIRCLE),
+    ("play_circle_filled", av::PLAY_CIRCLE_FILLED),
+    ("play_circle_outline", av::PLAY_CIRCLE_OUTLINE),
+    ("play_disabled", av::PLAY_DISABLED),
+    ("play_for_work", action::PLAY_FOR_WORK),
+    ("play_lesson", device::PLAY_LESSON),
+    ("playlist_add", av::PLAYLIST_ADD),
+    ("playlist_add_check", av::PLAYLIST_ADD_CHECK),
+    ("playlist_add_check_circle", av::PLAYLIST_ADD_CHECK_CIRCLE),
+    ("playlist_add_circle", av::PLAYLIST_ADD_CIRCLE),
+    ("playlist_play", av::PLAYLIST_PLAY),
+    ("playlist_remove", av::PLAYLIST_REMOVE),
+    ("plumbing", maps::PLUMBING),
+    ("plus_one", social::PLUS_ONE),
+    ("podcasts", search::PODCASTS),
+    ("point_of_sale", hardware::POINT_OF_SALE),
+    ("policy", content::POLICY),
+    ("poll", social::POLL),
+    ("polyline", editor::POLYLINE),
+    ("polymer", action::POLYMER),
+    ("pool", places::POOL),
+    ("portable_wifi_off", communication::PORTABLE_WIFI_OFF),
+    ("portrait", image::PORTRAIT),
+    ("post_add", editor::POST_ADD),
+    ("power", notification::POWER),
+    ("power_input", hardware::POWER_INPUT),
+    ("power_off", notification::POWER_OFF),
+    ("power_settings_new", action::POWER_SETTINGS_NEW),
+    ("precision_manufacturing", social::PRECISION_MANUFACTURING),
+    ("pregnant_woman", action::PREGNANT_WOMAN),
+    ("present_to_all", communication::PRESENT_TO_ALL),
+    ("preview", action::PREVIEW),
+    ("price_change", device::PRICE_CHANGE),
+    ("price_check", device::PRICE_CHECK),
+    ("print", action::PRINT),
+    ("print_disabled", communication::PRINT_DISABLED),
+    ("priority_high", notification::PRIORITY_HIGH),
+    ("privacy_tip", action::PRIVACY_TIP),
+    ("private_connectivity", action::PRIVATE_CONNECTIVITY),
+    ("production_quantity_limits", action::PRODUCTION_QUANTITY_LIMITS),
+    ("psychology", social::PSYCHOLOGY),
+    ("public", social::PUBLIC),
+    ("public_off", social::PUBLIC_OFF),
+    ("publish", editor::PUBLISH),
+    ("published_with_changes", action::PUBLISHED_WITH_CHANGES),
+    ("punch_clock", device::PUNCH_CLOCK),
+    ("push_pin", content::PUSH_PIN),
+    ("qr_code", communication::QR_CODE),
+    ("qr_code_2", communication::QR_CODE_2),
+    ("qr_code_scanner", communication::QR_CODE_SCANNER),
+    ("query_builder", action::QUERY_BUILDER),
+    ("query_stats", editor::QUERY_STATS),
+    ("question_answer", action::QUESTION_ANSWER),
+    ("question_mark", action::QUESTION_MARK),
+    ("queue", av::QUEUE),
+    ("queue_music", av::QUEUE_MUSIC),
+    ("queue_play_next", av::QUEUE_PLAY_NEXT),
+    ("quickreply", action::QUICKREPLY),
+    ("quiz", device::QUIZ),
+    ("r_mobiledata", device::R_MOBILEDATA),
+    ("radar", device::RADAR),
+    ("radio", av::RADIO),
+    ("radio_button_checked", toggle::RADIO_BUTTON_CHECKED),
+    ("radio_button_unchecked", toggle::RADIO_BUTTON_UNCHECKED),
+    ("railway_alert", maps::RAILWAY_ALERT),
+    ("ramen_dining", maps::RAMEN_DINING),
+    ("ramp_left", maps::RAMP_LEFT),
+    ("ramp_right", maps::RAMP_RIGHT),
+    ("rate_review", maps::RATE_REVIEW),
+    ("raw_off", image::RAW_OFF),
+    ("raw_on", image::RAW_ON),
+    ("read_more", communication::READ_MORE),
+    ("real_estate_agent", social::REAL_ESTATE_AGENT),
+    ("receipt", action::RECEIPT),
+    ("receipt_long", image::RECEIPT_LONG),
+    ("recent_actors", av::RECENT_ACTORS),
+    ("recommend", social::RECOMMEND),
+    ("record_voice_over", action::RECORD_VOICE_OVER),
+    ("rectangle", editor::RECTANGLE),
+    ("recycling", social::RECYCLING),
+    ("redeem", action::REDEEM),
+    ("redo", content::REDO),
+    ("reduce_capacity", social::REDUCE_CAPACITY),
+    ("refresh", navigation::REFRESH),
+    ("remember_me", device::REMEMBER_ME),
+    ("remove", content::REMOVE),
+    ("remove_circle", content::REMOVE_CIRCLE),
+    ("remove_circle_outline", content::REMOVE_CIRCLE_OUTLINE),
+    ("remove_done", action::REMOVE_DONE),
+    ("remove_from_queue", av::REMOVE_FROM_QUEUE),
+    ("remove_moderator", social::REMOVE_MODERATOR),
+    ("remove_red_eye", image::REMOVE_RED_EYE),
+    ("remove_shopping_cart", action::REMOVE_SHOPPING_CART),
+    ("reorder", action::REORDER),
+    ("repeat", av::REPEAT),
+    ("repeat_on", av::REPEAT_ON),
+    ("repeat_one", av::REPEAT_ONE),
+    ("repeat_one_on", av::REPEAT_ONE_ON),
+    ("replay", av::REPLAY),
+    ("replay_10", av::REPLAY_10),
+    ("replay_30", av::REPLAY_30),
+    ("replay_5", av::REPLAY_5),
+    ("replay_circle_filled", av::REPLAY_CIRCLE_FILLED),
+    ("reply", content::REPLY),
+    ("reply_all", content::REPLY_ALL),
+    ("report", content::REPORT),
+    ("report_gmailerrorred", content::REPORT_GMAILERRORRED),
+    ("report_off", content::REPORT_OFF),
+    ("report_problem", action::REPORT_PROBLEM),
+    ("request_page", action::REQUEST_PAGE),
+    ("request_quote", file::REQUEST_QUOTE),
+    ("reset_tv", device::RESET_TV),
+    ("restart_alt", device::RESTART_ALT),
+    ("restaurant", maps::RESTAURANT),
+    ("restaurant_menu", maps::RESTAURANT_MENU),
+    ("restore", action::RESTORE),
+    ("restore_from_trash", action::RESTORE_FROM_TRASH),
+    ("restore_page", action::RESTORE_PAGE),
+    ("reviews", device::REVIEWS),
+    ("rice_bowl", places::RICE_BOWL),
+    ("ring_volume", communication::RING_VOLUME),
+    ("rocket", action::ROCKET),
+    ("rocket_launch", action::ROCKET_LAUNCH),
+    ("roller_skating", social::ROLLER_SKATING),
+    ("roofing", places::ROOFING),
+    ("room", action::ROOM),
+    ("room_preferences", places::ROOM_PREFERENCES),
+    ("room_service", places::ROOM_SERVICE),
+    ("rotate_90_degrees_ccw", image::ROTATE_90_DEGREES_CCW),
+    ("rotate_90_degrees_cw", image::ROTATE_90_DEGREES_CW),
+    ("rotate_left", image::ROTATE_LEFT),
+    ("rotate_right", image::ROTATE_RIGHT),
+    ("roundabout_left", maps::ROUNDABOUT_LEFT),
+    ("roundabout_right", maps::ROUNDABOUT_RIGHT),
+    ("rounded_corner", action::ROUNDED_CORNER),
+    ("route", maps::ROUTE),
+    ("router", hardware::ROUTER),
+    ("rowing", action::ROWING),
+    ("rss_feed", communication::RSS_FEED),
+    ("rsvp", device::RSVP),
+    ("rtt", communication::RTT),
+    ("rule", action::RULE),
+    ("rule_folder", file::RULE_FOLDER),
+    ("run_circle", maps::RUN_CIRCLE),
+    ("running_with_errors", notification::RUNNING_WITH_ERRORS),
+    ("rv_hookup", places::RV_HOOKUP),
+    ("safety_check", maps::SAFETY_CHECK),
+    ("safety_divider", social::SAFETY_DIVIDER),
+    ("sailing", maps::SAILING),
+    ("sanitizer", social::SANITIZER),
+    ("satellite", maps::SATELLITE),
+    ("satellite_alt", action::SATELLITE_ALT),
+    ("save", content::SAVE),
+    ("save_alt", content::SAVE_ALT),
+    ("save_as", content::SAVE_AS),
+    ("saved_search", action::SAVED_SEARCH),
+    ("savings", action::SAVINGS),
+    ("scale", social::SCALE),
+    ("scanner", hardware::SCANNER),
+    ("scatter_plot", editor::SCATTER_PLOT),
+    ("schedule", action::SCHEDULE),
+    ("schedule_send", action::SCHEDULE_SEND),
+    ("schema", editor::SCHEMA),
+    ("school", social::SCHOOL),
+    ("science", social::SCIENCE),
+    ("score", editor::SCORE),
+    ("scoreboard", social::SCOREBOARD),
+    ("screen_lock_landscape", device::SCREEN_LOCK_LANDSCAPE),
+    ("screen_lock_portrait", device::SCREEN_LOCK_PORTRAIT),
+    ("screen_lock_rotation", device::SCREEN_LOCK_ROTATION),
+    ("screen_rotation", device::SCREEN_ROTATION),
+    ("screen_rotation_alt", maps::SCREEN_ROTATION_ALT),
+    ("screen_search_desktop", device::SCREEN_SEARCH_DESKTOP),
+    ("screen_share", communication::SCREEN_SHARE),
+    ("screenshot", device::SCREENSHOT),
+    ("scuba_diving", social::SCUBA_DIVING),
+    ("sd", av::SD),
+    ("sd_card", notification::SD_CARD),
+    ("sd_card_alert", notification::SD_CARD_ALERT),
+    ("sd_storage", device::SD_STORAGE),
+    ("search", action::SEARCH),
+    ("search_off", action::SEARCH_OFF),
+    ("security", hardware::SECURITY),
+    ("security_update", device::SECURITY_UPDATE),
+    ("security_update_good", device::SECURITY_UPDATE_GOOD),
+    ("security_update_warning", device::SECURITY_UPDATE_WARNING),
+    ("segment", action::SEGMENT),
+    ("select_all", content::SELECT_ALL),
+    ("self_improvement", social::SELF_IMPROVEMENT),
+    ("sell", device::SELL),
+    ("send", content::SEND),
+    ("send_and_archive", action::SEND_AND_ARCHIVE),
+    ("send_time_extension", communication::SEND_TIME_EXTENSION),
+    ("send_to_mobile", device::SEND_TO_MOBILE),
+    ("sensor_door", home::SENSOR_DOOR),
+    ("sensor_window", home::SENSOR_WINDOW),
+    ("sensors", action::SENSORS),
+    ("sensors_off", action::SENSORS_OFF),
+    ("sentiment_dissatisfied", social::SENTIMENT_DISSATISFIED),
+    ("sentiment_neutral", social::SENTIMENT_NEUTRAL),
+    ("sentiment_satisfied", social::SENTIMENT_SATISFIED),
+    ("sentiment_satisfied_alt", communication::SENTIMENT_SATISFIED_ALT),
+    ("sentiment_very_dissatisfied", social::SENTIMENT_VERY_DISSATISFIED),
+    ("sentiment_very_satisfied", social::SENTIMENT_VERY_SATISFIED),
+    ("set_meal", maps::SET_MEAL),
+    ("settings", action::SETTINGS),
+    ("settings_accessibility", action::SETTINGS_ACCESSIBILITY),
+    ("settings_applications", action::SETTINGS_APPLICATIONS),
+    ("settings_backup_restore", action::SETTINGS_BACKUP_RESTORE),
+    ("settings_bluetooth", action::SETTINGS_BLUETOOTH),
+    ("settings_brightness", action::SETTINGS_BRIGHTNESS),
+    ("settings_cell", action::SETTINGS_CELL),
+    ("settings_ethernet", action::SETTINGS_ETHERNET),
+    ("settings_input_antenna", action::SETTINGS_INPUT_ANTENNA),
+    ("settings_input_component", action::SETTINGS_INPUT_COMPONENT),
+    ("settings_input_composite", action::SETTINGS_INPUT_COMPOSITE),
+    ("settings_input_hdmi", action::SETTINGS_INPUT_HDMI),
+    ("settings_input_svideo", action::SETTINGS_INPUT_SVIDEO),
+    ("settings_overscan", action::SETTINGS_OVERSCAN),
+    ("settings_phone", action::SETTINGS_PHONE),
+    ("settings_power", action::SETTINGS_POWER),
+    ("settings_remote", action::SETTINGS_REMOTE),
+    ("settings_suggest", device::SETTINGS_SUGGEST),
+    ("settings_system_daydream", device::SETTINGS_SYSTEM_DAYDREAM),
+    ("settings_voice", action::SETTINGS_VOICE),
+    ("severe_cold", social::SEVERE_COLD),
+    ("share", social::SHARE),
+    ("share_location", device::SHARE_LOCATION),
+    ("shield", content::SHIELD),
+    ("shield_moon", home::SHIELD_MOON),
+    ("shop", action::SHOP),
+    ("shop_2", action::SHOP_2),
+    ("shop_two", action::SHOP_TWO),
+    ("shopping_bag", action::SHOPPING_BAG),
+    ("shopping_basket", action::SHOPPING_BASKET),
+    ("shopping_cart", action::SHOPPING_CART),
+    ("shopping_cart_checkout", action::SHOPPING_CART_CHECKOUT),
+    ("short_text", editor::SHORT_TEXT),
+    ("shortcut", device::SHORTCUT),
+    ("show_chart", editor::SHOW_CHART),
+    ("shower", search::SHOWER),
+    ("shuffle", av::SHUFFLE),
+    ("shuffle_on", av::SHUFFLE_ON),
+    ("shutter_speed", image::SHUTTER_SPEED),
+    ("sick", social::SICK),
+    ("sign_language", social::SIGN_LANGUAGE),
+    ("signal_cellular_0_bar", device::SIGNAL_CELLULAR_0_BAR),
+    ("signal_cellular_4_bar", device::SIGNAL_CELLULAR_4_BAR),
+    ("signal_cellular_alt", device::SIGNAL_CELLULAR_ALT),
+    ("signal_cellular_alt_1_bar", device::SIGNAL_CELLULAR_ALT_1_BAR),
+    ("signal_cellular_alt_2_bar", device::SIGNAL_CELLULAR_ALT_2_BAR),
+    ("signal_cellular_connected_no_internet_0_bar", device::SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_0_BAR),
+    ("signal_cellular_connected_no_internet_4_bar", device::SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_4_BAR),
+    ("signal_cellular_no_sim", device::SIGNAL_CELLULAR_NO_SIM),
+    ("signal_cellular_nodata", device::SIGNAL_CELLULAR_NODATA),
+    ("signal_cellular_null", device::SIGNAL_CELLULAR_NULL),
+    ("signal_cellular_off", device::SIGNAL_CELLULAR_OFF),
+    ("signal_wifi_0_bar", device::SIGNAL_WIFI_0_BAR),
+    ("signal_wifi_4_bar", device::SIGNAL_WIFI_4_BAR),
+    ("signal_wifi_4_bar_lock", device::SIGNAL_WIFI_4_BAR_LOCK),
+    ("signal_wifi_bad", device::SIGNAL_WIFI_BAD),
+    ("signal_wifi_connected_no_internet_4", device::SIGNAL_WIFI_CONNECTED_NO_INTERNET_4),
+    ("signal_wifi_off", device::SIGNAL_WIFI_OFF),
+    ("signal_wifi_statusbar_4_bar", device::SIGNAL_WIFI_STATUSBAR_4_BAR),
+    ("signal_wifi_statusbar_connected_no_internet_4", device::SIGNAL_WIFI_STATUSBAR_CONNECTED_NO_INTERNET_4),
+    ("signal_wifi_statusbar_null", device::SIGNAL_WIFI_STATUSBAR_NULL),
+    ("signpost", maps::SIGNPOST),
+    ("sim_card", hardware::SIM_CARD),
+    ("sim_card_alert", notification::SIM_CARD_ALERT),
+    ("sim_card_download", device::SIM_CARD_DOWNLOAD),
+    ("single_bed", social::SINGLE_BED),
+    ("sip", communication::SIP),
+    ("skateboarding", social::SKATEBOARDING),
+    ("skip_next", av::SKIP_NEXT),
+    ("skip_previous", av::SKIP_PREVIOUS),
+    ("sledding", social::SLEDDING),
+    ("slideshow", image::SLIDESHOW),
+    ("slow_motion_video", av::SLOW_MOTION_VIDEO),
+    ("smart_button", action::SMART_BUTTON),
+    ("smart_display", hardware::SMART_DISPLAY),
+    ("smart_screen", hardware::SMART_SCREEN),
+    ("smart_toy", hardware::SMART_TOY),
+    ("smartphone", hardware::SMARTPHONE),
+    ("smoke_free", places::SMOKE_FREE),
+    ("smoking_rooms", places::SMOKING_ROOMS),
+    ("sms", notification::SMS),
+    ("sms_failed", notification::SMS_FAILED),
+    ("snippet_folder", file::SNIPPET_FOLDER),
+    ("snooze", av::SNOOZE),
+    ("snowboarding", social::SNOWBOARDING),
+    ("snowing", home::SNOWING),
+    ("snowmobile", maps::SNOWMOBILE),
+    ("snowshoeing", social::SNOWSHOEING),
+    ("soap", places::SOAP),
+    ("social_distance", social::SOCIAL_DISTANCE),
+    ("sort", content::SORT),
+    ("sort_by_alpha", av::SORT_BY_ALPHA),
+    ("sos", maps::SOS),
+    ("soup_kitchen", maps::SOUP_KITCHEN),
+    ("source", action::SOURCE),
+    ("south", navigation::SOUTH),
+    ("south_america", social::SOUTH_AMERICA),
+    ("south_east", navigation::SOUTH_EAST),
+    ("south_west", navigation::SOUTH_WEST),
+    ("spa", places::SPA),
+    ("space_bar", editor::SPACE_BAR),
+    ("space_dashboard", action::SPACE_DASHBOARD),
+    ("spatial_audio", action::SPATIAL_AUDIO),
+    ("spatial_audio_off", action::SPATIAL_AUDIO_OFF),
+    ("spatial_tracking", action::SPATIAL_TRACKING),
+    ("speaker", hardware::SPEAKER),
+    ("speaker_group", hardware::SPEAKER_GROUP),
+    ("speaker_notes", action::SPEAKER_NOTES),
+    ("speaker_notes_off", action::SPEAKER_NOTES_OFF),
+    ("speaker_phone", communication::SPEAKER_PHONE),
+    ("speed", av::SPEED),
+    ("spellcheck", action::SPELLCHECK),
+    ("splitscreen", device::SPLITSCREEN),
+    ("spoke", communication::SPOKE),
+    ("sports", social::SPORTS),
+    ("sports_bar", places::SPORTS_BAR),
+    ("sports_baseball", social::SPORTS_BASEBALL),
+    ("sports_basketball", social::SPORTS_BASKETBALL),
+    ("sports_cricket", social::SPORTS_CRICKET),
+    ("sports_esports", social::SPORTS_ESPORTS),
+    ("sports_football", social::SPORTS_FOOTBALL),
+    ("sports_golf", social::SPORTS_GOLF),
+    ("sports_gymnastics", social::SPORTS_GYMNASTICS),
+    ("sports_handball", social::SPORTS_HANDBALL),
+    ("sports_hockey", social::SPORTS_HOCKEY),
+    ("sports_kabaddi", social::SPORTS_KABADDI),
+    ("sports_martial_arts", social::SPORTS_MARTIAL_ARTS),
+    ("sports_mma", social::SPORTS_MMA),
+    ("sports_motorsports", social::SPORTS_MOTORSPORTS),
+    ("sports_rugby", social::SPORTS_RUGBY),
+    ("sports_score", device::SPORTS_SCORE),
+    ("sports_soccer", social::SPORTS_SOCCER),
+    ("sports_tennis", social::SPORTS_TENNIS),
+    ("sports_volleyball", social::SPORTS_VOLLEYBALL),
+    ("square", editor::SQUARE),
+    ("square_foot", content::SQUARE_FOOT),
+    ("ssid_chart", device::SSID_CHART),
+    ("stacked_bar_chart", content::STACKED_BAR_CHART),
+    ("stacked_line_chart", editor::STACKED_LINE_CHART),
+    ("stadium", maps::STADIUM),
+    ("stairs", places::STAIRS),
+    ("star", toggle::STAR),
+    ("star_border", toggle::STAR_BORDER),
+    ("star_border_purple500", toggle::STAR_BORDER_PURPLE500),
+    ("star_half", toggle::STAR_HALF),
+    ("star_outline", toggle::STAR_OUTLINE),
+    ("star_purple500", toggle::STAR_PURPLE500),
+    ("star_rate", action::STAR_RATE),
+    ("stars", action::STARS),
+    ("start", hardware::START),
+    ("stay_current_landscape", communication::STAY_CURRENT_LANDSCAPE),
+    ("stay_current_portrait", communication::STAY_CURRENT_PORTRAIT),
+    ("stay_primary_landscape", communication::STAY_PRIMARY_LANDSCAPE),
+    ("stay_primary_portrait", communication::STAY_PRIMARY_PORTRAIT),
+    ("sticky_note_2", action::STICKY_NOTE_2),
+    ("stop", av::STOP),
+    ("stop_circle", av::STOP_CIRCLE),
+    ("stop_screen_share", communication::STOP_SCREEN_SHARE),
+    ("storage", device::STORAGE),
+    ("store", action::STORE),
+    ("store_mall_directory", maps::STORE_MALL_DIRECTORY),
+    ("storefront", places::STOREFRONT),
+    ("storm", device::STORM),
+    ("straight", maps::STRAIGHT),
+    ("straighten", image::STRAIGHTEN),
+    ("stream", content::STREAM),
+    ("streetview", maps::STREETVIEW),
+    ("strikethrough_s", editor::STRIKETHROUGH_S),
+    ("stroller", places::STROLLER),
+    ("style", image::STYLE),
+    ("subdirectory_arrow_left", navigation::SUBDIRECTORY_ARROW_LEFT),
+    ("subdirectory_arrow_right", navigation::SUBDIRECTORY_ARROW_RIGHT),
+    ("subject", action::SUBJECT),
+    ("subscript", editor::SUBSCRIPT),
+    ("subscriptions", av::SUBSCRIPTIONS),
+    ("subtitles", av::SUBTITLES),
+    ("subtitles_off", action::SUBTITLES_OFF),
+    ("subway", maps::SUBWAY),
+    ("summarize", device::SUMMARIZE),
+    ("sunny", home::SUNNY),
+    ("sunny_snowing", home::SUNNY_SNOWING),
+    ("superscript", editor::SUPERSCRIPT),
+    ("supervised_user_circle", action::SUPERVISED_USER_CIRCLE),
+    ("supervisor_account", action::SUPERVISOR_ACCOUNT),
+    ("support", action::SUPPORT),
+    ("support_agent", notification::SUPPORT_AGENT),
+    ("surfing", social::SURFING),
+    ("surround_sound", av::SURROUND_SOUND),
+    ("swap_calls", communication::SWAP_CALLS),
+    ("swap_horiz", action::SWAP_HORIZ),
+    ("swap_horizontal_circle", action::SWAP_HORIZONTAL_CIRCLE),
+    ("swap_vert", action::SWAP_VERT),
+    ("swap_vertical_circle", action::SWAP_VERTICAL_CIRCLE),
+    ("swipe", action::SWIPE),
+    ("swipe_down", action::SWIPE_DOWN),
+    ("swipe_down_alt", action::SWIPE_DOWN_ALT),
+    ("swipe_left", action::SWIPE_LEFT),
+    ("swipe_left_alt", action::SWIPE_LEFT_ALT),
+    ("swipe_right", action::SWIPE_RIGHT),
+    ("swipe_right_alt", action::SWIPE_RIGHT_ALT),
+    ("swipe_up", action::SWIPE_UP),
+    ("swipe_up_alt", action::SWIPE_UP_ALT),
+    ("swipe_vertical", action::SWIPE_VERTICAL),
+    ("switch_access_shortcut", action::SWITCH_ACCESS_SHORTCUT),
+    ("switch_access_shortcut_add", action::SWITCH_ACCESS_SHORTCUT_ADD),
+    ("switch_account", social::SWITCH_ACCOUNT),
+    ("switch_camera", image::SWITCH_CAMERA),
+    ("switch_left", navigation::SWITCH_LEFT),
+    ("switch_right", navigation::SWITCH_RIGHT),
+    ("switch_video", image::SWITCH_VIDEO),
+    ("synagogue", maps::SYNAGOGUE),
+    ("sync", notification::SYNC),
+    ("sync_alt", action::SYNC_ALT),
+    ("sync_disabled", notification::SYNC_DISABLED),
+    ("sync_lock", notification::SYNC_LOCK),
+    ("sync_problem", notification::SYNC_PROBLEM),
+    ("system_security_update", device::SYSTEM_SECURITY_UPDATE),
+    ("system_security_update_good", device::SYSTEM_SECURITY_UPDATE_GOOD),
+    ("system_security_update_warning", device::SYSTEM_SECURITY_UPDATE_WARNING),
+    ("system_update", notification::SYSTEM_UPDATE),
+    ("system_update_alt", action::SYSTEM_UPDATE_ALT),
+    ("tab", action::TAB),
+    ("tab_unselected", action::TAB_UNSELECTED),
+    ("table_bar", search::TABLE_BAR),
+    ("table_chart", editor::TABLE_CHART),
+    ("table_restaurant", search::TABLE_RESTAURANT),
+    ("table_rows", editor::TABLE_ROWS),
+    ("table_view", action::TABLE_VIEW),
+    ("tablet", hardware::TABLET),
+    ("tablet_android", hardware::TABLET_ANDROID),
+    ("tablet_mac", hardware::TABLET_MAC),
+    ("tag", content::TAG),
+    ("tag_faces", image::TAG_FACES),
+    ("takeout_dining", maps::TAKEOUT_DINING),
+    ("tap_and_play", notification::TAP_AND_PLAY),
+    ("tapas", places::TAPAS),
+    ("task", device::TASK),
+    ("task_alt", action::TASK_ALT),
+    ("taxi_alert", maps::TAXI_ALERT),
+    ("temple_buddhist", maps::TEMPLE_BUDDHIST),
+    ("temple_hindu", maps::TEMPLE_HINDU),
+    ("terminal", action::TERMINAL),
+    ("terrain", maps::TERRAIN),
+    ("text_decrease", editor::TEXT_DECREASE),
+    ("text_fields", editor::TEXT_FIELDS),
+    ("text_format", content::TEXT_FORMAT),
+    ("text_increase", editor::TEXT_INCREASE),
+    ("text_rotate_up", action::TEXT_ROTATE_UP),
+    ("text_rotate_vertical", action::TEXT_ROTATE_VERTICAL),
+    ("text_rotation_angledown", action::TEXT_ROTATION_ANGLEDOWN),
+    ("text_rotation_angleup", action::TEXT_ROTATION_ANGLEUP),
+    ("text_rotation_down", action::TEXT_ROTATION_DOWN),
+    ("text_rotation_none", action::TEXT_ROTATION_NONE),
+    ("text_snippet", file::TEXT_SNIPPET),
+    ("textsms", communication::TEXTSMS),
+    ("texture", image::TEXTURE),
+    ("theater_comedy", maps::THEATER_COMEDY),
+    ("theaters", action::THEATERS),
+    ("thermostat", device::THERMOSTAT),
+    ("thermostat_auto", image::THERMOSTAT_AUTO),
+    ("thumb_down", action::THUMB_DOWN),
+    ("thumb_down_alt", social::THUMB_DOWN_ALT),
+    ("thumb_down_off_alt", action::THUMB_DOWN_OFF_ALT),
+    ("thumb_up", action::THUMB_UP),
+    ("thumb_up_alt", social::THUMB_UP_ALT),
+    ("thumb_up_off_alt", action::THUMB_UP_OFF_ALT),
+    ("thumbs_up_down", action::THUMBS_UP_DOWN),
+    ("thunderstorm", social::THUNDERSTORM),
+    ("time_to_leave", notification::TIME_TO_LEAVE),
+    ("timelapse", image::TIMELAPSE),
+    ("timeline", action::TIMELINE),
+    ("timer", image::TIMER),
+    ("timer_10", image::TIMER_10),
+    ("timer_10_select", device::TIMER_10_SELECT),
+    ("timer_3", image::TIMER_3),
+    ("timer_3_select", device::TIMER_3_SELECT),
+    ("timer_off", image::TIMER_OFF),
+    ("tips_and_updates", action::TIPS_AND_UPDATES),
+    ("tire_repair", maps::TIRE_REPAIR),
+    ("title", editor::TITLE),
+    ("toc", action::TOC),
+    ("today", action::TODAY),
+    ("toggle_off", toggle::TOGGLE_OFF),
+    ("toggle_on", toggle::TOGGLE_ON),
+    ("token", action::TOKEN),
+    ("toll", action::TOLL),
+    ("tonality", image::TONALITY),
+    ("topic", file::TOPIC),
+    ("touch_app", action::TOUCH_APP),
+    ("tour", action::TOUR),
+    ("toys", hardware::TOYS),
+    ("track_changes", action::TRACK_CHANGES),
+    ("traffic", maps::TRAFFIC),
+    ("train", maps::TRAIN),
+    ("tram", maps::TRAM),
+    ("transfer_within_a_station", maps::TRANSFER_WITHIN_A_STATION),
+    ("transform", image::TRANSFORM),
+    ("transgender", social::TRANSGENDER),
+    ("transit_enterexit", maps::TRANSIT_ENTEREXIT),
+    ("translate", action::TRANSLATE),
+    ("travel_explore", social::TRAVEL_EXPLORE),
+    ("trending_down", action::TRENDING_DOWN),
+    ("trending_flat", action::TRENDING_FLAT),
+    ("trending_up", action::TRENDING_UP),
+    ("trip_origin", maps::TRIP_ORIGIN),
+    ("try", action::TRY),
+    ("tsunami", social::TSUNAMI),
+    ("tty", places::TTY),
+    ("tune", image::TUNE),
+    ("tungsten", device::TUNGSTEN),
+    ("turn_left", maps::TURN_LEFT),
+    ("turn_right", maps::TURN_RIGHT),
+    ("turn_sharp_left", maps::TURN_SHARP_LEFT),
+    ("turn_sharp_right", maps::TURN_SHARP_RIGHT),
+    ("turn_slight_left", maps::TURN_SLIGHT_LEFT),
+    ("turn_slight_right", maps::TURN_SLIGHT_RIGHT),
+    ("turned_in", action::TURNED_IN),
+    ("turned_in_not", action::TURNED_IN_NOT),
+    ("tv", hardware::TV),
+    ("tv_off", notification::TV_OFF),
+    ("two_wheeler", maps::TWO_WHEELER),
+    ("u_turn_left", maps::U_TURN_LEFT),
+    ("u_turn_right", maps::U_TURN_RIGHT),
+    ("umbrella", places::UMBRELLA),
+    ("unarchive", content::UNARCHIVE),
+    ("undo", content::UNDO),
+    ("unfold_less", navigation::UNFOLD_LESS),
+    ("unfold_more", navigation::UNFOLD_MORE),
+    ("unpublished", action::UNPUBLISHED),
+    ("unsubscribe", communication::UNSUBSCRIBE),
+    ("upcoming", content::UPCOMING),
+    ("update", action::UPDATE),
+    ("update_disabled", action::UPDATE_DISABLED),
+    ("upgrade", action::UPGRADE),
+    ("upload", file::UPLOAD),
+    ("upload_file", file::UPLOAD_FILE),
+    ("usb", device::USB),
+    ("usb_off", device::USB_OFF),
+    ("vaccines", social::VACCINES),
+    ("vape_free", places::VAPE_FREE),
+    ("vaping_rooms", places::VAPING_ROOMS),
+    ("verified", action::VERIFIED),
+    ("verified_user", action::VERIFIED_USER),
+    ("vertical_align_bottom", editor::VERTICAL_ALIGN_BOTTOM),
+    ("vertical_align_center", editor::VERTICAL_ALIGN_CENTER),
+    ("vertical_align_top", editor::VERTICAL_ALIGN_TOP),
+    ("vertical_distribute", editor::VERTICAL_DISTRIBUTE),
+    ("vertical_split", action::VERTICAL_SPLIT),
+    ("vibration", notification::VIBRATION),
+    ("video_call", av::VIDEO_CALL),
+    ("video_camera_back", image::VIDEO_CAMERA_BACK),
+    ("video_camera_front", image::VIDEO_CAMERA_FRONT),
+    ("video_file", av::VIDEO_FILE),
+    ("video_label", av::VIDEO_LABEL),
+    ("video_library", av::VIDEO_LIBRARY),
+    ("video_settings", av::VIDEO_SETTINGS),
+    ("video_stable", image::VIDEO_STABLE),
+    ("videocam", av::VIDEOCAM),
+    ("videocam_off", av::VIDEOCAM_OFF),
+    ("videogame_asset", hardware::VIDEOGAME_ASSET),
+    ("videogame_asset_off", hardware::VIDEOGAME_ASSET_OFF),
+    ("view_agenda", action::VIEW_AGENDA),
+    ("view_array", action::VIEW_ARRAY),
+    ("view_carousel", action::VIEW_CAROUSEL),
+    ("view_column", action::VIEW_COLUMN),
+    ("view_comfy", image::VIEW_COMFY),
+    ("view_comfy_alt", action::VIEW_COMFY_ALT),
+    ("view_compact", image::VIEW_COMPACT),
+    ("view_compact_alt", action::VIEW_COMPACT_ALT),
+    ("view_cozy", action::VIEW_COZY),
+    ("view_day", action::VIEW_DAY),
+    ("view_headline", action::VIEW_HEADLINE),
+    ("view_in_ar", action::VIEW_IN_AR),
+    ("view_kanban", action::VIEW_KANBAN),
+    ("view_list", action::VIEW_LIST),
+    ("view_module", action::VIEW_MODULE),
+    ("view_quilt", action::VIEW_QUILT),
+    ("view_sidebar", action::VIEW_SIDEBAR),
+    ("view_stream", action::VIEW_STREAM),
+    ("view_timeline", action::VIEW_TIMELINE),
+    ("view_week", action::VIEW_WEEK),
+    ("vignette", image::VIGNETTE),
+    ("villa", places::VILLA),
+    ("visibility", action::VISIBILITY),
+    ("visibility_off", action::VISIBILITY_OFF),
+    ("voice_chat", notification::VOICE_CHAT),
+    ("voice_over_off", action::VOICE_OVER_OFF),
+    ("voicemail", communication::VOICEMAIL),
+    ("volcano", social::VOLCANO),
+    ("volume_down", av::VOLUME_DOWN),
+    ("volume_down_alt", av::VOLUME_DOWN_ALT),
+    ("volume_mute", av::VOLUME_MUTE),
+    ("volume_off", av::VOLUME_OFF),
+    ("volume_up", av::VOLUME_UP),
+    ("volunteer_activism", maps::VOLUNTEER_ACTIVISM),
+    ("vpn_key", communication::VPN_KEY),
+    ("vpn_key_off", communication::VPN_KEY_OFF),
+    ("vpn_lock", notification::VPN_LOCK),
+    ("vrpano", image::VRPANO),
+    ("wallpaper", device::WALLPAPER),
+    ("warehouse", maps::WAREHOUSE),
+    ("warning", alert::WARNING),
+    ("warning_amber", alert::WARNING_AMBER),
+    ("wash", places::WASH),
+    ("watch", hardware::WATCH),
+    ("watch_later", action::WATCH_LATER),
+    ("watch_off", hardware::WATCH_OFF),
+    ("water", device::WATER),
+    ("water_damage", places::WATER_DAMAGE),
+    ("water_drop", social::WATER_DROP),
+    ("waterfall_chart", navigation::WATERFALL_CHART),
+    ("waves", content::WAVES),
+    ("waving_hand", social::WAVING_HAND),
+    ("wb_auto", image::WB_AUTO),
+    ("wb_cloudy", image::WB_CLOUDY),
+    ("wb_incandescent", image::WB_INCANDESCENT),
+    ("wb_iridescent", image::WB_IRIDESCENT),
+    ("wb_shade", image::WB_SHADE),
+    ("wb_sunny", image::WB_SUNNY),
+    ("wb_twighlight", image::WB_TWIGHLIGHT),
+    ("wb_twilight", image::WB_TWILIGHT),
+    ("wc", notification::WC),
+    ("web", av::WEB),
+    ("web_asset", av::WEB_ASSET),
+    ("web_asset_off", av::WEB_ASSET_OFF),
+    ("web_stories", content::WEB_STORIES),
+    ("webhook", action::WEBHOOK),
+    ("weekend", content::WEEKEND),
+    ("west", navigation::WEST),
+    ("whatsapp", social::WHATSAPP),
+    ("whatshot", social::WHATSHOT),
+    ("wheelchair_pickup", places::WHEELCHAIR_PICKUP),
+    ("where_to_vote", content::WHERE_TO_VOTE),
+    ("widgets", device::WIDGETS),
+    ("wifi", notification::WIFI),
+    ("wifi_1_bar", device::WIFI_1_BAR),
+    ("wifi_2_bar", device::WIFI_2_BAR),
+    ("wifi_calling", communication::WIFI_CALLING),
+    ("wifi_calling_3", device::WIFI_CALLING_3),
+    ("wifi_channel", device::WIFI_CHANNEL),
+    ("wifi_find", device::WIFI_FIND),
+    ("wifi_lock", device::WIFI_LOCK),
+    ("wifi_off", notification::WIFI_OFF),
+    ("wifi_password", device::WIFI_PASSWORD),
+    ("wifi_protected_setup", action::WIFI_PROTECTED_SETUP),
+    ("wifi_tethering", device::WIFI_TETHERING),
+    ("wifi_tethering_error", device::WIFI_TETHERING_ERROR),
+    ("wifi_tethering_error_rounded", device::WIFI_TETHERING_ERROR_ROUNDED),
+    ("wifi_tethering_off", device::WIFI_TETHERING_OFF),
+    ("window", search::WINDOW),
+    ("wine_bar", maps::WINE_BAR),
+    ("woman", social::WOMAN),
+    ("work", action::WORK),
+    ("work_off", action::WORK_OFF),
+    ("work_outline", action::WORK_OUTLINE),
+    ("workspace_premium", social::WORKSPACE_PREMIUM),
+    ("workspaces", file::WORKSPACES),
+    ("workspaces_filled", file::WORKSPACES_FILLED),
+    ("workspaces_outline", file::WORKSPACES_OUTLINE),
+    ("wrap_text", editor::WRAP_TEXT),
+    ("wrong_location", maps::WRONG_LOCATION),
+    ("wysiwyg", action::WYSIWYG),
+    ("yard", search::YARD),
+    ("youtube_searched_for", action::YOUTUBE_SEARCHED_FOR),
+    ("zoom_in", action::ZOOM_IN),
+    ("zoom_in_map", maps::ZOOM_IN_MAP),
+    ("zoom_out", action::ZOOM_OUT),
+    ("zoom_out_map", maps::ZOOM_OUT_MAP),
+];
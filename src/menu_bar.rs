@@ -0,0 +1,770 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A menu bar inside the window, for windows without the platform decorations.
+
+use druid::commands::CLOSE_WINDOW;
+use druid::keyboard_types::Key;
+use druid::kurbo::Line;
+use druid::widget::prelude::*;
+use druid::widget::SizedBox;
+use druid::{theme, Color, Point, Rect, Selector, Target, TextLayout, WidgetExt, WidgetPod};
+
+use crate::dropdown::{DROPDOWN_CLOSED, DROPDOWN_HIDE, DROPDOWN_SHOW};
+use crate::{AutoFocus, Dropdown};
+
+const HORIZONTAL_PADDING: f64 = 8.0;
+const VERTICAL_PADDING: f64 = 4.0;
+const MIN_POPUP_WIDTH: f64 = 120.0;
+const SEPARATOR_HEIGHT: f64 = 9.0;
+
+/// Sent by an open menu to the header of its dropdown, to open the menu on its left
+/// (-1) or on its right (1) instead.
+const MENU_BAR_STEP: Selector<isize> = Selector::new("menu_bar.step");
+
+type MenuFn<T> = Box<dyn Fn(&T, &Env) -> BarMenu<T>>;
+type ActionFn<T> = Box<dyn Fn(&mut EventCtx, &mut T, &Env)>;
+
+/// The items of a menu of a [`MenuBar`].
+///
+/// Unlike druid's [`Menu`], a `BarMenu` is drawn by the menu bar itself, which lets
+/// the arrow keys move between the menus of the bar. It has no submenus.
+///
+/// [`Menu`]: druid::Menu
+pub struct BarMenu<T> {
+    entries: Vec<Option<BarMenuItem<T>>>,
+}
+
+/// An item of a [`BarMenu`].
+pub struct BarMenuItem<T> {
+    title: String,
+    enabled: bool,
+    action: Option<ActionFn<T>>,
+}
+
+impl<T> BarMenu<T> {
+    /// Create a menu without items.
+    pub fn empty() -> Self {
+        BarMenu {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Builder-style method for adding an item.
+    pub fn entry(mut self, item: BarMenuItem<T>) -> Self {
+        self.entries.push(Some(item));
+        self
+    }
+
+    /// Builder-style method for adding a separator.
+    pub fn separator(mut self) -> Self {
+        self.entries.push(None);
+        self
+    }
+}
+
+impl<T> BarMenuItem<T> {
+    /// Create a new item with the given title, in which a `&` marks the mnemonic.
+    pub fn new(title: impl Into<String>) -> Self {
+        BarMenuItem {
+            title: title.into(),
+            enabled: true,
+            action: None,
+        }
+    }
+
+    /// Builder-style method for setting the function called when the item is activated.
+    pub fn on_activate(mut self, action: impl Fn(&mut EventCtx, &mut T, &Env) + 'static) -> Self {
+        self.action = Some(Box::new(action));
+        self
+    }
+
+    /// Builder-style method for enabling or disabling the item.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+struct MenuTitle<T> {
+    layout: TextLayout<String>,
+    /// The byte offset in the title and the lowercase character of the mnemonic.
+    mnemonic: Option<(usize, char)>,
+    /// The dropdown showing the menu, covering the title.
+    header: WidgetPod<T, Box<dyn Widget<T>>>,
+    rect: Rect,
+}
+
+/// A menu bar shown above some content, for windows which don't show the platform
+/// menu bar, such as windows using a [`TitleBar`].
+///
+/// Each menu of the bar is a [`BarMenu`], built when it is opened and shown in a
+/// [`Dropdown`] below its title. Up and Down move between the items of an open menu,
+/// Enter or Space activate the highlighted item, the mnemonic of an item activates it,
+/// Left and Right open the neighbouring menus and Escape closes the menu.
+///
+/// A `&` in a menu title marks the next character as its mnemonic, like in druid's
+/// menus: pressing Alt and that character opens the menu, and the mnemonic is
+/// underlined while Alt is held. Pressing and releasing Alt alone focuses the bar,
+/// after which Left and Right move between the menus, Down, Enter or Space open the
+/// highlighted menu and Escape leaves the bar. As keyboard events are only sent to
+/// the focused widget and its ancestors, mnemonics work while the focus is in the
+/// content of the menu bar.
+///
+/// ```ignore
+/// MenuBar::new(content)
+///     .with_menu("&File", |_data, _env| {
+///         BarMenu::empty()
+///             .entry(BarMenuItem::new("&Open").on_activate(open))
+///             .separator()
+///             .entry(BarMenuItem::new("&Quit").on_activate(quit))
+///     })
+///     .with_menu("&Edit", |data: &String, _env| {
+///         BarMenu::empty().entry(BarMenuItem::new("&Clear").enabled(!data.is_empty()))
+///     })
+/// ```
+///
+/// [`TitleBar`]: crate::TitleBar
+pub struct MenuBar<T> {
+    menus: Vec<MenuTitle<T>>,
+    content: WidgetPod<T, Box<dyn Widget<T>>>,
+    bar_height: f64,
+    hovered: Option<usize>,
+    /// The menu highlighted while the bar has the keyboard focus.
+    highlighted: usize,
+    /// The menu whose dropdown is shown.
+    open: Option<usize>,
+    show_mnemonics: bool,
+    /// Set when Alt is pressed, and cleared when another key is pressed before it is
+    /// released.
+    alt_pressed: bool,
+}
+
+impl<T: Data> MenuBar<T> {
+    /// Create a new menu bar above `content`, without menus.
+    pub fn new(content: impl Widget<T> + 'static) -> Self {
+        MenuBar {
+            menus: Vec::new(),
+            content: WidgetPod::new(Box::new(content)),
+            bar_height: 0.0,
+            hovered: None,
+            highlighted: 0,
+            open: None,
+            show_mnemonics: false,
+            alt_pressed: false,
+        }
+    }
+
+    /// Builder-style method for adding a menu, with the given title, built by `build`
+    /// whenever it is opened.
+    pub fn with_menu(
+        mut self,
+        title: &str,
+        build: impl Fn(&T, &Env) -> BarMenu<T> + 'static,
+    ) -> Self {
+        self.add_menu(title, build);
+        self
+    }
+
+    /// Add a menu, with the given title, built by `build` whenever it is opened.
+    pub fn add_menu(&mut self, title: &str, build: impl Fn(&T, &Env) -> BarMenu<T> + 'static) {
+        let (title, mnemonic) = parse_mnemonic(title);
+        let mut layout = TextLayout::new();
+        layout.set_text(title);
+
+        let header_id = WidgetId::next();
+        let build: MenuFn<T> = Box::new(build);
+        let header = Dropdown::new(SizedBox::empty(), move |data: &T, env: &Env| {
+            MenuPopup::new(build(data, env), header_id).controller(AutoFocus)
+        })
+        .with_id(header_id);
+        self.menus.push(MenuTitle {
+            layout,
+            mnemonic,
+            header: WidgetPod::new(header.boxed()),
+            rect: Rect::ZERO,
+        });
+    }
+
+    fn menu_at(&self, pos: Point) -> Option<usize> {
+        self.menus.iter().position(|menu| menu.rect.contains(pos))
+    }
+
+    fn open(&mut self, ctx: &mut EventCtx, index: usize) {
+        ctx.submit_command(DROPDOWN_SHOW.to(self.menus[index].header.id()));
+        self.open = Some(index);
+        self.highlighted = index;
+        self.show_mnemonics = false;
+        ctx.request_paint();
+    }
+
+    fn close(&mut self, ctx: &mut EventCtx) {
+        if let Some(open) = self.open.take() {
+            ctx.submit_command(DROPDOWN_HIDE.to(self.menus[open].header.id()));
+            ctx.request_paint();
+        }
+    }
+
+    /// Closes the open menu, if any, and opens the menu at `index`.
+    fn switch_to(&mut self, ctx: &mut EventCtx, index: usize) {
+        self.close(ctx);
+        self.open(ctx, index);
+    }
+
+    /// Handles a key pressed while the bar has the focus.
+    fn navigate(&mut self, ctx: &mut EventCtx, key: &Key) -> bool {
+        let count = self.menus.len();
+        match key {
+            Key::ArrowLeft => self.highlighted = adjacent(self.highlighted, -1, count),
+            Key::ArrowRight => self.highlighted = adjacent(self.highlighted, 1, count),
+            Key::ArrowDown | Key::Enter => self.switch_to(ctx, self.highlighted),
+            Key::Character(c) if c == " " => self.switch_to(ctx, self.highlighted),
+            Key::Escape => ctx.resign_focus(),
+            _ => return false,
+        }
+        ctx.request_paint();
+        true
+    }
+
+    /// Handles the commands sent to the dropdown of a menu, returning whether the event
+    /// was handled.
+    fn header_command(&mut self, ctx: &mut EventCtx, event: &Event) -> bool {
+        let cmd = match event {
+            Event::Command(cmd) => cmd,
+            _ => return false,
+        };
+        let index = match cmd.target() {
+            Target::Widget(id) => self.menus.iter().position(|menu| menu.header.id() == id),
+            _ => None,
+        };
+        let index = match index {
+            Some(index) => index,
+            None => return false,
+        };
+        if let Some(step) = cmd.get(MENU_BAR_STEP) {
+            self.switch_to(ctx, adjacent(index, *step, self.menus.len()));
+            return true;
+        }
+        if cmd.is(DROPDOWN_CLOSED) && self.open == Some(index) {
+            self.open = None;
+            ctx.request_paint();
+        }
+        false
+    }
+}
+
+/// Returns the index `step` places after `index`, wrapping around `count`.
+fn adjacent(index: usize, step: isize, count: usize) -> usize {
+    (index as isize + step).rem_euclid(count as isize) as usize
+}
+
+/// Removes the `&` marking the mnemonic from `title`, returning the title and the
+/// byte offset and lowercase character of the mnemonic. `&&` is a literal `&`.
+fn parse_mnemonic(title: &str) -> (String, Option<(usize, char)>) {
+    let mut text = String::new();
+    let mut mnemonic = None;
+    let mut chars = title.chars();
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.next() {
+                Some('&') => text.push('&'),
+                Some(c) => {
+                    if mnemonic.is_none() {
+                        mnemonic = c.to_lowercase().next().map(|lower| (text.len(), lower));
+                    }
+                    text.push(c);
+                }
+                None => {}
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    (text, mnemonic)
+}
+
+/// Returns the lowercase character of a key, to compare it with a mnemonic.
+fn key_char(key: &Key) -> Option<char> {
+    match key {
+        Key::Character(c) => c.chars().next().and_then(|c| c.to_lowercase().next()),
+        _ => None,
+    }
+}
+
+/// Underlines the mnemonic at `offset` of the text drawn at `origin`.
+fn draw_mnemonic(
+    ctx: &mut PaintCtx,
+    layout: &TextLayout<String>,
+    origin: Point,
+    (offset, mnemonic): (usize, char),
+    color: &Color,
+) {
+    let start = layout.point_for_text_position(offset);
+    let end = layout.point_for_text_position(offset + mnemonic.len_utf8());
+    let y = origin.y + start.y + 1.5;
+    let underline = Line::new((origin.x + start.x, y), (origin.x + end.x, y));
+    ctx.stroke(underline, color, 1.0);
+}
+
+impl<T: Data> Widget<T> for MenuBar<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if self.header_command(ctx, event) {
+            ctx.set_handled();
+            return;
+        }
+        for menu in &mut self.menus {
+            menu.header.event(ctx, event, data, env);
+        }
+        if ctx.is_handled() {
+            return;
+        }
+
+        match event {
+            Event::MouseDown(mouse) if mouse.button.is_left() => {
+                if let Some(index) = self.menu_at(mouse.pos) {
+                    // a click outside of the open dropdown closes it
+                    if self.open == Some(index) {
+                        self.close(ctx);
+                    } else {
+                        self.open(ctx, index);
+                    }
+                    ctx.set_handled();
+                    return;
+                }
+            }
+            Event::MouseMove(mouse) => {
+                let hovered = self.menu_at(mouse.pos);
+                if hovered != self.hovered {
+                    self.hovered = hovered;
+                    ctx.request_paint();
+                }
+                // while a menu is open, hovering another title opens its menu
+                if let (Some(open), Some(hovered)) = (self.open, hovered) {
+                    if open != hovered {
+                        self.switch_to(ctx, hovered);
+                    }
+                }
+            }
+            Event::KeyDown(key) => {
+                if key.key == Key::Alt {
+                    self.alt_pressed = true;
+                    self.show_mnemonics = true;
+                    ctx.request_paint();
+                } else {
+                    self.alt_pressed = false;
+                    if key.mods.alt() {
+                        let c = key_char(&key.key);
+                        let index = self.menus.iter().position(|menu| {
+                            c.is_some() && menu.mnemonic.map(|(_, mnemonic)| mnemonic) == c
+                        });
+                        if let Some(index) = index {
+                            self.switch_to(ctx, index);
+                            ctx.set_handled();
+                            return;
+                        }
+                    }
+                    if ctx.is_focused() && self.navigate(ctx, &key.key) {
+                        ctx.set_handled();
+                        return;
+                    }
+                }
+            }
+            Event::KeyUp(key) if key.key == Key::Alt => {
+                self.show_mnemonics = false;
+                if self.alt_pressed && !self.menus.is_empty() {
+                    // Alt on its own toggles the keyboard navigation of the bar
+                    if ctx.is_focused() {
+                        ctx.resign_focus();
+                    } else {
+                        self.highlighted = 0;
+                        ctx.request_focus();
+                    }
+                }
+                self.alt_pressed = false;
+                ctx.request_paint();
+            }
+            _ => {}
+        }
+        self.content.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        match event {
+            LifeCycle::FocusChanged(_) => ctx.request_paint(),
+            LifeCycle::HotChanged(false) => {
+                self.hovered = None;
+                ctx.request_paint();
+            }
+            _ => {}
+        }
+        for menu in &mut self.menus {
+            menu.header.lifecycle(ctx, event, data, env);
+        }
+        self.content.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        let mut relayout = false;
+        for menu in &mut self.menus {
+            relayout |= menu.layout.needs_rebuild_after_update(ctx);
+            menu.header.update(ctx, data, env);
+        }
+        if relayout {
+            ctx.request_layout();
+        }
+        self.content.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let mut x = 0.0;
+        let mut text_height: f64 = 0.0;
+        for menu in &mut self.menus {
+            menu.layout.rebuild_if_needed(ctx.text(), env);
+            let size = menu.layout.size();
+            text_height = text_height.max(size.height);
+            menu.rect = Rect::from_origin_size((x, 0.0), (size.width, 0.0));
+            x += size.width + 2.0 * HORIZONTAL_PADDING;
+        }
+        self.bar_height = text_height + 2.0 * VERTICAL_PADDING;
+        for menu in &mut self.menus {
+            let width = menu.rect.width() + 2.0 * HORIZONTAL_PADDING;
+            menu.rect = Rect::from_origin_size(menu.rect.origin(), (width, self.bar_height));
+            let header_bc = BoxConstraints::tight(menu.rect.size());
+            menu.header.layout(ctx, &header_bc, data, env);
+            menu.header.set_origin(ctx, menu.rect.origin());
+        }
+
+        let content_bc = bc.shrink((0.0, self.bar_height));
+        let content_size = self.content.layout(ctx, &content_bc, data, env);
+        self.content
+            .set_origin(ctx, Point::new(0.0, self.bar_height));
+
+        let width = if bc.max().width.is_finite() {
+            bc.max().width
+        } else {
+            content_size.width.max(x)
+        };
+        bc.constrain(Size::new(width, content_size.height + self.bar_height))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let bar = Size::new(ctx.size().width, self.bar_height).to_rect();
+        ctx.fill(bar, &env.get(theme::BACKGROUND_LIGHT));
+
+        let highlighted = self
+            .open
+            .or(self.hovered)
+            .or_else(|| ctx.is_focused().then_some(self.highlighted));
+        let text_color = env.get(theme::TEXT_COLOR);
+        for (index, menu) in self.menus.iter_mut().enumerate() {
+            menu.header.paint(ctx, data, env);
+            if highlighted == Some(index) {
+                ctx.fill(menu.rect, &env.get(theme::BUTTON_LIGHT));
+            }
+            let origin = menu.rect.origin() + (HORIZONTAL_PADDING, VERTICAL_PADDING);
+            menu.layout.draw(ctx, origin);
+
+            if let (true, Some(mnemonic)) = (self.show_mnemonics, menu.mnemonic) {
+                draw_mnemonic(ctx, &menu.layout, origin, mnemonic, &text_color);
+            }
+        }
+
+        self.content.paint(ctx, data, env);
+    }
+}
+
+struct PopupItem<T> {
+    layout: TextLayout<String>,
+    mnemonic: Option<(usize, char)>,
+    enabled: bool,
+    action: Option<ActionFn<T>>,
+}
+
+/// The content of the dropdown of an open menu.
+struct MenuPopup<T> {
+    /// The items of the menu, `None` for the separators, with their bounds.
+    entries: Vec<(Option<PopupItem<T>>, Rect)>,
+    highlighted: Option<usize>,
+    /// The dropdown showing the popup, which forwards [`MENU_BAR_STEP`] to the bar.
+    header: WidgetId,
+}
+
+impl<T: Data> MenuPopup<T> {
+    fn new(menu: BarMenu<T>, header: WidgetId) -> Self {
+        let entries = menu
+            .entries
+            .into_iter()
+            .map(|entry| {
+                let item = entry.map(|item| {
+                    let (title, mnemonic) = parse_mnemonic(&item.title);
+                    let mut layout = TextLayout::new();
+                    layout.set_text(title);
+                    if !item.enabled {
+                        layout.set_text_color(theme::DISABLED_TEXT_COLOR);
+                    }
+                    PopupItem {
+                        layout,
+                        mnemonic,
+                        enabled: item.enabled,
+                        action: item.action,
+                    }
+                });
+                (item, Rect::ZERO)
+            })
+            .collect();
+        MenuPopup {
+            entries,
+            highlighted: None,
+            header,
+        }
+    }
+
+    fn is_enabled(&self, index: usize) -> bool {
+        matches!(&self.entries[index].0, Some(item) if item.enabled)
+    }
+
+    fn item_at(&self, pos: Point) -> Option<usize> {
+        (0..self.entries.len())
+            .find(|&index| self.entries[index].1.contains(pos))
+            .filter(|&index| self.is_enabled(index))
+    }
+
+    /// Moves the highlight to the next enabled item in the direction of `step`.
+    fn move_highlight(&mut self, step: isize) {
+        let count = self.entries.len();
+        let mut index = match self.highlighted {
+            Some(index) => index,
+            // the first step lands on the first or the last item
+            None if step > 0 => count - 1,
+            None => 0,
+        };
+        for _ in 0..count {
+            index = adjacent(index, step, count);
+            if self.is_enabled(index) {
+                self.highlighted = Some(index);
+                return;
+            }
+        }
+    }
+
+    fn activate(&mut self, ctx: &mut EventCtx, index: usize, data: &mut T, env: &Env) {
+        if let Some(PopupItem {
+            action: Some(action),
+            ..
+        }) = &self.entries[index].0
+        {
+            action(ctx, data, env);
+        }
+        ctx.submit_command(CLOSE_WINDOW.to(ctx.window_id()));
+    }
+}
+
+impl<T: Data> Widget<T> for MenuPopup<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseMove(mouse) => {
+                let hovered = self.item_at(mouse.pos);
+                if hovered.is_some() && hovered != self.highlighted {
+                    self.highlighted = hovered;
+                    ctx.request_paint();
+                }
+            }
+            Event::MouseUp(mouse) if mouse.button.is_left() => {
+                if let Some(index) = self.item_at(mouse.pos) {
+                    self.activate(ctx, index, data, env);
+                }
+            }
+            Event::KeyDown(key) if !self.entries.is_empty() => {
+                match &key.key {
+                    Key::ArrowDown => self.move_highlight(1),
+                    Key::ArrowUp => self.move_highlight(-1),
+                    Key::ArrowLeft => ctx.submit_command(MENU_BAR_STEP.with(-1).to(self.header)),
+                    Key::ArrowRight => ctx.submit_command(MENU_BAR_STEP.with(1).to(self.header)),
+                    Key::Escape => ctx.submit_command(CLOSE_WINDOW.to(ctx.window_id())),
+                    Key::Enter => {
+                        if let Some(index) = self.highlighted {
+                            self.activate(ctx, index, data, env);
+                        }
+                    }
+                    key => {
+                        let c = key_char(key);
+                        let index = (0..self.entries.len()).find(|&index| {
+                            let mnemonic = match &self.entries[index].0 {
+                                Some(item) => item.mnemonic.map(|(_, mnemonic)| mnemonic),
+                                None => None,
+                            };
+                            c.is_some() && mnemonic == c && self.is_enabled(index)
+                        });
+                        match (c, index, self.highlighted) {
+                            (_, Some(index), _) => self.activate(ctx, index, data, env),
+                            (Some(' '), None, Some(highlighted)) => {
+                                self.activate(ctx, highlighted, data, env)
+                            }
+                            _ => return,
+                        }
+                    }
+                }
+                ctx.request_paint();
+                ctx.set_handled();
+            }
+            _ => {}
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, _data: &T, _env: &Env) {
+        if let LifeCycle::HotChanged(false) = event {
+            self.highlighted = None;
+            ctx.request_paint();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, _data: &T, _env: &Env) {
+        let relayout = self
+            .entries
+            .iter_mut()
+            .filter_map(|(item, _)| item.as_mut())
+            .any(|item| item.layout.needs_rebuild_after_update(ctx));
+        if relayout {
+            ctx.request_layout();
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, env: &Env) -> Size {
+        let mut width: f64 = MIN_POPUP_WIDTH;
+        for item in self
+            .entries
+            .iter_mut()
+            .filter_map(|(item, _)| item.as_mut())
+        {
+            item.layout.rebuild_if_needed(ctx.text(), env);
+            width = width.max(item.layout.size().width + 2.0 * HORIZONTAL_PADDING);
+        }
+
+        let mut y = 0.0;
+        for (item, rect) in &mut self.entries {
+            let height = match item {
+                Some(item) => item.layout.size().height + 2.0 * VERTICAL_PADDING,
+                None => SEPARATOR_HEIGHT,
+            };
+            *rect = Rect::from_origin_size((0.0, y), (width, height));
+            y += height;
+        }
+        bc.constrain(Size::new(width, y))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, env: &Env) {
+        let bounds = ctx.size().to_rect();
+        ctx.fill(bounds, &env.get(theme::BACKGROUND_LIGHT));
+
+        let text_color = env.get(theme::TEXT_COLOR);
+        for (index, (item, rect)) in self.entries.iter().enumerate() {
+            match item {
+                Some(item) => {
+                    if self.highlighted == Some(index) {
+                        ctx.fill(*rect, &env.get(theme::BUTTON_LIGHT));
+                    }
+                    let origin = rect.origin() + (HORIZONTAL_PADDING, VERTICAL_PADDING);
+                    item.layout.draw(ctx, origin);
+                    if let Some(mnemonic) = item.mnemonic {
+                        draw_mnemonic(ctx, &item.layout, origin, mnemonic, &text_color);
+                    }
+                }
+                None => {
+                    let y = rect.center().y;
+                    let line = Line::new(
+                        (rect.x0 + HORIZONTAL_PADDING, y),
+                        (rect.x1 - HORIZONTAL_PADDING, y),
+                    );
+                    ctx.stroke(line, &env.get(theme::BORDER_LIGHT), 1.0);
+                }
+            }
+        }
+
+        ctx.stroke(bounds.inset(-0.5), &env.get(theme::BORDER_DARK), 1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WidgetExt as _;
+    use druid::tests::harness::Harness;
+    use druid::{KeyEvent, Modifiers};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn mnemonics() {
+        assert_eq!(
+            parse_mnemonic("&File"),
+            ("File".to_string(), Some((0, 'f')))
+        );
+        assert_eq!(
+            parse_mnemonic("Sa&ve"),
+            ("Save".to_string(), Some((2, 'v')))
+        );
+        assert_eq!(
+            parse_mnemonic("Drag && Drop"),
+            ("Drag & Drop".to_string(), None)
+        );
+        assert_eq!(
+            parse_mnemonic("&Ünits"),
+            ("Ünits".to_string(), Some((0, 'ü')))
+        );
+    }
+
+    #[test]
+    fn adjacent_menus_wrap_around() {
+        assert_eq!(adjacent(0, 1, 3), 1);
+        assert_eq!(adjacent(2, 1, 3), 0);
+        assert_eq!(adjacent(0, -1, 3), 2);
+    }
+
+    #[test]
+    fn popup_keyboard_navigation() {
+        let menu = BarMenu::empty()
+            .entry(
+                BarMenuItem::new("&Hello")
+                    .on_activate(|_, data: &mut String, _| data.push_str("Hello")),
+            )
+            .separator()
+            .entry(BarMenuItem::new("&Disabled").enabled(false))
+            .entry(
+                BarMenuItem::new("&Goodbye")
+                    .on_activate(|_, data: &mut String, _| data.push_str("Goodbye")),
+            );
+        let header = WidgetId::next();
+        let step = Rc::new(Cell::new(0));
+        let popup = MenuPopup::new(menu, header)
+            .controller(AutoFocus)
+            .on_command(MENU_BAR_STEP, {
+                let step = step.clone();
+                move |_, &s, _| step.set(s)
+            })
+            .with_id(header);
+        let key = |key| Event::KeyDown(KeyEvent::for_test(Modifiers::empty(), key));
+
+        Harness::create_simple(String::new(), popup, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+
+            // the separator and the disabled item are skipped
+            harness.event(key(Key::ArrowUp));
+            harness.event(key(Key::Enter));
+            assert_eq!(harness.data(), "Goodbye");
+            harness.event(key(Key::ArrowDown));
+            harness.event(key(Key::Character(" ".into())));
+            assert_eq!(harness.data(), "GoodbyeHello");
+            // disabled items can't be activated by their mnemonic
+            harness.event(key(Key::Character("d".into())));
+            harness.event(key(Key::Character("h".into())));
+            assert_eq!(harness.data(), "GoodbyeHelloHello");
+
+            // Left and Right ask the bar to open the neighbouring menus
+            harness.event(key(Key::ArrowRight));
+            assert_eq!(step.get(), 1);
+            harness.event(key(Key::ArrowLeft));
+            assert_eq!(step.get(), -1);
+        });
+    }
+}
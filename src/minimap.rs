@@ -0,0 +1,331 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A scaled down overview of some large content, showing and moving its viewport.
+
+use druid::kurbo::Affine;
+use druid::widget::prelude::*;
+use druid::widget::Controller;
+use druid::{theme, Color, Data, KeyOrValue, Lens, LensExt, Point, Rect, Selector, Vec2};
+
+const UPDATE_VIEWPORT: Selector<(Rect, Size)> = Selector::new("minimap.update-viewport");
+
+/// The part of some content which is visible, shared between a [`Minimap`] and the
+/// [`MinimapViewport`] controlling the content.
+#[derive(Clone, Copy, Data, Debug, Default, Lens, PartialEq)]
+pub struct MinimapState {
+    /// The visible area, in content coordinates.
+    pub viewport: Rect,
+    /// The size of the whole content.
+    pub content_size: Size,
+}
+
+type PaintFn<T> = Box<dyn Fn(&mut PaintCtx, &T, &Env)>;
+
+/// A scaled down overview of some content, with a rectangle marking the visible part
+/// of it, which can be dragged to scroll the content.
+///
+/// The overview is painted by a callback, in content coordinates; the minimap scales
+/// it to fit. The visible part is read from and written to a [`MinimapState`], which
+/// is kept in sync with the content, typically the child of a [`Scroll`], by a
+/// [`MinimapViewport`] controller on the content:
+///
+/// ```ignore
+/// let content = content.controller(MinimapViewport::new(AppData::minimap));
+/// let main_view = Scroll::new(content);
+/// let minimap = Minimap::new(AppData::minimap, |ctx, data, env| paint_overview(ctx, data, env));
+/// ```
+///
+/// [`Scroll`]: druid::widget::Scroll
+pub struct Minimap<T, L> {
+    lens: L,
+    paint: PaintFn<T>,
+    viewport_color: KeyOrValue<Color>,
+    /// The offset between the mouse and the origin of the viewport while dragging.
+    drag_offset: Option<Vec2>,
+}
+
+impl<T: Data, L: Lens<T, MinimapState>> Minimap<T, L> {
+    /// Create a new minimap, reading the viewport through `lens` and painting the
+    /// overview with `paint`.
+    pub fn new(lens: L, paint: impl Fn(&mut PaintCtx, &T, &Env) + 'static) -> Self {
+        Minimap {
+            lens,
+            paint: Box::new(paint),
+            viewport_color: theme::PRIMARY_LIGHT.into(),
+            drag_offset: None,
+        }
+    }
+
+    /// Builder-style method for setting the color of the viewport rectangle.
+    pub fn with_viewport_color(mut self, color: impl Into<KeyOrValue<Color>>) -> Self {
+        self.set_viewport_color(color);
+        self
+    }
+
+    /// Set the color of the viewport rectangle.
+    pub fn set_viewport_color(&mut self, color: impl Into<KeyOrValue<Color>>) {
+        self.viewport_color = color.into();
+    }
+
+    /// Returns the transform from content to minimap coordinates, which scales the
+    /// content to fit and centers it.
+    fn transform(&self, state: &MinimapState, size: Size) -> Affine {
+        let content = state.content_size;
+        if content.width <= 0.0 || content.height <= 0.0 {
+            return Affine::IDENTITY;
+        }
+        let scale = (size.width / content.width).min(size.height / content.height);
+        let offset = Vec2::new(
+            (size.width - content.width * scale) / 2.0,
+            (size.height - content.height * scale) / 2.0,
+        );
+        Affine::translate(offset) * Affine::scale(scale)
+    }
+
+    /// Moves the viewport so that its origin is at `origin` in content coordinates,
+    /// keeping it within the content.
+    fn move_viewport(&self, data: &mut T, origin: Point) {
+        self.lens.with_mut(data, |state| {
+            let size = state.viewport.size();
+            let max_x = (state.content_size.width - size.width).max(0.0);
+            let max_y = (state.content_size.height - size.height).max(0.0);
+            let origin = Point::new(origin.x.clamp(0.0, max_x), origin.y.clamp(0.0, max_y));
+            state.viewport = Rect::from_origin_size(origin, size);
+        });
+    }
+}
+
+impl<T: Data, L: Lens<T, MinimapState>> Widget<T> for Minimap<T, L> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, _env: &Env) {
+        match event {
+            Event::MouseDown(mouse) if mouse.button.is_left() => {
+                let state = self.lens.get(data);
+                let inverse = self.transform(&state, ctx.size()).inverse();
+                let pos = inverse * mouse.pos;
+                let offset = if state.viewport.contains(pos) {
+                    pos - state.viewport.origin()
+                } else {
+                    // center the viewport on the click
+                    let offset = state.viewport.size().to_vec2() / 2.0;
+                    self.move_viewport(data, pos - offset);
+                    offset
+                };
+                self.drag_offset = Some(offset);
+                ctx.set_active(true);
+                ctx.set_handled();
+            }
+            Event::MouseMove(mouse) if ctx.is_active() => {
+                if let Some(offset) = self.drag_offset {
+                    let state = self.lens.get(data);
+                    let pos = self.transform(&state, ctx.size()).inverse() * mouse.pos;
+                    self.move_viewport(data, pos - offset);
+                }
+            }
+            Event::MouseUp(_) if ctx.is_active() => {
+                self.drag_offset = None;
+                ctx.set_active(false);
+            }
+            _ => {}
+        }
+    }
+
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &T, _env: &Env) {}
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, _env: &Env) {
+        if !old_data.same(data) {
+            ctx.request_paint();
+        }
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, _env: &Env) -> Size {
+        bc.max()
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let state = self.lens.get(data);
+        let transform = self.transform(&state, ctx.size());
+        let clip = ctx.size().to_rect();
+        ctx.clip(clip);
+        ctx.with_save(|ctx| {
+            ctx.transform(transform);
+            (self.paint)(ctx, data, env);
+        });
+
+        let viewport = transform.transform_rect_bbox(state.viewport);
+        let color = self.viewport_color.resolve(env);
+        ctx.fill(viewport, &color.with_alpha(0.2));
+        ctx.stroke(viewport, &color, 1.0);
+    }
+}
+
+/// A controller keeping a [`MinimapState`] in sync with the visible part of the
+/// widget it controls, typically the child of a [`Scroll`].
+///
+/// When the widget is scrolled or resized, the new viewport is written to the data;
+/// when the viewport in the data changes, for example because it was dragged in a
+/// [`Minimap`], the widget is scrolled to show it.
+///
+/// [`Scroll`]: druid::widget::Scroll
+pub struct MinimapViewport<L> {
+    lens: L,
+    last: MinimapState,
+}
+
+impl<L> MinimapViewport<L> {
+    pub fn new(lens: L) -> Self {
+        MinimapViewport {
+            lens,
+            last: MinimapState::default(),
+        }
+    }
+}
+
+impl<T: Data, L: Lens<T, MinimapState>, W: Widget<T>> Controller<T, W> for MinimapViewport<L> {
+    fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::Command(cmd) = event {
+            if let Some((viewport, content_size)) = cmd.get(UPDATE_VIEWPORT) {
+                self.last = MinimapState {
+                    viewport: *viewport,
+                    content_size: *content_size,
+                };
+                self.lens.put(data, self.last);
+                ctx.set_handled();
+                return;
+            }
+        }
+        child.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(
+        &mut self,
+        child: &mut W,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &T,
+        env: &Env,
+    ) {
+        match event {
+            LifeCycle::ViewContextChanged(view_context) => {
+                let viewport = view_context.clip.intersect(ctx.size().to_rect());
+                ctx.submit_command(
+                    UPDATE_VIEWPORT
+                        .with((viewport, ctx.size()))
+                        .to(ctx.widget_id()),
+                );
+            }
+            LifeCycle::Size(size) => {
+                let viewport = self.last.viewport;
+                ctx.submit_command(UPDATE_VIEWPORT.with((viewport, *size)).to(ctx.widget_id()));
+            }
+            _ => {}
+        }
+        child.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, child: &mut W, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        let state = self.lens.get(data);
+        if state.viewport != self.last.viewport {
+            self.last = state;
+            ctx.scroll_area_to_view(state.viewport);
+        }
+        child.update(ctx, old_data, data, env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WidgetExt as _;
+    use druid::tests::harness::Harness;
+    use druid::widget::{Scroll, SizedBox};
+    use druid::{MouseButton, MouseEvent, TimerToken, WidgetExt as _};
+
+    fn state(viewport: Rect, content_size: Size) -> MinimapState {
+        MinimapState {
+            viewport,
+            content_size,
+        }
+    }
+
+    #[test]
+    fn minimap_mapping() {
+        let minimap = Minimap::new(druid::lens::Identity, |_, _: &MinimapState, _| {});
+        let mut data = state(Rect::new(0.0, 0.0, 400.0, 300.0), Size::new(800.0, 1000.0));
+
+        // the content is scaled down to the height of the minimap, and centered
+        let transform = minimap.transform(&data, Size::new(200.0, 100.0));
+        assert_eq!(transform * Point::ORIGIN, Point::new(60.0, 0.0));
+        assert_eq!(
+            transform * Point::new(800.0, 1000.0),
+            Point::new(140.0, 100.0)
+        );
+
+        minimap.move_viewport(&mut data, Point::new(100.0, 200.0));
+        assert_eq!(data.viewport, Rect::new(100.0, 200.0, 500.0, 500.0));
+        // the viewport stays within the content
+        minimap.move_viewport(&mut data, Point::new(-50.0, 900.0));
+        assert_eq!(data.viewport, Rect::new(0.0, 700.0, 400.0, 1000.0));
+    }
+
+    #[test]
+    fn scrolled_content() {
+        const SET_VIEWPORT: Selector<Rect> =
+            Selector::new("druid-widget-nursery.test.set-viewport");
+
+        let content = SizedBox::empty()
+            .fix_size(400.0, 1000.0)
+            .controller(MinimapViewport::new(druid::lens::Identity));
+        let scroll = Scroll::new(content)
+            .vertical()
+            .on_command(SET_VIEWPORT, |_, viewport, data: &mut MinimapState| {
+                data.viewport = *viewport
+            });
+        let wheel = Event::Wheel(MouseEvent {
+            pos: Point::new(200.0, 200.0),
+            window_pos: Point::new(200.0, 200.0),
+            buttons: Default::default(),
+            mods: Default::default(),
+            count: 0,
+            focus: false,
+            button: MouseButton::None,
+            wheel_delta: Vec2::new(0.0, 100.0),
+        });
+        // the commands submitted while handling an event are delivered with the next one
+        let flush = Event::Timer(TimerToken::INVALID);
+
+        Harness::create_simple(MinimapState::default(), scroll, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            harness.event(flush.clone());
+            let content_size = Size::new(400.0, 1000.0);
+            assert_eq!(
+                *harness.data(),
+                state(Rect::new(0.0, 0.0, 400.0, 400.0), content_size)
+            );
+
+            harness.event(wheel.clone());
+            harness.event(flush.clone());
+            assert_eq!(
+                *harness.data(),
+                state(Rect::new(0.0, 100.0, 400.0, 500.0), content_size)
+            );
+
+            // moving the viewport in the data scrolls the content to it
+            harness.submit_command(SET_VIEWPORT.with(Rect::new(0.0, 500.0, 400.0, 900.0)));
+            harness.just_layout();
+            harness.event(flush.clone());
+            assert_eq!(
+                *harness.data(),
+                state(Rect::new(0.0, 500.0, 400.0, 900.0), content_size)
+            );
+            // and scrolling goes on from there
+            harness.event(wheel.clone());
+            harness.event(flush.clone());
+            assert_eq!(
+                *harness.data(),
+                state(Rect::new(0.0, 600.0, 400.0, 1000.0), content_size)
+            );
+        });
+    }
+}
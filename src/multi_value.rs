@@ -58,17 +58,37 @@ where
     }
 
     /// Set show_when_disabled, the default is false.
+    ///
+    /// When true, the inner widget stays laid out and painted while this widget is not
+    /// selected, but it is disabled: it is drawn as such and receives no events, so it
+    /// is effectively read-only.
     pub fn set_show_when_disabled(&mut self, show_when_disabled: bool) {
         self.layout.always_visible = show_when_disabled;
     }
 
     /// Builder-style method to set show_when_disabled to true.
     /// The default is false.
+    ///
+    /// See [`set_show_when_disabled`](Self::set_show_when_disabled).
     pub fn show_when_disabled(mut self) -> Self {
         self.layout.always_visible = true;
         self
     }
 
+    /// Set whether the indent of the inner widget is animated with its height, the
+    /// default is false.
+    pub fn set_animate_indent(&mut self, animate_indent: bool) {
+        self.layout.animate_indent = animate_indent;
+    }
+
+    /// Builder-style method to animate the indent of the inner widget with its height,
+    /// so that it slides in from the left while it is shown.
+    /// The default is false.
+    pub fn animate_indent(mut self, animate_indent: bool) -> Self {
+        self.layout.animate_indent = animate_indent;
+        self
+    }
+
     /// A Builder-style method to set the duration for the transition
     /// between shown and hidden.
     pub fn set_transition_duration(&mut self, duration: f64) {
@@ -219,17 +239,37 @@ where
     }
 
     /// Set show_when_disabled, the default is false.
+    ///
+    /// When true, the inner widget stays laid out and painted while this widget is not
+    /// selected, but it is disabled: it is drawn as such and receives no events, so it
+    /// is effectively read-only.
     pub fn set_show_when_disabled(&mut self, show_when_disabled: bool) {
         self.layout.always_visible = show_when_disabled;
     }
 
     /// Builder-style method to set show_when_disabled to true.
     /// The default is false.
+    ///
+    /// See [`set_show_when_disabled`](Self::set_show_when_disabled).
     pub fn show_when_disabled(mut self) -> Self {
         self.layout.always_visible = true;
         self
     }
 
+    /// Set whether the indent of the inner widget is animated with its height, the
+    /// default is false.
+    pub fn set_animate_indent(&mut self, animate_indent: bool) {
+        self.layout.animate_indent = animate_indent;
+    }
+
+    /// Builder-style method to animate the indent of the inner widget with its height,
+    /// so that it slides in from the left while it is shown.
+    /// The default is false.
+    pub fn animate_indent(mut self, animate_indent: bool) -> Self {
+        self.layout.animate_indent = animate_indent;
+        self
+    }
+
     /// A Builder-style method to set the duration for the transition
     /// between shown and hidden.
     pub fn set_transition_duration(&mut self, duration: f64) {
@@ -362,6 +402,7 @@ pub struct IndentLayout {
     space: KeyOrValue<f64>,
     indent: KeyOrValue<f64>,
     always_visible: bool,
+    animate_indent: bool,
     height: Animated<f64>,
 }
 
@@ -371,6 +412,7 @@ impl IndentLayout {
             space: KeyOrValue::Key(WIDGET_PADDING_VERTICAL),
             indent: KeyOrValue::Key(INDENT),
             always_visible: false,
+            animate_indent: false,
             height: Animated::new(0.0)
                 .duration(0.2)
                 .curve(AnimationCurve::EASE_OUT)
@@ -425,7 +467,7 @@ impl IndentLayout {
         let inner_bc = bc.shrink(inner_origin.to_size());
 
         let inner_size = body.layout(ctx, &inner_bc, data_b, env);
-        body.set_origin(ctx, inner_origin.to_point());
+        body.set_origin(ctx, self.animated_origin(inner_origin).to_point());
 
         if !inner_size.is_empty() {
             Size::new(
@@ -439,6 +481,16 @@ impl IndentLayout {
         }
     }
 
+    /// The origin of the body, which slides in from the left while it is shown if the
+    /// indent is animated.
+    fn animated_origin(&self, origin: Vec2) -> Vec2 {
+        if self.animate_indent {
+            Vec2::new(origin.x * self.height.get(), origin.y)
+        } else {
+            origin
+        }
+    }
+
     pub fn paint<A: Data, B: Data>(
         &self,
         header: &mut WidgetPod<A, impl Widget<A>>,
@@ -456,3 +508,21 @@ impl IndentLayout {
         body.paint(ctx, data_b, env);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indent_animation() {
+        let mut layout = IndentLayout::new();
+        let origin = Vec2::new(20.0, 30.0);
+        layout.height.jump_to_value(0.5);
+        assert_eq!(layout.animated_origin(origin), origin);
+
+        layout.animate_indent = true;
+        assert_eq!(layout.animated_origin(origin), Vec2::new(10.0, 30.0));
+        layout.height.jump_to_value(1.0);
+        assert_eq!(layout.animated_origin(origin), origin);
+    }
+}
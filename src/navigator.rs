@@ -3,7 +3,21 @@
 
 use std::{cmp::Ordering, collections::HashMap, fmt, hash::Hash, unreachable};
 
-use druid::{widget::prelude::*, Point, WidgetPod};
+use druid::{widget::prelude::*, Affine, Point, WidgetPod};
+
+use crate::animation::{Animated, AnimationCurve};
+use crate::RequestCtx;
+
+crate::selectors! {
+    /// Notification sent by a [`Navigator`] with its new [`depth`] whenever views were
+    /// pushed or popped, for example to enable a back button only when popping is safe.
+    ///
+    /// [`depth`]: Navigator::depth
+    NAVIGATOR_DEPTH_CHANGED: usize,
+    /// Internal use, sent by the navigator to itself with its new depth, as notifications
+    /// can't be sent from `update`.
+    NAVIGATOR_DEPTH_CHANGED_INTERNAL: usize,
+}
 
 /// This widget navigates through the widgets it stores using the Application Data
 /// to manage which widget is currently in view. This most likely will be the root
@@ -14,6 +28,11 @@ use druid::{widget::prelude::*, Point, WidgetPod};
 pub struct Navigator<T, H> {
     state: Vec<WidgetPod<T, Box<dyn Widget<T>>>>,
     views: Views<H, T>,
+    /// The progress of the slide between the previous and the current view, from 0.0
+    /// to 1.0.
+    transition: Animated<f64>,
+    /// The popped view, still shown while it slides out.
+    popped: Option<WidgetPod<T, Box<dyn Widget<T>>>>,
 }
 type Views<H, T> = HashMap<H, Box<dyn Fn() -> Box<dyn Widget<T>>>>;
 
@@ -27,6 +46,10 @@ impl<T: Data, H: View> Navigator<T, H> {
         let mut this = Self {
             state: views,
             views: HashMap::new(),
+            transition: Animated::jump(1.0)
+                .duration(0.0)
+                .curve(AnimationCurve::EASE_OUT),
+            popped: None,
         };
         if this.views.insert(name, Box::new(ui_builder)).is_some() {
             unreachable!("Map should be empty at this point");
@@ -46,12 +69,58 @@ impl<T: Data, H: View> Navigator<T, H> {
         self
     }
 
+    /// The number of views on the stack of the navigator, including the displayed view.
+    ///
+    /// Popping a view is only safe when the depth is larger than one.
+    pub fn depth(&self) -> usize {
+        self.state.len()
+    }
+
+    /// Builder-style method to slide the views in and out over `duration` seconds,
+    /// instead of swapping them instantly.
+    ///
+    /// A pushed view slides in from the right over the previous view, and a popped
+    /// view slides out to the right, revealing the view below.
+    pub fn with_transition(mut self, duration: f64) -> Self {
+        self.transition.set_duration(duration);
+        self
+    }
+
+    /// Builder-style method to set the curve of the transition between views.
+    pub fn with_transition_curve(mut self, curve: impl Into<AnimationCurve>) -> Self {
+        self.transition.set_curve(curve.into());
+        self
+    }
+
+    /// Restarts the transition, after the view stack changed.
+    fn start_transition(&mut self, ctx: &mut impl RequestCtx) {
+        self.transition.jump_to_value(0.0);
+        self.transition.animate(ctx, 1.0);
+        if !self.transition.animating() {
+            self.popped = None;
+        }
+    }
+
+    /// Advances the transition by `nanos`, dropping the popped view once it slid out.
+    fn advance_transition(&mut self, ctx: &mut impl RequestCtx, nanos: u64) {
+        self.transition.update(ctx, nanos);
+        if !self.transition.animating() {
+            self.popped = None;
+        }
+    }
+
+    /// How far the sliding view is from its place, in a navigator of `width`.
+    fn slide_offset(&self, width: f64) -> f64 {
+        width * (1.0 - self.transition.get())
+    }
+
     /// Pushes a new view into navigator's state to be displayed
     fn push_view(&mut self, view: H) {
         let ui_builder = self.views.get(&view).unwrap();
         let new_view = (ui_builder)();
         let widget = WidgetPod::new(new_view);
         self.state.push(widget);
+        self.popped = None;
     }
 
     /// Removes a view from navigator's state
@@ -59,7 +128,8 @@ impl<T: Data, H: View> Navigator<T, H> {
         if self.state.len() == 1 {
             log::warn!("The view state should always have at least one child view");
         }
-        self.state.truncate(new_len);
+        // only the view that was displayed slides out
+        self.popped = self.state.split_off(new_len).pop();
     }
 }
 /// This gives your Application State the behavior necessary to manipulate its views.
@@ -69,6 +139,15 @@ impl<T: Data, H: View> Navigator<T, H> {
 pub trait ViewController<T: Hash + PartialEq + Eq + Clone> {
     /// Pushes a new view to be displayed.
     fn add_view(&mut self, view: T);
+    /// Pushes a new view to be displayed, the counterpart of [`pop_view`].
+    ///
+    /// This calls [`add_view`] by default.
+    ///
+    /// [`pop_view`]: ViewController::pop_view
+    /// [`add_view`]: ViewController::add_view
+    fn push_view(&mut self, view: T) {
+        self.add_view(view);
+    }
     /// Removes the current view from display.
     fn pop_view(&mut self);
     /// Gets the current view that is being displayed.
@@ -86,6 +165,16 @@ pub trait View: Hash + PartialEq + Eq + Clone + fmt::Debug {}
 
 impl<H: View, T: Data + ViewController<H>> Widget<T> for Navigator<T, H> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::AnimFrame(nanos) = event {
+            self.advance_transition(ctx, *nanos);
+        }
+        if let Event::Command(cmd) = event {
+            if let Some(depth) = cmd.get(NAVIGATOR_DEPTH_CHANGED_INTERNAL) {
+                ctx.submit_notification(NAVIGATOR_DEPTH_CHANGED.with(*depth));
+                ctx.set_handled();
+                return;
+            }
+        }
         // each child view gets to handle the event before a view might be popped
         if event.should_propagate_to_hidden() {
             for view in self.state.iter_mut() {
@@ -120,13 +209,22 @@ impl<H: View, T: Data + ViewController<H>> Widget<T> for Navigator<T, H> {
             Ordering::Greater => {
                 self.push_view(data.current_view().clone());
                 ctx.children_changed();
+                self.start_transition(ctx);
             }
             Ordering::Less => {
                 self.truncate_views(data.len());
                 ctx.children_changed();
+                self.start_transition(ctx);
             }
             Ordering::Equal => {}
         }
+        if data.len() != old_data.len() {
+            ctx.submit_command(
+                NAVIGATOR_DEPTH_CHANGED_INTERNAL
+                    .with(self.depth())
+                    .to(ctx.widget_id()),
+            );
+        }
         let current_view = self.state.last_mut().unwrap();
 
         if current_view.is_initialized() {
@@ -144,6 +242,165 @@ impl<H: View, T: Data + ViewController<H>> Widget<T> for Navigator<T, H> {
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
-        self.state.last_mut().unwrap().paint(ctx, data, env)
+        if !self.transition.animating() {
+            self.state.last_mut().unwrap().paint(ctx, data, env);
+            return;
+        }
+
+        let size = ctx.size();
+        let offset = self.slide_offset(size.width);
+        ctx.clip(size.to_rect());
+        let len = self.state.len();
+        if let Some(popped) = &mut self.popped {
+            // the current view is revealed below the popped view
+            self.state[len - 1].paint(ctx, data, env);
+            ctx.with_save(|ctx| {
+                ctx.transform(Affine::translate((size.width - offset, 0.0)));
+                popped.paint(ctx, data, env);
+            });
+        } else {
+            // the pushed view slides in over the previous view
+            if len > 1 {
+                self.state[len - 2].paint(ctx, data, env);
+            }
+            ctx.with_save(|ctx| {
+                ctx.transform(Affine::translate((offset, 0.0)));
+                self.state[len - 1].paint(ctx, data, env);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::test::TestCtx;
+    use crate::WidgetExt as _;
+    use druid::tests::harness::Harness;
+    use druid::widget::{Controller, SizedBox};
+    use druid::{Command, Selector, TimerToken, WidgetExt as _};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    const PUSH: Selector<&'static str> = Selector::new("druid-widget-nursery.test.push");
+    const POP: Selector = Selector::new("druid-widget-nursery.test.pop");
+
+    impl View for &'static str {}
+
+    #[derive(Clone, Data)]
+    struct Views(#[data(same_fn = "PartialEq::eq")] Vec<&'static str>);
+
+    impl ViewController<&'static str> for Views {
+        fn add_view(&mut self, view: &'static str) {
+            self.0.push(view);
+        }
+
+        fn pop_view(&mut self) {
+            self.0.pop();
+        }
+
+        fn current_view(&self) -> &&'static str {
+            self.0.last().unwrap()
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    /// Records the depths notified by a navigator.
+    struct DepthRecorder(Rc<RefCell<Vec<usize>>>);
+
+    impl<T, W: Widget<T>> Controller<T, W> for DepthRecorder {
+        fn event(
+            &mut self,
+            child: &mut W,
+            ctx: &mut EventCtx,
+            event: &Event,
+            data: &mut T,
+            env: &Env,
+        ) {
+            if let Event::Notification(notification) = event {
+                if let Some(depth) = notification.get(NAVIGATOR_DEPTH_CHANGED) {
+                    self.0.borrow_mut().push(*depth);
+                }
+            }
+            child.event(ctx, event, data, env)
+        }
+    }
+
+    fn views() -> Navigator<Views, &'static str> {
+        Navigator::new("list", || Box::new(SizedBox::empty()))
+            .with_view_builder("details", || Box::new(SizedBox::empty()))
+    }
+
+    #[test]
+    fn depth() {
+        let mut navigator = Navigator::<(), &str>::new("list", || Box::new(SizedBox::empty()))
+            .with_view_builder("details", || Box::new(SizedBox::empty()));
+        assert_eq!(navigator.depth(), 1);
+        navigator.push_view("details");
+        navigator.push_view("details");
+        assert_eq!(navigator.depth(), 3);
+        navigator.truncate_views(1);
+        assert_eq!(navigator.depth(), 1);
+    }
+
+    #[test]
+    fn depth_notifications() {
+        let depths = Rc::new(RefCell::new(Vec::new()));
+        // the padding puts the navigator in its own pod, whose notifications reach the
+        // recorder
+        let navigator = views()
+            .padding(0.)
+            .controller(DepthRecorder(depths.clone()))
+            .on_command(PUSH, |_, view, data: &mut Views| data.push_view(*view))
+            .on_command(POP, |_, _, data| data.pop_view());
+
+        Harness::create_simple(Views(vec!["list"]), navigator, |harness| {
+            harness.send_initial_events();
+            // the harness dispatches the commands submitted in `update` with the next
+            // event
+            let mut step = |cmd: Command| {
+                harness.submit_command(cmd);
+                harness.event(Event::Timer(TimerToken::INVALID));
+                depths.take()
+            };
+            assert_eq!(step(PUSH.with("details")), vec![2]);
+            assert_eq!(step(PUSH.with("details")), vec![3]);
+            assert_eq!(step(POP.into()), vec![2]);
+            assert_eq!(step(POP.into()), vec![1]);
+        });
+    }
+
+    #[test]
+    fn slide_transition() {
+        const FRAME: u64 = 100_000_000;
+        let mut navigator = views()
+            .with_transition(0.4)
+            .with_transition_curve(AnimationCurve::LINEAR);
+
+        // a pushed view slides in from the right
+        navigator.push_view("details");
+        navigator.start_transition(&mut TestCtx);
+        assert_eq!(navigator.slide_offset(400.0), 400.0);
+        navigator.advance_transition(&mut TestCtx, FRAME);
+        assert!((navigator.slide_offset(400.0) - 300.0).abs() < 1e-9);
+        navigator.advance_transition(&mut TestCtx, FRAME);
+        assert!((navigator.slide_offset(400.0) - 200.0).abs() < 1e-9);
+
+        // a popped view is kept until it slid out
+        navigator.truncate_views(1);
+        navigator.start_transition(&mut TestCtx);
+        navigator.advance_transition(&mut TestCtx, FRAME);
+        assert!((navigator.slide_offset(400.0) - 300.0).abs() < 1e-9);
+        assert!(navigator.popped.is_some());
+        navigator.advance_transition(&mut TestCtx, 3 * FRAME);
+        assert_eq!(navigator.slide_offset(400.0), 0.0);
+        assert!(navigator.popped.is_none());
     }
 }
@@ -1,24 +1,198 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::time::Duration;
+
 use druid::widget::prelude::*;
 use druid::widget::Controller;
+use druid::TimerToken;
+
+type ChangeFn<T> = Box<dyn Fn(&mut EventCtx, &T, &mut T, &Env)>;
 
-#[allow(clippy::type_complexity)]
-pub struct OnChange<T>(Box<dyn Fn(&mut EventCtx, &T, &mut T, &Env)>);
+/// A controller calling a function when the data is changed by its child widget.
+///
+/// The function receives the data from before and after the event, so it can react to
+/// a specific transition, such as a flag going from `false` to `true`:
+///
+/// ```
+/// # use druid::widget::Checkbox;
+/// # use druid::{Selector, WidgetExt as _};
+/// # use druid_widget_nursery::OnChange;
+/// const ENABLED: Selector = Selector::new("example.enabled");
+///
+/// let checkbox = Checkbox::new("Enabled").controller(OnChange::new(|ctx, old: &bool, new: &mut bool, _env| {
+///     if !*old && *new {
+///         ctx.submit_command(ENABLED);
+///     }
+/// }));
+/// ```
+///
+/// With [`debounced`](OnChange::debounced), rapid changes are coalesced into a single
+/// call, for example to search as the user types.
+pub struct OnChange<T> {
+    f: ChangeFn<T>,
+    delay: Option<Duration>,
+    timer: TimerToken,
+    /// The data from before the first change not passed to the function yet.
+    pending: Option<T>,
+}
 
 impl<T> OnChange<T> {
+    /// Create the controller with a function receiving the old and the new data.
     pub fn new(f: impl Fn(&mut EventCtx, &T, &mut T, &Env) + 'static) -> Self {
-        Self(Box::new(f))
+        OnChange {
+            f: Box::new(f),
+            delay: None,
+            timer: TimerToken::INVALID,
+            pending: None,
+        }
+    }
+
+    /// Builder-style method to call the function only once the data has not changed
+    /// for `delay`. Each change restarts the wait, and the function receives the data
+    /// from before the first of the coalesced changes and the latest data.
+    pub fn debounced(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+impl<T: Data> OnChange<T> {
+    /// Record a change while debouncing, keeping the data from before the first one.
+    fn debounce(&mut self, old_data: &T) {
+        if self.pending.is_none() {
+            self.pending = Some(old_data.clone());
+        }
+    }
+
+    /// Returns the data from before the pending changes when `token` is the timer of
+    /// the wait, which is then over.
+    fn settle(&mut self, token: TimerToken) -> Option<T> {
+        if token != self.timer {
+            return None;
+        }
+        self.timer = TimerToken::INVALID;
+        self.pending.take()
     }
 }
 
 impl<T: Data, W: Widget<T>> Controller<T, W> for OnChange<T> {
     fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::Timer(token) = event {
+            if let Some(old_data) = self.settle(*token) {
+                if !old_data.same(data) {
+                    (self.f)(ctx, &old_data, data, env);
+                }
+                return;
+            }
+        }
         let old_data = data.clone();
         child.event(ctx, event, data, env);
         if !old_data.same(data) {
-            (self.0)(ctx, &old_data, data, env);
+            match self.delay {
+                Some(delay) => {
+                    self.debounce(&old_data);
+                    self.timer = ctx.request_timer(delay);
+                }
+                None => (self.f)(ctx, &old_data, data, env),
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WidgetExt as _;
+    use druid::tests::harness::Harness;
+    use druid::widget::SizedBox;
+    use druid::{Selector, WidgetExt as _};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    const SET: Selector<u32> = Selector::new("druid-widget-nursery.test.set");
+
+    /// A controller shared with the test, to read the timer it requested.
+    struct Shared(Rc<RefCell<OnChange<u32>>>);
+
+    impl<W: Widget<u32>> Controller<u32, W> for Shared {
+        fn event(
+            &mut self,
+            child: &mut W,
+            ctx: &mut EventCtx,
+            event: &Event,
+            data: &mut u32,
+            env: &Env,
+        ) {
+            self.0.borrow_mut().event(child, ctx, event, data, env)
+        }
+    }
+
+    #[test]
+    fn old_and_new_data() {
+        const ENABLE: Selector<bool> = Selector::new("druid-widget-nursery.test.enable");
+
+        let enabled = Rc::new(RefCell::new(0));
+        let widget = SizedBox::empty()
+            .on_command(ENABLE, |_, value, data| *data = *value)
+            .controller(OnChange::new({
+                let enabled = enabled.clone();
+                move |_, old: &bool, new: &mut bool, _| {
+                    if !*old && *new {
+                        *enabled.borrow_mut() += 1;
+                    }
+                }
+            }));
+
+        Harness::create_simple(false, widget, |harness| {
+            harness.send_initial_events();
+
+            harness.submit_command(ENABLE.with(true));
+            assert_eq!(*enabled.borrow(), 1);
+            // setting the same value is not a change
+            harness.submit_command(ENABLE.with(true));
+            assert_eq!(*enabled.borrow(), 1);
+            // the function sees the flag going back to false, but ignores it
+            harness.submit_command(ENABLE.with(false));
+            assert_eq!(*enabled.borrow(), 1);
+            assert!(!harness.data());
+            harness.submit_command(ENABLE.with(true));
+            assert_eq!(*enabled.borrow(), 2);
+        });
+    }
+
+    #[test]
+    fn debounced_changes() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let on_change = OnChange::new({
+            let calls = calls.clone();
+            move |_, old: &u32, new: &mut u32, _| calls.borrow_mut().push((*old, *new))
+        })
+        .debounced(Duration::from_millis(300));
+        let on_change = Rc::new(RefCell::new(on_change));
+        let widget = SizedBox::empty()
+            .on_command(SET, |_, value, data| *data = *value)
+            .controller(Shared(on_change.clone()));
+
+        Harness::create_simple(0, widget, |harness| {
+            harness.send_initial_events();
+
+            // three changes within the wait, each restarting the timer
+            let mut timers = Vec::new();
+            for value in 1..=3 {
+                harness.submit_command(SET.with(value));
+                timers.push(on_change.borrow().timer);
+            }
+            assert!(calls.borrow().is_empty());
+
+            // the timers of the restarted waits are ignored
+            harness.event(Event::Timer(timers[0]));
+            harness.event(Event::Timer(timers[1]));
+            assert!(calls.borrow().is_empty());
+            // the function is called once, from the data before the first change to
+            // the last one
+            harness.event(Event::Timer(timers[2]));
+            assert_eq!(*calls.borrow(), vec![(0, 3)]);
+        });
+    }
+}
@@ -1,27 +1,59 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::marker::PhantomData;
+
 use druid::widget::prelude::*;
 use druid::widget::Controller;
-use druid::Selector;
+use druid::{Command, Selector};
 
-type HandlerFn<CT, WT> = Box<dyn Fn(&mut EventCtx, &CT, &mut WT)>;
+/// A handler for one selector, returning whether the command matched it.
+type HandlerFn<WT> = Box<dyn Fn(&mut EventCtx, &Command, &mut WT) -> bool>;
 
+/// A controller calling handlers for commands.
+///
+/// It is created with a handler for one selector, and [`and`] adds handlers for more
+/// selectors, which may have different payload types.
+///
+/// [`and`]: OnCmd::and
 pub struct OnCmd<CT, WT> {
-    selector: Selector<CT>,
-    handler: HandlerFn<CT, WT>,
+    handlers: Vec<HandlerFn<WT>>,
+    phantom: PhantomData<CT>,
 }
 
-impl<CT, WT> OnCmd<CT, WT> {
+impl<CT: 'static, WT> OnCmd<CT, WT> {
     pub fn new(
         selector: Selector<CT>,
         handler: impl Fn(&mut EventCtx, &CT, &mut WT) + 'static,
     ) -> Self {
         Self {
-            selector,
-            handler: Box::new(handler),
+            handlers: vec![erase(selector, handler)],
+            phantom: PhantomData,
         }
     }
+
+    /// Builder-style method to also call `handler` for commands with `selector`.
+    pub fn and<CT2: 'static>(
+        mut self,
+        selector: Selector<CT2>,
+        handler: impl Fn(&mut EventCtx, &CT2, &mut WT) + 'static,
+    ) -> Self {
+        self.handlers.push(erase(selector, handler));
+        self
+    }
+}
+
+fn erase<CT: 'static, WT>(
+    selector: Selector<CT>,
+    handler: impl Fn(&mut EventCtx, &CT, &mut WT) + 'static,
+) -> HandlerFn<WT> {
+    Box::new(move |ctx, command, data| match command.get(selector) {
+        Some(payload) => {
+            handler(ctx, payload, data);
+            true
+        }
+        None => false,
+    })
 }
 
 impl<WT: Data, W: Widget<WT>, CT: 'static> Controller<WT, W> for OnCmd<CT, WT> {
@@ -33,12 +65,41 @@ impl<WT: Data, W: Widget<WT>, CT: 'static> Controller<WT, W> for OnCmd<CT, WT> {
         data: &mut WT,
         env: &Env,
     ) {
-        match event {
-            Event::Command(c) if c.is(self.selector) => {
-                (self.handler)(ctx, c.get_unchecked(self.selector), data);
+        if let Event::Command(c) = event {
+            for handler in &self.handlers {
+                if handler(ctx, c, data) {
+                    break;
+                }
             }
-            _ => {}
         }
         child.event(ctx, event, data, env);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::widget::SizedBox;
+    use druid::WidgetExt;
+
+    const SET_COUNT: Selector<u32> = Selector::new("druid-widget-nursery.test.set-count");
+    const SET_NAME: Selector<&'static str> = Selector::new("druid-widget-nursery.test.set-name");
+
+    #[test]
+    fn handlers_for_several_selectors() {
+        let widget = SizedBox::empty().controller(
+            OnCmd::new(SET_COUNT, |_, count, data: &mut (u32, String)| {
+                data.0 = *count
+            })
+            .and(SET_NAME, |_, name, data| data.1 = name.to_string()),
+        );
+
+        Harness::create_simple((0, String::new()), widget, |harness| {
+            harness.send_initial_events();
+            harness.submit_command(SET_COUNT.with(3));
+            harness.submit_command(SET_NAME.with("three"));
+            assert_eq!(harness.data(), &(3, "three".to_string()));
+        });
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2021 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use druid::widget::prelude::*;
+use druid::widget::Controller;
+
+type OnAddedFn<T> = Box<dyn Fn(&mut LifeCycleCtx, &T, &Env)>;
+
+/// A controller calling a function when its widget is added to the widget tree.
+///
+/// Unlike [`druid::WidgetExt::on_added`], the function doesn't receive the widget, so
+/// the controller can be created before the widget it is added to.
+pub struct OnAdded<T>(OnAddedFn<T>);
+
+impl<T> OnAdded<T> {
+    /// Create the controller with a function called on [`LifeCycle::WidgetAdded`].
+    pub fn new(f: impl Fn(&mut LifeCycleCtx, &T, &Env) + 'static) -> Self {
+        Self(Box::new(f))
+    }
+}
+
+impl<T: Data, W: Widget<T>> Controller<T, W> for OnAdded<T> {
+    fn lifecycle(
+        &mut self,
+        child: &mut W,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &T,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            (self.0)(ctx, data, env);
+        }
+        child.lifecycle(ctx, event, data, env)
+    }
+}
+
+/// A controller calling a function when its widget is removed from the widget tree.
+///
+/// Druid has no lifecycle event for removed widgets, so the function is called when the
+/// controller is dropped, for example when a [`ViewSwitcher`] replaces the widget, or
+/// when its window is closed. It is only called if the widget was added.
+///
+/// [`ViewSwitcher`]: druid::widget::ViewSwitcher
+pub struct OnRemoved {
+    on_removed: Option<Box<dyn FnOnce()>>,
+    added: bool,
+}
+
+impl OnRemoved {
+    /// Create the controller with a function called once when the widget is removed.
+    pub fn new(f: impl FnOnce() + 'static) -> Self {
+        OnRemoved {
+            on_removed: Some(Box::new(f)),
+            added: false,
+        }
+    }
+}
+
+impl Drop for OnRemoved {
+    fn drop(&mut self) {
+        if let (Some(on_removed), true) = (self.on_removed.take(), self.added) {
+            on_removed();
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> Controller<T, W> for OnRemoved {
+    fn lifecycle(
+        &mut self,
+        child: &mut W,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &T,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            self.added = true;
+        }
+        child.lifecycle(ctx, event, data, env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::widget::Label;
+    use druid::WidgetExt as _;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn added_and_removed() {
+        let added = Rc::new(Cell::new(0));
+        let removed = Rc::new(Cell::new(0));
+        let widget = Label::new("label")
+            .controller(OnAdded::new({
+                let added = added.clone();
+                move |_, _, _| added.set(added.get() + 1)
+            }))
+            .controller(OnRemoved::new({
+                let removed = removed.clone();
+                move || removed.set(removed.get() + 1)
+            }));
+
+        Harness::create_simple((), widget, |harness| {
+            assert_eq!(added.get(), 0);
+            harness.send_initial_events();
+            assert_eq!(added.get(), 1);
+            assert_eq!(removed.get(), 0);
+        });
+        // the widget is dropped with its window
+        assert_eq!(added.get(), 1);
+        assert_eq!(removed.get(), 1);
+    }
+
+    #[test]
+    fn removed_once() {
+        let removed = Rc::new(Cell::new(0));
+        let counter = removed.clone();
+        let mut controller = OnRemoved::new(move || counter.set(counter.get() + 1));
+        controller.added = true;
+        drop(controller);
+        assert_eq!(removed.get(), 1);
+
+        // a widget which was never added isn't removed
+        let counter = removed.clone();
+        drop(OnRemoved::new(move || counter.set(counter.get() + 1)));
+        assert_eq!(removed.get(), 1);
+    }
+}
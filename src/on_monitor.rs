@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use druid::widget::prelude::*;
-use druid::{Data, Point, Rect, Scalable, Screen, Vec2, WindowHandle};
+use druid::{Data, Monitor, Point, Rect, Scalable, Scale, Screen, Vec2, WindowHandle};
+
+type MonitorChangedFn = Box<dyn Fn(&mut EventCtx, &Monitor)>;
 
 /// This is a wrapper widget that attempts to ensure that the widget it wraps is fully contained in
 /// one monitor.
@@ -11,21 +13,122 @@ use druid::{Data, Point, Rect, Scalable, Screen, Vec2, WindowHandle};
 pub struct OnMonitor<W> {
     pub(crate) inner: W,
     pub(crate) parent: WindowHandle,
+    on_monitor_changed: Option<MonitorChangedFn>,
+    placement: Placement<Monitor>,
+    monitors: MonitorCache<Monitor>,
 }
 
-/// Returns the bounds (in virtual screen coordinates) of a monitor containing the origin of `w`.
+impl<W> OnMonitor<W> {
+    pub(crate) fn new(inner: W, parent: WindowHandle) -> Self {
+        OnMonitor {
+            inner,
+            parent,
+            on_monitor_changed: None,
+            placement: Placement::default(),
+            monitors: MonitorCache::default(),
+        }
+    }
+
+    /// Builder-style method to call `f` with the new monitor when the window of this
+    /// widget moves to another monitor, or when its scale factor changes.
+    ///
+    /// This can be used to re-anchor popups after the window was dragged to another
+    /// screen. As druid has no event for window moves, the monitor is checked when the
+    /// window is connected, scaled or resized, and when the mouse moves over it.
+    pub fn on_monitor_changed(mut self, f: impl Fn(&mut EventCtx, &Monitor) + 'static) -> Self {
+        self.on_monitor_changed = Some(Box::new(f));
+        self
+    }
+}
+
+/// The monitor and scale of a window, to notice when they change.
+struct Placement<M> {
+    last: Option<(M, Scale)>,
+}
+
+impl<M> Default for Placement<M> {
+    fn default() -> Self {
+        Placement { last: None }
+    }
+}
+
+impl<M: PartialEq> Placement<M> {
+    /// Stores the current monitor and scale, and returns whether they changed since
+    /// the last call. The first call doesn't count as a change.
+    fn observe(&mut self, monitor: M, scale: Scale) -> bool {
+        let changed = matches!(&self.last, Some((m, s)) if *m != monitor || *s != scale);
+        self.last = Some((monitor, scale));
+        changed
+    }
+}
+
+/// The monitors of the screen, fetched again only when none of them contains the
+/// window anymore or when they are invalidated, as querying them on every mouse move
+/// is expensive.
+struct MonitorCache<M> {
+    monitors: Option<Vec<M>>,
+    /// The origin of the window when its monitor was last looked up.
+    origin: Option<Point>,
+}
+
+impl<M> Default for MonitorCache<M> {
+    fn default() -> Self {
+        MonitorCache {
+            monitors: None,
+            origin: None,
+        }
+    }
+}
+
+impl<M: Clone> MonitorCache<M> {
+    /// Forgets the monitors, for example after the scale of the window changed.
+    fn invalidate(&mut self) {
+        self.monitors = None;
+        self.origin = None;
+    }
+
+    /// Returns the monitor for which `contains` is true at `origin`, if the window
+    /// moved since the last call. The monitors are fetched with `fetch` when they are
+    /// not cached, or when none of the cached monitors contains `origin`.
+    fn moved_to(
+        &mut self,
+        origin: Point,
+        fetch: impl FnOnce() -> Vec<M>,
+        contains: impl Fn(&M, Point) -> bool,
+    ) -> Option<M> {
+        if self.origin == Some(origin) {
+            return None;
+        }
+        self.origin = Some(origin);
+        let find = |monitors: &[M]| monitors.iter().find(|m| contains(m, origin)).cloned();
+        if let Some(monitor) = self.monitors.as_deref().and_then(find) {
+            return Some(monitor);
+        }
+        let monitors = fetch();
+        let monitor = find(&monitors);
+        self.monitors = Some(monitors);
+        monitor
+    }
+}
+
+/// Returns a monitor containing the origin of `w`.
 ///
 /// (We don't guarantee any particular behavior if there is more than one such monitor).
-fn screen_bounds(w: &WindowHandle) -> Rect {
-    let monitors = Screen::get_monitors();
+fn monitor_containing(w: &WindowHandle) -> Option<Monitor> {
     let scale = w.get_scale().unwrap_or_default();
     let window_origin = w.get_position();
-    for m in monitors {
-        if m.virtual_rect().to_dp(scale).contains(window_origin) {
-            return m.virtual_work_rect().to_dp(scale);
-        }
+    Screen::get_monitors()
+        .into_iter()
+        .find(|m| m.virtual_rect().to_dp(scale).contains(window_origin))
+}
+
+/// Returns the bounds (in virtual screen coordinates) of a monitor containing the origin of `w`.
+pub(crate) fn screen_bounds(w: &WindowHandle) -> Rect {
+    let scale = w.get_scale().unwrap_or_default();
+    match monitor_containing(w) {
+        Some(m) => m.virtual_work_rect().to_dp(scale),
+        None => Rect::from_origin_size(Point::ZERO, Size::new(f64::INFINITY, f64::INFINITY)),
     }
-    Rect::from_origin_size(Point::ZERO, Size::new(f64::INFINITY, f64::INFINITY))
 }
 
 fn calc_nudge(rect: Rect, bounds: Rect) -> Vec2 {
@@ -47,6 +150,29 @@ fn calc_nudge(rect: Rect, bounds: Rect) -> Vec2 {
 
 impl<T: Data, W: Widget<T>> Widget<T> for OnMonitor<W> {
     fn event(&mut self, ctx: &mut EventCtx, ev: &Event, data: &mut T, env: &Env) {
+        if let Some(on_monitor_changed) = &self.on_monitor_changed {
+            if let Event::WindowConnected | Event::WindowScale(_) = ev {
+                self.monitors.invalidate();
+            }
+            if let Event::WindowConnected
+            | Event::WindowScale(_)
+            | Event::WindowSize(_)
+            | Event::MouseMove(_) = ev
+            {
+                let w = ctx.window();
+                let scale = w.get_scale().unwrap_or_default();
+                let monitor =
+                    self.monitors
+                        .moved_to(w.get_position(), Screen::get_monitors, |m, origin| {
+                            m.virtual_rect().to_dp(scale).contains(origin)
+                        });
+                if let Some(monitor) = monitor {
+                    if self.placement.observe(monitor.clone(), scale) {
+                        on_monitor_changed(ctx, &monitor);
+                    }
+                }
+            }
+        }
         self.inner.event(ctx, ev, data, env);
     }
 
@@ -77,3 +203,67 @@ impl<T: Data, W: Widget<T>> Widget<T> for OnMonitor<W> {
         self.inner.paint(ctx, data, env);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placement_changes() {
+        let left = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+        let right = Rect::new(1920.0, 0.0, 3840.0, 1080.0);
+        let mut placement = Placement::default();
+
+        assert!(!placement.observe(left, Scale::new(1.0, 1.0)));
+        assert!(!placement.observe(left, Scale::new(1.0, 1.0)));
+        // the scale factor changes
+        assert!(placement.observe(left, Scale::new(2.0, 2.0)));
+        // the window is moved to another monitor
+        assert!(placement.observe(right, Scale::new(2.0, 2.0)));
+        assert!(!placement.observe(right, Scale::new(2.0, 2.0)));
+    }
+
+    #[test]
+    fn cached_monitors() {
+        let left = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+        let right = Rect::new(1920.0, 0.0, 3840.0, 1080.0);
+        let fetches = std::cell::Cell::new(0);
+        let mut cache = MonitorCache::default();
+        let moved_to = |cache: &mut MonitorCache<Rect>, origin: Point, monitors: &[Rect]| {
+            cache.moved_to(
+                origin,
+                || {
+                    fetches.set(fetches.get() + 1);
+                    monitors.to_vec()
+                },
+                |m, origin| m.contains(origin),
+            )
+        };
+
+        assert_eq!(
+            moved_to(&mut cache, Point::new(10.0, 10.0), &[left]),
+            Some(left)
+        );
+        // the window didn't move
+        assert_eq!(moved_to(&mut cache, Point::new(10.0, 10.0), &[left]), None);
+        assert_eq!(
+            moved_to(&mut cache, Point::new(50.0, 10.0), &[left]),
+            Some(left)
+        );
+        assert_eq!(fetches.get(), 1);
+        // a monitor was plugged in, and the window was moved to it
+        let monitors = [left, right];
+        assert_eq!(
+            moved_to(&mut cache, Point::new(2000.0, 10.0), &monitors),
+            Some(right)
+        );
+        assert_eq!(fetches.get(), 2);
+        // the scale changed
+        cache.invalidate();
+        assert_eq!(
+            moved_to(&mut cache, Point::new(2000.0, 10.0), &monitors),
+            Some(right)
+        );
+        assert_eq!(fetches.get(), 3);
+    }
+}
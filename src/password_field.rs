@@ -0,0 +1,270 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A text field for passwords, masking its text.
+
+use druid::kurbo::{BezPath, Circle, Line};
+use druid::text::Selection;
+use druid::widget::prelude::*;
+use druid::widget::TextBox;
+use druid::{theme, Cursor, Point, Rect, WidgetPod};
+
+const DEFAULT_MASK: char = '\u{2022}';
+
+/// A text field for passwords and other secrets, showing a mask character in place
+/// of each character of its text.
+///
+/// The field edits a masked copy of the text, and applies the edits to the actual
+/// data, which is never shown unless the user reveals it with the eye button at
+/// the end of the field.
+///
+/// Typing the mask character itself is ambiguous, and may be applied at a
+/// different position of the actual text.
+pub struct PasswordField {
+    textbox: WidgetPod<String, TextBox<String>>,
+    /// The text shown by the textbox, masked unless revealed.
+    shown: String,
+    mask: char,
+    revealed: bool,
+    reveal_toggle: bool,
+    toggle_rect: Rect,
+}
+
+impl PasswordField {
+    pub fn new() -> Self {
+        PasswordField {
+            textbox: WidgetPod::new(TextBox::new()),
+            shown: String::new(),
+            mask: DEFAULT_MASK,
+            revealed: false,
+            reveal_toggle: true,
+            toggle_rect: Rect::ZERO,
+        }
+    }
+
+    /// Builder-style method for setting the placeholder text.
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.textbox
+            .widget_mut()
+            .set_placeholder(placeholder.into());
+        self
+    }
+
+    /// Builder-style method for setting the character shown in place of each
+    /// character of the text. The default is a bullet.
+    pub fn with_mask_char(mut self, mask: char) -> Self {
+        self.set_mask_char(mask);
+        self
+    }
+
+    /// Set the character shown in place of each character of the text.
+    pub fn set_mask_char(&mut self, mask: char) {
+        self.mask = mask;
+    }
+
+    /// Builder-style method for setting whether the button revealing the text is shown.
+    pub fn with_reveal_toggle(mut self, reveal_toggle: bool) -> Self {
+        self.set_reveal_toggle(reveal_toggle);
+        self
+    }
+
+    /// Set whether the button revealing the text is shown.
+    pub fn set_reveal_toggle(&mut self, reveal_toggle: bool) {
+        self.reveal_toggle = reveal_toggle;
+        if !reveal_toggle {
+            self.revealed = false;
+        }
+    }
+
+    /// The text which should be shown for `data`.
+    fn shown_text(&self, data: &str) -> String {
+        if self.revealed {
+            data.to_string()
+        } else {
+            data.chars().map(|_| self.mask).collect()
+        }
+    }
+
+    fn selection(&self) -> Selection {
+        let text = self.textbox.widget().text();
+        if text.can_read() {
+            text.borrow().selection()
+        } else {
+            Selection::caret(0)
+        }
+    }
+
+    fn paint_toggle(&self, ctx: &mut PaintCtx, env: &Env) {
+        let color = if ctx.is_hot() && ctx.is_active() {
+            env.get(theme::FOREGROUND_LIGHT)
+        } else {
+            env.get(theme::FOREGROUND_DARK)
+        };
+        let center = self.toggle_rect.center();
+        let (width, height) = (6.0, 4.0);
+
+        // an eye, crossed out while the text is hidden
+        let mut eye = BezPath::new();
+        eye.move_to((center.x - width, center.y));
+        eye.quad_to(
+            (center.x, center.y - 2.0 * height),
+            (center.x + width, center.y),
+        );
+        eye.quad_to(
+            (center.x, center.y + 2.0 * height),
+            (center.x - width, center.y),
+        );
+        ctx.stroke(eye, &color, 1.5);
+        ctx.fill(Circle::new(center, 2.0), &color);
+        if !self.revealed {
+            let slash = Line::new(
+                (center.x - width, center.y + width),
+                (center.x + width, center.y - width),
+            );
+            ctx.stroke(slash, &color, 1.5);
+        }
+    }
+}
+
+impl Default for PasswordField {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Applies an edit of the masked text to `text`, which has as many characters as
+/// the masked text had before the edit.
+///
+/// As all masked characters look the same, the edited range can't be found by
+/// comparing the texts; instead it is derived from the start of the selection
+/// before the edit and the position of the caret after it, both in characters.
+/// Inserted characters are taken from `new_shown`.
+fn apply_masked_edit(text: &str, new_shown: &str, selection_start: usize, caret: usize) -> String {
+    let text: Vec<char> = text.chars().collect();
+    let new: Vec<char> = new_shown.chars().collect();
+
+    let start = selection_start.min(caret).min(text.len());
+    let inserted = &new[start.min(new.len())..caret.min(new.len())];
+    let removed = (text.len() + inserted.len()).saturating_sub(new.len());
+    let end = (start + removed).min(text.len());
+    text[..start]
+        .iter()
+        .chain(inserted)
+        .chain(&text[end..])
+        .collect()
+}
+
+/// Returns the number of characters before the byte offset `index` of `text`.
+fn char_index(text: &str, index: usize) -> usize {
+    text.char_indices().take_while(|(i, _)| *i < index).count()
+}
+
+impl Widget<String> for PasswordField {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut String, env: &Env) {
+        if self.reveal_toggle {
+            match event {
+                Event::MouseDown(mouse) if self.toggle_rect.contains(mouse.pos) => {
+                    ctx.set_active(true);
+                    ctx.request_paint();
+                    ctx.set_handled();
+                }
+                Event::MouseUp(mouse) if ctx.is_active() => {
+                    ctx.set_active(false);
+                    if self.toggle_rect.contains(mouse.pos) {
+                        self.revealed = !self.revealed;
+                        self.shown = self.shown_text(data);
+                        ctx.request_update();
+                    }
+                    ctx.request_paint();
+                    ctx.set_handled();
+                }
+                Event::MouseMove(mouse) => {
+                    if self.toggle_rect.contains(mouse.pos) {
+                        ctx.set_cursor(&Cursor::Pointer);
+                    } else {
+                        ctx.clear_cursor();
+                    }
+                }
+                _ => {}
+            }
+            if ctx.is_handled() {
+                return;
+            }
+        }
+
+        let selection = self.selection();
+        let mut shown = self.shown.clone();
+        self.textbox.event(ctx, event, &mut shown, env);
+        if shown != self.shown {
+            if self.revealed {
+                *data = shown;
+            } else {
+                let selection_start = char_index(&self.shown, selection.min());
+                let caret = char_index(&shown, self.selection().active);
+                *data = apply_masked_edit(data, &shown, selection_start, caret);
+            }
+            // this masks newly typed characters again
+            self.shown = self.shown_text(data);
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &String, env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            self.shown = self.shown_text(data);
+        }
+        self.textbox.lifecycle(ctx, event, &self.shown, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &String, data: &String, env: &Env) {
+        let shown = self.shown_text(data);
+        if shown != self.shown {
+            self.shown = shown;
+        }
+        self.textbox.update(ctx, &self.shown, env);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        _data: &String,
+        env: &Env,
+    ) -> Size {
+        let toggle_width = if self.reveal_toggle {
+            env.get(theme::BASIC_WIDGET_HEIGHT)
+        } else {
+            0.0
+        };
+        let textbox_bc = bc.shrink((toggle_width, 0.0));
+        let size = self.textbox.layout(ctx, &textbox_bc, &self.shown, env);
+        self.textbox.set_origin(ctx, Point::ORIGIN);
+        self.toggle_rect = Rect::from_origin_size((size.width, 0.0), (toggle_width, size.height));
+        bc.constrain(Size::new(size.width + toggle_width, size.height))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _data: &String, env: &Env) {
+        self.textbox.paint(ctx, &self.shown, env);
+        if self.reveal_toggle {
+            self.paint_toggle(ctx, env);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_masked_edit;
+
+    #[test]
+    fn masked_edits() {
+        // typing at the end and in the middle
+        assert_eq!(apply_masked_edit("abc", "•••d", 3, 4), "abcd");
+        assert_eq!(apply_masked_edit("abc", "••x•", 2, 3), "abxc");
+        // backspace and delete
+        assert_eq!(apply_masked_edit("abc", "••", 3, 2), "ab");
+        assert_eq!(apply_masked_edit("abc", "••", 1, 0), "bc");
+        assert_eq!(apply_masked_edit("abc", "••", 1, 1), "ac");
+        // replacing a selection
+        assert_eq!(apply_masked_edit("abcd", "•xy", 1, 3), "axy");
+        assert_eq!(apply_masked_edit("", "pässwörd", 0, 8), "pässwörd");
+    }
+}
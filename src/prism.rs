@@ -1,6 +1,8 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::marker::PhantomData;
+
 use druid::{
     BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, PaintCtx,
     Point, Size, UpdateCtx, Widget, WidgetPod,
@@ -21,6 +23,87 @@ pub trait Prism<T, U> {
     fn get(&self, data: &T) -> Option<U>;
     ///Store the data back in the outer type
     fn put(&self, data: &mut T, inner: U);
+
+    /// Compose this prism with `other`, focusing on the data of `other` within the
+    /// data of this prism.
+    ///
+    /// For example, `ResultOk.then(OptionSome)` focuses `Result<Option<X>, E>` down
+    /// to `X`.
+    fn then<B, Other: Prism<U, B>>(self, other: Other) -> Then<Self, Other, T, U>
+    where
+        Self: Sized,
+    {
+        Then {
+            first: self,
+            second: other,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Transform the focused data with a pair of functions, like parsing a `String`
+    /// into a number and formatting it back.
+    ///
+    /// When `forward` returns `None`, the data is treated as not present.
+    fn map_value<W, F, G>(self, forward: F, backward: G) -> MapValue<Self, F, G, T, U>
+    where
+        Self: Sized,
+        F: Fn(U) -> Option<W>,
+        G: Fn(W) -> U,
+    {
+        MapValue {
+            prism: self,
+            forward,
+            backward,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A prism composed of two prisms, see [`Prism::then`].
+pub struct Then<A, B, T, U> {
+    first: A,
+    second: B,
+    _marker: PhantomData<fn(&T) -> U>,
+}
+
+impl<T, U, V, A: Prism<T, U>, B: Prism<U, V>> Prism<T, V> for Then<A, B, T, U> {
+    fn get(&self, data: &T) -> Option<V> {
+        self.first
+            .get(data)
+            .and_then(|inner| self.second.get(&inner))
+    }
+
+    /// Only writes back when the intermediate data is present, otherwise the data is
+    /// left unchanged.
+    fn put(&self, data: &mut T, inner: V) {
+        if let Some(mut intermediate) = self.first.get(data) {
+            self.second.put(&mut intermediate, inner);
+            self.first.put(data, intermediate);
+        }
+    }
+}
+
+/// A prism transforming the data of another prism, see [`Prism::map_value`].
+pub struct MapValue<P, F, G, T, U> {
+    prism: P,
+    forward: F,
+    backward: G,
+    _marker: PhantomData<fn(&T) -> U>,
+}
+
+impl<T, U, W, P, F, G> Prism<T, W> for MapValue<P, F, G, T, U>
+where
+    P: Prism<T, U>,
+    F: Fn(U) -> Option<W>,
+    G: Fn(W) -> U,
+{
+    fn get(&self, data: &T) -> Option<W> {
+        self.prism.get(data).and_then(&self.forward)
+    }
+
+    fn put(&self, data: &mut T, inner: W) {
+        self.prism.put(data, (self.backward)(inner));
+    }
 }
 
 /// A trait implemented by PrismWrappers to check if this widget can handle the current data.
@@ -189,6 +272,14 @@ impl<W: Widget<U>, P, U> PrismWrap<W, P, U> {
             cached_data: None,
         }
     }
+
+    /// Whether the inner widget has data and received `WidgetAdded`.
+    ///
+    /// When the prism starts to match in `update`, the inner widget only receives
+    /// `WidgetAdded` with the following lifecycle pass, and events may come before.
+    fn is_ready(&self) -> bool {
+        self.cached_data.is_some() && self.inner.is_initialized()
+    }
 }
 
 impl<T, U: Data, P: Prism<T, U>, W: Widget<U>> PrismWidget<T> for PrismWrap<W, P, U> {
@@ -199,11 +290,12 @@ impl<T, U: Data, P: Prism<T, U>, W: Widget<U>> PrismWidget<T> for PrismWrap<W, P
 
 impl<T, U: Data, P: Prism<T, U>, W: Widget<U>> Widget<T> for PrismWrap<W, P, U> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if !self.is_ready() {
+            return;
+        }
         if let Some(mut inner_data) = self.prism.get(data) {
-            if self.cached_data.is_some() {
-                self.inner.event(ctx, event, &mut inner_data, env);
-                self.prism.put(data, inner_data);
-            }
+            self.inner.event(ctx, event, &mut inner_data, env);
+            self.prism.put(data, inner_data);
         } else if let Some(mut data) = self.cached_data.clone() {
             self.inner.event(ctx, event, &mut data, env);
         }
@@ -220,15 +312,18 @@ impl<T, U: Data, P: Prism<T, U>, W: Widget<U>> Widget<T> for PrismWrap<W, P, U>
 
     fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
         if let Some(data) = self.prism.get(data) {
-            if self.cached_data.is_some() {
+            if self.inner.is_initialized() {
                 self.inner.update(ctx, &data, env);
+            } else if self.cached_data.is_none() {
+                // the inner widget gets WidgetAdded once it has data
+                ctx.children_changed();
             }
             self.cached_data = Some(data);
         }
     }
 
     fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, env: &Env) -> Size {
-        if let Some(data) = &self.cached_data {
+        if let (Some(data), true) = (&self.cached_data, self.inner.is_initialized()) {
             let size = self.inner.layout(ctx, bc, data, env);
             self.inner.set_origin(ctx, Point::ORIGIN);
             size
@@ -238,7 +333,7 @@ impl<T, U: Data, P: Prism<T, U>, W: Widget<U>> Widget<T> for PrismWrap<W, P, U>
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, env: &Env) {
-        if let Some(data) = &self.cached_data {
+        if let (Some(data), true) = (&self.cached_data, self.inner.is_initialized()) {
             self.inner.paint(ctx, data, env);
         }
     }
@@ -298,6 +393,19 @@ impl<T: Data, E: Data> Prism<Result<T, E>, E> for ResultErr {
 
 pub struct Closures<F, G>(pub F, pub G);
 
+/// Create a prism from a function getting the data, if present, and a function
+/// storing it back.
+///
+/// This is the same as [`Closures`], but the types of the closure arguments are
+/// inferred.
+pub fn field<T, U, F, G>(get: F, put: G) -> Closures<F, G>
+where
+    F: Fn(&T) -> Option<U>,
+    G: Fn(&mut T, U),
+{
+    Closures(get, put)
+}
+
 impl<F, G, T, U> Prism<T, U> for Closures<F, G>
 where
     F: Fn(&T) -> Option<U>,
@@ -311,3 +419,95 @@ where
         (self.1)(data, inner);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WidgetExt as _;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::{Record, Recording, TestWidgetExt};
+    use druid::widget::SizedBox;
+    use druid::{MouseButton, MouseEvent, Selector};
+
+    #[test]
+    fn uninitialized_inner_widget() {
+        const SET: Selector<u32> = Selector::new("prism.test.set");
+        let mouse_down = Event::MouseDown(MouseEvent {
+            pos: Point::new(10.0, 10.0),
+            window_pos: Point::new(10.0, 10.0),
+            buttons: Default::default(),
+            mods: Default::default(),
+            count: 1,
+            focus: false,
+            button: MouseButton::Left,
+            wheel_delta: Default::default(),
+        });
+        let recording = Recording::default();
+        let wrap = PrismWrap::new(SizedBox::empty().expand().record(&recording), OptionSome)
+            .on_command(SET, |_, value, data: &mut Option<u32>| *data = Some(*value));
+
+        Harness::create_simple(None, wrap, |harness| {
+            harness.send_initial_events();
+            harness.event(mouse_down.clone());
+            assert_eq!(recording.len(), 0);
+
+            // the variant is switched to in update, the inner widget is then added before
+            // it gets the next event
+            harness.submit_command(SET.with(1));
+            harness.just_layout();
+            harness.event(mouse_down.clone());
+            let records: Vec<_> = recording.drain().collect();
+            let added = records
+                .iter()
+                .position(|record| matches!(record, Record::L(LifeCycle::WidgetAdded)));
+            let clicked = records
+                .iter()
+                .position(|record| matches!(record, Record::E(Event::MouseDown(_))));
+            assert!(matches!((added, clicked), (Some(added), Some(clicked)) if added < clicked));
+            assert_eq!(harness.data(), &Some(1));
+        });
+    }
+
+    #[test]
+    fn composed_prisms() {
+        let prism = ResultOk.then(OptionSome);
+
+        assert_eq!(prism.get(&Ok::<_, String>(Some(3))), Some(3));
+        assert_eq!(prism.get(&Ok::<Option<u32>, String>(None)), None);
+        assert_eq!(prism.get(&Err::<Option<u32>, _>("error".to_string())), None);
+
+        let mut data = Ok(None);
+        prism.put(&mut data, 5);
+        assert_eq!(data, Ok(Some(5)));
+
+        // without the intermediate data, nothing is written back
+        let mut data = Err("error".to_string());
+        prism.put(&mut data, 5);
+        assert_eq!(data, Err("error".to_string()));
+    }
+
+    #[test]
+    fn mapped_field() {
+        struct Input {
+            text: String,
+        }
+
+        let prism = field(
+            |input: &Input| Some(input.text.clone()),
+            |input, text| input.text = text,
+        )
+        .map_value(|text| text.parse::<f64>().ok(), |value| value.to_string());
+
+        let mut input = Input {
+            text: "1.5".to_string(),
+        };
+        assert_eq!(prism.get(&input), Some(1.5));
+        prism.put(&mut input, 2.25);
+        assert_eq!(input.text, "2.25");
+
+        // invalid text isn't present as a number, and is left unchanged
+        input.text = "one".to_string();
+        assert_eq!(prism.get(&input), None);
+        assert_eq!(input.text, "one");
+    }
+}
@@ -20,16 +20,91 @@ use druid::widget::prelude::*;
 use druid::{theme, Color, KeyOrValue, LinearGradient, Point, Rect, UnitPoint};
 use tracing::instrument;
 
+use crate::animation::{Animated, AnimationCurve};
+
+/// The duration of one sweep of the block in indeterminate mode, in seconds.
+const SWEEP_DURATION: f64 = 1.0;
+/// The width of the block in indeterminate mode, relative to the bar.
+const SWEEP_BLOCK_WIDTH: f64 = 0.3;
+
+/// The position of the block sweeping back and forth in indeterminate mode,
+/// between 0.0 (left) and 1.0 (right). Clones start from the left.
+#[derive(Debug)]
+struct Sweep(Animated<f64>);
+
+impl Sweep {
+    fn new() -> Self {
+        Sweep(
+            Animated::new(0.0)
+                .duration(SWEEP_DURATION)
+                .curve(AnimationCurve::EASE_IN_OUT),
+        )
+    }
+}
+
+impl Clone for Sweep {
+    fn clone(&self) -> Self {
+        Sweep::new()
+    }
+}
+
+/// The data of a [`ProgressBar`].
+///
+/// This is a float in the range `0.0..1.0`, or a tuple of the progress and of the
+/// buffered fraction, like the part of a video which is already downloaded.
+pub trait Progress: Data {
+    /// The progress, in the range `0.0..1.0`.
+    fn progress(&self) -> f64;
+
+    /// The buffered fraction, shown behind the progress.
+    fn buffered(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl Progress for f64 {
+    fn progress(&self) -> f64 {
+        *self
+    }
+}
+
+impl Progress for (f64, f64) {
+    fn progress(&self) -> f64 {
+        self.0
+    }
+
+    fn buffered(&self) -> Option<f64> {
+        Some(self.1)
+    }
+}
+
+/// Returns the widths of the bar and of the buffered part after it, for a bar of
+/// the given width.
+fn bar_widths(progress: f64, buffered: Option<f64>, width: f64) -> (f64, f64) {
+    let progress = progress.max(0.0).min(1.0);
+    let buffered = buffered.unwrap_or(0.0).max(progress).min(1.0);
+    (progress * width, (buffered - progress) * width)
+}
+
 /// A progress bar, displaying a numeric progress value.
 ///
-/// This type impls `Widget<f64>`, expecting a float in the range `0.0..1.0`.
+/// This type impls `Widget<f64>`, expecting a float in the range `0.0..1.0`, and
+/// `Widget<(f64, f64)>`, which also shows a buffered fraction behind the progress
+/// (see [`Progress`]).
+///
+/// When the progress is unknown, an indeterminate bar ignores the value and shows
+/// a block sweeping back and forth instead. To switch modes depending on the data,
+/// use two bars in an [`Either`](druid::widget::Either).
 #[derive(Debug, Clone)]
 pub struct ProgressBar {
     bar_brush: Option<PaintBrush>,
+    buffer_brush: Option<PaintBrush>,
     background_brush: Option<PaintBrush>,
     corner_radius: KeyOrValue<RoundedRectRadii>,
     border_colour: KeyOrValue<Color>,
     border_width: KeyOrValue<f64>,
+    indeterminate: bool,
+    sweep: Sweep,
 }
 
 impl ProgressBar {
@@ -43,6 +118,13 @@ impl ProgressBar {
         self.bar_brush = Some(cl);
         self
     }
+    /// Builder-style method for the brush of the buffered fraction.
+    ///
+    /// By default, this is a dimmer version of the default bar brush.
+    pub fn with_buffer_brush(mut self, cl: PaintBrush) -> Self {
+        self.buffer_brush = Some(cl);
+        self
+    }
     pub fn with_back_brush(mut self, cl: PaintBrush) -> Self {
         self.background_brush = Some(cl);
         self
@@ -59,10 +141,19 @@ impl ProgressBar {
         self.border_colour = KeyOrValue::Concrete(cl);
         self
     }
+    /// Builder-style method for making the bar indeterminate, showing that something
+    /// is in progress without telling how far it is.
+    pub fn with_indeterminate(mut self, indeterminate: bool) -> Self {
+        self.set_indeterminate(indeterminate);
+        self
+    }
     //Set functions, returning
     pub fn set_bar_brush(mut self, cl: PaintBrush) {
         self.bar_brush = Some(cl);
     }
+    pub fn set_buffer_brush(&mut self, cl: PaintBrush) {
+        self.buffer_brush = Some(cl);
+    }
     pub fn set_back_brush(mut self, cl: PaintBrush) {
         self.background_brush = Some(cl);
     }
@@ -75,6 +166,10 @@ impl ProgressBar {
     pub fn set_border_colour(mut self, cl: Color) {
         self.border_colour = KeyOrValue::Concrete(cl);
     }
+    /// Set whether the bar is indeterminate.
+    pub fn set_indeterminate(&mut self, indeterminate: bool) {
+        self.indeterminate = indeterminate;
+    }
 
     //Internal getters that resolve using theme or control values.
     fn bar_brush(&self, env: &Env) -> PaintBrush {
@@ -86,6 +181,18 @@ impl ProgressBar {
             ))
         })
     }
+    fn buffer_brush(&self, env: &Env) -> PaintBrush {
+        self.buffer_brush.clone().unwrap_or_else(|| {
+            PaintBrush::Linear(LinearGradient::new(
+                UnitPoint::TOP,
+                UnitPoint::BOTTOM,
+                (
+                    env.get(theme::PRIMARY_LIGHT).with_alpha(0.4),
+                    env.get(theme::PRIMARY_DARK).with_alpha(0.4),
+                ),
+            ))
+        })
+    }
     fn background_brush(&self, env: &Env) -> PaintBrush {
         self.background_brush.clone().unwrap_or_else(|| {
             PaintBrush::Linear(LinearGradient::new(
@@ -104,35 +211,60 @@ impl Default for ProgressBar {
     fn default() -> Self {
         ProgressBar {
             bar_brush: None,
+            buffer_brush: None,
             background_brush: None,
             corner_radius: KeyOrValue::Key(theme::PROGRESS_BAR_RADIUS),
             border_colour: KeyOrValue::Key(theme::BORDER_DARK),
             border_width: KeyOrValue::Key(theme::BUTTON_BORDER_WIDTH),
+            indeterminate: false,
+            sweep: Sweep::new(),
         }
     }
 }
 
-impl Widget<f64> for ProgressBar {
+impl<P: Progress> Widget<P> for ProgressBar {
     #[instrument(
         name = "ProgressBar",
         level = "trace",
-        skip(self, _ctx, _event, _data, _env)
+        skip(self, ctx, event, _data, _env)
     )]
-    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut f64, _env: &Env) {}
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, _data: &mut P, _env: &Env) {
+        if let Event::AnimFrame(nanos) = event {
+            if self.indeterminate {
+                let sweep = &mut self.sweep.0;
+                sweep.update(ctx, *nanos);
+                if !sweep.animating() {
+                    // turn around at either end
+                    let target = 1.0 - sweep.end();
+                    sweep.animate(ctx, target);
+                }
+            }
+        }
+    }
 
     #[instrument(
         name = "ProgressBar",
         level = "trace",
-        skip(self, _ctx, _event, _data, _env)
+        skip(self, ctx, event, _data, _env)
     )]
-    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &f64, _env: &Env) {}
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, _data: &P, _env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            if self.indeterminate {
+                self.sweep.0.animate(ctx, 1.0);
+            }
+        }
+    }
 
     #[instrument(
         name = "ProgressBar",
         level = "trace",
         skip(self, ctx, _old_data, _data, _env)
     )]
-    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &f64, _data: &f64, _env: &Env) {
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &P, _data: &P, _env: &Env) {
+        if self.indeterminate && !self.sweep.0.animating() {
+            let target = 1.0 - self.sweep.0.end();
+            self.sweep.0.animate(ctx, target);
+        }
         ctx.request_paint();
     }
 
@@ -145,7 +277,7 @@ impl Widget<f64> for ProgressBar {
         &mut self,
         _layout_ctx: &mut LayoutCtx,
         bc: &BoxConstraints,
-        _data: &f64,
+        _data: &P,
         env: &Env,
     ) -> Size {
         bc.debug_check("ProgressBar");
@@ -160,7 +292,7 @@ impl Widget<f64> for ProgressBar {
     }
 
     #[instrument(name = "ProgressBar", level = "trace", skip(self, ctx, data, env))]
-    fn paint(&mut self, ctx: &mut PaintCtx, data: &f64, env: &Env) {
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &P, env: &Env) {
         let border_width = self.border_width.resolve(env);
 
         let height = env.get(theme::BASIC_WIDGET_HEIGHT);
@@ -178,11 +310,30 @@ impl Widget<f64> for ProgressBar {
         // This has been changed from a gradient from top to bottom because I thought this made more sense visually.
         ctx.fill(full_rect, &self.background_brush(env));
 
-        // Paint the bar
-        let calculated_bar_width = data.max(0.0).min(1.0) * full_rect.width();
+        // Paint the bar, or the sweeping block of an indeterminate bar
+        let (bar_x, calculated_bar_width) = if self.indeterminate {
+            let block_width = SWEEP_BLOCK_WIDTH * full_rect.width();
+            let free_width = full_rect.width() - block_width;
+            (-inset + self.sweep.0.get() * free_width, block_width)
+        } else {
+            let (bar_width, buffer_width) =
+                bar_widths(data.progress(), data.buffered(), full_rect.width());
+            if buffer_width > 0.0 {
+                // the buffered part is painted behind the end of the bar, so that
+                // the rounded corners of the bar show over it
+                let buffer_rect = Rect::from_origin_size(
+                    Point::new(-inset, 0.),
+                    Size::new(bar_width + buffer_width, height),
+                )
+                .inset((0.0, inset))
+                .to_rounded_rect(self.corner_radius.resolve(env));
+                ctx.fill(buffer_rect, &self.buffer_brush(env));
+            }
+            (-inset, bar_width)
+        };
 
         let bar_rect = Rect::from_origin_size(
-            Point::new(-inset, 0.),
+            Point::new(bar_x, 0.),
             Size::new(calculated_bar_width, height),
         )
         .inset((0.0, inset))
@@ -202,3 +353,22 @@ impl Widget<f64> for ProgressBar {
             .expect("Could not restore render context in, ProgressBar Widget.");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffered_bar() {
+        let data = (0.3, 0.6);
+        let (bar, buffer) = bar_widths(data.progress(), data.buffered(), 100.0);
+        assert!((bar - 30.0).abs() < 1e-9);
+        assert!((buffer - 30.0).abs() < 1e-9);
+        // the background shows after the buffered part
+        assert!((100.0 - bar - buffer - 40.0).abs() < 1e-9);
+
+        // a buffer behind the progress isn't shown
+        assert_eq!(bar_widths(0.5, Some(0.2), 100.0), (50.0, 0.0));
+        assert_eq!(bar_widths(0.5, None, 100.0), (50.0, 0.0));
+    }
+}
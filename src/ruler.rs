@@ -0,0 +1,267 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A ruler showing the coordinates of a panned and zoomed canvas.
+
+use druid::kurbo::{Affine, Line};
+use druid::piet::{Text, TextLayoutBuilder};
+use druid::widget::prelude::*;
+use druid::{theme, Color, FontFamily, KeyOrValue, Point, Vec2};
+
+use crate::Orientation;
+
+/// The thickness of the ruler.
+const THICKNESS: f64 = 20.0;
+/// The length of major ticks, minor ticks are half as long.
+const MAJOR_TICK: f64 = 8.0;
+const MINOR_TICKS: usize = 5;
+const LABEL_SIZE: f64 = 9.0;
+
+/// A ruler painting tick marks and coordinates along one axis of a [`Canvas`].
+///
+/// The data is the transform from canvas coordinates to view coordinates, which
+/// should be the same `Affine` used to pan and zoom the canvas. Only its scale
+/// and translation along the ruler's axis are used. The start of the ruler is
+/// assumed to be aligned with the origin of the view.
+///
+/// The spacing between labelled major ticks is chosen from 1, 2 and 5 times a
+/// power of ten, so that they are at least [`min_spacing`] pixels apart, and
+/// refines as the canvas is zoomed in.
+///
+/// [`Canvas`]: crate::Canvas
+/// [`min_spacing`]: Ruler::with_min_spacing
+pub struct Ruler {
+    orientation: Orientation,
+    min_spacing: f64,
+    color: KeyOrValue<Color>,
+    background: KeyOrValue<Color>,
+    text_color: KeyOrValue<Color>,
+}
+
+impl Ruler {
+    /// Create a new ruler with the given orientation.
+    pub fn new(orientation: Orientation) -> Self {
+        Ruler {
+            orientation,
+            min_spacing: 60.0,
+            color: theme::BORDER_LIGHT.into(),
+            background: theme::BACKGROUND_LIGHT.into(),
+            text_color: theme::TEXT_COLOR.into(),
+        }
+    }
+
+    /// Create a new horizontal ruler, showing x coordinates.
+    pub fn horizontal() -> Self {
+        Self::new(Orientation::Horizontal)
+    }
+
+    /// Create a new vertical ruler, showing y coordinates.
+    pub fn vertical() -> Self {
+        Self::new(Orientation::Vertical)
+    }
+
+    /// Builder-style method for setting the minimum distance, in pixels, between
+    /// major ticks.
+    pub fn with_min_spacing(mut self, min_spacing: f64) -> Self {
+        self.set_min_spacing(min_spacing);
+        self
+    }
+
+    /// Set the minimum distance, in pixels, between major ticks.
+    pub fn set_min_spacing(&mut self, min_spacing: f64) {
+        assert!(min_spacing > 0.0);
+        self.min_spacing = min_spacing;
+    }
+
+    /// Builder-style method for setting the color of the ticks.
+    pub fn with_color(mut self, color: impl Into<KeyOrValue<Color>>) -> Self {
+        self.set_color(color);
+        self
+    }
+
+    /// Set the color of the ticks.
+    pub fn set_color(&mut self, color: impl Into<KeyOrValue<Color>>) {
+        self.color = color.into();
+    }
+
+    /// Builder-style method for setting the background color.
+    pub fn with_background(mut self, color: impl Into<KeyOrValue<Color>>) -> Self {
+        self.set_background(color);
+        self
+    }
+
+    /// Set the background color.
+    pub fn set_background(&mut self, color: impl Into<KeyOrValue<Color>>) {
+        self.background = color.into();
+    }
+
+    /// Builder-style method for setting the color of the labels.
+    pub fn with_text_color(mut self, color: impl Into<KeyOrValue<Color>>) -> Self {
+        self.set_text_color(color);
+        self
+    }
+
+    /// Set the color of the labels.
+    pub fn set_text_color(&mut self, color: impl Into<KeyOrValue<Color>>) {
+        self.text_color = color.into();
+    }
+
+    /// Returns the scale and translation of the transform along the axis of the ruler.
+    fn scale_and_offset(&self, transform: &Affine) -> (f64, f64) {
+        let [xx, _, _, yy, x, y] = transform.as_coeffs();
+        match self.orientation {
+            Orientation::Horizontal => (xx, x),
+            Orientation::Vertical => (yy, y),
+        }
+    }
+
+    fn tick_line(&self, pos: f64, length: f64) -> Line {
+        match self.orientation {
+            Orientation::Horizontal => Line::new((pos, THICKNESS - length), (pos, THICKNESS)),
+            Orientation::Vertical => Line::new((THICKNESS - length, pos), (THICKNESS, pos)),
+        }
+    }
+}
+
+/// Returns the distance, in canvas units, between major ticks: the smallest of 1,
+/// 2 or 5 times a power of ten which is at least `min_spacing` pixels at `scale`.
+fn tick_step(scale: f64, min_spacing: f64) -> f64 {
+    let min_step = min_spacing / scale.abs();
+    let magnitude = 10f64.powf(min_step.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= min_step)
+        .unwrap_or(10.0 * magnitude)
+}
+
+/// Formats a coordinate with as many decimals as needed for `step`.
+fn format_label(value: f64, step: f64) -> String {
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    format!("{value:.decimals$}")
+}
+
+impl Widget<Affine> for Ruler {
+    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut Affine, _env: &Env) {}
+
+    fn lifecycle(
+        &mut self,
+        _ctx: &mut LifeCycleCtx,
+        _event: &LifeCycle,
+        _data: &Affine,
+        _env: &Env,
+    ) {
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &Affine, data: &Affine, _env: &Env) {
+        if self.scale_and_offset(old_data) != self.scale_and_offset(data) {
+            ctx.request_paint();
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        _data: &Affine,
+        _env: &Env,
+    ) -> Size {
+        let max = bc.max();
+        let size = match self.orientation {
+            Orientation::Horizontal if max.width.is_finite() => Size::new(max.width, THICKNESS),
+            Orientation::Horizontal => Size::new(bc.min().width, THICKNESS),
+            Orientation::Vertical if max.height.is_finite() => Size::new(THICKNESS, max.height),
+            Orientation::Vertical => Size::new(THICKNESS, bc.min().height),
+        };
+        bc.constrain(size)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &Affine, env: &Env) {
+        let size = ctx.size();
+        ctx.fill(size.to_rect(), &self.background.resolve(env));
+
+        let (scale, offset) = self.scale_and_offset(data);
+        if scale == 0.0 || !scale.is_finite() {
+            return;
+        }
+        let length = match self.orientation {
+            Orientation::Horizontal => size.width,
+            Orientation::Vertical => size.height,
+        };
+        let color = self.color.resolve(env);
+        let text_color = self.text_color.resolve(env);
+
+        let step = tick_step(scale, self.min_spacing);
+        let minor_step = step / MINOR_TICKS as f64;
+        // the range of canvas coordinates visible on the ruler
+        let (start, end) = {
+            let (a, b) = (-offset / scale, (length - offset) / scale);
+            (a.min(b), a.max(b))
+        };
+
+        let first = (start / step).floor() as i64;
+        let last = (end / step).ceil() as i64;
+        for major in first..=last {
+            let value = major as f64 * step;
+            for minor in 0..MINOR_TICKS {
+                let pos = (value + minor as f64 * minor_step) * scale + offset;
+                if pos < 0.0 || pos > length {
+                    continue;
+                }
+                let tick_length = if minor == 0 {
+                    MAJOR_TICK
+                } else {
+                    MAJOR_TICK / 2.0
+                };
+                ctx.stroke(self.tick_line(pos.round() + 0.5, tick_length), &color, 1.0);
+            }
+
+            let pos = value * scale + offset;
+            let label = ctx
+                .text()
+                .new_text_layout(format_label(value, step))
+                .font(FontFamily::SYSTEM_UI, LABEL_SIZE)
+                .text_color(text_color)
+                .build();
+            let label = match label {
+                Ok(label) => label,
+                Err(_) => continue,
+            };
+            let orientation = &self.orientation;
+            ctx.with_save(|ctx| {
+                if let Orientation::Vertical = orientation {
+                    // labels read from bottom to top along a vertical ruler
+                    ctx.transform(
+                        Affine::translate(Vec2::new(0.0, pos))
+                            * Affine::rotate(-std::f64::consts::FRAC_PI_2),
+                    );
+                    ctx.draw_text(&label, Point::new(2.0, 0.0));
+                } else {
+                    ctx.draw_text(&label, Point::new(pos + 2.0, 0.0));
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps() {
+        assert_eq!(tick_step(1.0, 60.0), 100.0);
+        assert_eq!(tick_step(1.0, 100.0), 100.0);
+        assert_eq!(tick_step(2.0, 60.0), 50.0);
+        assert_eq!(tick_step(4.0, 60.0), 20.0);
+        assert_eq!(tick_step(0.5, 60.0), 200.0);
+        assert_eq!(tick_step(-1.0, 60.0), 100.0);
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(format_label(200.0, 100.0), "200");
+        assert_eq!(format_label(0.5, 0.5), "0.5");
+        assert_eq!(format_label(0.25, 0.05), "0.25");
+    }
+}
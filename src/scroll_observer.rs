@@ -0,0 +1,158 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Publishing the visible part of a scrolled widget to its descendants.
+
+use druid::widget::prelude::*;
+use druid::widget::Controller;
+use druid::{Point, Rect};
+
+crate::selectors! {
+    /// Command sent by a [`ScrollObserver`] to the widget it controls, and from there
+    /// to all its descendants, whenever the visible part of the widget changed.
+    VIEWPORT_CHANGED: Viewport,
+}
+
+/// The visible part of a widget observed by a [`ScrollObserver`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Viewport {
+    /// The visible area, relative to the observed widget.
+    ///
+    /// When the observed widget is the child of a [`Scroll`], this is the scroll
+    /// offset and the size of the viewport.
+    ///
+    /// [`Scroll`]: druid::widget::Scroll
+    pub rect: Rect,
+    /// The origin of the observed widget relative to the window.
+    pub window_origin: Point,
+}
+
+impl Viewport {
+    /// Returns the visible area relative to a descendant of the observed widget,
+    /// given the `window_origin` of the descendant.
+    pub fn to_local(&self, window_origin: Point) -> Rect {
+        self.rect - (window_origin - self.window_origin)
+    }
+}
+
+/// A controller which tells the descendants of its widget which part of it is
+/// visible, typically inside a [`Scroll`].
+///
+/// Whenever the visible area changes, for example when scrolling or resizing the
+/// window, a [`VIEWPORT_CHANGED`] command is sent to the controlled widget. As it
+/// is an ordinary command, it is passed on to all descendants, which can use
+/// [`Viewport::to_local`] with their own [`window_origin`] to find out which part
+/// of them is visible.
+///
+/// The observer only sends a command when the visible area changed, and
+/// [`ScrollObserver::viewport`] returns the last visible area.
+///
+/// [`Scroll`]: druid::widget::Scroll
+/// [`window_origin`]: druid::LifeCycleCtx::window_origin
+#[derive(Default)]
+pub struct ScrollObserver {
+    viewport: Option<Viewport>,
+}
+
+impl ScrollObserver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The last visible area of the controlled widget, if it was laid out.
+    pub fn viewport(&self) -> Option<Viewport> {
+        self.viewport
+    }
+}
+
+impl<T: Data, W: Widget<T>> Controller<T, W> for ScrollObserver {
+    fn lifecycle(
+        &mut self,
+        child: &mut W,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &T,
+        env: &Env,
+    ) {
+        if let LifeCycle::ViewContextChanged(view_context) = event {
+            let viewport = Viewport {
+                rect: view_context.clip,
+                window_origin: view_context.window_origin,
+            };
+            if self.viewport != Some(viewport) {
+                self.viewport = Some(viewport);
+                ctx.submit_command(VIEWPORT_CHANGED.with(viewport).to(ctx.widget_id()));
+            }
+        }
+        child.lifecycle(ctx, event, data, env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::ModularWidget;
+    use druid::widget::{Flex, Scroll, SizedBox};
+    use druid::{MouseButton, MouseEvent, Vec2, WidgetExt as _};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn wheel(delta: Vec2) -> Event {
+        let pos = Point::new(200.0, 200.0);
+        Event::Wheel(MouseEvent {
+            pos,
+            window_pos: pos,
+            buttons: Default::default(),
+            mods: Default::default(),
+            count: 0,
+            focus: false,
+            button: MouseButton::None,
+            wheel_delta: delta,
+        })
+    }
+
+    #[test]
+    fn scrolled_viewport() {
+        let visible = Rc::new(RefCell::new(Vec::new()));
+        // a descendant 100 pixels below the top of the observed widget
+        let descendant = ModularWidget::new(visible.clone())
+            .event_fn(|visible, ctx, event, _, _| {
+                if let Event::Command(cmd) = event {
+                    if let Some(viewport) = cmd.get(VIEWPORT_CHANGED) {
+                        let local = viewport.to_local(ctx.window_origin());
+                        visible.borrow_mut().push((viewport.rect, local));
+                    }
+                }
+            })
+            .layout_fn(|_, _, _, _, _| Size::new(400.0, 900.0));
+        let observed = Flex::column()
+            .with_child(SizedBox::empty().fix_size(400.0, 100.0))
+            .with_child(descendant)
+            .controller(ScrollObserver::new());
+
+        Harness::create_simple((), Scroll::new(observed).vertical(), |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            // the commands submitted while handling an event are delivered with the next one
+            harness.event(Event::Timer(druid::TimerToken::INVALID));
+            assert_eq!(
+                visible.borrow_mut().drain(..).collect::<Vec<_>>(),
+                [(
+                    Rect::new(0.0, 0.0, 400.0, 400.0),
+                    Rect::new(0.0, -100.0, 400.0, 300.0)
+                )]
+            );
+
+            harness.event(wheel(Vec2::new(0.0, 200.0)));
+            harness.event(Event::Timer(druid::TimerToken::INVALID));
+            assert_eq!(
+                *visible.borrow(),
+                [(
+                    Rect::new(0.0, 200.0, 400.0, 600.0),
+                    Rect::new(0.0, 100.0, 400.0, 500.0)
+                )]
+            );
+        });
+    }
+}
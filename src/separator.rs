@@ -3,16 +3,37 @@
 
 //! A separator widget.
 
+use druid::kurbo::Line;
+use druid::piet::{LineCap, StrokeStyle};
 use druid::widget::prelude::*;
-use druid::{kurbo::Line, piet::StrokeStyle};
-use druid::{theme, Color, KeyOrValue};
+use druid::widget::{Axis, Label, LabelText};
+use druid::{theme, Color, KeyOrValue, WidgetPod};
+
+// space between the lines and the label of a labeled separator.
+const LABEL_PADDING: f64 = 8.0;
 
 /// A separator widget.
 pub struct Separator {
     width: KeyOrValue<f64>,
     color: KeyOrValue<Color>,
+    inset: KeyOrValue<f64>,
     orientation: Orientation,
     stroke_style: StrokeStyle,
+    style: SeparatorStyle,
+}
+
+/// The pattern of the line drawn by a [`Separator`].
+///
+/// The lengths of [`SeparatorStyle::Dashed`] are in logical pixels for a separator
+/// one pixel thick, and are scaled with the thickness of the separator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SeparatorStyle {
+    /// A continuous line.
+    Solid,
+    /// Dashes of the first length, separated by gaps of the second length.
+    Dashed(f64, f64),
+    /// Round dots, separated by gaps as wide as the separator is thick.
+    Dotted,
 }
 
 pub enum Orientation {
@@ -25,8 +46,10 @@ impl Default for Separator {
         Separator {
             width: theme::BUTTON_BORDER_WIDTH.into(),
             color: theme::BORDER_LIGHT.into(),
+            inset: 0.0.into(),
             orientation: Orientation::Horizontal,
             stroke_style: StrokeStyle::new(),
+            style: SeparatorStyle::Solid,
         }
     }
 }
@@ -56,6 +79,17 @@ impl Separator {
         self.color = color.into();
     }
 
+    /// Builder-style method to leave `inset` empty at both ends of the line.
+    pub fn with_inset(mut self, inset: impl Into<KeyOrValue<f64>>) -> Self {
+        self.inset = inset.into();
+        self
+    }
+
+    /// Set the space left empty at both ends of the line.
+    pub fn set_inset(&mut self, inset: impl Into<KeyOrValue<f64>>) {
+        self.inset = inset.into();
+    }
+
     pub fn with_stroke_style(mut self, stroke_style: StrokeStyle) -> Self {
         self.stroke_style = stroke_style;
         self
@@ -65,6 +99,34 @@ impl Separator {
         self.stroke_style = stroke_style;
     }
 
+    /// Builder-style method to set the [`SeparatorStyle`], which replaces the dash
+    /// pattern of the stroke style.
+    pub fn with_style(mut self, style: SeparatorStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the [`SeparatorStyle`], which replaces the dash pattern of the stroke style.
+    pub fn set_style(&mut self, style: SeparatorStyle) {
+        self.style = style;
+    }
+
+    fn resolved_stroke_style(&self, width: f64) -> StrokeStyle {
+        let mut stroke_style = self.stroke_style.clone();
+        match self.style {
+            SeparatorStyle::Solid => {}
+            SeparatorStyle::Dashed(dash, gap) => {
+                stroke_style.set_dash_pattern([dash * width, gap * width]);
+            }
+            SeparatorStyle::Dotted => {
+                // zero length dashes with round caps are drawn as dots
+                stroke_style.set_line_cap(LineCap::Round);
+                stroke_style.set_dash_pattern([0.0, 2.0 * width]);
+            }
+        }
+        stroke_style
+    }
+
     pub fn with_orientation(mut self, orientation: Orientation) -> Self {
         self.orientation = orientation;
         self
@@ -73,6 +135,44 @@ impl Separator {
     pub fn set_orientation(&mut self, orientation: Orientation) {
         self.orientation = orientation;
     }
+
+    /// Turn this separator into one with `label` in its center, like a rule with an
+    /// "OR" caption. The lines fill the space on both sides of the label.
+    pub fn with_label<T: Data>(self, label: impl Into<LabelText<T>>) -> LabeledSeparator<T> {
+        LabeledSeparator {
+            separator: self,
+            label: WidgetPod::new(Label::new(label)),
+        }
+    }
+
+    fn axis(&self) -> Axis {
+        match self.orientation {
+            Orientation::Vertical => Axis::Vertical,
+            Orientation::Horizontal => Axis::Horizontal,
+        }
+    }
+
+    /// Request a layout or a paint when the keys of the separator changed in the env.
+    fn update_env(&self, ctx: &mut UpdateCtx) {
+        if ctx.env_key_changed(&self.width) || ctx.env_key_changed(&self.inset) {
+            ctx.request_layout();
+        }
+        if ctx.env_key_changed(&self.color) {
+            ctx.request_paint();
+        }
+    }
+
+    /// Strokes the separator along the major axis, between `start` and `end`, centered
+    /// on the minor axis of `size`.
+    fn stroke(&self, ctx: &mut PaintCtx, size: Size, start: f64, end: f64, env: &Env) {
+        let axis = self.axis();
+        let minor = axis.minor(size) / 2.0;
+        let line = Line::new(axis.pack(start, minor), axis.pack(end, minor));
+        let color = self.color.resolve(env);
+        let width = self.width.resolve(env);
+        let stroke_style = self.resolved_stroke_style(width);
+        ctx.stroke_styled(line, &color, width, &stroke_style);
+    }
 }
 
 impl<T> Widget<T> for Separator {
@@ -80,7 +180,9 @@ impl<T> Widget<T> for Separator {
 
     fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &T, _env: &Env) {}
 
-    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &T, _data: &T, _env: &Env) {}
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, _data: &T, _env: &Env) {
+        self.update_env(ctx);
+    }
 
     fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, env: &Env) -> Size {
         let width = self.width.resolve(env);
@@ -92,9 +194,157 @@ impl<T> Widget<T> for Separator {
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, env: &Env) {
-        let line = Line::new((0., 0.), ctx.size().to_vec2().to_point());
-        let color = self.color.resolve(env);
-        let width = self.width.resolve(env);
-        ctx.stroke_styled(line, &color, width, &self.stroke_style);
+        let size = ctx.size();
+        let (start, end) = inset_span(self.axis().major(size), self.inset.resolve(env));
+        if start < end {
+            self.stroke(ctx, size, start, end, env);
+        }
+    }
+}
+
+/// A [`Separator`] with a label in its center, created with [`Separator::with_label`].
+pub struct LabeledSeparator<T> {
+    separator: Separator,
+    label: WidgetPod<T, Label<T>>,
+}
+
+/// The start and the end of a line along a major axis of length `major`, leaving `inset`
+/// at both ends.
+fn inset_span(major: f64, inset: f64) -> (f64, f64) {
+    let inset = inset.max(0.0).min(major / 2.0);
+    (inset, major - inset)
+}
+
+/// The end of the line before the label, and the start of the line after it, along
+/// the major axis.
+fn line_ends(major: f64, label_major: f64) -> (f64, f64) {
+    let label_start = ((major - label_major) / 2.0).max(0.0);
+    (
+        (label_start - LABEL_PADDING).max(0.0),
+        (label_start + label_major + LABEL_PADDING).min(major),
+    )
+}
+
+impl<T: Data> Widget<T> for LabeledSeparator<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.label.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.label.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.label.update(ctx, data, env);
+        self.separator.update_env(ctx);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let axis = self.separator.axis();
+        let label_size = self.label.layout(ctx, &bc.loosen(), data, env);
+        let width = self.separator.width.resolve(env);
+        let size = bc.constrain(axis.pack(f64::INFINITY, axis.minor(label_size).max(width)));
+        let origin = (size.to_vec2() - label_size.to_vec2()) / 2.0;
+        self.label.set_origin(ctx, origin.to_point());
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let size = ctx.size();
+        let axis = self.separator.axis();
+        let major = axis.major(size);
+        let (first_end, second_start) =
+            line_ends(major, axis.major(self.label.layout_rect().size()));
+        let (start, end) = inset_span(major, self.separator.inset.resolve(env));
+        if start < first_end {
+            self.separator.stroke(ctx, size, start, first_end, env);
+        }
+        if second_start < end {
+            self.separator.stroke(ctx, size, second_start, end, env);
+        }
+        self.label.paint(ctx, data, env);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WidgetExt as _;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::{Record, Recording, TestWidgetExt};
+    use druid::{Selector, WidgetExt as _};
+
+    #[test]
+    fn labeled_line_ends() {
+        assert_eq!(line_ends(200.0, 20.0), (82.0, 118.0));
+        // no room for the lines
+        assert_eq!(line_ends(30.0, 20.0), (0.0, 30.0));
+    }
+
+    #[test]
+    fn inset_line() {
+        assert_eq!(inset_span(100.0, 10.0), (10.0, 90.0));
+        // the insets can't overlap
+        assert_eq!(inset_span(100.0, 80.0), (50.0, 50.0));
+        assert_eq!(inset_span(100.0, -5.0), (0.0, 100.0));
+    }
+
+    #[test]
+    fn labeled_separator_in_column() {
+        let label_recording = Recording::default();
+        let recording = Recording::default();
+        let separator = Separator::new()
+            .with_style(SeparatorStyle::Dashed(4.0, 2.0))
+            .with_label("OR")
+            .record(&recording);
+        let column = druid::widget::Flex::column()
+            .with_child(Label::new("Sign in with a password").record(&label_recording))
+            .with_child(separator)
+            .with_child(Label::new("Sign in with a passkey"));
+
+        let layout_size = |recording: &Recording| {
+            recording
+                .drain()
+                .find_map(|record| match record {
+                    Record::Layout(size) => Some(size),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        Harness::create_simple((), column, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            let size = layout_size(&recording);
+            // the separator fills the width of the column and is as tall as its label
+            assert_eq!(size.width, 400.0);
+            assert!(size.height > 0.0);
+            assert_eq!(size.height, layout_size(&label_recording).height);
+        });
+    }
+
+    #[test]
+    fn repaint_on_color_change() {
+        const SET_RED: Selector<bool> = Selector::new("druid-widget-nursery.test.set-red");
+
+        let recording = Recording::default();
+        let separator = TestWidgetExt::<bool>::record(Separator::new(), &recording)
+            .env_scope(|env, red: &bool| {
+                if *red {
+                    env.set(theme::BORDER_LIGHT, Color::RED);
+                }
+            })
+            .on_command(SET_RED, |_, red, data| *data = *red);
+
+        Harness::create_simple(false, separator, |harness| {
+            harness.send_initial_events();
+            harness.paint_invalid();
+            recording.clear();
+
+            harness.submit_command(SET_RED.with(true));
+            harness.paint_invalid();
+            assert!(recording
+                .drain()
+                .any(|record| matches!(record, Record::Paint)));
+        });
     }
 }
@@ -1,16 +1,44 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::cell::RefCell;
 use std::cmp::Ordering;
+use std::rc::Rc;
 
+use druid::im::Vector;
 use druid::widget::{Axis, ListIter};
 use druid::{
     theme, BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx,
-    PaintCtx, Point, Rect, Size, UpdateCtx, Widget, WidgetPod,
+    PaintCtx, Point, Rect, Selector, Size, Target, UpdateCtx, Widget, WidgetPod,
 };
 use druid::{widget::prelude::*, Cursor};
 use log::trace;
 
+/// Bar positions shared between several [`Splits`].
+///
+/// The vector holds the end position of each child along the split axis, in the
+/// same units as the layout. It is owned jointly by all `Splits` it was passed to
+/// (and by the application, if it keeps a clone): whichever instance is dragged
+/// writes its positions into it, the others read them back when they receive
+/// [`SPLITS_SYNC`]. Each `Splits` still keeps its own copy, so the shared vector
+/// may be shorter or longer than the number of children of a given instance;
+/// only the overlapping part is exchanged.
+pub type SharedPositions = Rc<RefCell<Vec<f64>>>;
+
+crate::selectors! {
+    /// Command sent globally by a [`Splits`] after it wrote new bar positions into
+    /// its [`SharedPositions`]. Every `Splits` using the same shared positions
+    /// reads them back and relayouts.
+    ///
+    /// This can also be submitted by the application after changing the shared
+    /// positions itself.
+    SPLITS_SYNC: SharedPositions,
+}
+
+// Sent by a SplitsWithPositions to itself when the number of children changed, to store
+// its positions in the data again.
+const STORE_POSITIONS: Selector = Selector::new("splits.store-positions");
+
 /// Split meet List, with resizable width/height, use like a List
 pub struct Splits<T> {
     closure: Box<dyn Fn() -> Box<dyn Widget<T>>>,
@@ -21,6 +49,7 @@ pub struct Splits<T> {
     bar_selected: i16,
     min_size: f64,
     bar_size: f64,
+    shared: Option<SharedPositions>,
 }
 
 impl<T: Data> Splits<T> {
@@ -34,6 +63,7 @@ impl<T: Data> Splits<T> {
             draggable: false,
             major_pos_vec: vec![],
             bar_selected: 0,
+            shared: None,
         }
     }
 
@@ -48,8 +78,8 @@ impl<T: Data> Splits<T> {
             Ordering::Greater => {
                 if index >= 0 && (index as usize) < self.children.len() {
                     self.children.remove(index as usize);
+                    remove_position(&mut self.major_pos_vec, index as usize);
                     self.major_pos_vec.truncate(data.data_len());
-                    // TODO: recalculate positions after removal
                 }
             }
             Ordering::Less => data.for_each(|_, i| {
@@ -65,6 +95,7 @@ impl<T: Data> Splits<T> {
             }),
             Ordering::Equal => (),
         }
+        self.read_shared();
         len != data.data_len()
     }
 
@@ -84,6 +115,77 @@ impl<T: Data> Splits<T> {
         self
     }
 
+    /// Builder-style method for synchronizing the bar positions with other `Splits`
+    /// through `positions`. See [`SharedPositions`] for the ownership model.
+    pub fn with_shared_positions(mut self, positions: SharedPositions) -> Self {
+        self.set_shared_positions(positions);
+        self
+    }
+
+    /// Set the positions shared with other `Splits`.
+    pub fn set_shared_positions(&mut self, positions: SharedPositions) {
+        self.shared = Some(positions);
+        self.read_shared();
+    }
+
+    /// Read and write the bar positions from the application data, so that they can
+    /// be saved and restored.
+    ///
+    /// The returned widget's data is a tuple of the list and of the end position of
+    /// each child along the split axis. Dragging a bar writes all the positions into
+    /// the data; when the data has fewer positions than there are children, the
+    /// remaining children keep their default positions, and extra positions are
+    /// ignored until there are as many children. When children are added or removed,
+    /// the positions in the data are extended or truncated to match.
+    pub fn with_positions_in_data(self) -> SplitsWithPositions<T> {
+        SplitsWithPositions { splits: self }
+    }
+
+    /// The end position of each child along the split axis.
+    pub fn positions(&self) -> &[f64] {
+        &self.major_pos_vec
+    }
+
+    /// Copies the shared positions into this instance. Returns true if any changed.
+    fn read_shared(&mut self) -> bool {
+        let shared = match &self.shared {
+            Some(shared) => shared.clone(),
+            None => return false,
+        };
+        let shared = shared.borrow();
+        self.read_positions(shared.iter())
+    }
+
+    /// Copies `positions` into this instance, for the children it has. Returns true if
+    /// any changed.
+    fn read_positions<'a>(&mut self, positions: impl Iterator<Item = &'a f64>) -> bool {
+        let mut changed = false;
+        for (pos, new_pos) in self.major_pos_vec.iter_mut().zip(positions) {
+            if *pos != *new_pos {
+                *pos = *new_pos;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Writes the positions of this instance into the shared positions and
+    /// notifies the other instances.
+    fn write_shared(&self, ctx: &mut EventCtx) {
+        if let Some(shared) = &self.shared {
+            {
+                let mut shared_vec = shared.borrow_mut();
+                for (i, pos) in self.major_pos_vec.iter().enumerate() {
+                    match shared_vec.get_mut(i) {
+                        Some(shared_pos) => *shared_pos = *pos,
+                        None => shared_vec.push(*pos),
+                    }
+                }
+            }
+            ctx.submit_command(SPLITS_SYNC.with(shared.clone()).to(Target::Global));
+        }
+    }
+
     fn paint_bar(&mut self, ctx: &mut PaintCtx, env: &Env) {
         let splitter_color = env.get(theme::BORDER_LIGHT);
         let size = ctx.size();
@@ -143,6 +245,23 @@ impl<T: Data> Splits<T> {
     }
 }
 
+/// Removes the end position of the child at `index`, moving the following children
+/// back by the size of the removed child (including its bar).
+fn remove_position(positions: &mut Vec<f64>, index: usize) {
+    if index >= positions.len() {
+        return;
+    }
+    let start = if index == 0 {
+        0.0
+    } else {
+        positions[index - 1]
+    };
+    let removed_size = positions.remove(index) - start;
+    for pos in &mut positions[index..] {
+        *pos -= removed_size;
+    }
+}
+
 // Copy of Axis.constraints() because is crate only
 fn axis_constraints(axis: Axis, bc: &BoxConstraints, min_major: f64, major: f64) -> BoxConstraints {
     match axis {
@@ -182,6 +301,7 @@ impl<C: Data, T: ListIter<C>> Widget<T> for Splits<C> {
                     if mouse.button.is_left() && ctx.is_active() {
                         ctx.set_active(false);
                         self.update_bar_pos(mouse.pos);
+                        self.write_shared(ctx);
                         ctx.request_paint();
                         self.bar_selected = 0;
                     }
@@ -189,6 +309,7 @@ impl<C: Data, T: ListIter<C>> Widget<T> for Splits<C> {
                 Event::MouseMove(mouse) => {
                     if ctx.is_active() {
                         self.update_bar_pos(mouse.pos);
+                        self.write_shared(ctx);
                         ctx.request_layout();
                     }
 
@@ -206,6 +327,15 @@ impl<C: Data, T: ListIter<C>> Widget<T> for Splits<C> {
                 _ => {}
             }
         }
+
+        if let Event::Command(cmd) = event {
+            if let Some(positions) = cmd.get(SPLITS_SYNC) {
+                let same = matches!(&self.shared, Some(shared) if Rc::ptr_eq(shared, positions));
+                if same && self.read_shared() {
+                    ctx.request_layout();
+                }
+            }
+        }
     }
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
@@ -303,3 +433,170 @@ impl<C: Data, T: ListIter<C>> Widget<T> for Splits<C> {
         });
     }
 }
+
+/// [`Splits`] whose bar positions are stored in the data, created with
+/// [`Splits::with_positions_in_data`].
+pub struct SplitsWithPositions<C> {
+    splits: Splits<C>,
+}
+
+impl<C: Data, T: ListIter<C>> Widget<(T, Vector<f64>)> for SplitsWithPositions<C> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut (T, Vector<f64>), env: &Env) {
+        if let Event::Command(cmd) = event {
+            if cmd.is(STORE_POSITIONS) {
+                data.1 = self.splits.major_pos_vec.iter().copied().collect();
+                ctx.set_handled();
+                return;
+            }
+        }
+        let old_positions = self.splits.major_pos_vec.clone();
+        self.splits.event(ctx, event, &mut data.0, env);
+        if self.splits.major_pos_vec != old_positions {
+            data.1 = self.splits.major_pos_vec.iter().copied().collect();
+        }
+    }
+
+    fn lifecycle(
+        &mut self,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &(T, Vector<f64>),
+        env: &Env,
+    ) {
+        self.splits.lifecycle(ctx, event, &data.0, env);
+        if let LifeCycle::WidgetAdded = event {
+            self.splits.read_positions(data.1.iter());
+        }
+    }
+
+    fn update(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        old_data: &(T, Vector<f64>),
+        data: &(T, Vector<f64>),
+        env: &Env,
+    ) {
+        self.splits.update(ctx, &old_data.0, &data.0, env);
+        let len = data.0.data_len();
+        let old_len = old_data.0.data_len();
+        // the splits moved the positions following a removed child, so the stored
+        // positions are stale
+        if len >= old_len && self.splits.read_positions(data.1.iter()) {
+            ctx.request_layout();
+        }
+        if len != old_len && data.1.len() != len {
+            ctx.submit_command(STORE_POSITIONS.to(ctx.widget_id()));
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &(T, Vector<f64>),
+        env: &Env,
+    ) -> Size {
+        self.splits.layout(ctx, bc, &data.0, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &(T, Vector<f64>), env: &Env) {
+        self.splits.paint(ctx, &data.0, env);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::widget::{Controller, SizedBox};
+    use druid::WidgetExt as _;
+
+    const REMOVE_CHILD: Selector<usize> = Selector::new("splits.test.remove-child");
+    const ADD_CHILD: Selector = Selector::new("splits.test.add-child");
+    const FLUSH: Selector = Selector::new("splits.test.flush");
+
+    /// Edits the list of the splits on commands.
+    struct EditList;
+
+    impl<W: Widget<(Vector<u32>, Vector<f64>)>> Controller<(Vector<u32>, Vector<f64>), W> for EditList {
+        fn event(
+            &mut self,
+            child: &mut W,
+            ctx: &mut EventCtx,
+            event: &Event,
+            data: &mut (Vector<u32>, Vector<f64>),
+            env: &Env,
+        ) {
+            match event {
+                Event::Command(cmd) if cmd.is(REMOVE_CHILD) => {
+                    data.0.remove(*cmd.get_unchecked(REMOVE_CHILD));
+                }
+                Event::Command(cmd) if cmd.is(ADD_CHILD) => {
+                    let value = data.0.iter().max().map_or(0, |max| max + 1);
+                    data.0.push_back(value);
+                }
+                _ => child.event(ctx, event, data, env),
+            }
+        }
+    }
+
+    #[test]
+    fn read_saved_positions() {
+        let mut splits = Splits::<()>::new(SizedBox::empty);
+        splits.major_pos_vec = vec![100.0, 200.0, 300.0];
+
+        let saved: Vector<f64> = vec![120.0, 250.0].into();
+        assert!(splits.read_positions(saved.iter()));
+        assert_eq!(splits.positions(), &[120.0, 250.0, 300.0]);
+        assert!(!splits.read_positions(saved.iter()));
+
+        // positions for children which don't exist are ignored
+        let saved: Vector<f64> = vec![120.0, 250.0, 310.0, 400.0].into();
+        assert!(splits.read_positions(saved.iter()));
+        assert_eq!(splits.positions(), &[120.0, 250.0, 310.0]);
+    }
+
+    #[test]
+    fn stored_positions_follow_children() {
+        let splits = Splits::new(SizedBox::empty)
+            .with_positions_in_data()
+            .controller(EditList);
+        let data = (
+            Vector::from(vec![0, 1, 2]),
+            Vector::from(vec![100.0, 250.0, 300.0]),
+        );
+
+        Harness::create_simple(data, splits, |harness| {
+            harness.send_initial_events();
+            harness.submit_command(REMOVE_CHILD.with(1));
+            // the positions are stored by a command submitted during update
+            harness.submit_command(FLUSH);
+            assert_eq!(harness.data().1, Vector::from(vec![100.0, 150.0]));
+
+            // a new child gets the default position, after the last one
+            harness.submit_command(ADD_CHILD);
+            harness.submit_command(FLUSH);
+            assert_eq!(harness.data().1, Vector::from(vec![100.0, 150.0, 156.0]));
+
+            harness.submit_command(REMOVE_CHILD.with(2));
+            harness.submit_command(FLUSH);
+            assert_eq!(harness.data().1, Vector::from(vec![100.0, 150.0]));
+        });
+    }
+
+    #[test]
+    fn remove_middle_child() {
+        let mut positions = vec![100.0, 250.0, 300.0, 420.0];
+        remove_position(&mut positions, 1);
+        // the first pane keeps its size, the following ones move back by 150
+        assert_eq!(positions, vec![100.0, 150.0, 270.0]);
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+
+        remove_position(&mut positions, 0);
+        assert_eq!(positions, vec![50.0, 170.0]);
+        remove_position(&mut positions, 1);
+        assert_eq!(positions, vec![50.0]);
+        remove_position(&mut positions, 3);
+        assert_eq!(positions, vec![50.0]);
+    }
+}
@@ -23,7 +23,14 @@ use druid::kurbo::Shape;
 /// need to be `None`).
 ///
 /// If `width` or `height` is unconstrained, they are positioned
-/// according to the [Stack::align] property.
+/// according to the [Stack::align] property, or to the `align` of the
+/// position if set (see [`StackChildPosition::anchored`]).
+///
+/// Each value may also be given as a fraction of the container size,
+/// like `left_fraction` for a distance from the left edge relative to
+/// the container width. When both are given, the values are added, so
+/// `left_fraction(Some(0.5))` with `left(Some(-10.))` places the child
+/// 10 pixels left of the center.
 #[derive(Clone, Debug, Default, PartialEq, Data)]
 pub struct StackChildPosition {
     /// Disance from left edge.
@@ -38,6 +45,21 @@ pub struct StackChildPosition {
     pub width: Option<f64>,
     /// Widhet height.
     pub height: Option<f64>,
+    /// Distance from left edge, as a fraction of the container width.
+    pub left_fraction: Option<f64>,
+    /// Distance from right edge, as a fraction of the container width.
+    pub right_fraction: Option<f64>,
+    /// Distance from top edge, as a fraction of the container height.
+    pub top_fraction: Option<f64>,
+    /// Distance from bottom edge, as a fraction of the container height.
+    pub bottom_fraction: Option<f64>,
+    /// Widget width, as a fraction of the container width.
+    pub width_fraction: Option<f64>,
+    /// Widget height, as a fraction of the container height.
+    pub height_fraction: Option<f64>,
+    /// Alignment of the widget, overriding [Stack::align], as the point
+    /// of the unit square given by a [`UnitPoint`].
+    pub align: Option<Point>,
 }
 
 impl Interpolate for StackChildPosition {
@@ -69,6 +91,22 @@ impl Interpolate for StackChildPosition {
             bottom: lerp(self.bottom, other.bottom, fraction),
             width: lerp(self.width, other.width, fraction),
             height: lerp(self.height, other.height, fraction),
+            left_fraction: lerp(self.left_fraction, other.left_fraction, fraction),
+            right_fraction: lerp(self.right_fraction, other.right_fraction, fraction),
+            top_fraction: lerp(self.top_fraction, other.top_fraction, fraction),
+            bottom_fraction: lerp(self.bottom_fraction, other.bottom_fraction, fraction),
+            width_fraction: lerp(self.width_fraction, other.width_fraction, fraction),
+            height_fraction: lerp(self.height_fraction, other.height_fraction, fraction),
+            align: match (self.align, other.align) {
+                (Some(a), Some(b)) => Some(a.lerp(b, fraction)),
+                (a, b) => {
+                    if fraction < 0.5 {
+                        a
+                    } else {
+                        b
+                    }
+                }
+            },
         }
     }
 }
@@ -114,6 +152,112 @@ impl StackChildPosition {
         self.height = value;
         self
     }
+
+    /// Create a position placing the child at the center of the stack.
+    pub fn centered() -> Self {
+        Self::anchored(UnitPoint::CENTER)
+    }
+
+    /// Create a position placing the child at `anchor` of the stack, with
+    /// the same point of the child, so that `UnitPoint::BOTTOM_RIGHT` puts
+    /// the child in the bottom right corner.
+    pub fn anchored(anchor: UnitPoint) -> Self {
+        Self::new().align(Some(anchor))
+    }
+
+    /// Builder-style method to set the alignment of the child, overriding
+    /// [Stack::align] when its position isn't constrained.
+    pub fn align(mut self, align: Option<UnitPoint>) -> Self {
+        self.align = align.map(|align| align.resolve(Rect::new(0., 0., 1., 1.)));
+        self
+    }
+
+    /// Builder-style method to set distance from left edge, as a
+    /// fraction of the container width.
+    pub fn left_fraction(mut self, value: Option<f64>) -> Self {
+        self.left_fraction = value;
+        self
+    }
+
+    /// Builder-style method to set distance from right edge, as a
+    /// fraction of the container width.
+    pub fn right_fraction(mut self, value: Option<f64>) -> Self {
+        self.right_fraction = value;
+        self
+    }
+
+    /// Builder-style method to set distance from top edge, as a
+    /// fraction of the container height.
+    pub fn top_fraction(mut self, value: Option<f64>) -> Self {
+        self.top_fraction = value;
+        self
+    }
+
+    /// Builder-style method to set distance from bottom edge, as a
+    /// fraction of the container height.
+    pub fn bottom_fraction(mut self, value: Option<f64>) -> Self {
+        self.bottom_fraction = value;
+        self
+    }
+
+    /// Builder-style method to set child width, as a fraction of the
+    /// container width.
+    pub fn width_fraction(mut self, value: Option<f64>) -> Self {
+        self.width_fraction = value;
+        self
+    }
+
+    /// Builder-style method to set child height, as a fraction of the
+    /// container height.
+    pub fn height_fraction(mut self, value: Option<f64>) -> Self {
+        self.height_fraction = value;
+        self
+    }
+
+    /// Returns the position in pixels for a container of the given size,
+    /// with the fractions added to the absolute values.
+    fn resolve(&self, size: Size) -> StackChildPosition {
+        let resolve =
+            |value: Option<f64>, fraction: Option<f64>, extent: f64| match (value, fraction) {
+                (None, None) => None,
+                (value, fraction) => Some(value.unwrap_or(0.) + fraction.unwrap_or(0.) * extent),
+            };
+        StackChildPosition {
+            left: resolve(self.left, self.left_fraction, size.width),
+            right: resolve(self.right, self.right_fraction, size.width),
+            top: resolve(self.top, self.top_fraction, size.height),
+            bottom: resolve(self.bottom, self.bottom_fraction, size.height),
+            width: resolve(self.width, self.width_fraction, size.width),
+            height: resolve(self.height, self.height_fraction, size.height),
+            align: self.align,
+            ..StackChildPosition::default()
+        }
+    }
+
+    /// Returns the origin of a child of `child_size` in a stack of
+    /// `stack_size`, for a resolved position. `align` is the alignment of
+    /// the stack.
+    fn origin(&self, align: UnitPoint, stack_size: Size, child_size: Size) -> Point {
+        let align = self
+            .align
+            .unwrap_or_else(|| align.resolve(Rect::new(0., 0., 1., 1.)));
+        let extra = stack_size - child_size;
+        let aligned = Point::new(extra.width * align.x, extra.height * align.y).expand();
+
+        let x = match (self.left, self.right) {
+            (Some(left), _) => left,
+            (None, Some(right)) => stack_size.width - right - child_size.width,
+            (None, None) => aligned.x,
+        };
+
+        let y = match (self.top, self.bottom) {
+            (Some(top), _) => top,
+            (None, Some(bottom)) => stack_size.height - bottom - child_size.height,
+            (None, None) => aligned.y,
+        };
+
+        Point::new(x, y)
+    }
 }
 
 type PositionCallback<T> = Box<dyn for<'a> Fn(&'a T, &Env) -> &'a StackChildPosition>;
@@ -131,6 +275,8 @@ enum Position<T> {
 /// positioned children.
 pub struct StackChildParams<T> {
     position: Position<T>,
+    z_index: i32,
+    clip: bool,
     // We also store the animation state here - just to keep it simple
     animated_position: Animated<StackChildPosition>,
 }
@@ -146,6 +292,8 @@ impl<T> StackChildParams<T> {
     fn new() -> Self {
         Self {
             position: Position::None,
+            z_index: 0,
+            clip: false,
             animated_position: Animated::jump(StackChildPosition::new()).layout(true),
         }
     }
@@ -154,6 +302,8 @@ impl<T> StackChildParams<T> {
     pub fn fixed(position: StackChildPosition) -> Self {
         Self {
             position: Position::Fixed(position),
+            z_index: 0,
+            clip: false,
             animated_position: Animated::jump(StackChildPosition::new()).layout(true),
         }
     }
@@ -165,6 +315,8 @@ impl<T> StackChildParams<T> {
     {
         Self {
             position: Position::Dynamic(Box::new(position)),
+            z_index: 0,
+            clip: false,
             animated_position: Animated::new(StackChildPosition::new())
                 .curve(AnimationCurve::EASE_OUT)
                 .duration(0.3)
@@ -207,6 +359,43 @@ impl<T> StackChildParams<T> {
     pub fn set_duration(&mut self, duration: f64) {
         self.animated_position.set_duration(duration);
     }
+
+    /// Builder-style method for specifying the z-index.
+    ///
+    /// For the non-builder varient, see [`set_z_index`].
+    ///
+    /// [`set_z_index`]: #method.set_z_index
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.set_z_index(z_index);
+        self
+    }
+
+    /// Set the z-index.
+    ///
+    /// Children with a higher z-index are painted above, and receive mouse
+    /// events before, children with a lower z-index. Children with the same
+    /// z-index are stacked in the order they were added. The default is 0.
+    pub fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    /// Builder-style method for specifying the `clip` attribute.
+    ///
+    /// For the non-builder varient, see [`set_clip`].
+    ///
+    /// [`set_clip`]: #method.set_clip
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.set_clip(clip);
+        self
+    }
+
+    /// Set the `clip` attribute.
+    ///
+    /// Clip the paint region of the child at its own boundaries,
+    /// independently of the [`Stack::clip`] setting. The default is `false`.
+    pub fn set_clip(&mut self, clip: bool) {
+        self.clip = clip;
+    }
 }
 
 struct StackChild<T> {
@@ -236,8 +425,13 @@ impl<T: Data> StackChild<T> {
 /// *Positioned* children are layed-out after *non-positioned*
 /// children. Their position is relative to the container edges (see
 /// [`StackChildPosition`]).
+///
+/// Children are stacked in the order they were added, unless they have
+/// a different z-index (see [`StackChildParams::z_index`]).
 pub struct Stack<T> {
     children: Vec<StackChild<T>>,
+    /// Indices of the children, from bottom to top.
+    stack_order: Vec<usize>,
     align: UnitPoint,
     fit: bool,
     clip: bool,
@@ -257,6 +451,7 @@ impl<T: Data> Stack<T> {
     pub fn new() -> Self {
         Self {
             children: Vec::new(),
+            stack_order: Vec::new(),
             align: UnitPoint::TOP_LEFT,
             fit: false,
             clip: false,
@@ -308,8 +503,19 @@ impl<T: Data> Stack<T> {
 
     /// Add another stack child.
     pub fn add_child(&mut self, child: impl Widget<T> + 'static) {
-        let child = StackChild::new(child, StackChildParams::new());
-        self.children.push(child);
+        self.add_child_z(child, 0);
+    }
+
+    /// Builder-style variant of `add_child_z`.
+    pub fn with_child_z(mut self, child: impl Widget<T> + 'static, z_index: i32) -> Self {
+        self.add_child_z(child, z_index);
+        self
+    }
+
+    /// Add another stack child with the given z-index.
+    pub fn add_child_z(&mut self, child: impl Widget<T> + 'static, z_index: i32) {
+        let child = StackChild::new(child, StackChildParams::new().z_index(z_index));
+        self.push_child(child);
     }
 
     /// Builder-style variant of `add_positioned_child`.
@@ -329,16 +535,26 @@ impl<T: Data> Stack<T> {
         params: impl Into<StackChildParams<T>>,
     ) {
         let child = StackChild::new(child, params.into());
+        self.push_child(child);
+    }
+
+    fn push_child(&mut self, child: StackChild<T>) {
         self.children.push(child);
+        self.stack_order = (0..self.children.len()).collect();
+        // a stable sort keeps the insertion order of children with the same z-index
+        let children = &self.children;
+        self.stack_order
+            .sort_by_key(|index| children[*index].params.z_index);
     }
 }
 
 impl<T: Data> Widget<T> for Stack<T> {
     fn event(&mut self, ctx: &mut EventCtx<'_, '_>, event: &Event, data: &mut T, env: &Env) {
-        for child in self.children.iter_mut().rev() {
+        for index in self.stack_order.iter().rev() {
             if ctx.is_handled() {
                 return;
             }
+            let child = &mut self.children[*index];
 
             let rect = child.widget.layout_rect();
             let pos_match = match event {
@@ -437,7 +653,8 @@ impl<T: Data> Widget<T> for Stack<T> {
                 Position::None => continue,
                 Position::Fixed(position) => position,
                 Position::Dynamic(_) => &animated_position,
-            };
+            }
+            .resolve(size);
 
             let mut min_width = 0f64;
             let mut max_width = std::f64::INFINITY;
@@ -483,30 +700,7 @@ impl<T: Data> Widget<T> for Stack<T> {
             );
 
             let child_size = child.widget.layout(ctx, &child_bc, data, env);
-            let align = self.align;
-
-            let offset_x = match (position.left, position.right) {
-                (Some(left), _) => left,
-                (None, Some(right)) => stack_width - right - child_size.width,
-                (None, None) => {
-                    let extra_width = stack_width - child_size.width;
-                    align.resolve(Rect::new(0., 0., extra_width, 0.)).expand().x
-                }
-            };
-
-            let offset_y = match (position.top, position.bottom) {
-                (Some(top), _) => top,
-                (None, Some(bottom)) => stack_height - bottom - child_size.height,
-                (None, None) => {
-                    let extra_height = stack_height - child_size.height;
-                    align
-                        .resolve(Rect::new(0., 0., 0., extra_height))
-                        .expand()
-                        .y
-                }
-            };
-
-            let origin = Point::new(offset_x, offset_y);
+            let origin = position.origin(self.align, size, child_size);
             child.widget.set_origin(ctx, origin);
         }
 
@@ -519,8 +713,64 @@ impl<T: Data> Widget<T> for Stack<T> {
         if self.clip {
             ctx.clip(size.to_rect());
         }
-        for child in &mut self.children {
-            child.widget.paint(ctx, data, env);
+        for index in &self.stack_order {
+            let child = &mut self.children[*index];
+            if child.params.clip {
+                let rect = child.widget.layout_rect();
+                ctx.with_save(|ctx| {
+                    ctx.clip(rect);
+                    child.widget.paint(ctx, data, env);
+                });
+            } else {
+                child.widget.paint(ctx, data, env);
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fractional_position() {
+        let position = StackChildPosition::new()
+            .left_fraction(Some(0.5))
+            .width(Some(20.))
+            .top(Some(10.))
+            .top_fraction(Some(0.25));
+
+        let resolved = position.resolve(Size::new(200., 100.));
+        assert_eq!(resolved.left, Some(100.));
+        assert_eq!(resolved.width, Some(20.));
+        assert_eq!(resolved.top, Some(35.));
+        assert_eq!(resolved.right, None);
+
+        // the origin tracks the container width
+        assert_eq!(position.resolve(Size::new(300., 100.)).left, Some(150.));
+    }
+
+    #[test]
+    fn anchored_position() {
+        let stack_size = Size::new(200., 100.);
+        let child_size = Size::new(20., 10.);
+        let origin = |position: StackChildPosition| {
+            position
+                .resolve(stack_size)
+                .origin(UnitPoint::TOP_LEFT, stack_size, child_size)
+        };
+
+        assert_eq!(
+            origin(StackChildPosition::anchored(UnitPoint::BOTTOM_RIGHT)),
+            Point::new(180., 90.)
+        );
+        assert_eq!(origin(StackChildPosition::centered()), Point::new(90., 45.));
+        // the stack alignment is used without an anchor
+        assert_eq!(origin(StackChildPosition::new()), Point::ZERO);
+        // an edge takes precedence over the anchor
+        assert_eq!(
+            origin(StackChildPosition::centered().left(Some(5.))),
+            Point::new(5., 45.)
+        );
+    }
+}
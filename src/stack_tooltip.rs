@@ -4,7 +4,7 @@
 //! A stack based tooltip widget.
 
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     convert::{TryFrom, TryInto},
     rc::Rc,
     sync::Arc,
@@ -15,11 +15,11 @@ use druid::{
     piet::{Text, TextAttribute, TextLayoutBuilder, TextStorage},
     text::{Attribute, RichText},
     widget::{
-        DefaultScopePolicy, Either, Label, LensScopeTransfer, RawLabel, Scope, SizedBox,
-        WidgetWrapper,
+        DefaultScopePolicy, Either, Label, LensScopeTransfer, LineBreaking, RawLabel, Scope,
+        SizedBox, WidgetWrapper,
     },
-    Color, Data, KeyOrValue, Lens, Point, RenderContext, Selector, SingleUse, Size, Widget,
-    WidgetExt, WidgetId, WidgetPod,
+    BoxConstraints, Color, Data, KeyOrValue, Lens, Point, RenderContext, Selector, SingleUse, Size,
+    Widget, WidgetExt, WidgetId, WidgetPod,
 };
 
 const FORWARD: Selector<SingleUse<(WidgetId, Point)>> = Selector::new("tooltip.forward");
@@ -43,6 +43,32 @@ impl<T: Data> StackTooltip<T> {
         Self(StackTooltipInternal::new(widget, label))
     }
 
+    /// Create a tooltip showing the widget `content`, which is given the data of `widget`.
+    ///
+    /// Unlike a text, the content is painted in the order of the stack instead of above
+    /// the rest of the window, and text attributes don't apply to it.
+    pub fn with_widget<W: Widget<T> + 'static, C: Widget<T> + 'static>(
+        widget: W,
+        content: C,
+    ) -> Self {
+        Self(StackTooltipInternal::from_content(
+            widget,
+            TooltipContent::Widget(Box::new(content)),
+            Rc::new(Cell::new(true)),
+        ))
+    }
+
+    /// Create a tooltip whose text and visibility are shared with the wrapped widget.
+    ///
+    /// The tooltip is only shown while `enabled` is set.
+    pub(crate) fn from_shared<W: Widget<T> + 'static>(
+        widget: W,
+        text: RichTextCell,
+        enabled: Rc<Cell<bool>>,
+    ) -> Self {
+        Self(StackTooltipInternal::from_shared(widget, text, enabled))
+    }
+
     pub fn set_text_attribute(&mut self, attribute: Attribute) {
         self.0.wrapped_mut().set_text_attribute(attribute);
     }
@@ -92,6 +118,19 @@ impl<T: Data> StackTooltip<T> {
 
         self
     }
+
+    /// Set the maximum width of the tooltip, beyond which its text is wrapped.
+    pub fn set_max_width(&mut self, max_width: f64) {
+        self.0.wrapped_mut().set_max_width(max_width)
+    }
+
+    /// Builder-style method to set the maximum width of the tooltip, beyond which its
+    /// text is wrapped.
+    pub fn with_max_width(mut self, max_width: f64) -> Self {
+        self.set_max_width(max_width);
+
+        self
+    }
 }
 
 impl<T: Data> Widget<T> for StackTooltip<T> {
@@ -142,17 +181,27 @@ struct TooltipState<T> {
     label_size: Option<Size>,
 }
 
-type RichTextCell = Rc<RefCell<(RichText, Vec<YetAnotherAttribute>)>>;
+pub(crate) type RichTextCell = Rc<RefCell<(RichText, Vec<YetAnotherAttribute>)>>;
 type BackgroundCell = Rc<RefCell<Option<KeyOrValue<Color>>>>;
 type BorderCell = Rc<RefCell<(Option<KeyOrValue<Color>>, Option<f64>)>>;
+type MaxWidthCell = Rc<Cell<Option<f64>>>;
+
+/// What a stack tooltip shows.
+enum TooltipContent<T> {
+    Text(RichTextCell),
+    Widget(Box<dyn Widget<T>>),
+}
 
 struct StackTooltipInternal<T> {
     widget: WidgetPod<TooltipState<T>, Stack<TooltipState<T>>>,
     label_id: Option<WidgetId>,
-    text: RichTextCell,
+    /// The text of the tooltip, if it shows a text.
+    text: Option<RichTextCell>,
     background: BackgroundCell,
     border: BorderCell,
+    max_width: MaxWidthCell,
     use_crosshair: bool,
+    enabled: Rc<Cell<bool>>,
 }
 
 fn make_state<T: Data>(data: T) -> TooltipState<T> {
@@ -176,15 +225,42 @@ impl<T: Data> StackTooltipInternal<T> {
         let attrs = vec![];
 
         let text = Rc::new(RefCell::new((rich_text, attrs)));
+        Self::from_shared(widget, text, Rc::new(Cell::new(true)))
+    }
+
+    fn from_shared<W: Widget<T> + 'static>(
+        widget: W,
+        text: RichTextCell,
+        enabled: Rc<Cell<bool>>,
+    ) -> StackTooltipActual<T> {
+        Self::from_content(widget, TooltipContent::Text(text), enabled)
+    }
+
+    fn from_content<W: Widget<T> + 'static>(
+        widget: W,
+        content: TooltipContent<T>,
+        enabled: Rc<Cell<bool>>,
+    ) -> StackTooltipActual<T> {
+        let text = match &content {
+            TooltipContent::Text(text) => Some(text.clone()),
+            TooltipContent::Widget(_) => None,
+        };
         let background = BackgroundCell::default();
         let border = BorderCell::default();
+        let max_width = MaxWidthCell::default();
         let label_id = WidgetId::next();
         let stack = Stack::new()
             .with_child(widget.lens(TooltipState::data))
             .with_positioned_child(
                 Either::new(
                     |state: &TooltipState<T>, _| state.show && is_some_position(&state.position),
-                    TooltipLabel::new(text.clone(), label_id, background.clone(), border.clone()),
+                    TooltipLabel::new(
+                        content,
+                        label_id,
+                        background.clone(),
+                        border.clone(),
+                        max_width.clone(),
+                    ),
                     SizedBox::empty(),
                 ),
                 StackChildParams::dynamic(|TooltipState { position, .. }: &TooltipState<T>, _| {
@@ -201,19 +277,22 @@ impl<T: Data> StackTooltipInternal<T> {
                 text,
                 background,
                 border,
+                max_width,
                 use_crosshair: false,
+                enabled,
             },
         )
     }
 
     pub fn set_text_attribute(&mut self, attribute: Attribute) {
-        self.text
-            .borrow_mut()
-            .0
-            .add_attribute(0.., attribute.clone());
+        let text = match &self.text {
+            Some(text) => text,
+            None => return,
+        };
+        text.borrow_mut().0.add_attribute(0.., attribute.clone());
         match attribute.try_into() {
-            Ok(attr) => self.text.borrow_mut().1.push(attr),
-            Err(attrs) => self.text.borrow_mut().1.extend(attrs),
+            Ok(attr) => text.borrow_mut().1.push(attr),
+            Err(attrs) => text.borrow_mut().1.extend(attrs),
         };
     }
 
@@ -232,6 +311,10 @@ impl<T: Data> StackTooltipInternal<T> {
     pub fn set_crosshair(&mut self, crosshair: bool) {
         self.use_crosshair = crosshair
     }
+
+    pub fn set_max_width(&mut self, max_width: f64) {
+        self.max_width.set(Some(max_width));
+    }
 }
 
 impl<T: Data> Widget<TooltipState<T>> for StackTooltipInternal<T> {
@@ -256,7 +339,7 @@ impl<T: Data> Widget<TooltipState<T>> for StackTooltipInternal<T> {
         } else {
             None
         } {
-            if ctx.is_hot() && ctx.size().to_rect().contains(pos) {
+            if ctx.is_hot() && self.enabled.get() && ctx.size().to_rect().contains(pos) {
                 let mut x = pos.x;
                 let mut y = pos.y;
 
@@ -271,6 +354,9 @@ impl<T: Data> Widget<TooltipState<T>> for StackTooltipInternal<T> {
                     {
                         y -= size.height
                     };
+                    // keep the label within the window when it doesn't fit on either side
+                    x = x.max(-ctx.window_origin().x);
+                    y = y.max(-ctx.window_origin().y);
                 }
 
                 data.position = StackChildPosition::new()
@@ -347,34 +433,67 @@ impl<T: Data> Widget<TooltipState<T>> for StackTooltipInternal<T> {
     }
 }
 
-struct TooltipLabel {
+struct TooltipLabel<T> {
     id: WidgetId,
-    label: WidgetPod<RichText, RawLabel<RichText>>,
-    text: RichTextCell,
+    body: TooltipBody<T>,
     background: BackgroundCell,
     border: BorderCell,
+    max_width: MaxWidthCell,
 }
 
-impl TooltipLabel {
-    pub fn new(
+enum TooltipBody<T> {
+    /// A text, painted above the rest of the window.
+    Text {
+        label: Box<WidgetPod<RichText, RawLabel<RichText>>>,
         text: RichTextCell,
+        /// The width the text was wrapped at during the last layout, if it was wrapped.
+        wrap_width: Option<f64>,
+    },
+    Widget(Box<WidgetPod<T, Box<dyn Widget<T>>>>),
+}
+
+impl<T> TooltipLabel<T> {
+    pub fn new(
+        content: TooltipContent<T>,
         id: WidgetId,
         background: BackgroundCell,
         border: BorderCell,
+        max_width: MaxWidthCell,
     ) -> Self {
-        let label = WidgetPod::new(Label::raw());
+        let body = match content {
+            TooltipContent::Text(text) => TooltipBody::Text {
+                label: Box::new(WidgetPod::new(Label::raw())),
+                text,
+                wrap_width: None,
+            },
+            TooltipContent::Widget(widget) => TooltipBody::Widget(Box::new(WidgetPod::new(widget))),
+        };
 
         Self {
             id,
-            label,
-            text,
+            body,
             background,
             border,
+            max_width,
         }
     }
 }
 
-impl<T: Data> Widget<TooltipState<T>> for TooltipLabel {
+/// The constraints of the text of a tooltip, whose width is at most `max_width`.
+fn label_constraints(bc: &BoxConstraints, max_width: Option<f64>) -> BoxConstraints {
+    match max_width {
+        Some(max_width) => {
+            let max = Size::new(bc.max().width.min(max_width), bc.max().height);
+            BoxConstraints::new(
+                Size::new(bc.min().width.min(max.width), bc.min().height),
+                max,
+            )
+        }
+        None => *bc,
+    }
+}
+
+impl<T: Data> Widget<TooltipState<T>> for TooltipLabel<T> {
     fn event(
         &mut self,
         ctx: &mut druid::EventCtx,
@@ -407,41 +526,75 @@ impl<T: Data> Widget<TooltipState<T>> for TooltipLabel {
             }
         }
 
-        self.label
-            .event(ctx, event, &mut self.text.borrow_mut().0, env)
+        match &mut self.body {
+            TooltipBody::Text { label, text, .. } => {
+                label.event(ctx, event, &mut text.borrow_mut().0, env)
+            }
+            TooltipBody::Widget(widget) => widget.event(ctx, event, &mut data.data, env),
+        }
     }
 
     fn lifecycle(
         &mut self,
         ctx: &mut druid::LifeCycleCtx,
         event: &druid::LifeCycle,
-        _data: &TooltipState<T>,
+        data: &TooltipState<T>,
         env: &druid::Env,
     ) {
-        self.label.lifecycle(ctx, event, &self.text.borrow().0, env)
+        match &mut self.body {
+            TooltipBody::Text { label, text, .. } => {
+                label.lifecycle(ctx, event, &text.borrow().0, env)
+            }
+            TooltipBody::Widget(widget) => widget.lifecycle(ctx, event, &data.data, env),
+        }
     }
 
     fn update(
         &mut self,
         ctx: &mut druid::UpdateCtx,
         _old_data: &TooltipState<T>,
-        _data: &TooltipState<T>,
+        data: &TooltipState<T>,
         env: &druid::Env,
     ) {
-        self.label.update(ctx, &self.text.borrow().0, env)
+        match &mut self.body {
+            TooltipBody::Text { label, text, .. } => label.update(ctx, &text.borrow().0, env),
+            TooltipBody::Widget(widget) => widget.update(ctx, &data.data, env),
+        }
     }
 
     fn layout(
         &mut self,
         ctx: &mut druid::LayoutCtx,
         bc: &druid::BoxConstraints,
-        _data: &TooltipState<T>,
+        data: &TooltipState<T>,
         env: &druid::Env,
     ) -> druid::Size {
-        self.label.layout(ctx, bc, &self.text.borrow().0, env)
+        let max_width = self.max_width.get();
+        let bc = label_constraints(bc, max_width);
+        match &mut self.body {
+            TooltipBody::Text {
+                label,
+                text,
+                wrap_width,
+            } => {
+                let line_break_mode = if max_width.is_some() {
+                    LineBreaking::WordWrap
+                } else {
+                    LineBreaking::Overflow
+                };
+                label.widget_mut().set_line_break_mode(line_break_mode);
+                *wrap_width = max_width.map(|_| bc.max().width);
+                label.layout(ctx, &bc, &text.borrow().0, env)
+            }
+            TooltipBody::Widget(widget) => {
+                let size = widget.layout(ctx, &bc, &data.data, env);
+                widget.set_origin(ctx, Point::ORIGIN);
+                size
+            }
+        }
     }
 
-    fn paint(&mut self, ctx: &mut druid::PaintCtx, _data: &TooltipState<T>, env: &druid::Env) {
+    fn paint(&mut self, ctx: &mut druid::PaintCtx, data: &TooltipState<T>, env: &druid::Env) {
         let mut rect = ctx.size().to_rect();
         rect.x0 -= 2.0;
         rect.y1 += 2.0;
@@ -464,9 +617,21 @@ impl<T: Data> Widget<TooltipState<T>> for TooltipLabel {
             env.get(druid::theme::TEXTBOX_BORDER_WIDTH)
         };
 
-        let mut text = ctx.text().new_text_layout(<&str as Into<Arc<str>>>::into(
-            self.text.borrow().0.as_str(),
-        ));
+        let (rich_text, wrap_width) = match &mut self.body {
+            TooltipBody::Text {
+                text, wrap_width, ..
+            } => (text.borrow(), *wrap_width),
+            TooltipBody::Widget(widget) => {
+                ctx.fill(rect, &fill_brush);
+                widget.paint(ctx, &data.data, env);
+                ctx.stroke(rect, &border_brush, border_width);
+                return;
+            }
+        };
+
+        let mut text = ctx
+            .text()
+            .new_text_layout(<&str as Into<Arc<str>>>::into(rich_text.0.as_str()));
         text = text.default_attribute(TextAttribute::FontFamily(
             env.get(druid::theme::UI_FONT).family,
         ));
@@ -474,9 +639,12 @@ impl<T: Data> Widget<TooltipState<T>> for TooltipLabel {
         text = text.default_attribute(TextAttribute::Style(env.get(druid::theme::UI_FONT).style));
         text = text.default_attribute(TextAttribute::Weight(env.get(druid::theme::UI_FONT).weight));
         text = text.default_attribute(TextAttribute::TextColor(env.get(druid::theme::TEXT_COLOR)));
-        for attribute in self.text.borrow().1.iter() {
+        for attribute in rich_text.1.iter() {
             text = text.default_attribute(attribute.clone().resolve(env));
         }
+        if let Some(wrap_width) = wrap_width {
+            text = text.max_width(wrap_width);
+        }
         if let Ok(text) = text.build() {
             ctx.paint_with_z_index(1_000_000, move |ctx| {
                 ctx.fill(rect, &fill_brush);
@@ -538,7 +706,7 @@ impl From<RichText> for PlainOrRich {
     }
 }
 
-enum YetAnotherAttribute {
+pub(crate) enum YetAnotherAttribute {
     Unresolved(Attribute),
     UnresolvedFamily(Attribute),
     UnresolvedSize(Attribute),
@@ -633,3 +801,67 @@ impl Clone for YetAnotherAttribute {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::{Record, Recording, TestWidgetExt};
+    use druid::{Env, Event, MouseButton, MouseEvent};
+
+    fn mouse_move(pos: Point) -> Event {
+        Event::MouseMove(MouseEvent {
+            pos,
+            window_pos: pos,
+            buttons: Default::default(),
+            mods: Default::default(),
+            count: 0,
+            focus: false,
+            button: MouseButton::None,
+            wheel_delta: Default::default(),
+        })
+    }
+
+    #[test]
+    fn max_width_bounds_label() {
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(800.0, 600.0));
+        let wrapped = label_constraints(&bc, Some(200.0));
+        assert_eq!(wrapped.max(), Size::new(200.0, 600.0));
+        // a long text measured without constraints is clamped to the wrap width
+        assert_eq!(wrapped.constrain((1500.0, 20.0)).width, 200.0);
+
+        let tight = BoxConstraints::tight(Size::new(300.0, 20.0));
+        assert_eq!(label_constraints(&tight, Some(200.0)).max().width, 200.0);
+        assert_eq!(label_constraints(&bc, None), bc);
+    }
+
+    #[test]
+    fn widget_content() {
+        let recording = Recording::default();
+        let content = Label::new(|data: &u32, _: &Env| format!("{} items", data))
+            .fix_size(80.0, 30.0)
+            .record(&recording);
+        let tooltip = StackTooltip::with_widget(SizedBox::empty().expand(), content);
+
+        Harness::create_simple(3u32, tooltip, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            harness.paint();
+            // hidden until the mouse is over the widget
+            assert!(!recording
+                .drain()
+                .any(|record| matches!(record, Record::Paint)));
+
+            harness.event(mouse_move(Point::new(50.0, 50.0)));
+            // finish moving the tooltip to the mouse
+            harness.event(Event::AnimFrame(1_000_000_000));
+            harness.just_layout();
+            harness.paint();
+            let records = recording.drain().collect::<Vec<_>>();
+            assert!(records.iter().any(
+                |record| matches!(record, Record::Layout(size) if *size == Size::new(80.0, 30.0))
+            ));
+            assert!(records.iter().any(|record| matches!(record, Record::Paint)));
+        });
+    }
+}
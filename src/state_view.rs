@@ -0,0 +1,304 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A widget switching between loading, empty, error and content views.
+
+use std::rc::Rc;
+
+use druid::widget::prelude::*;
+use druid::widget::{Label, Spinner};
+use druid::{theme, Point, WidgetExt, WidgetPod};
+
+use crate::animation::{Animated, AnimationCurve};
+use crate::prism::{Prism, PrismWrap};
+
+/// The state of some data which is loaded, for example by a [`FutureWidget`].
+///
+/// [`FutureWidget`]: crate::FutureWidget
+#[derive(Clone, Data, Debug)]
+pub enum LoadState<T, E> {
+    /// The data is being loaded.
+    Loading,
+    /// Loading the data failed with this error.
+    Error(E),
+    /// The data was loaded.
+    Loaded(T),
+}
+
+struct LoadedPrism;
+
+impl<T: Data, E> Prism<LoadState<T, E>, T> for LoadedPrism {
+    fn get(&self, data: &LoadState<T, E>) -> Option<T> {
+        match data {
+            LoadState::Loaded(inner) => Some(inner.clone()),
+            _ => None,
+        }
+    }
+
+    fn put(&self, data: &mut LoadState<T, E>, inner: T) {
+        *data = LoadState::Loaded(inner);
+    }
+}
+
+struct ErrorPrism;
+
+impl<T, E: Data> Prism<LoadState<T, E>, E> for ErrorPrism {
+    fn get(&self, data: &LoadState<T, E>) -> Option<E> {
+        match data {
+            LoadState::Error(inner) => Some(inner.clone()),
+            _ => None,
+        }
+    }
+
+    fn put(&self, data: &mut LoadState<T, E>, inner: E) {
+        *data = LoadState::Error(inner);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StateKind {
+    Loading,
+    Empty,
+    Error,
+    Content,
+}
+
+type StateBuilder<T, E> = Box<dyn Fn() -> Box<dyn Widget<LoadState<T, E>>>>;
+type StatePod<T, E> = WidgetPod<LoadState<T, E>, Box<dyn Widget<LoadState<T, E>>>>;
+
+/// A widget which shows one of four views, depending on a [`LoadState`]:
+///
+/// - a loading view while the data is loading, by default a spinner.
+/// - an error view if loading failed.
+/// - an empty view if the data was loaded, but is considered empty by the
+///   predicate passed to [`with_empty`](StateView::with_empty).
+/// - the content view otherwise.
+///
+/// The views are built when they are shown, and faded in when switching between them.
+pub struct StateView<T, E> {
+    loading: StateBuilder<T, E>,
+    error: StateBuilder<T, E>,
+    empty: Option<StateBuilder<T, E>>,
+    content: StateBuilder<T, E>,
+    is_empty: Rc<dyn Fn(&T) -> bool>,
+    current: Option<(StateKind, StatePod<T, E>)>,
+    fade: Animated<f64>,
+}
+
+impl<T: Data, E: Data> StateView<T, E> {
+    /// Create a new `StateView`, building the content view with `content`.
+    pub fn new<W: Widget<T> + 'static>(content: impl Fn() -> W + 'static) -> Self {
+        StateView {
+            loading: Box::new(|| Spinner::new().center().boxed()),
+            error: Box::new(|| PrismWrap::new(Label::new("Error").center(), ErrorPrism).boxed()),
+            empty: None,
+            content: Box::new(move || PrismWrap::new(content(), LoadedPrism).boxed()),
+            is_empty: Rc::new(|_| false),
+            current: None,
+            fade: Animated::jump(0.0)
+                .duration(0.15)
+                .curve(AnimationCurve::EASE_OUT),
+        }
+    }
+
+    /// Builder-style method for setting the view shown while loading.
+    pub fn with_loading<W: Widget<()> + 'static>(
+        mut self,
+        loading: impl Fn() -> W + 'static,
+    ) -> Self {
+        self.loading = Box::new(move || loading().lens(druid::lens::Unit).boxed());
+        self
+    }
+
+    /// Builder-style method for setting the view shown if loading failed.
+    pub fn with_error<W: Widget<E> + 'static>(mut self, error: impl Fn() -> W + 'static) -> Self {
+        self.error = Box::new(move || PrismWrap::new(error(), ErrorPrism).boxed());
+        self
+    }
+
+    /// Builder-style method for setting the view shown if the loaded data is empty,
+    /// according to `is_empty`.
+    pub fn with_empty<W: Widget<T> + 'static>(
+        mut self,
+        is_empty: impl Fn(&T) -> bool + 'static,
+        empty: impl Fn() -> W + 'static,
+    ) -> Self {
+        self.is_empty = Rc::new(is_empty);
+        self.empty = Some(Box::new(move || {
+            PrismWrap::new(empty(), LoadedPrism).boxed()
+        }));
+        self
+    }
+
+    /// Builder-style method for setting the duration of the fade between views,
+    /// in seconds.
+    pub fn with_transition_duration(mut self, duration: f64) -> Self {
+        self.set_transition_duration(duration);
+        self
+    }
+
+    /// Set the duration of the fade between views, in seconds.
+    pub fn set_transition_duration(&mut self, duration: f64) {
+        self.fade.set_duration(duration);
+    }
+
+    fn kind(&self, data: &LoadState<T, E>) -> StateKind {
+        match data {
+            LoadState::Loading => StateKind::Loading,
+            LoadState::Error(_) => StateKind::Error,
+            LoadState::Loaded(inner) if self.empty.is_some() && (self.is_empty)(inner) => {
+                StateKind::Empty
+            }
+            LoadState::Loaded(_) => StateKind::Content,
+        }
+    }
+
+    /// Rebuilds the view if the kind of state changed. Returns true if it did.
+    fn rebuild_if_needed(&mut self, data: &LoadState<T, E>) -> bool {
+        let kind = self.kind(data);
+        if matches!(&self.current, Some((current, _)) if *current == kind) {
+            return false;
+        }
+        let widget = match kind {
+            StateKind::Loading => (self.loading)(),
+            StateKind::Error => (self.error)(),
+            StateKind::Empty => (self.empty.as_ref().unwrap())(),
+            StateKind::Content => (self.content)(),
+        };
+        self.current = Some((kind, WidgetPod::new(widget)));
+        true
+    }
+}
+
+impl<T: Data, E: Data> Widget<LoadState<T, E>> for StateView<T, E> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut LoadState<T, E>, env: &Env) {
+        if let Event::AnimFrame(nanos) = event {
+            self.fade.update(ctx, *nanos);
+        }
+        if let Some((_, inner)) = &mut self.current {
+            inner.event(ctx, event, data, env);
+        }
+    }
+
+    fn lifecycle(
+        &mut self,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &LoadState<T, E>,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            self.rebuild_if_needed(data);
+        }
+        if let Some((_, inner)) = &mut self.current {
+            inner.lifecycle(ctx, event, data, env);
+        }
+    }
+
+    fn update(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _old_data: &LoadState<T, E>,
+        data: &LoadState<T, E>,
+        env: &Env,
+    ) {
+        if self.rebuild_if_needed(data) {
+            ctx.children_changed();
+            ctx.request_layout();
+            self.fade.jump_to_value(1.0);
+            self.fade.animate(ctx, 0.0);
+        } else if let Some((_, inner)) = &mut self.current {
+            inner.update(ctx, data, env);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &LoadState<T, E>,
+        env: &Env,
+    ) -> Size {
+        if let Some((_, inner)) = &mut self.current {
+            let size = inner.layout(ctx, bc, data, env);
+            inner.set_origin(ctx, Point::ORIGIN);
+            size
+        } else {
+            bc.min()
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &LoadState<T, E>, env: &Env) {
+        if let Some((_, inner)) = &mut self.current {
+            inner.paint(ctx, data, env);
+        }
+        if self.fade.animating() {
+            let rect = ctx.size().to_rect();
+            let color = env
+                .get(theme::WINDOW_BACKGROUND_COLOR)
+                .with_alpha(self.fade.get());
+            ctx.fill(rect, &color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WidgetExt as _;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::{Record, Recording, TestWidgetExt};
+    use druid::widget::SizedBox;
+    use druid::Selector;
+
+    const SET: Selector<LoadState<u32, String>> =
+        Selector::new("druid-widget-nursery.test.set-state");
+
+    #[test]
+    fn switch_views() {
+        fn view<T: Data>(recording: &Recording) -> impl Fn() -> Box<dyn Widget<T>> {
+            let recording = recording.clone();
+            move || SizedBox::empty().record(&recording).boxed()
+        }
+
+        let recordings: Vec<Recording> = (0..4).map(|_| Recording::default()).collect();
+        let state_view = StateView::new(view(&recordings[3]))
+            .with_loading(view(&recordings[0]))
+            .with_error(view(&recordings[1]))
+            .with_empty(|count: &u32| *count == 0, view(&recordings[2]))
+            .on_command(SET, |_, state, data| *data = state.clone());
+
+        // the indices of the views laid out since the last check
+        let shown = || {
+            (0..4)
+                .filter(|index| {
+                    recordings[*index]
+                        .drain()
+                        .any(|record| matches!(record, Record::Layout(_)))
+                })
+                .collect::<Vec<usize>>()
+        };
+
+        Harness::create_simple(LoadState::Loading, state_view, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            assert_eq!(shown(), [0]);
+
+            harness.submit_command(SET.with(LoadState::Loaded(0)));
+            harness.just_layout();
+            assert_eq!(shown(), [2]);
+
+            harness.submit_command(SET.with(LoadState::Loaded(3)));
+            harness.just_layout();
+            assert_eq!(shown(), [3]);
+
+            harness.submit_command(SET.with(LoadState::Error("failed".into())));
+            harness.just_layout();
+            assert_eq!(shown(), [1]);
+
+            harness.submit_command(SET.with(LoadState::Loading));
+            harness.just_layout();
+            assert_eq!(shown(), [0]);
+        });
+    }
+}
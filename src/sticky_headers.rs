@@ -0,0 +1,254 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A sectioned list whose section headers stick to the top of the viewport.
+
+use std::cmp::Ordering;
+
+use druid::widget::prelude::*;
+use druid::widget::ListIter;
+use druid::{Point, Rect, WidgetPod};
+
+type SectionBuilder<S> = Box<dyn Fn() -> Box<dyn Widget<S>>>;
+type SectionPod<S> = WidgetPod<S, Box<dyn Widget<S>>>;
+
+struct Section<S> {
+    header: SectionPod<S>,
+    content: SectionPod<S>,
+    /// The position of the header in the list, when it isn't pinned.
+    top: f64,
+    /// The end of the content of the section.
+    bottom: f64,
+}
+
+/// A vertical list of sections, each made of a header and some content.
+///
+/// When used inside a [`Scroll`], the header of the section at the top of the
+/// visible area stays pinned to the top of the viewport, until the header of the
+/// next section pushes it away. The visible area is taken from
+/// [`LifeCycle::ViewContextChanged`], so the `StickyHeaders` doesn't need to be
+/// the direct child of the `Scroll`.
+///
+/// Headers are painted above the content, and should have an opaque background.
+///
+/// Like [`List`], this works on any [`ListIter`], one item per section.
+///
+/// [`Scroll`]: druid::widget::Scroll
+/// [`List`]: druid::widget::List
+pub struct StickyHeaders<S> {
+    header: SectionBuilder<S>,
+    content: SectionBuilder<S>,
+    sections: Vec<Section<S>>,
+    viewport: Rect,
+}
+
+impl<S: Data> StickyHeaders<S> {
+    /// Create a new `StickyHeaders`, building the header and the content of each
+    /// section with the given closures.
+    pub fn new<H: Widget<S> + 'static, W: Widget<S> + 'static>(
+        header: impl Fn() -> H + 'static,
+        content: impl Fn() -> W + 'static,
+    ) -> Self {
+        StickyHeaders {
+            header: Box::new(move || Box::new(header())),
+            content: Box::new(move || Box::new(content())),
+            sections: Vec::new(),
+            viewport: Rect::ZERO,
+        }
+    }
+
+    fn update_child_count(&mut self, data: &impl ListIter<S>) -> bool {
+        let len = self.sections.len();
+        match len.cmp(&data.data_len()) {
+            Ordering::Greater => self.sections.truncate(data.data_len()),
+            Ordering::Less => data.for_each(|_, i| {
+                if i >= len {
+                    self.sections.push(Section {
+                        header: WidgetPod::new((self.header)()),
+                        content: WidgetPod::new((self.content)()),
+                        top: 0.0,
+                        bottom: 0.0,
+                    });
+                }
+            }),
+            Ordering::Equal => (),
+        }
+        len != data.data_len()
+    }
+
+    /// Returns the index of the section whose header is pinned.
+    fn pinned(&self) -> Option<usize> {
+        let top = self.viewport.y0;
+        self.sections
+            .iter()
+            .position(|section| section.top < top && top < section.bottom)
+    }
+}
+
+impl<S: Data, T: ListIter<S>> Widget<T> for StickyHeaders<S> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        // headers are on top, so they get events first
+        let mut sections = self.sections.iter_mut();
+        data.for_each_mut(|child_data, _| {
+            if let Some(section) = sections.next() {
+                section.header.event(ctx, event, child_data, env);
+            }
+        });
+        if ctx.is_handled() {
+            return;
+        }
+        let mut sections = self.sections.iter_mut();
+        data.for_each_mut(|child_data, _| {
+            if let Some(section) = sections.next() {
+                section.content.event(ctx, event, child_data, env);
+            }
+        });
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            if self.update_child_count(data) {
+                ctx.children_changed();
+            }
+        }
+        if let LifeCycle::ViewContextChanged(view_context) = event {
+            if view_context.clip.y0 != self.viewport.y0 {
+                ctx.request_layout();
+            }
+            self.viewport = view_context.clip;
+        }
+
+        let mut sections = self.sections.iter_mut();
+        data.for_each(|child_data, _| {
+            if let Some(section) = sections.next() {
+                section.header.lifecycle(ctx, event, child_data, env);
+                section.content.lifecycle(ctx, event, child_data, env);
+            }
+        });
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        let mut sections = self.sections.iter_mut();
+        data.for_each(|child_data, _| {
+            if let Some(section) = sections.next() {
+                section.header.update(ctx, child_data, env);
+                section.content.update(ctx, child_data, env);
+            }
+        });
+
+        if self.update_child_count(data) {
+            ctx.children_changed();
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let content_bc = BoxConstraints::new(
+            Size::new(bc.min().width, 0.0),
+            Size::new(bc.max().width, f64::INFINITY),
+        );
+        let mut width = bc.min().width;
+        let mut sections = self.sections.iter_mut();
+        let mut content_sizes = Vec::new();
+        data.for_each(|child_data, _| {
+            if let Some(section) = sections.next() {
+                let size = section.content.layout(ctx, &content_bc, child_data, env);
+                width = width.max(size.width);
+                content_sizes.push(size);
+            }
+        });
+
+        // headers span the whole width
+        let header_bc = BoxConstraints::new(Size::new(width, 0.0), Size::new(width, f64::INFINITY));
+        let mut y = 0.0;
+        let mut sections = self.sections.iter_mut().zip(content_sizes);
+        data.for_each(|child_data, _| {
+            if let Some((section, content_size)) = sections.next() {
+                let header_size = section.header.layout(ctx, &header_bc, child_data, env);
+                section.top = y;
+                section
+                    .content
+                    .set_origin(ctx, Point::new(0.0, y + header_size.height));
+                y += header_size.height + content_size.height;
+                section.bottom = y;
+            }
+        });
+
+        let pinned = self.pinned();
+        for (i, section) in self.sections.iter_mut().enumerate() {
+            let mut header_y = section.top;
+            if Some(i) == pinned {
+                let header_height = section.header.layout_rect().height();
+                header_y = self.viewport.y0.min(section.bottom - header_height);
+            }
+            section.header.set_origin(ctx, Point::new(0.0, header_y));
+        }
+
+        bc.constrain(Size::new(width, y))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let pinned = self.pinned();
+        let mut sections = self.sections.iter_mut();
+        data.for_each(|child_data, _| {
+            if let Some(section) = sections.next() {
+                section.content.paint(ctx, child_data, env);
+            }
+        });
+        let mut sections = self.sections.iter_mut().enumerate();
+        data.for_each(|child_data, _| {
+            if let Some((i, section)) = sections.next() {
+                if Some(i) != pinned {
+                    section.header.paint(ctx, child_data, env);
+                }
+            }
+        });
+        // the pinned header is painted last, above everything else
+        if let Some(pinned) = pinned {
+            let mut sections = self.sections.iter_mut();
+            data.for_each(|child_data, i| {
+                if let Some(section) = sections.next() {
+                    if i == pinned {
+                        section.header.paint(ctx, child_data, env);
+                    }
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::im::Vector;
+    use druid::widget::SizedBox;
+
+    #[test]
+    fn pinned_section() {
+        let mut headers = StickyHeaders::<u32>::new(SizedBox::empty, SizedBox::empty);
+        headers.update_child_count(&Vector::from(vec![0, 1, 2]));
+        for (section, (top, bottom)) in
+            headers
+                .sections
+                .iter_mut()
+                .zip([(0.0, 100.0), (100.0, 250.0), (250.0, 300.0)])
+        {
+            section.top = top;
+            section.bottom = bottom;
+        }
+        let mut pinned_at = |top: f64| {
+            headers.viewport = Rect::new(0.0, top, 400.0, top + 200.0);
+            headers.pinned()
+        };
+
+        // a header at the top of the viewport is in its place, not pinned
+        assert_eq!(pinned_at(0.0), None);
+        assert_eq!(pinned_at(50.0), Some(0));
+        assert_eq!(pinned_at(99.0), Some(0));
+        assert_eq!(pinned_at(100.0), None);
+        assert_eq!(pinned_at(101.0), Some(1));
+        assert_eq!(pinned_at(299.0), Some(2));
+        // past the last section
+        assert_eq!(pinned_at(300.0), None);
+        assert_eq!(pinned_at(-20.0), None);
+    }
+}
@@ -0,0 +1,760 @@
+// Copyright 2023 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A tab strip with closable, reorderable tabs and a content area showing the active tab.
+
+use std::rc::Rc;
+
+use druid::commands::CLOSE_WINDOW;
+use druid::im::Vector;
+use druid::kurbo::{Circle, Line};
+use druid::widget::prelude::*;
+use druid::widget::{CrossAxisAlignment, Flex, Label};
+use druid::{theme, Affine, Insets, Lens, Point, Rect, TextLayout, WidgetExt, WidgetPod};
+
+use crate::dropdown::DROPDOWN_SHOW;
+use crate::Dropdown;
+
+const TAB_PADDING: Insets = Insets::uniform_xy(10., 5.);
+const MIN_TAB_WIDTH: f64 = 60.;
+const MAX_TAB_WIDTH: f64 = 200.;
+const CLOSE_SIZE: f64 = 7.;
+const CLOSE_GAP: f64 = 8.;
+
+type TitleFn<T, K> = Rc<dyn Fn(&K, &T) -> String>;
+type ContentFn<T, K> = Box<dyn Fn(&K) -> Box<dyn Widget<T>>>;
+type ContentPod<T> = WidgetPod<T, Box<dyn Widget<T>>>;
+
+/// The state of a [`TabView`]: the open tabs, in display order, and the active one.
+///
+/// Each tab is identified by a key, which is handed to the [`TabView`] closures
+/// to build its title and its content.
+#[derive(Clone, Data, Lens, Debug)]
+pub struct TabsState<K: Clone> {
+    pub tabs: Vector<K>,
+    pub active: usize,
+}
+
+impl<K: Data> Default for TabsState<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Data> TabsState<K> {
+    pub fn new() -> Self {
+        TabsState {
+            tabs: Vector::new(),
+            active: 0,
+        }
+    }
+
+    /// The key of the active tab, if there is any tab open.
+    pub fn active_tab(&self) -> Option<&K> {
+        self.tabs.get(self.active)
+    }
+
+    /// Activates the tab with the given key, opening it right after the active
+    /// tab if it is not open yet.
+    pub fn open(&mut self, key: K) {
+        if let Some(index) = self.tabs.iter().position(|k| k.same(&key)) {
+            self.active = index;
+        } else if self.tabs.is_empty() {
+            self.tabs.push_back(key);
+            self.active = 0;
+        } else {
+            self.active += 1;
+            self.tabs.insert(self.active, key);
+        }
+    }
+
+    /// Closes the tab at `index`. If it was active, the tab following it becomes active.
+    pub fn close(&mut self, index: usize) {
+        if index >= self.tabs.len() {
+            return;
+        }
+        self.tabs.remove(index);
+        if index < self.active || self.active >= self.tabs.len() {
+            self.active = self.active.saturating_sub(1);
+        }
+    }
+
+    /// Moves the tab at `from` to `to`, keeping the same tab active.
+    pub fn move_tab(&mut self, from: usize, to: usize) {
+        if from >= self.tabs.len() || to >= self.tabs.len() || from == to {
+            return;
+        }
+        let key = self.tabs.remove(from);
+        self.tabs.insert(to, key);
+        if self.active == from {
+            self.active = to;
+        } else if from < self.active && self.active <= to {
+            self.active -= 1;
+        } else if to <= self.active && self.active < from {
+            self.active += 1;
+        }
+    }
+}
+
+/// A horizontal strip of tabs paired with a content area showing the widget of
+/// the active tab.
+///
+/// Tabs can be activated by clicking on them, reordered by dragging them and
+/// closed with their close button or a middle click. When there are more tabs
+/// than fit the available width, the strip scrolls to keep the active tab visible
+/// and an overflow button lists all open tabs in a dropdown.
+///
+/// The open tabs and the active tab live in a [`TabsState`], accessed through a lens.
+/// Content widgets are built lazily the first time their tab is activated and are
+/// kept around, with their state, until the tab is closed.
+pub struct TabView<T, K, L> {
+    lens: L,
+    strip: WidgetPod<T, TabStrip<T, K, L>>,
+    make_content: ContentFn<T, K>,
+    content: Vec<(K, ContentPod<T>)>,
+}
+
+impl<T: Data, K: Data, L: Lens<T, TabsState<K>> + Clone + 'static> TabView<T, K, L> {
+    /// Create a new `TabView`.
+    ///
+    /// `title` returns the text shown in the tab of a key, while `make_content`
+    /// builds the widget displayed when that tab is active.
+    pub fn new<W: Widget<T> + 'static>(
+        lens: L,
+        title: impl Fn(&K, &T) -> String + 'static,
+        make_content: impl Fn(&K) -> W + 'static,
+    ) -> Self {
+        TabView {
+            strip: WidgetPod::new(TabStrip::new(lens.clone(), Rc::new(title))),
+            lens,
+            make_content: Box::new(move |key| make_content(key).boxed()),
+            content: Vec::new(),
+        }
+    }
+
+    /// Drops the content of closed tabs and builds the content of the active tab.
+    /// Returns true if a new content widget was created.
+    fn sync_content(&mut self, data: &T) -> bool {
+        let make_content = &self.make_content;
+        let content = &mut self.content;
+        self.lens.with(data, |state| {
+            content.retain(|(key, _)| state.tabs.iter().any(|k| k.same(key)));
+            match state.active_tab() {
+                Some(active) if !content.iter().any(|(key, _)| key.same(active)) => {
+                    content.push((active.clone(), WidgetPod::new(make_content(active))));
+                    true
+                }
+                _ => false,
+            }
+        })
+    }
+
+    fn active_content(&mut self, data: &T) -> Option<&mut ContentPod<T>> {
+        let content = &self.content;
+        let index = self.lens.with(data, |state| {
+            let active = state.active_tab()?;
+            content.iter().position(|(key, _)| key.same(active))
+        })?;
+        Some(&mut self.content[index].1)
+    }
+}
+
+impl<T: Data, K: Data, L: Lens<T, TabsState<K>> + Clone + 'static> Widget<T> for TabView<T, K, L> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.strip.event(ctx, event, data, env);
+
+        let active_id = self.active_content(data).map(|pod| pod.id());
+        for (_, pod) in &mut self.content {
+            if Some(pod.id()) == active_id || event.should_propagate_to_hidden() {
+                pod.event(ctx, event, data, env);
+            }
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            self.sync_content(data);
+        }
+        self.strip.lifecycle(ctx, event, data, env);
+        for (_, pod) in &mut self.content {
+            pod.lifecycle(ctx, event, data, env);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.strip.update(ctx, data, env);
+
+        let active_changed = self.lens.with(old_data, |old| {
+            self.lens.with(data, |new| {
+                !old.tabs.same(&new.tabs) || old.active != new.active
+            })
+        });
+        if active_changed {
+            if self.sync_content(data) {
+                ctx.children_changed();
+            }
+            ctx.request_layout();
+        }
+
+        for (_, pod) in &mut self.content {
+            if pod.is_initialized() {
+                pod.update(ctx, data, env);
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let strip_bc = BoxConstraints::new(
+            Size::new(bc.min().width, 0.),
+            Size::new(bc.max().width, f64::INFINITY),
+        );
+        let strip_size = self.strip.layout(ctx, &strip_bc, data, env);
+        self.strip.set_origin(ctx, Point::ORIGIN);
+
+        let content_bc = bc.shrink((0., strip_size.height));
+        let content_size = match self.active_content(data) {
+            Some(pod) => {
+                let size = pod.layout(ctx, &content_bc, data, env);
+                pod.set_origin(ctx, Point::new(0., strip_size.height));
+                size
+            }
+            None => content_bc.min(),
+        };
+
+        bc.constrain(Size::new(
+            strip_size.width.max(content_size.width),
+            strip_size.height + content_size.height,
+        ))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        if let Some(pod) = self.active_content(data) {
+            pod.paint(ctx, data, env);
+        }
+        self.strip.paint(ctx, data, env);
+    }
+}
+
+struct TabDrag {
+    index: usize,
+    start_x: f64,
+    offset: f64,
+}
+
+/// The strip of tab headers of a [`TabView`].
+struct TabStrip<T, K, L> {
+    lens: L,
+    title: TitleFn<T, K>,
+    titles: Vec<String>,
+    layouts: Vec<TextLayout<String>>,
+    tabs: Vec<Rect>,
+    overflow: WidgetPod<T, Box<dyn Widget<T>>>,
+    overflowing: bool,
+    visible_width: f64,
+    scroll: f64,
+    hot: Option<usize>,
+    hot_close: Option<usize>,
+    pressed_close: Option<usize>,
+    drag: Option<TabDrag>,
+}
+
+impl<T: Data, K: Data, L: Lens<T, TabsState<K>> + Clone + 'static> TabStrip<T, K, L> {
+    fn new(lens: L, title: TitleFn<T, K>) -> Self {
+        let overflow_button = Label::new("\u{bb}")
+            .padding(TAB_PADDING)
+            .on_click(|ctx, _, _| ctx.submit_notification(DROPDOWN_SHOW));
+        let make_drop = {
+            let lens = lens.clone();
+            let title = title.clone();
+            move |data: &T, env: &Env| overflow_list(&lens, &title, data, env)
+        };
+        TabStrip {
+            lens,
+            title,
+            titles: Vec::new(),
+            layouts: Vec::new(),
+            tabs: Vec::new(),
+            overflow: WidgetPod::new(Dropdown::new(overflow_button, make_drop).boxed()),
+            overflowing: false,
+            visible_width: 0.,
+            scroll: 0.,
+            hot: None,
+            hot_close: None,
+            pressed_close: None,
+            drag: None,
+        }
+    }
+
+    /// Recomputes the titles of the tabs. Returns true if any of them changed.
+    fn update_titles(&mut self, data: &T) -> bool {
+        let title = &self.title;
+        let titles: Vec<String> = self.lens.with(data, |state| {
+            state.tabs.iter().map(|key| title(key, data)).collect()
+        });
+        if titles == self.titles {
+            return false;
+        }
+        self.layouts = titles
+            .iter()
+            .map(|text| {
+                let mut layout = TextLayout::new();
+                layout.set_text(text.clone());
+                layout
+            })
+            .collect();
+        self.titles = titles;
+        true
+    }
+
+    /// Swaps two neighbouring tabs locally, so that dragging stays consistent
+    /// until the next layout pass.
+    fn swap_tabs(&mut self, a: usize, b: usize) {
+        self.titles.swap(a, b);
+        self.layouts.swap(a, b);
+        self.tabs.swap(a, b);
+        let mut x = self.tabs[a.min(b)].x0.min(self.tabs[a.max(b)].x0);
+        for rect in &mut self.tabs[a.min(b)..=a.max(b)] {
+            *rect = rect.with_origin(Point::new(x, rect.y0));
+            x = rect.x1;
+        }
+    }
+
+    fn close_rect(&self, index: usize) -> Rect {
+        let tab = self.tabs[index];
+        let center = Point::new(tab.x1 - TAB_PADDING.x1 - CLOSE_SIZE / 2., tab.center().y);
+        Rect::from_center_size(center, Size::new(CLOSE_SIZE, CLOSE_SIZE)).inflate(4., 4.)
+    }
+
+    /// Returns the tab under `pos`, and whether `pos` is on its close button.
+    fn hit_test(&self, pos: Point) -> Option<(usize, bool)> {
+        if pos.x > self.visible_width {
+            return None;
+        }
+        let pos = pos + (self.scroll, 0.);
+        let index = self.tabs.iter().position(|rect| rect.contains(pos))?;
+        Some((index, self.close_rect(index).contains(pos)))
+    }
+
+    fn close_tab(&self, index: usize, data: &mut T) {
+        self.lens.with_mut(data, |state| state.close(index));
+    }
+}
+
+/// The content of the overflow dropdown, listing all open tabs.
+fn overflow_list<T: Data, K: Data>(
+    lens: &(impl Lens<T, TabsState<K>> + Clone + 'static),
+    title: &TitleFn<T, K>,
+    data: &T,
+    env: &Env,
+) -> impl Widget<T> {
+    let mut list = Flex::column().cross_axis_alignment(CrossAxisAlignment::Fill);
+    lens.with(data, |state| {
+        for (index, key) in state.tabs.iter().enumerate() {
+            let mut label = Label::new(title(key, data));
+            if index == state.active {
+                label.set_text_color(theme::PRIMARY_LIGHT);
+            }
+            let lens = lens.clone();
+            list.add_child(label.padding(TAB_PADDING).expand_width().on_click(
+                move |ctx, data: &mut T, _| {
+                    lens.with_mut(data, |state| state.active = index);
+                    ctx.submit_command(CLOSE_WINDOW.to(ctx.window_id()));
+                },
+            ));
+        }
+    });
+    list.background(env.get(theme::BACKGROUND_LIGHT))
+        .border(env.get(theme::BORDER_DARK), 1.0)
+}
+
+impl<T: Data, K: Data, L: Lens<T, TabsState<K>> + Clone + 'static> Widget<T> for TabStrip<T, K, L> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if self.overflowing {
+            self.overflow.event(ctx, event, data, env);
+            if ctx.is_handled() {
+                return;
+            }
+        }
+
+        match event {
+            Event::MouseDown(mouse) => match self.hit_test(mouse.pos) {
+                Some((index, true)) if mouse.button.is_left() => {
+                    self.pressed_close = Some(index);
+                    ctx.set_active(true);
+                    ctx.set_handled();
+                }
+                Some((index, false)) if mouse.button.is_left() => {
+                    self.lens.with_mut(data, |state| state.active = index);
+                    self.drag = Some(TabDrag {
+                        index,
+                        start_x: mouse.pos.x,
+                        offset: 0.,
+                    });
+                    ctx.set_active(true);
+                    ctx.set_handled();
+                }
+                Some((index, _)) if mouse.button.is_middle() => {
+                    self.close_tab(index, data);
+                    ctx.set_handled();
+                }
+                _ => {}
+            },
+            Event::MouseMove(mouse) => {
+                if let Some(drag) = &mut self.drag {
+                    drag.offset = mouse.pos.x - drag.start_x;
+                    let index = drag.index;
+                    let center = self.tabs[index].center().x + drag.offset;
+                    let target = if drag.offset > 0.
+                        && index + 1 < self.tabs.len()
+                        && center > self.tabs[index + 1].center().x
+                    {
+                        Some(index + 1)
+                    } else if drag.offset < 0.
+                        && index > 0
+                        && center < self.tabs[index - 1].center().x
+                    {
+                        Some(index - 1)
+                    } else {
+                        None
+                    };
+                    if let Some(target) = target {
+                        let shift = self.tabs[target].width();
+                        if target > index {
+                            drag.start_x += shift;
+                        } else {
+                            drag.start_x -= shift;
+                        }
+                        drag.offset = mouse.pos.x - drag.start_x;
+                        drag.index = target;
+                        self.swap_tabs(index, target);
+                        self.lens
+                            .with_mut(data, |state| state.move_tab(index, target));
+                    }
+                    ctx.request_paint();
+                } else {
+                    let hit = self.hit_test(mouse.pos);
+                    let hot = hit.map(|(index, _)| index);
+                    let hot_close = hit.and_then(|(index, close)| close.then_some(index));
+                    if hot != self.hot || hot_close != self.hot_close {
+                        self.hot = hot;
+                        self.hot_close = hot_close;
+                        ctx.request_paint();
+                    }
+                }
+            }
+            Event::MouseUp(mouse) if mouse.button.is_left() => {
+                if let Some(index) = self.pressed_close.take() {
+                    if self.hit_test(mouse.pos) == Some((index, true)) {
+                        self.close_tab(index, data);
+                    }
+                }
+                if self.drag.take().is_some() {
+                    ctx.request_paint();
+                }
+                ctx.set_active(false);
+            }
+            _ => {}
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        match event {
+            LifeCycle::WidgetAdded => {
+                self.update_titles(data);
+            }
+            LifeCycle::HotChanged(false) => {
+                self.hot = None;
+                self.hot_close = None;
+                ctx.request_paint();
+            }
+            _ => {}
+        }
+        self.overflow.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if !old_data.same(data) && self.update_titles(data) {
+            ctx.request_layout();
+        }
+        let (active_changed, count_changed) = self.lens.with(old_data, |old| {
+            self.lens.with(data, |new| {
+                (old.active != new.active, old.tabs.len() != new.tabs.len())
+            })
+        });
+        // the index of the dragged tab may not point to the same tab anymore
+        if count_changed && self.drag.take().is_some() {
+            ctx.request_paint();
+        }
+        if active_changed {
+            // the strip may need to scroll to show the new active tab
+            ctx.request_layout();
+        }
+        self.overflow.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let mut height: f64 = 0.;
+        let widths: Vec<f64> = self
+            .layouts
+            .iter_mut()
+            .map(|layout| {
+                layout.rebuild_if_needed(ctx.text(), env);
+                let text_size = layout.size();
+                height = height.max(text_size.height);
+                (TAB_PADDING.x_value() + text_size.width + CLOSE_GAP + CLOSE_SIZE)
+                    .clamp(MIN_TAB_WIDTH, MAX_TAB_WIDTH)
+            })
+            .collect();
+        let height = (height + TAB_PADDING.y_value()).max(env.get(theme::BORDERED_WIDGET_HEIGHT));
+
+        let mut x = 0.;
+        self.tabs = widths
+            .into_iter()
+            .map(|width| {
+                let rect = Rect::new(x, 0., x + width, height);
+                x += width;
+                rect
+            })
+            .collect();
+        let total_width = x;
+
+        let available = if bc.max().width.is_finite() {
+            bc.max().width
+        } else {
+            total_width
+        };
+        self.overflowing = total_width > available;
+
+        let overflow_bc = BoxConstraints::new(Size::ZERO, Size::new(available, height));
+        let overflow_size = self.overflow.layout(ctx, &overflow_bc, data, env);
+        self.overflow.set_origin(
+            ctx,
+            Point::new(
+                available - overflow_size.width,
+                (height - overflow_size.height) / 2.,
+            ),
+        );
+
+        if self.overflowing {
+            self.visible_width = available - overflow_size.width;
+            let active = self.lens.with(data, |state| state.active);
+            if let Some(rect) = self.tabs.get(active) {
+                if rect.x1 - self.scroll > self.visible_width {
+                    self.scroll = rect.x1 - self.visible_width;
+                }
+                if rect.x0 < self.scroll {
+                    self.scroll = rect.x0;
+                }
+            }
+            self.scroll = self.scroll.clamp(0., total_width - self.visible_width);
+        } else {
+            self.visible_width = available;
+            self.scroll = 0.;
+        }
+
+        bc.constrain(Size::new(available, height))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let size = ctx.size();
+        ctx.fill(size.to_rect(), &env.get(theme::BACKGROUND_DARK));
+
+        let active = self.lens.with(data, |state| state.active);
+        let dragged = self.drag.as_ref().map(|drag| (drag.index, drag.offset));
+        let visible = Rect::new(0., 0., self.visible_width, size.height);
+
+        ctx.with_save(|ctx| {
+            ctx.clip(visible);
+            ctx.transform(Affine::translate((-self.scroll, 0.)));
+
+            let order = (0..self.tabs.len())
+                .filter(|index| Some(*index) != dragged.map(|(index, _)| index))
+                .chain(dragged.map(|(index, _)| index));
+            for index in order {
+                let offset = match dragged {
+                    Some((dragged, offset)) if dragged == index => offset,
+                    _ => 0.,
+                };
+                ctx.with_save(|ctx| {
+                    ctx.transform(Affine::translate((offset, 0.)));
+                    self.paint_tab(ctx, index, index == active, env);
+                });
+            }
+        });
+
+        if self.overflowing {
+            self.overflow.paint(ctx, data, env);
+        }
+    }
+}
+
+impl<T: Data, K: Data, L: Lens<T, TabsState<K>> + Clone + 'static> TabStrip<T, K, L> {
+    fn paint_tab(&self, ctx: &mut PaintCtx, index: usize, active: bool, env: &Env) {
+        let rect = self.tabs[index];
+        let background = if active {
+            env.get(theme::BACKGROUND_LIGHT)
+        } else if self.hot == Some(index) {
+            env.get(theme::BUTTON_DARK)
+        } else {
+            env.get(theme::BACKGROUND_DARK)
+        };
+        ctx.fill(rect, &background);
+        let separator = Line::new((rect.x1 - 0.5, rect.y0), (rect.x1 - 0.5, rect.y1));
+        ctx.stroke(separator, &env.get(theme::BORDER_DARK), 1.);
+
+        let text_area = Rect::new(
+            rect.x0 + TAB_PADDING.x0,
+            rect.y0,
+            rect.x1 - TAB_PADDING.x1 - CLOSE_SIZE - CLOSE_GAP,
+            rect.y1,
+        );
+        let layout = &self.layouts[index];
+        let text_origin = Point::new(
+            text_area.x0,
+            rect.y0 + (rect.height() - layout.size().height) / 2.,
+        );
+        ctx.with_save(|ctx| {
+            ctx.clip(text_area);
+            layout.draw(ctx, text_origin);
+        });
+
+        if active || self.hot == Some(index) {
+            let close = self.close_rect(index);
+            if self.hot_close == Some(index) {
+                let circle = Circle::new(close.center(), close.width() / 2.);
+                ctx.fill(circle, &env.get(theme::BUTTON_LIGHT));
+            }
+            let cross = close.inset(-4.);
+            let color = env.get(theme::TEXT_COLOR);
+            ctx.stroke(Line::new(cross.origin(), (cross.x1, cross.y1)), &color, 1.5);
+            ctx.stroke(
+                Line::new((cross.x0, cross.y1), (cross.x1, cross.y0)),
+                &color,
+                1.5,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::{MouseButton, MouseEvent};
+
+    fn state(tabs: &[u32], active: usize) -> TabsState<u32> {
+        TabsState {
+            tabs: tabs.iter().copied().collect(),
+            active,
+        }
+    }
+
+    fn mouse(pos: Point, button: MouseButton) -> MouseEvent {
+        MouseEvent {
+            pos,
+            window_pos: pos,
+            buttons: Default::default(),
+            mods: Default::default(),
+            count: 1,
+            focus: false,
+            button,
+            wheel_delta: Default::default(),
+        }
+    }
+
+    #[test]
+    fn open_tabs() {
+        let mut tabs = TabsState::new();
+        tabs.open(1);
+        assert_eq!(tabs.tabs, state(&[1], 0).tabs);
+        assert_eq!(tabs.active, 0);
+        // new tabs open right after the active one
+        tabs.open(2);
+        tabs.active = 0;
+        tabs.open(3);
+        assert_eq!(tabs.tabs, state(&[1, 3, 2], 0).tabs);
+        assert_eq!(tabs.active, 1);
+        // an open tab is only activated
+        tabs.open(2);
+        assert_eq!(tabs.tabs, state(&[1, 3, 2], 0).tabs);
+        assert_eq!(tabs.active, 2);
+    }
+
+    #[test]
+    fn close_tabs() {
+        let mut tabs = state(&[1, 2, 3, 4], 2);
+        // closing a tab before the active one keeps the same tab active
+        tabs.close(0);
+        assert_eq!(tabs.tabs, state(&[2, 3, 4], 0).tabs);
+        assert_eq!(tabs.active, 1);
+        // closing the active tab activates the one following it
+        tabs.close(1);
+        assert_eq!(tabs.tabs, state(&[2, 4], 0).tabs);
+        assert_eq!(tabs.active, 1);
+        // or the one before it when it was the last one
+        tabs.close(1);
+        assert_eq!(tabs.tabs, state(&[2], 0).tabs);
+        assert_eq!(tabs.active, 0);
+        tabs.close(5);
+        assert_eq!(tabs.tabs.len(), 1);
+        tabs.close(0);
+        assert!(tabs.tabs.is_empty());
+        assert_eq!(tabs.active_tab(), None);
+    }
+
+    #[test]
+    fn move_tabs() {
+        let mut tabs = state(&[1, 2, 3, 4], 1);
+        // the moved tab stays active
+        tabs.move_tab(1, 3);
+        assert_eq!(tabs.tabs, state(&[1, 3, 4, 2], 0).tabs);
+        assert_eq!(tabs.active, 3);
+        // moving a tab over the active one shifts it
+        tabs.move_tab(0, 3);
+        assert_eq!(tabs.tabs, state(&[3, 4, 2, 1], 0).tabs);
+        assert_eq!(tabs.active, 2);
+        tabs.move_tab(3, 0);
+        assert_eq!(tabs.tabs, state(&[1, 3, 4, 2], 0).tabs);
+        assert_eq!(tabs.active, 3);
+        // out of bounds moves are ignored
+        tabs.move_tab(4, 0);
+        tabs.move_tab(0, 4);
+        assert_eq!(tabs.tabs, state(&[1, 3, 4, 2], 0).tabs);
+        assert_eq!(tabs.active_tab(), Some(&2));
+    }
+
+    #[test]
+    fn close_tab_while_dragging() {
+        let strip = TabStrip::new(
+            druid::lens::Identity,
+            Rc::new(|key: &u32, _: &TabsState<u32>| key.to_string()),
+        );
+
+        Harness::create_simple(state(&[1, 2, 3], 0), strip, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+
+            // drag the last tab, and close the first one with a middle click meanwhile
+            harness.event(Event::MouseDown(mouse(
+                Point::new(125., 10.),
+                MouseButton::Left,
+            )));
+            harness.event(Event::MouseDown(mouse(
+                Point::new(5., 10.),
+                MouseButton::Middle,
+            )));
+            harness.just_layout();
+            harness.event(Event::MouseMove(mouse(
+                Point::new(10., 10.),
+                MouseButton::None,
+            )));
+            harness.event(Event::MouseUp(mouse(
+                Point::new(10., 10.),
+                MouseButton::Left,
+            )));
+
+            assert_eq!(harness.data().tabs, state(&[2, 3], 0).tabs);
+            assert_eq!(harness.data().active_tab(), Some(&3));
+        });
+    }
+}
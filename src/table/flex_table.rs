@@ -1,13 +1,88 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use druid::widget::BackgroundBrush;
+use druid::kurbo::BezPath;
+use druid::widget::{BackgroundBrush, SizedBox};
 use druid::{
-    BoxConstraints, Color, Data, Env, Event, EventCtx, KeyOrValue, LayoutCtx, LifeCycle,
-    LifeCycleCtx, PaintCtx, Point, RenderContext, Size, UpdateCtx, Widget,
+    theme, BoxConstraints, Color, Data, Env, Event, EventCtx, KeyOrValue, LayoutCtx, Lens,
+    LifeCycle, LifeCycleCtx, PaintCtx, Point, Rect, RenderContext, Size, UpdateCtx, Vec2, Widget,
 };
 
 use super::{ComplexTableColumnWidth, TableCellVerticalAlignment, TableColumnWidth, TableRow};
+use crate::DynLens;
+
+crate::keys! {
+    /// The index of the row of a [`FlexTable`] cell, set in the env of the cell.
+    ROW_IDX: u64,
+    /// The index of the column of a [`FlexTable`] cell, set in the env of the cell.
+    COL_IDX: u64,
+    /// The number of columns of the [`FlexTable`], set in the env of each cell.
+    TOTAL_COLUMNS: u64,
+}
+
+crate::selectors! {
+    /// Notification sent by a [`FlexTable`] with selectable rows when a row was clicked,
+    /// with the index of the row.
+    FLEX_TABLE_ROW_CLICKED: usize,
+    /// Notification moving the row at the first index of the payload to the second index,
+    /// which is its index after the move. For example, a drag handle cell can find its
+    /// row with [`ROW_IDX`].
+    FLEX_TABLE_MOVE_ROW: (usize, usize),
+}
+
+/// The env of the cells, telling each cell its position.
+///
+/// The env of the table is cloned once per pass and only the position keys are changed
+/// for each cell, instead of keeping or cloning a full env for every cell.
+struct CellEnv(Env);
+
+impl CellEnv {
+    fn new(env: &Env, column_count: usize) -> Self {
+        CellEnv(
+            env.clone()
+                .adding(ROW_IDX, 0u64)
+                .adding(COL_IDX, 0u64)
+                .adding(TOTAL_COLUMNS, column_count as u64),
+        )
+    }
+
+    /// Returns the env of the cell at `row` and `col`.
+    fn at(&mut self, row: usize, col: usize) -> &Env {
+        self.0.set(ROW_IDX, row as u64);
+        self.0.set(COL_IDX, col as u64);
+        &self.0
+    }
+}
+
+/// Returns how far a sticky row from `start` to `end` is moved down so that it stays at
+/// the top of the viewport, without going past the end of the table.
+fn sticky_offset(viewport_top: f64, start: f64, end: f64, table_height: f64) -> f64 {
+    let top = viewport_top.min(table_height - (end - start));
+    (top - start).max(0.0)
+}
+
+/// The direction in which a [`FlexTable`] column is sorted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the direction following `direction` when clicking a header:
+    /// unsorted, ascending, descending and unsorted again.
+    pub fn cycle(direction: Option<SortDirection>) -> Option<SortDirection> {
+        match direction {
+            None => Some(SortDirection::Ascending),
+            Some(SortDirection::Ascending) => Some(SortDirection::Descending),
+            Some(SortDirection::Descending) => None,
+        }
+    }
+}
+
+type SortFn<T> = Box<dyn Fn(&mut EventCtx, &mut T, usize, Option<SortDirection>)>;
+
+const SORT_INDICATOR_SIZE: f64 = 4.0;
 
 #[derive(Debug)]
 struct TableBorderStyle {
@@ -20,6 +95,9 @@ struct TableBorderStyle {
 /// Uses the flex layout algorithm (like [druid::widget::Flex]) to layout
 /// cells in each row.
 ///
+/// The position of each cell is available in its env, with the [`ROW_IDX`],
+/// [`COL_IDX`] and [`TOTAL_COLUMNS`] keys.
+///
 /// # Examples
 /// ```
 /// # use druid::widget::Label;
@@ -50,6 +128,16 @@ pub struct FlexTable<T> {
     background: Option<BackgroundBrush<T>>,
     row_starts: Option<Vec<f64>>,
     col_starts: Option<Vec<f64>>,
+    on_sort: Option<SortFn<T>>,
+    sort: Option<(usize, SortDirection)>,
+    /// The header column pressed by the mouse.
+    pressed_column: Option<usize>,
+    selected_row: Option<Box<dyn DynLens<T, Option<usize>>>>,
+    header_row: Option<usize>,
+    /// The visible part of the table.
+    viewport: Rect,
+    /// Where the header row is shown, and its offset from its position in the table.
+    pinned_header: Option<(Rect, f64)>,
 }
 
 impl<T: Data> Default for FlexTable<T> {
@@ -71,6 +159,13 @@ impl<T: Data> FlexTable<T> {
             row_starts: None,
             col_starts: None,
             background: None,
+            on_sort: None,
+            sort: None,
+            pressed_column: None,
+            selected_row: None,
+            header_row: None,
+            viewport: Rect::ZERO,
+            pinned_header: None,
         }
     }
 
@@ -222,6 +317,89 @@ impl<T: Data> FlexTable<T> {
         self.default_vertical_alignment = default_vertical_alignment;
     }
 
+    /// Builder-style method to make the first row a header, which sorts the table
+    /// when clicked.
+    ///
+    /// Clicking a header cell cycles the sort direction of its column from unsorted
+    /// to ascending, descending and unsorted again, and calls `on_sort` with the
+    /// column and its new direction, so that the application can reorder its rows.
+    /// The direction of the sorted column is shown by an arrow in its header cell.
+    pub fn on_sort(
+        mut self,
+        on_sort: impl Fn(&mut EventCtx, &mut T, usize, Option<SortDirection>) + 'static,
+    ) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Returns the sorted column and its direction.
+    pub fn sort(&self) -> Option<(usize, SortDirection)> {
+        self.sort
+    }
+
+    /// Set the sorted column and its direction, without calling back.
+    pub fn set_sort(&mut self, sort: Option<(usize, SortDirection)>) {
+        self.sort = sort;
+    }
+
+    /// Builder-style method to keep the row at index `row` at the top of the visible area
+    /// when the table is scrolled, until the end of the table.
+    ///
+    /// The visible area is taken from [`LifeCycle::ViewContextChanged`], so the table
+    /// doesn't need to be the direct child of the [`Scroll`]. The row is painted over the
+    /// table background.
+    ///
+    /// [`Scroll`]: druid::widget::Scroll
+    pub fn with_header_row(mut self, row: usize) -> Self {
+        self.header_row = Some(row);
+        self
+    }
+
+    /// Maps a point on the pinned header row to where the row is in the table.
+    fn unpin(&self, pos: Point) -> Point {
+        match self.pinned_header {
+            Some((rect, offset)) if rect.contains(pos) => pos - Vec2::new(0.0, offset),
+            _ => pos,
+        }
+    }
+
+    /// Returns the header column at `pos`, if the table is sortable.
+    fn header_column_at(&self, pos: Point) -> Option<usize> {
+        let pos = self.unpin(pos);
+        let (row_starts, col_starts) = (self.row_starts.as_ref()?, self.col_starts.as_ref()?);
+        self.on_sort.as_ref()?;
+        let header_end = row_starts.get(1).copied().unwrap_or(f64::INFINITY);
+        if pos.y < 0.0 || pos.y >= header_end || pos.x < 0.0 {
+            return None;
+        }
+        col_starts.iter().rposition(|start| *start <= pos.x)
+    }
+
+    /// Builder-style method to let the user select a row by clicking it, storing the index
+    /// of the selected row through `selected_row`.
+    ///
+    /// The selected row is highlighted, and the table submits a [`FLEX_TABLE_ROW_CLICKED`]
+    /// notification on click. Clicks handled by the cells don't select their row, nor do
+    /// clicks on the header of a sortable table.
+    pub fn selectable_rows(mut self, selected_row: impl Lens<T, Option<usize>> + 'static) -> Self {
+        self.selected_row = Some(Box::new(selected_row));
+        self
+    }
+
+    /// Returns the selectable row at `pos`, with `size` the size of the table.
+    fn selectable_row_at(&self, pos: Point, size: Size) -> Option<usize> {
+        let pos = self.unpin(pos);
+        let (row_starts, col_starts) = (self.row_starts.as_ref()?, self.col_starts.as_ref()?);
+        self.selected_row.as_ref()?;
+        let table_start = Point::new(*col_starts.first()?, *row_starts.first()?);
+        if !Rect::from_points(table_start, size.to_vec2().to_point()).contains(pos) {
+            return None;
+        }
+        let row = row_starts.iter().rposition(|start| *start <= pos.y)?;
+        // the header of a sortable table isn't a row to select
+        Some(row).filter(|row| self.on_sort.is_none() || *row > 0)
+    }
+
     /// Returns the column count
     pub fn column_count(&self) -> usize {
         if self.children.is_empty() {
@@ -233,7 +411,7 @@ impl<T: Data> FlexTable<T> {
 
     /// Builder-style method to add a table row.
     ///
-    /// All row must have equal number of cells. Panics if not!
+    /// Rows with fewer cells than others are padded with empty cells.
     pub fn with_row(mut self, row: TableRow<T>) -> Self {
         self.add_row(row);
         self
@@ -241,27 +419,132 @@ impl<T: Data> FlexTable<T> {
 
     /// Add a table row.
     ///
-    /// All row must have equal number of cells. Panics if not!
+    /// Rows with fewer cells than others are padded with empty cells, so that the table
+    /// is a consistent grid.
     pub fn add_row(&mut self, row: TableRow<T>) {
-        if !self.children.is_empty() && row.children.len() != self.column_count() {
-            panic!("Table::add_row - wrong row length");
-        }
+        let column_count = self.column_count().max(row.children.len());
         self.children.push(row);
+        for row in &mut self.children {
+            while row.children.len() < column_count {
+                row.add_child(SizedBox::empty());
+            }
+        }
+    }
+
+    /// Move the row at index `from` to index `to`, keeping its cell widgets.
+    ///
+    /// The header row follows the rows. The row selection lives in the data, so it only
+    /// follows moves made with the [`FLEX_TABLE_MOVE_ROW`] notification. A `to` past the
+    /// last row moves the row to the end, and nothing happens when there is no row at
+    /// `from`.
+    pub fn move_row(&mut self, from: usize, to: usize) {
+        if from >= self.children.len() {
+            return;
+        }
+        let row = self.children.remove(from);
+        let to = to.min(self.children.len());
+        self.children.insert(to, row);
+        self.header_row = self.header_row.map(|row| moved_index(row, from, to));
+    }
+}
+
+/// Returns the new index of the row at `index` when the row at `from` is moved to `to`.
+fn moved_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < index && index <= to {
+        index - 1
+    } else if to <= index && index < from {
+        index + 1
+    } else {
+        index
     }
 }
 
 impl<T: Data> Widget<T> for FlexTable<T> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
-        for row in self.children.iter_mut() {
-            for cell in row.children.iter_mut() {
+        let rows = self.children.len();
+        let mut cell_env = CellEnv::new(env, self.column_count());
+        // the header row is on top, so it gets events first
+        if let Some(row_num) = self.header_row.filter(|row| *row < rows) {
+            for (col_num, cell) in self.children[row_num].children.iter_mut().enumerate() {
+                let env = cell_env.at(row_num, col_num);
+                cell.event(ctx, event, data, env);
+            }
+        }
+        for (row_num, row) in self.children.iter_mut().enumerate() {
+            if Some(row_num) == self.header_row || ctx.is_handled() {
+                continue;
+            }
+            for (col_num, cell) in row.children.iter_mut().enumerate() {
+                let env = cell_env.at(row_num, col_num);
                 cell.event(ctx, event, data, env);
             }
         }
+
+        match event {
+            Event::MouseDown(mouse) if !ctx.is_handled() && mouse.button.is_left() => {
+                self.pressed_column = self.header_column_at(mouse.pos);
+                if self.pressed_column.is_some() {
+                    ctx.set_active(true);
+                    ctx.set_handled();
+                } else if let Some(row) = self.selectable_row_at(mouse.pos, ctx.size()) {
+                    if let Some(selected_row) = &self.selected_row {
+                        selected_row.with_mut(data, |selected| *selected = Some(row));
+                    }
+                    ctx.submit_notification(FLEX_TABLE_ROW_CLICKED.with(row));
+                    ctx.set_handled();
+                }
+            }
+            Event::MouseUp(mouse) if ctx.is_active() => {
+                ctx.set_active(false);
+                let released = self.header_column_at(mouse.pos);
+                if let Some(column) = self.pressed_column.take().filter(|c| Some(*c) == released) {
+                    let direction = match self.sort {
+                        Some((sorted, direction)) if sorted == column => Some(direction),
+                        _ => None,
+                    };
+                    let direction = SortDirection::cycle(direction);
+                    self.sort = direction.map(|direction| (column, direction));
+                    if let Some(on_sort) = &self.on_sort {
+                        on_sort(ctx, data, column, direction);
+                    }
+                    ctx.request_paint();
+                }
+                ctx.set_handled();
+            }
+            Event::Notification(notif) if notif.is(FLEX_TABLE_MOVE_ROW) => {
+                let (from, to) = *notif.get(FLEX_TABLE_MOVE_ROW).unwrap();
+                if from < self.children.len() {
+                    self.move_row(from, to);
+                    let to = to.min(self.children.len() - 1);
+                    if let Some(selected_row) = &self.selected_row {
+                        selected_row.with_mut(data, |selected| {
+                            *selected = selected.map(|row| moved_index(row, from, to))
+                        });
+                    }
+                    // the cells get their new position in the env
+                    ctx.request_update();
+                    ctx.request_layout();
+                }
+                ctx.set_handled();
+            }
+            _ => {}
+        }
     }
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
-        for row in self.children.iter_mut() {
-            for cell in row.children.iter_mut() {
+        if let LifeCycle::ViewContextChanged(view_context) = event {
+            if self.header_row.is_some() && view_context.clip.y0 != self.viewport.y0 {
+                ctx.request_layout();
+            }
+            self.viewport = view_context.clip;
+        }
+
+        let mut cell_env = CellEnv::new(env, self.column_count());
+        for (row_num, row) in self.children.iter_mut().enumerate() {
+            for (col_num, cell) in row.children.iter_mut().enumerate() {
+                let env = cell_env.at(row_num, col_num);
                 cell.lifecycle(ctx, event, data, env);
             }
         }
@@ -272,6 +555,13 @@ impl<T: Data> Widget<T> for FlexTable<T> {
             brush.update(ctx, old_data, data, env);
         }
 
+        if let Some(selected_row) = &self.selected_row {
+            let selected = |data| selected_row.with(data, |row| *row);
+            if selected(old_data) != selected(data) {
+                ctx.request_paint();
+            }
+        }
+
         if let Some(border) = &self.row_border {
             if ctx.env_key_changed(&border.width) {
                 ctx.request_layout();
@@ -290,8 +580,10 @@ impl<T: Data> Widget<T> for FlexTable<T> {
             }
         }
 
-        for row in self.children.iter_mut() {
-            for cell in row.children.iter_mut() {
+        let mut cell_env = CellEnv::new(env, self.column_count());
+        for (row_num, row) in self.children.iter_mut().enumerate() {
+            for (col_num, cell) in row.children.iter_mut().enumerate() {
+                let env = cell_env.at(row_num, col_num);
                 cell.update(ctx, data, env);
             }
         }
@@ -302,6 +594,7 @@ impl<T: Data> Widget<T> for FlexTable<T> {
         if column_count == 0 {
             return Size::ZERO;
         }
+        let mut cell_env = CellEnv::new(env, column_count);
 
         if self.column_widths.len() < column_count {
             // make sure we have all elements so that we can directly
@@ -340,8 +633,9 @@ impl<T: Data> Widget<T> for FlexTable<T> {
             if cw.need_intrinsic_width() {
                 let mut row_width = 0f64;
                 let mut found_size = false;
-                for row in self.children.iter_mut() {
+                for (row_num, row) in self.children.iter_mut().enumerate() {
                     if let Some(cell) = row.children.get_mut(col_num) {
+                        let env = cell_env.at(row_num, col_num);
                         let child_bc = BoxConstraints::new(
                             Size::new(0., 0.),
                             Size::new(std::f64::INFINITY, std::f64::INFINITY),
@@ -393,6 +687,7 @@ impl<T: Data> Widget<T> for FlexTable<T> {
                     Size::new(0., 0.),
                     Size::new(col_widths[col_num], std::f64::INFINITY),
                 );
+                let env = cell_env.at(row_num, col_num);
                 let size = cell.layout(ctx, &child_bc, data, env);
 
                 if size.height.is_finite() {
@@ -419,6 +714,7 @@ impl<T: Data> Widget<T> for FlexTable<T> {
                         Size::new(0., 0.),
                         Size::new(col_widths[col_num], row_height),
                     );
+                    let env = cell_env.at(row_num, col_num);
                     let size = cell.layout(ctx, &child_bc, data, env);
 
                     let baseline_offset = cell.baseline_offset();
@@ -462,6 +758,7 @@ impl<T: Data> Widget<T> for FlexTable<T> {
                         if size.height < real_height {
                             let child_bc =
                                 BoxConstraints::tight(Size::new(size.width, real_height));
+                            let env = cell_env.at(row_num, col_num);
                             let _size = cell.layout(ctx, &child_bc, data, env);
                         }
                         0f64
@@ -489,6 +786,22 @@ impl<T: Data> Widget<T> for FlexTable<T> {
             col_start += old_width;
         }
 
+        self.pinned_header = None;
+        if let Some(header_row) = self.header_row.filter(|row| *row < rows) {
+            let start = row_starts[header_row];
+            let end = row_starts
+                .get(header_row + 1)
+                .map(|next| next - row_border_width)
+                .unwrap_or(table_height);
+            let offset = sticky_offset(self.viewport.y0, start, end, table_height);
+            for cell in &mut self.children[header_row].children {
+                let origin = cell.layout_rect().origin() + Vec2::new(0.0, offset);
+                cell.set_origin(ctx, origin);
+            }
+            let rect = Rect::new(0.0, start + offset, table_width, end + offset);
+            self.pinned_header = Some((rect, offset));
+        }
+
         self.col_starts = Some(col_starts);
         self.row_starts = Some(row_starts);
 
@@ -527,6 +840,21 @@ impl<T: Data> Widget<T> for FlexTable<T> {
             });
         }
 
+        if let (Some(selected_row), Some(row_starts)) = (&self.selected_row, &self.row_starts) {
+            let selected = selected_row.with(data, |row| *row);
+            if let Some(start) = selected.and_then(|row| row_starts.get(row)) {
+                let end = row_starts
+                    .iter()
+                    .find(|next| *next > start)
+                    .map(|next| next - row_border_width)
+                    .unwrap_or(size.height);
+                let rect = Rect::new(0.0, *start, size.width, end);
+                ctx.fill(rect, &env.get(theme::SELECTED_TEXT_BACKGROUND_COLOR));
+            }
+        }
+
+        let mut cell_env = CellEnv::new(env, self.column_count());
+        let header_row = self.header_row;
         for (row_num, row) in self.children.iter_mut().enumerate() {
             if row_num > 0 && row_border_width > 0.0 {
                 if let Some(ref row_starts) = self.row_starts {
@@ -549,8 +877,218 @@ impl<T: Data> Widget<T> for FlexTable<T> {
                     }
                 }
 
+                if Some(row_num) != header_row {
+                    let env = cell_env.at(row_num, col_num);
+                    cell.paint(ctx, data, env);
+                }
+            }
+        }
+
+        // the header row is painted last, above the rows scrolled under it
+        let mut sort_offset = 0.0;
+        if let (Some(row_num), Some((rect, offset))) = (header_row, self.pinned_header) {
+            let background = &mut self.background;
+            ctx.with_save(|ctx| {
+                ctx.clip(rect);
+                match background.as_mut() {
+                    Some(background) => background.paint(ctx, data, env),
+                    None => ctx.fill(rect, &env.get(theme::WINDOW_BACKGROUND_COLOR)),
+                }
+            });
+            for (col_num, cell) in self.children[row_num].children.iter_mut().enumerate() {
+                let env = cell_env.at(row_num, col_num);
                 cell.paint(ctx, data, env);
             }
+            if row_num == 0 {
+                sort_offset = offset;
+            }
+        }
+
+        if let (Some((column, direction)), Some(row_starts), Some(col_starts)) =
+            (self.sort, &self.row_starts, &self.col_starts)
+        {
+            if column < col_starts.len() {
+                let header_end = row_starts
+                    .get(1)
+                    .map(|start| start - row_border_width)
+                    .unwrap_or(size.height);
+                let column_end = col_starts
+                    .get(column + 1)
+                    .map(|start| start - col_border_width)
+                    .unwrap_or(size.width);
+                let center = Point::new(
+                    column_end - 2.0 * SORT_INDICATOR_SIZE,
+                    sort_offset + header_end / 2.0,
+                );
+                let dy = match direction {
+                    SortDirection::Ascending => -SORT_INDICATOR_SIZE / 2.0,
+                    SortDirection::Descending => SORT_INDICATOR_SIZE / 2.0,
+                };
+                let mut arrow = BezPath::new();
+                arrow.move_to((center.x - SORT_INDICATOR_SIZE, center.y - dy));
+                arrow.line_to((center.x + SORT_INDICATOR_SIZE, center.y - dy));
+                arrow.line_to((center.x, center.y + dy));
+                arrow.close_path();
+                ctx.fill(arrow, &env.get(theme::TEXT_COLOR));
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::ModularWidget;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// The pass and the position keys seen by the cells of a table.
+    type Seen = Rc<RefCell<Vec<(&'static str, u64, u64, u64)>>>;
+
+    fn position_cell(seen: &Seen) -> impl Widget<()> {
+        let position = |env: &Env| (env.get(ROW_IDX), env.get(COL_IDX), env.get(TOTAL_COLUMNS));
+        ModularWidget::new(seen.clone())
+            .layout_fn(move |seen, _ctx, _bc, _data, env| {
+                let (row, col, total) = position(env);
+                seen.borrow_mut().push(("layout", row, col, total));
+                Size::new(20.0, 10.0)
+            })
+            .paint_fn(move |seen, _ctx, _data, env| {
+                let (row, col, total) = position(env);
+                seen.borrow_mut().push(("paint", row, col, total));
+            })
+    }
+
+    #[test]
+    fn cell_position_keys() {
+        let seen = Seen::default();
+        let row = || {
+            (0..3).fold(TableRow::new(), |row, _| {
+                row.with_child(position_cell(&seen))
+            })
+        };
+        let table = FlexTable::new().with_row(row()).with_row(row());
+
+        Harness::create_simple((), table, |harness| {
+            harness.send_initial_events();
+            harness.paint();
+        });
+
+        let mut seen = seen.take();
+        seen.sort_unstable();
+        seen.dedup();
+        let expected: Vec<_> = ["layout", "paint"]
+            .iter()
+            .flat_map(|&pass| {
+                (0..2).flat_map(move |row| (0..3).map(move |col| (pass, row, col, 3)))
+            })
+            .collect();
+        assert_eq!(seen, expected);
+    }
+
+    #[test]
+    fn row_hit_test() {
+        let mut table = FlexTable::<Option<usize>>::new().selectable_rows(druid::lens::Identity);
+        table.row_starts = Some(vec![0.0, 21.0, 42.0]);
+        table.col_starts = Some(vec![0.0, 51.0]);
+        let size = Size::new(100.0, 60.0);
+
+        assert_eq!(
+            table.selectable_row_at(Point::new(10.0, 5.0), size),
+            Some(0)
+        );
+        assert_eq!(
+            table.selectable_row_at(Point::new(60.0, 30.0), size),
+            Some(1)
+        );
+        assert_eq!(
+            table.selectable_row_at(Point::new(60.0, 59.0), size),
+            Some(2)
+        );
+        assert_eq!(table.selectable_row_at(Point::new(60.0, 61.0), size), None);
+        assert_eq!(table.selectable_row_at(Point::new(101.0, 30.0), size), None);
+
+        // the header of a sortable table can't be selected
+        let table = FlexTable {
+            on_sort: Some(Box::new(|_, _, _, _| ())),
+            ..table
+        };
+        assert_eq!(table.selectable_row_at(Point::new(10.0, 5.0), size), None);
+        assert_eq!(
+            table.selectable_row_at(Point::new(10.0, 30.0), size),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn sticky_header() {
+        // a header row from 0 to 20 in a table of 100
+        assert_eq!(sticky_offset(0.0, 0.0, 20.0, 100.0), 0.0);
+        assert_eq!(sticky_offset(30.0, 0.0, 20.0, 100.0), 30.0);
+        // it stops with the end of the table
+        assert_eq!(sticky_offset(90.0, 0.0, 20.0, 100.0), 80.0);
+        // a row further down is only moved once it reaches the top
+        assert_eq!(sticky_offset(10.0, 40.0, 60.0, 100.0), 0.0);
+        assert_eq!(sticky_offset(50.0, 40.0, 60.0, 100.0), 10.0);
+
+        let mut table = FlexTable::<()>::new().with_header_row(0);
+        table.pinned_header = Some((Rect::new(0.0, 30.0, 100.0, 50.0), 30.0));
+        assert_eq!(table.unpin(Point::new(10.0, 40.0)), Point::new(10.0, 10.0));
+        assert_eq!(table.unpin(Point::new(10.0, 60.0)), Point::new(10.0, 60.0));
+    }
+
+    #[test]
+    fn move_rows() {
+        let mut table = FlexTable::<()>::new().with_header_row(2);
+        for _ in 0..4 {
+            table.add_row(TableRow::new().with_child(SizedBox::empty()));
+        }
+        let ids = |table: &FlexTable<()>| -> Vec<_> {
+            table
+                .children
+                .iter()
+                .map(|row| row.children[0].id())
+                .collect()
+        };
+        let before = ids(&table);
+
+        table.move_row(0, 2);
+        assert_eq!(ids(&table), [before[1], before[2], before[0], before[3]]);
+        assert_eq!(table.header_row, Some(1));
+        table.move_row(3, 0);
+        assert_eq!(ids(&table), [before[3], before[1], before[2], before[0]]);
+        assert_eq!(table.header_row, Some(2));
+        // past the end means last
+        table.move_row(0, 10);
+        assert_eq!(ids(&table), [before[1], before[2], before[0], before[3]]);
+        // there is no row to move
+        table.move_row(4, 0);
+        assert_eq!(ids(&table), [before[1], before[2], before[0], before[3]]);
+    }
+
+    #[test]
+    fn pad_short_rows() {
+        let row = |cells: usize| {
+            (0..cells).fold(TableRow::new(), |row, _| row.with_child(SizedBox::empty()))
+        };
+        let table = FlexTable::<()>::new()
+            .with_row(row(2))
+            .with_row(row(3))
+            .with_row(row(1));
+
+        assert_eq!(table.column_count(), 3);
+        for row in &table.children {
+            assert_eq!(row.children.len(), 3);
+        }
+    }
+
+    #[test]
+    fn sort_cycle() {
+        let asc = SortDirection::cycle(None);
+        assert_eq!(asc, Some(SortDirection::Ascending));
+        let desc = SortDirection::cycle(asc);
+        assert_eq!(desc, Some(SortDirection::Descending));
+        assert_eq!(SortDirection::cycle(desc), None);
+    }
+}
@@ -34,7 +34,7 @@ pub enum TableCellVerticalAlignment {
 
 /// A table row is a horizontal group of widgets.
 ///
-/// All rows in a table must have the same number of children.
+/// Rows of a table with fewer children than others are padded with empty cells.
 pub struct TableRow<T> {
     min_height: Option<f64>,
     vertical_alignment: Option<TableCellVerticalAlignment>,
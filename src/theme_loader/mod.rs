@@ -33,8 +33,18 @@
 //!
 //! If you would like your app to update the theme when it is edited, you need
 //! to enable the `notify` feature of this crate in your manifest.
+//!
+//! ## Serde themes
+//!
+//! With the `serde-theme` feature, the keys of [`druid::theme`] can also be set
+//! from a [`Theme`] deserialized with serde, for example with [`from_str`] for
+//! TOML.
 
+#[cfg(feature = "serde-theme")]
+mod theme;
 mod widget;
+#[cfg(feature = "serde-theme")]
+pub use theme::{from_reader, from_str, Theme};
 pub use widget::ThemeLoader;
 
 use druid::Env;
@@ -94,7 +104,7 @@ macro_rules! loadable_theme {
             fn load(&self, raw: &std::collections::HashMap<&str, &str>, current: &druid::Env) -> Result<druid::Env, $crate::theme_loader::ThemeLoadError> {
                 use std::any::TypeId;
                 use druid::{Color, Value};
-                use $crate::theme_loader::{ThemeLoadError, LoadableTheme, ValueKind};
+                use $crate::theme_loader::{ThemeLoadError, ValueKind};
 
                 fn get_kind<T: 'static>(_k: &druid::Key<T>) -> Result<ValueKind, ThemeLoadError> {
                     let this_type = TypeId::of::<T>();
@@ -149,6 +159,9 @@ pub enum ThemeLoadError {
     ParseFloatError(std::num::ParseFloatError),
     ValueTypeError(druid::ValueTypeError),
     ParseThemeLineError(String),
+    UnknownKey(String),
+    InvalidValue(String, ValueKind),
+    ParseThemeError(String),
 }
 
 impl std::fmt::Display for ThemeLoadError {
@@ -163,6 +176,20 @@ impl std::fmt::Display for ThemeLoadError {
             Self::ParseThemeLineError(s) => {
                 write!(f, "Theme contained malformed line: '{}'", s.escape_debug())
             }
+            Self::UnknownKey(k) => write!(
+                f,
+                "Unknown theme key '{k}', expected the name of a druid theme key like 'TEXT_COLOR'"
+            ),
+            Self::InvalidValue(k, ValueKind::Color) => {
+                write!(
+                    f,
+                    "Theme key '{k}' expects a color like '#rrggbb' or '#rrggbbaa'"
+                )
+            }
+            Self::InvalidValue(k, ValueKind::Float) => {
+                write!(f, "Theme key '{k}' expects a number")
+            }
+            Self::ParseThemeError(e) => write!(f, "Theme failed to parse: {e}"),
         }
     }
 }
@@ -0,0 +1,248 @@
+// Copyright 2021 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::io::Read;
+
+use druid::kurbo::RoundedRectRadii;
+use druid::{theme, Color, Env, FontDescriptor, FontFamily, Key};
+use serde::{Deserialize, Serialize};
+
+use super::{ThemeLoadError, ValueKind};
+
+/// A theme for the keys of [`druid::theme`], deserialized with serde.
+///
+/// The theme maps the names of the keys, like `BACKGROUND_DARK`, to their values:
+/// colors are written `#rrggbb` or `#rrggbbaa`, and the other keys take a number.
+/// The number given to `UI_FONT`, `UI_FONT_BOLD` or `UI_FONT_ITALIC` is the font size.
+///
+/// ```toml
+/// BACKGROUND_DARK = "#1e1e1e"
+/// TEXT_COLOR = "#ffffffcc"
+/// TEXT_SIZE_NORMAL = 14
+/// UI_FONT = 14
+/// ```
+///
+/// Unknown keys, or values of the wrong kind, are an error.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    try_from = "BTreeMap<String, RawValue>",
+    into = "BTreeMap<String, RawValue>"
+)]
+pub struct Theme {
+    values: BTreeMap<String, ThemeValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ThemeValue {
+    Color(Color),
+    Float(f64),
+}
+
+/// A value as written in a theme file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawValue {
+    Float(f64),
+    Text(String),
+}
+
+/// A key of [`druid::theme`] which can be set by a [`Theme`].
+#[derive(Clone)]
+enum ThemeKey {
+    Color(Key<Color>),
+    Float(Key<f64>),
+    Radius(Key<RoundedRectRadii>),
+    FontSize(Key<FontDescriptor>),
+}
+
+impl ThemeKey {
+    fn kind(&self) -> ValueKind {
+        match self {
+            ThemeKey::Color(_) => ValueKind::Color,
+            _ => ValueKind::Float,
+        }
+    }
+}
+
+macro_rules! theme_keys {
+    ($name:expr, $($kind:ident: $($key:ident),+;)+) => {
+        match $name {
+            $($(stringify!($key) => Some(ThemeKey::$kind(theme::$key)),)+)+
+            _ => None,
+        }
+    };
+}
+
+/// Returns the key of [`druid::theme`] named `name`.
+fn theme_key(name: &str) -> Option<ThemeKey> {
+    theme_keys!(name,
+        Color: WINDOW_BACKGROUND_COLOR, TEXT_COLOR, DISABLED_TEXT_COLOR, PLACEHOLDER_COLOR,
+            PRIMARY_LIGHT, PRIMARY_DARK, BACKGROUND_LIGHT, BACKGROUND_DARK, FOREGROUND_LIGHT,
+            FOREGROUND_DARK, DISABLED_FOREGROUND_LIGHT, DISABLED_FOREGROUND_DARK, BUTTON_DARK,
+            BUTTON_LIGHT, DISABLED_BUTTON_DARK, DISABLED_BUTTON_LIGHT, BORDER_DARK, BORDER_LIGHT,
+            SELECTED_TEXT_BACKGROUND_COLOR, SELECTED_TEXT_INACTIVE_BACKGROUND_COLOR,
+            SELECTION_TEXT_COLOR, CURSOR_COLOR, SCROLLBAR_COLOR, SCROLLBAR_BORDER_COLOR;
+        Float: BUTTON_BORDER_WIDTH, TEXT_SIZE_NORMAL, TEXT_SIZE_LARGE, BASIC_WIDGET_HEIGHT,
+            WIDE_WIDGET_WIDTH, BORDERED_WIDGET_HEIGHT, TEXTBOX_BORDER_WIDTH,
+            WIDGET_PADDING_HORIZONTAL, WIDGET_PADDING_VERTICAL, WIDGET_CONTROL_COMPONENT_PADDING,
+            SCROLLBAR_MAX_OPACITY, SCROLLBAR_WIDTH, SCROLLBAR_PAD, SCROLLBAR_EDGE_WIDTH,
+            SCROLLBAR_MIN_SIZE;
+        Radius: PROGRESS_BAR_RADIUS, BUTTON_BORDER_RADIUS, TEXTBOX_BORDER_RADIUS,
+            SCROLLBAR_RADIUS;
+        FontSize: UI_FONT, UI_FONT_BOLD, UI_FONT_ITALIC;
+    )
+}
+
+/// Parses a color written `#rrggbb` or `#rrggbbaa`.
+fn parse_color(text: &str) -> Option<Color> {
+    let hex = text.strip_prefix('#')?;
+    if hex.len() != 6 && hex.len() != 8 {
+        return None;
+    }
+    Color::from_hex_str(hex).ok()
+}
+
+impl TryFrom<BTreeMap<String, RawValue>> for Theme {
+    type Error = ThemeLoadError;
+
+    fn try_from(raw: BTreeMap<String, RawValue>) -> Result<Self, Self::Error> {
+        let values = raw
+            .into_iter()
+            .map(|(name, value)| {
+                let key =
+                    theme_key(&name).ok_or_else(|| ThemeLoadError::UnknownKey(name.clone()))?;
+                let value = match (key.kind(), value) {
+                    (ValueKind::Color, RawValue::Text(text)) => {
+                        parse_color(&text).map(ThemeValue::Color)
+                    }
+                    (ValueKind::Float, RawValue::Float(value)) => Some(ThemeValue::Float(value)),
+                    _ => None,
+                };
+                let value =
+                    value.ok_or_else(|| ThemeLoadError::InvalidValue(name.clone(), key.kind()))?;
+                Ok((name, value))
+            })
+            .collect::<Result<_, ThemeLoadError>>()?;
+        Ok(Theme { values })
+    }
+}
+
+impl From<Theme> for BTreeMap<String, RawValue> {
+    fn from(theme: Theme) -> Self {
+        theme
+            .values
+            .into_iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    ThemeValue::Color(color) => {
+                        let (r, g, b, a) = color.as_rgba8();
+                        RawValue::Text(format!("#{r:02x}{g:02x}{b:02x}{a:02x}"))
+                    }
+                    ThemeValue::Float(value) => RawValue::Float(value),
+                };
+                (name, value)
+            })
+            .collect()
+    }
+}
+
+impl Theme {
+    /// Set the values of the theme in `env`.
+    pub fn apply(&self, env: &mut Env) {
+        for (name, value) in &self.values {
+            // the keys and values were checked when deserializing
+            match (theme_key(name), value) {
+                (Some(ThemeKey::Color(key)), ThemeValue::Color(color)) => env.set(key, *color),
+                (Some(ThemeKey::Float(key)), ThemeValue::Float(value)) => env.set(key, *value),
+                (Some(ThemeKey::Radius(key)), ThemeValue::Float(value)) => env.set(key, *value),
+                (Some(ThemeKey::FontSize(key)), ThemeValue::Float(size)) => {
+                    let font = env
+                        .try_get(&key)
+                        .unwrap_or_else(|_| FontDescriptor::new(FontFamily::SYSTEM_UI));
+                    env.set(key, font.with_size(*size));
+                }
+                _ => unreachable!("invalid theme value for '{}'", name),
+            }
+        }
+    }
+
+    /// Returns a function setting the values of the theme, which can be given to
+    /// [`AppLauncher::configure_env`].
+    ///
+    /// [`AppLauncher::configure_env`]: druid::AppLauncher::configure_env
+    pub fn into_configure_env<T>(self) -> impl Fn(&mut Env, &T) + 'static {
+        move |env, _| self.apply(env)
+    }
+}
+
+/// Parses a [`Theme`] written in TOML, and returns a function setting its values, which
+/// can be given to [`AppLauncher::configure_env`].
+///
+/// [`AppLauncher::configure_env`]: druid::AppLauncher::configure_env
+pub fn from_str<T>(toml: &str) -> Result<impl Fn(&mut Env, &T) + 'static, ThemeLoadError> {
+    let theme: Theme =
+        toml::from_str(toml).map_err(|e| ThemeLoadError::ParseThemeError(e.to_string()))?;
+    Ok(theme.into_configure_env())
+}
+
+/// Like [`from_str`], reading the TOML theme from `reader`.
+pub fn from_reader<T>(
+    mut reader: impl Read,
+) -> Result<impl Fn(&mut Env, &T) + 'static, ThemeLoadError> {
+    let mut toml = String::new();
+    reader.read_to_string(&mut toml)?;
+    from_str(&toml)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML: &str = r##"
+        BACKGROUND_DARK = "#1e1e1e"
+        TEXT_COLOR = "#ffffff80"
+        TEXT_SIZE_NORMAL = 14
+        BUTTON_BORDER_RADIUS = 2.5
+        UI_FONT = 13
+    "##;
+
+    #[test]
+    fn apply_theme() {
+        let mut env = Env::empty();
+        from_reader(TOML.as_bytes()).unwrap()(&mut env, &());
+
+        assert_eq!(
+            env.get(theme::BACKGROUND_DARK),
+            Color::rgb8(0x1e, 0x1e, 0x1e)
+        );
+        assert_eq!(
+            env.get(theme::TEXT_COLOR),
+            Color::rgba8(0xff, 0xff, 0xff, 0x80)
+        );
+        assert_eq!(env.get(theme::TEXT_SIZE_NORMAL), 14.0);
+        assert_eq!(
+            env.get(theme::BUTTON_BORDER_RADIUS),
+            RoundedRectRadii::from(2.5)
+        );
+        assert_eq!(env.get(theme::UI_FONT).size, 13.0);
+    }
+
+    #[test]
+    fn round_trip() {
+        let theme: Theme = toml::from_str(TOML).unwrap();
+        let json = serde_json::to_string(&theme).unwrap();
+        assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+    }
+
+    #[test]
+    fn invalid_themes() {
+        let error = |toml| from_str::<()>(toml).err().unwrap().to_string();
+
+        assert!(error("BACKGROUND = \"#000000\"").contains("Unknown theme key 'BACKGROUND'"));
+        assert!(error("TEXT_COLOR = 3").contains("'TEXT_COLOR' expects a color"));
+        assert!(error("TEXT_COLOR = \"#fff\"").contains("'TEXT_COLOR' expects a color"));
+        assert!(error("TEXT_SIZE_NORMAL = \"big\"").contains("'TEXT_SIZE_NORMAL' expects a number"));
+    }
+}
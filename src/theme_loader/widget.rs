@@ -14,12 +14,15 @@ pub const RELOAD_THEME: Selector<()> = Selector::new("runebender.theme-loader-re
 /// A widget that loads a theme from file and applies it to the [`Env`].
 ///
 /// This can optionally reload the theme when it changes, if the `notify`
-/// feature is enabled.
+/// feature is enabled. The new values are then given to the child widgets, as
+/// when their env changes.
 pub struct ThemeLoader<T, W> {
     theme_path: PathBuf,
     theme: T,
     current_env: Option<Env>,
     inner: W,
+    #[cfg(feature = "notify")]
+    watch: bool,
 }
 
 impl<T: LoadableTheme, W> ThemeLoader<T, W> {
@@ -35,9 +38,19 @@ impl<T: LoadableTheme, W> ThemeLoader<T, W> {
             theme,
             inner,
             current_env: None,
+            #[cfg(feature = "notify")]
+            watch: true,
         }
     }
 
+    /// Builder-style method to set whether the theme is reloaded when the file
+    /// changes, which is the default.
+    #[cfg(feature = "notify")]
+    pub fn watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
     fn add_env_to_theme(&mut self, env: &Env) -> Result<Env, ThemeLoadError> {
         let file_contents = std::fs::read_to_string(&self.theme_path)?;
         let contents = iter_items(&file_contents).collect::<Result<_, _>>()?;
@@ -76,7 +89,7 @@ where
         match event {
             Event::WindowConnected => {
                 #[cfg(feature = "notify")]
-                {
+                if self.watch {
                     let event_snk = ctx.get_external_handle();
                     start_watcher(event_snk, self.theme_path.clone(), ctx.widget_id());
                 }
@@ -87,6 +100,8 @@ where
             Event::Command(cmd) if cmd.is(RELOAD_THEME) => {
                 log::info!("reloading theme");
                 self.reload_theme_and_log_errors(env);
+                // the children see the new env in update
+                ctx.request_update();
                 ctx.request_layout();
                 ctx.set_handled();
             }
@@ -155,3 +170,41 @@ fn start_watcher(sink: ExtEventSink, path: PathBuf, target: WidgetId) {
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::{Color, Key};
+
+    const TEXT_COLOR: Key<Color> = Key::new("druid.nursery.test.text-color");
+    const PADDING: Key<f64> = Key::new("druid.nursery.test.padding");
+
+    crate::loadable_theme!(TestTheme {
+        TEXT_COLOR,
+        PADDING
+    });
+
+    #[test]
+    fn reload_edited_file() {
+        let path = std::env::temp_dir().join(format!("nursery-theme-{}.txt", std::process::id()));
+        std::fs::write(&path, "TEXT_COLOR: #112233\nPADDING: 4").unwrap();
+        let mut loader = ThemeLoader::new(&path, TestTheme, ());
+
+        loader.reload_theme_and_log_errors(&Env::empty());
+        let env = loader.current_env.clone().unwrap();
+        assert_eq!(env.get(TEXT_COLOR), Color::rgb8(0x11, 0x22, 0x33));
+        assert_eq!(env.get(PADDING), 4.0);
+
+        std::fs::write(&path, "TEXT_COLOR: #ffffff\nPADDING: 8.5").unwrap();
+        loader.reload_theme_and_log_errors(&Env::empty());
+        let env = loader.current_env.clone().unwrap();
+        assert_eq!(env.get(TEXT_COLOR), Color::WHITE);
+        assert_eq!(env.get(PADDING), 8.5);
+
+        // a broken file keeps the last theme
+        std::fs::write(&path, "PADDING: wide").unwrap();
+        loader.reload_theme_and_log_errors(&Env::empty());
+        assert_eq!(loader.current_env.as_ref().unwrap().get(PADDING), 8.5);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,18 +1,29 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use druid::{Data, Event, Widget};
+use druid::kurbo::Line;
+use druid::widget::prelude::*;
+use druid::{theme, Cursor, Point, Rect, Vec2, WidgetPod, WindowHandle, WindowState};
 
 /// A TitleBar widget.
 ///
-/// Handles mouse clicks over itself like a titlebar - you can drag the window around by it.
-/// It does prevent some events from being passed into inner, like mouse click.
-/// Presumably, this is because clicking means you are now dragging the window,
-/// not interacting with it.
+/// Handles mouse clicks over itself like a titlebar - you can drag the window around by it,
+/// and double-click it to maximize or restore the window. Mouse events are passed to the
+/// inner widget first, so a click handled by it, for example by a button, doesn't move
+/// the window.
 ///
 /// It uses the layout of its inner widget.
 ///
-/// This only works on windows due to it's use of [`handle_titlebar`](<https://docs.rs/druid-shell/0.7.0/druid_shell/struct.WindowHandle.html#method.handle_titlebar>)
+/// Both behaviours can be turned off with [`draggable`] and [`double_click_maximize`].
+///
+/// On Windows, a draggable titlebar is handed to the system with [`handle_titlebar`],
+/// which also snaps the window to the edges of the screen. Mouse clicks over it then
+/// go to the system instead of the inner widget, so buttons should be placed next to
+/// the titlebar, as in the example below.
+///
+/// [`draggable`]: TitleBar::draggable
+/// [`double_click_maximize`]: TitleBar::double_click_maximize
+/// [`handle_titlebar`]: druid::WindowHandle::handle_titlebar
 ///
 /// ## Example
 /// ```ignore
@@ -64,17 +75,158 @@ use druid::{Data, Event, Widget};
 /// ```
 pub struct TitleBar<T> {
     inner: Box<dyn Widget<T>>,
+    draggable: bool,
+    double_click_maximize: bool,
+    drag: Option<MoveDrag>,
 }
 
 impl<T: Data> TitleBar<T> {
     pub fn new(inner: impl Widget<T> + 'static) -> TitleBar<T> {
         TitleBar {
             inner: Box::new(inner),
+            draggable: true,
+            double_click_maximize: true,
+            drag: None,
+        }
+    }
+
+    /// Builder-style method for setting whether dragging the titlebar moves the
+    /// window. The default is true.
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.set_draggable(draggable);
+        self
+    }
+
+    /// Set whether dragging the titlebar moves the window.
+    pub fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    /// Builder-style method for setting whether double-clicking the titlebar
+    /// maximizes the window, or restores it when it is maximized. The default is true.
+    pub fn double_click_maximize(mut self, double_click_maximize: bool) -> Self {
+        self.set_double_click_maximize(double_click_maximize);
+        self
+    }
+
+    /// Set whether double-clicking the titlebar maximizes or restores the window.
+    pub fn set_double_click_maximize(&mut self, double_click_maximize: bool) {
+        self.double_click_maximize = double_click_maximize;
+    }
+}
+
+/// Whether the system moves the window when the mouse is over a titlebar, see
+/// [`WindowHandle::handle_titlebar`](druid::WindowHandle::handle_titlebar).
+const NATIVE_TITLEBAR: bool = cfg!(target_os = "windows");
+
+struct MoveDrag {
+    /// The mouse position when the drag started, in screen coordinates.
+    start: Point,
+    /// The window position when the drag started.
+    window: Point,
+}
+
+impl MoveDrag {
+    /// Returns the window position after moving the mouse to `pos`, in screen
+    /// coordinates.
+    fn window_position(&self, pos: Point) -> Point {
+        self.window + (pos - self.start)
+    }
+}
+
+/// The state a double click on the titlebar puts a window in.
+fn toggle_maximized(state: WindowState) -> WindowState {
+    match state {
+        WindowState::Maximized => WindowState::Restored,
+        _ => WindowState::Maximized,
+    }
+}
+
+/// The window operations of a titlebar, behind a trait so that tests can record them.
+trait TitleBarWindow {
+    fn position(&self) -> Point;
+    fn set_position(&mut self, position: Point);
+    fn window_state(&self) -> WindowState;
+    fn set_window_state(&mut self, state: WindowState);
+    fn handle_titlebar(&mut self, handle: bool);
+}
+
+impl TitleBarWindow for WindowHandle {
+    fn position(&self) -> Point {
+        self.get_position()
+    }
+
+    fn set_position(&mut self, position: Point) {
+        WindowHandle::set_position(self, position)
+    }
+
+    fn window_state(&self) -> WindowState {
+        self.get_window_state()
+    }
+
+    fn set_window_state(&mut self, state: WindowState) {
+        WindowHandle::set_window_state(self, state)
+    }
+
+    fn handle_titlebar(&mut self, handle: bool) {
+        WindowHandle::handle_titlebar(self, handle)
+    }
+}
+
+impl<T: Data> TitleBar<T> {
+    fn window_event(
+        &mut self,
+        window: &mut impl TitleBarWindow,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut T,
+        env: &Env,
+    ) {
+        match event {
+            Event::MouseMove(mouse) if self.drag.is_some() => {
+                let Some(drag) = &self.drag else { return };
+                let pos = window.position() + mouse.window_pos.to_vec2();
+                window.set_position(drag.window_position(pos));
+                ctx.set_handled();
+                return;
+            }
+            Event::MouseUp(_) if self.drag.is_some() => {
+                self.drag = None;
+                ctx.set_active(false);
+                ctx.set_handled();
+                return;
+            }
+            // the location of the mouse is checked by the system before the next click
+            Event::MouseMove(_) if NATIVE_TITLEBAR && self.draggable => {
+                window.handle_titlebar(true);
+            }
+            _ => {}
+        }
+
+        self.inner.event(ctx, event, data, env);
+
+        if let Event::MouseDown(mouse) = event {
+            if !ctx.is_handled() && mouse.button.is_left() {
+                if mouse.count == 2 && self.double_click_maximize {
+                    window.set_window_state(toggle_maximized(window.window_state()));
+                    ctx.set_handled();
+                } else if self.draggable
+                    && !NATIVE_TITLEBAR
+                    && window.window_state() == WindowState::Restored
+                {
+                    let origin = window.position();
+                    self.drag = Some(MoveDrag {
+                        start: origin + mouse.window_pos.to_vec2(),
+                        window: origin,
+                    });
+                    ctx.set_active(true);
+                    ctx.set_handled();
+                }
+            }
         }
     }
 }
 
-// Feed all calls through into inner.
 impl<T: Data> Widget<T> for TitleBar<T> {
     fn event(
         &mut self,
@@ -83,11 +235,8 @@ impl<T: Data> Widget<T> for TitleBar<T> {
         data: &mut T,
         env: &druid::Env,
     ) {
-        // On any mousemove event in this tell the window to handle the titlebar.
-        if let Event::MouseMove(_) = event {
-            ctx.window().handle_titlebar(true);
-        }
-        self.inner.event(ctx, event, data, env);
+        let mut window = ctx.window().clone();
+        self.window_event(&mut window, ctx, event, data, env);
     }
 
     fn lifecycle(
@@ -118,3 +267,414 @@ impl<T: Data> Widget<T> for TitleBar<T> {
         self.inner.paint(ctx, data, env);
     }
 }
+
+const GRIP_SIZE: f64 = 14.0;
+
+/// The edges of the window moved by a drag, at most two of them.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Edges {
+    left: bool,
+    right: bool,
+    top: bool,
+    bottom: bool,
+}
+
+impl Edges {
+    /// Returns the edges near `pos`, in a window of the given size.
+    fn at(pos: Point, size: Size, border: f64, grip: bool) -> Edges {
+        let in_grip = grip && pos.x >= size.width - GRIP_SIZE && pos.y >= size.height - GRIP_SIZE;
+        Edges {
+            left: pos.x < border,
+            right: pos.x >= size.width - border || in_grip,
+            top: pos.y < border,
+            bottom: pos.y >= size.height - border || in_grip,
+        }
+    }
+
+    fn any(&self) -> bool {
+        self.left || self.right || self.top || self.bottom
+    }
+
+    fn cursor(&self) -> Cursor {
+        match (self.left || self.right, self.top || self.bottom) {
+            (true, false) => Cursor::ResizeLeftRight,
+            (false, true) => Cursor::ResizeUpDown,
+            // druid has no diagonal resize cursors
+            _ => Cursor::Crosshair,
+        }
+    }
+
+    /// Returns the window `rect`, in screen coordinates, after dragging these edges
+    /// by `delta`, keeping at least `min_size`. The opposite edges don't move.
+    fn resize(&self, rect: Rect, delta: Vec2, min_size: Size) -> Rect {
+        let mut new = rect;
+        if self.left {
+            new.x0 = (rect.x0 + delta.x).min(rect.x1 - min_size.width);
+        }
+        if self.right {
+            new.x1 = (rect.x1 + delta.x).max(rect.x0 + min_size.width);
+        }
+        if self.top {
+            new.y0 = (rect.y0 + delta.y).min(rect.y1 - min_size.height);
+        }
+        if self.bottom {
+            new.y1 = (rect.y1 + delta.y).max(rect.y0 + min_size.height);
+        }
+        new
+    }
+}
+
+struct ResizeDrag {
+    edges: Edges,
+    /// The mouse position when the drag started, in screen coordinates.
+    start: Point,
+    /// The window when the drag started, in screen coordinates.
+    window: Rect,
+}
+
+/// A widget making its window resizable by dragging its edges and corners, for
+/// windows without the platform decorations, typically used with a [`TitleBar`].
+///
+/// It should be the root widget of the window. Mouse events within
+/// [`border_width`] of an edge, or on the grip painted in the bottom right corner,
+/// resize the window instead of being passed to the content. Unlike [`TitleBar`],
+/// this resizes the window itself, so it works on all platforms.
+///
+/// ```ignore
+/// let root = ResizeFrame::new(Flex::column().with_child(titlebar).with_flex_child(body, 1.0))
+///     .with_min_size((300.0, 200.0));
+/// let window = WindowDesc::new(root).show_titlebar(false);
+/// ```
+///
+/// [`border_width`]: ResizeFrame::with_border_width
+pub struct ResizeFrame<T> {
+    inner: WidgetPod<T, Box<dyn Widget<T>>>,
+    border_width: f64,
+    min_size: Size,
+    grip: bool,
+    drag: Option<ResizeDrag>,
+}
+
+impl<T: Data> ResizeFrame<T> {
+    pub fn new(inner: impl Widget<T> + 'static) -> ResizeFrame<T> {
+        ResizeFrame {
+            inner: WidgetPod::new(Box::new(inner)),
+            border_width: 5.0,
+            min_size: Size::new(100.0, 60.0),
+            grip: true,
+            drag: None,
+        }
+    }
+
+    /// Builder-style method for setting the width of the area along the edges
+    /// which resizes the window.
+    pub fn with_border_width(mut self, border_width: f64) -> Self {
+        self.set_border_width(border_width);
+        self
+    }
+
+    /// Set the width of the area along the edges which resizes the window.
+    pub fn set_border_width(&mut self, border_width: f64) {
+        self.border_width = border_width;
+    }
+
+    /// Builder-style method for setting the size below which the window can't be
+    /// resized.
+    pub fn with_min_size(mut self, min_size: impl Into<Size>) -> Self {
+        self.set_min_size(min_size);
+        self
+    }
+
+    /// Set the size below which the window can't be resized.
+    pub fn set_min_size(&mut self, min_size: impl Into<Size>) {
+        self.min_size = min_size.into();
+    }
+
+    /// Builder-style method for setting whether the resize grip is shown in the
+    /// bottom right corner.
+    pub fn with_grip(mut self, grip: bool) -> Self {
+        self.set_grip(grip);
+        self
+    }
+
+    /// Set whether the resize grip is shown in the bottom right corner.
+    pub fn set_grip(&mut self, grip: bool) {
+        self.grip = grip;
+    }
+
+    fn can_resize(ctx: &EventCtx) -> bool {
+        ctx.window().get_window_state() == WindowState::Restored
+    }
+}
+
+impl<T: Data> Widget<T> for ResizeFrame<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseDown(mouse) if mouse.button.is_left() && Self::can_resize(ctx) => {
+                let edges = Edges::at(mouse.pos, ctx.size(), self.border_width, self.grip);
+                if edges.any() {
+                    let origin = ctx.window().get_position();
+                    self.drag = Some(ResizeDrag {
+                        edges,
+                        start: origin + mouse.window_pos.to_vec2(),
+                        window: Rect::from_origin_size(origin, ctx.window().get_size()),
+                    });
+                    ctx.set_active(true);
+                    ctx.set_handled();
+                    return;
+                }
+            }
+            Event::MouseMove(mouse) if self.drag.is_some() => {
+                let Some(drag) = &self.drag else { return };
+                let pos = ctx.window().get_position() + mouse.window_pos.to_vec2();
+                let rect = drag
+                    .edges
+                    .resize(drag.window, pos - drag.start, self.min_size);
+                if rect.origin() != ctx.window().get_position() {
+                    ctx.window().set_position(rect.origin());
+                }
+                ctx.window().set_size(rect.size());
+                ctx.set_cursor(&drag.edges.cursor());
+                ctx.set_handled();
+                return;
+            }
+            Event::MouseUp(_) if self.drag.is_some() => {
+                self.drag = None;
+                ctx.set_active(false);
+                ctx.clear_cursor();
+                ctx.set_handled();
+                return;
+            }
+            _ => {}
+        }
+        self.inner.event(ctx, event, data, env);
+
+        if let Event::MouseMove(mouse) = event {
+            let edges = Edges::at(mouse.pos, ctx.size(), self.border_width, self.grip);
+            if edges.any() && Self::can_resize(ctx) {
+                ctx.set_cursor(&edges.cursor());
+            } else {
+                ctx.clear_cursor();
+            }
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let size = self.inner.layout(ctx, bc, data, env);
+        self.inner.set_origin(ctx, Point::ORIGIN);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env);
+
+        if self.grip {
+            let size = ctx.size();
+            let color = env.get(theme::BORDER_LIGHT);
+            for i in 1..=3 {
+                let offset = i as f64 * GRIP_SIZE / 4.0;
+                let line = Line::new(
+                    (size.width - offset, size.height - 2.0),
+                    (size.width - 2.0, size.height - offset),
+                );
+                ctx.stroke(line, &color, 1.0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::ModularWidget;
+    use druid::{MouseButton, MouseEvent};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Default)]
+    struct TestWindow {
+        position: Point,
+        state: Option<WindowState>,
+        moves: Vec<Point>,
+        handled_titlebar: bool,
+    }
+
+    impl TitleBarWindow for TestWindow {
+        fn position(&self) -> Point {
+            self.position
+        }
+
+        fn set_position(&mut self, position: Point) {
+            self.position = position;
+            self.moves.push(position);
+        }
+
+        fn window_state(&self) -> WindowState {
+            self.state.unwrap_or(WindowState::Restored)
+        }
+
+        fn set_window_state(&mut self, state: WindowState) {
+            self.state = Some(state);
+        }
+
+        fn handle_titlebar(&mut self, handle: bool) {
+            self.handled_titlebar = handle;
+        }
+    }
+
+    /// A titlebar driven with a test window.
+    struct WithWindow(TitleBar<()>, Rc<RefCell<TestWindow>>);
+
+    impl Widget<()> for WithWindow {
+        fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut (), env: &Env) {
+            let window = &mut *self.1.borrow_mut();
+            self.0.window_event(window, ctx, event, data, env);
+        }
+
+        fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &(), env: &Env) {
+            self.0.lifecycle(ctx, event, data, env);
+        }
+
+        fn update(&mut self, ctx: &mut UpdateCtx, old_data: &(), data: &(), env: &Env) {
+            self.0.update(ctx, old_data, data, env);
+        }
+
+        fn layout(
+            &mut self,
+            ctx: &mut LayoutCtx,
+            bc: &BoxConstraints,
+            data: &(),
+            env: &Env,
+        ) -> Size {
+            self.0.layout(ctx, bc, data, env)
+        }
+
+        fn paint(&mut self, ctx: &mut PaintCtx, data: &(), env: &Env) {
+            self.0.paint(ctx, data, env);
+        }
+    }
+
+    fn mouse(x: f64, count: u8) -> MouseEvent {
+        let pos = Point::new(x, 10.0);
+        MouseEvent {
+            pos,
+            window_pos: pos,
+            buttons: Default::default(),
+            mods: Default::default(),
+            count,
+            focus: false,
+            button: MouseButton::Left,
+            wheel_delta: Default::default(),
+        }
+    }
+
+    #[test]
+    fn drag_and_double_click() {
+        let window = Rc::new(RefCell::new(TestWindow {
+            position: Point::new(100.0, 100.0),
+            ..Default::default()
+        }));
+        // a button on the left of the titlebar handles its clicks
+        let button = ModularWidget::new(())
+            .event_fn(|_, ctx, event, _, _| {
+                if matches!(event, Event::MouseDown(mouse) if mouse.pos.x < 50.0) {
+                    ctx.set_handled();
+                }
+            })
+            .layout_fn(|_, _, bc, _, _| bc.constrain((200.0, 30.0)));
+        let titlebar = WithWindow(TitleBar::new(button), window.clone());
+
+        Harness::create_simple((), titlebar, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+
+            harness.event(Event::MouseDown(mouse(100.0, 1)));
+            harness.event(Event::MouseMove(mouse(130.0, 0)));
+            harness.event(Event::MouseUp(mouse(130.0, 0)));
+            harness.event(Event::MouseMove(mouse(150.0, 0)));
+            if NATIVE_TITLEBAR {
+                // the system moves the window
+                assert!(window.borrow().moves.is_empty());
+                assert!(window.borrow().handled_titlebar);
+            } else {
+                assert_eq!(window.borrow().moves, [Point::new(130.0, 100.0)]);
+            }
+
+            // the clicks handled by the inner widget don't move the window
+            window.borrow_mut().moves.clear();
+            harness.event(Event::MouseDown(mouse(10.0, 1)));
+            harness.event(Event::MouseMove(mouse(40.0, 0)));
+            harness.event(Event::MouseUp(mouse(40.0, 0)));
+            assert!(window.borrow().moves.is_empty());
+
+            harness.event(Event::MouseDown(mouse(100.0, 2)));
+            assert_eq!(window.borrow().state, Some(WindowState::Maximized));
+            harness.event(Event::MouseUp(mouse(100.0, 2)));
+            harness.event(Event::MouseDown(mouse(100.0, 2)));
+            assert_eq!(window.borrow().state, Some(WindowState::Restored));
+        });
+    }
+
+    #[test]
+    fn move_window() {
+        let drag = MoveDrag {
+            start: Point::new(150.0, 110.0),
+            window: Point::new(100.0, 100.0),
+        };
+        assert_eq!(
+            drag.window_position(Point::new(180.0, 90.0)),
+            Point::new(130.0, 80.0)
+        );
+    }
+
+    #[test]
+    fn double_click_toggles_maximized() {
+        let state = toggle_maximized(WindowState::Restored);
+        assert_eq!(state, WindowState::Maximized);
+        assert_eq!(toggle_maximized(state), WindowState::Restored);
+        assert_eq!(
+            toggle_maximized(WindowState::Minimized),
+            WindowState::Maximized
+        );
+    }
+
+    #[test]
+    fn resize_edges() {
+        let size = Size::new(200.0, 100.0);
+        let at = |x, y| Edges::at(Point::new(x, y), size, 5.0, true);
+        assert!(!at(100.0, 50.0).any());
+        assert_eq!(at(2.0, 50.0).cursor(), Cursor::ResizeLeftRight);
+        assert_eq!(at(100.0, 98.0).cursor(), Cursor::ResizeUpDown);
+        // the grip is a corner
+        assert!(at(190.0, 90.0).right && at(190.0, 90.0).bottom);
+    }
+
+    #[test]
+    fn resize_rect() {
+        let window = Rect::new(100.0, 100.0, 400.0, 300.0);
+        let min_size = Size::new(100.0, 50.0);
+        let top_left = Edges {
+            left: true,
+            top: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            top_left.resize(window, Vec2::new(-10.0, 20.0), min_size),
+            Rect::new(90.0, 120.0, 400.0, 300.0)
+        );
+        // the opposite edges stay in place at the minimum size
+        assert_eq!(
+            top_left.resize(window, Vec2::new(500.0, 500.0), min_size),
+            Rect::new(300.0, 250.0, 400.0, 300.0)
+        );
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use druid::commands::CLOSE_WINDOW;
+use druid::commands::{CLOSE_WINDOW, CONFIGURE_WINDOW};
 use druid::widget::prelude::*;
 use druid::widget::{Controller, Label, LabelText};
 use druid::{
@@ -29,15 +29,87 @@ pub(crate) enum TooltipState {
     },
 }
 
+type MakeTooltipFn<T> = Box<dyn Fn() -> Box<dyn Widget<T>>>;
+
+/// What a tooltip shows.
+enum TooltipContent<T> {
+    Text(LabelText<T>),
+    Widget(MakeTooltipFn<T>),
+}
+
 /// A [`Controller`] responsible for listening to mouse hovers and launching tooltip windows.
 ///
-/// Instead of constructing this widget explicitly, you probably want to use
-/// [`TooltipExt::tooltip`].
+/// The tooltip shows a text, or any widget built with [`with_widget`], like an image
+/// preview.
+///
+/// The tooltip is shown once the mouse rested over the widget for a delay, which is
+/// restarted whenever the mouse moves, and cancelled when the mouse leaves the widget.
+///
+/// Unless the delay or the cursor following must be configured, you probably want to
+/// use [`TooltipExt::tooltip`] instead of constructing this widget explicitly.
 ///
 /// [`Controller`]: druid::widget::Controller
+/// [`with_widget`]: TooltipController::with_widget
 pub struct TooltipController<T> {
-    pub(crate) text: LabelText<T>,
+    content: TooltipContent<T>,
     pub(crate) state: TooltipState,
+    delay: Duration,
+    follow_cursor: bool,
+}
+
+impl<T> TooltipController<T> {
+    /// Create a controller showing a tooltip with `text`.
+    pub fn new(text: impl Into<LabelText<T>>) -> Self {
+        Self::with_content(TooltipContent::Text(text.into()))
+    }
+
+    /// Create a controller showing a tooltip with a widget built by `make_widget`.
+    ///
+    /// A new widget is built each time the tooltip is shown, in its own window, and is
+    /// given the same data as the widget of the controller.
+    pub fn with_widget<W: Widget<T> + 'static>(make_widget: impl Fn() -> W + 'static) -> Self {
+        Self::with_content(TooltipContent::Widget(Box::new(move || {
+            Box::new(make_widget())
+        })))
+    }
+
+    fn with_content(content: TooltipContent<T>) -> Self {
+        TooltipController {
+            content,
+            state: TooltipState::Off,
+            delay: TOOLTIP_DELAY,
+            follow_cursor: false,
+        }
+    }
+
+    /// Builder-style method to set how long the mouse must rest over the widget before
+    /// the tooltip is shown. The default is 350ms.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Builder-style method to make the tooltip follow the mouse while it moves over
+    /// the widget, instead of closing it until the mouse rests again.
+    pub fn follow_cursor(mut self, follow_cursor: bool) -> Self {
+        self.follow_cursor = follow_cursor;
+        self
+    }
+}
+
+impl<T: Data> TooltipController<T> {
+    /// Build the widget shown in the tooltip window.
+    fn content_widget(&mut self, data: &T, env: &Env) -> Box<dyn Widget<T>> {
+        match &mut self.content {
+            TooltipContent::Text(text) => {
+                text.resolve(data, env);
+                // FIXME: we'd like to use the actual label text instead of
+                // resolving, but LabelText isn't Clone
+                Box::new(Label::new(text.display_text()))
+            }
+            TooltipContent::Widget(make_widget) => make_widget(),
+        }
+    }
 }
 
 impl<T: Data, W: Widget<T>> Controller<T, W> for TooltipController<T> {
@@ -57,8 +129,8 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for TooltipController<T> {
                 Event::Timer(tok) if tok == &timer => {
                     ctx.set_handled();
                     let elapsed = Instant::now().duration_since(last_mouse_move);
-                    if elapsed > TOOLTIP_DELAY_CHECK {
-                        self.text.resolve(data, env);
+                    if elapsed > self.delay.saturating_sub(TOOLTIP_DELAY_TOLERANCE) {
+                        let content = self.content_widget(data, env);
                         let tooltip_position_in_window_coordinates =
                             last_mouse_pos + TOOLTIP_OFFSET;
                         let win_id = ctx.new_sub_window(
@@ -67,9 +139,7 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for TooltipController<T> {
                                 .window_size_policy(WindowSizePolicy::Content)
                                 .set_level(WindowLevel::Tooltip(ctx.window().clone()))
                                 .set_position(tooltip_position_in_window_coordinates),
-                            // FIXME: we'd like to use the actual label text instead of
-                            // resolving, but LabelText isn't Clone
-                            Label::new(self.text.display_text())
+                            content
                                 .border(TOOLTIP_BORDER_COLOR, TOOLTIP_BORDER_WIDTH)
                                 .on_monitor(ctx.window()),
                             data.clone(),
@@ -81,7 +151,7 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for TooltipController<T> {
                         }
                     } else {
                         TooltipState::Waiting {
-                            timer: ctx.request_timer(TOOLTIP_DELAY - elapsed),
+                            timer: ctx.request_timer(self.delay.saturating_sub(elapsed)),
                             last_mouse_move,
                             last_mouse_pos,
                         }
@@ -91,7 +161,7 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for TooltipController<T> {
             },
             TooltipState::Off => match ev {
                 Event::MouseMove(ev) if ctx.is_hot() => TooltipState::Waiting {
-                    timer: ctx.request_timer(TOOLTIP_DELAY),
+                    timer: ctx.request_timer(self.delay),
                     last_mouse_move: Instant::now(),
                     last_mouse_pos: ev.window_pos,
                 },
@@ -103,15 +173,23 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for TooltipController<T> {
                     // MouseMove event, with a mouse position that tends to be slightly different
                     // than the previous one. If we don't test the positions, this causes the
                     // tooltip to immediately close.
-                    if (ev.window_pos - last_mouse_pos).hypot2() > 1.0 {
+                    if (ev.window_pos - last_mouse_pos).hypot2() <= 1.0 {
+                        self.state.clone()
+                    } else if self.follow_cursor {
+                        let config =
+                            WindowConfig::default().set_position(ev.window_pos + TOOLTIP_OFFSET);
+                        ctx.submit_command(CONFIGURE_WINDOW.with(config).to(id));
+                        TooltipState::Showing {
+                            id,
+                            last_mouse_pos: ev.window_pos,
+                        }
+                    } else {
                         ctx.submit_command(CLOSE_WINDOW.to(id));
                         TooltipState::Waiting {
-                            timer: ctx.request_timer(TOOLTIP_DELAY),
+                            timer: ctx.request_timer(self.delay),
                             last_mouse_move: Instant::now(),
                             last_mouse_pos: ev.window_pos,
                         }
-                    } else {
-                        self.state.clone()
                     }
                 }
                 Event::MouseMove(_) | Event::MouseUp(_) | Event::MouseDown(_) => {
@@ -133,9 +211,14 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for TooltipController<T> {
         env: &Env,
     ) {
         if let LifeCycle::HotChanged(false) = ev {
-            if let TooltipState::Showing { id, .. } = self.state {
-                ctx.submit_command(CLOSE_WINDOW.to(id));
-                self.state = TooltipState::Off;
+            match self.state {
+                TooltipState::Showing { id, .. } => {
+                    ctx.submit_command(CLOSE_WINDOW.to(id));
+                    self.state = TooltipState::Off;
+                }
+                // leaving the widget cancels a pending tooltip
+                TooltipState::Waiting { .. } => self.state = TooltipState::Off,
+                TooltipState::Off => {}
             }
         }
         child.lifecycle(ctx, ev, data, env);
@@ -143,7 +226,9 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for TooltipController<T> {
 }
 
 const TOOLTIP_DELAY: Duration = Duration::from_millis(350);
-const TOOLTIP_DELAY_CHECK: Duration = Duration::from_millis(320);
+// Timers may fire a little early, so the tooltip is shown if the mouse rested for
+// almost the whole delay.
+const TOOLTIP_DELAY_TOLERANCE: Duration = Duration::from_millis(30);
 const TOOLTIP_BORDER_COLOR: Color = Color::BLACK;
 const TOOLTIP_BORDER_WIDTH: f64 = 1.0;
 // It looks better if we don't put the tooltip *right* on the tip of the mouse,
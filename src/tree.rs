@@ -3,21 +3,30 @@
 
 //! A tree widget.
 
+use std::any::Any;
 use std::convert::TryFrom;
 use std::fmt::Display;
+#[cfg(feature = "async")]
+use std::future::Future;
 use std::marker::PhantomData;
+#[cfg(feature = "async")]
+use std::pin::Pin;
 use std::sync::Arc;
 
-use druid::kurbo::{BezPath, Size};
-use druid::piet::{LineCap, LineJoin, RenderContext, StrokeStyle};
-use druid::widget::Label;
+use druid::kurbo::Size;
+use druid::widget::{Label, Spinner};
 use druid::{theme, Lens, LensExt};
 use druid::{
-    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, PaintCtx,
-    Point, Selector, UpdateCtx, Widget, WidgetId, WidgetPod,
+    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, Modifiers,
+    PaintCtx, Point, Selector, SingleUse, Target, UpdateCtx, Widget, WidgetId, WidgetPod,
 };
 
 use crate::selectors;
+use crate::wedge::WedgeRotation;
+use crate::DynLens;
+
+#[cfg(feature = "derive")]
+pub use druid_widget_nursery_derive::TreeNode;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ChrootStatus {
@@ -26,10 +35,6 @@ pub enum ChrootStatus {
     ROOT,
 }
 
-// TODO:
-//   - TREE_CLOSE command that mirrors TreeOpen
-//   - TREE_OPEN_ALL command to open recursively
-//   - TREE_CLOSE_ALL command to close recursively
 selectors! {
     /// Notification to send from the widget that requires removal
     TREE_NODE_REMOVE,
@@ -38,6 +43,12 @@ selectors! {
     TREE_CHILD_REMOVE_INTERNAL: i32,
     /// Notification that opens the first encountered branch node.
     TREE_OPEN,
+    /// Notification that closes the first encountered branch node.
+    TREE_CLOSE,
+    /// Notification that opens the first encountered branch node and all its descendants.
+    TREE_OPEN_ALL,
+    /// Notification that closes the first encountered branch node and all its descendants.
+    TREE_CLOSE_ALL,
     /// Command sent to children on open
     TREE_CHILD_SHOW,
     /// Command sent to children on close
@@ -58,6 +69,40 @@ selectors! {
     TREE_NOTIFY_PARENT: Selector,
     /// Notify an opener's widget on click.
     TREE_ACTIVATE_NODE,
+    /// Notification sent by the tree when the user expanded a node through its opener.
+    /// The payload is the path of the node: the indices of the children leading to it
+    /// from the root node, which has an empty path.
+    ///
+    /// This isn't sent when the expanded state is changed in the data.
+    TREE_NODE_EXPANDED: Vec<usize>,
+    /// Notification sent by the tree when the user collapsed a node through its opener.
+    /// The payload is the path of the node, as for [`TREE_NODE_EXPANDED`].
+    TREE_NODE_COLLAPSED: Vec<usize>,
+}
+
+/// A click on the widget of a node, sent up to the tree with the path of the node.
+struct NodeClick {
+    path: Vec<usize>,
+    mods: Modifiers,
+}
+
+// Notification sent by a node when its widget was clicked, each parent prepends its index.
+const TREE_NODE_CLICKED: Selector<NodeClick> = Selector::new("tree.node-clicked");
+
+// Command sent to a node by the task loading its children, see Tree::with_loader. The
+// payload is the generation of the load, and a Vec of the children.
+const TREE_CHILDREN_LOADED: Selector<SingleUse<(u64, Box<dyn Any + Send>)>> =
+    Selector::new("tree.children-loaded");
+
+// Notification sent by a node when the mouse was released over its widget, with its path
+// as for TREE_NODE_CLICKED. The tree moves the dragged node there.
+const TREE_NODE_MOVE: Selector<Vec<usize>> = Selector::new("tree.node-move");
+
+selectors! {
+    /// Notification sent by a tree with a selection, see [`Tree::with_selection`], when the
+    /// user changed the selection. The payload is the paths of all the selected nodes, in
+    /// the order they are shown, with the same paths as for [`TREE_NODE_EXPANDED`].
+    TREE_SELECTION_CHANGED: Vec<Vec<usize>>,
 }
 
 /// A tree widget for a collection of items organized in a hierarchical way.
@@ -69,6 +114,163 @@ where
     /// The root node of this tree
     root_node: WidgetPod<T, TreeNodeWidget<T, L>>,
     chroot: WidgetId,
+    selection: Option<Selection<T>>,
+    draggable: bool,
+    /// The path of the node being dragged.
+    drag: Option<Vec<usize>>,
+}
+
+/// The selection model of a tree, see [`Tree::with_selection`].
+struct Selection<T> {
+    lens: Box<dyn DynLens<T, bool>>,
+    /// The path of the node a shift-click extends the selection from.
+    anchor: Option<Vec<usize>>,
+}
+
+impl<T: TreeNode> Selection<T> {
+    /// Updates the selected flags after a click on the node at `path`, with `visible` the
+    /// paths of the shown nodes, in order.
+    fn click(&mut self, data: &mut T, path: &[usize], mods: Modifiers, visible: &[Vec<usize>]) {
+        let toggle = mods.ctrl() || mods.meta();
+        let position = |path: &[usize]| visible.iter().position(|p| p == path);
+        let range = match (&self.anchor, mods.shift()) {
+            (Some(anchor), true) => position(anchor).zip(position(path)),
+            _ => None,
+        };
+
+        if let Some((anchor, clicked)) = range {
+            // the anchor stays, so that the range can be changed by another shift-click
+            if !toggle {
+                self.select_all(data, false);
+            }
+            for path in &visible[anchor.min(clicked)..=anchor.max(clicked)] {
+                self.put(data, path, true);
+            }
+        } else {
+            if toggle {
+                let selected = self.get(data, path);
+                self.put(data, path, !selected);
+            } else {
+                self.select_all(data, false);
+                self.put(data, path, true);
+            }
+            self.anchor = Some(path.to_vec());
+        }
+    }
+
+    fn get(&self, data: &T, path: &[usize]) -> bool {
+        let node = path.iter().fold(data, |node, index| node.get_child(*index));
+        self.lens.with(node, |selected| *selected)
+    }
+
+    fn put(&self, data: &mut T, path: &[usize], selected: bool) {
+        with_node_mut(data, path, &mut |node| {
+            self.lens.with_mut(node, |value| *value = selected)
+        });
+    }
+
+    fn select_all(&self, data: &mut T, selected: bool) {
+        self.lens.with_mut(data, |value| *value = selected);
+        for index in 0..data.children_count() {
+            data.for_child_mut(index, |child, _| self.select_all(child, selected));
+        }
+    }
+
+    /// The paths of the selected nodes, in the order they are shown.
+    fn selected_paths(&self, data: &T) -> Vec<Vec<usize>> {
+        fn visit<T: TreeNode>(
+            selection: &Selection<T>,
+            data: &T,
+            path: &mut Vec<usize>,
+            paths: &mut Vec<Vec<usize>>,
+        ) {
+            if selection.lens.with(data, |selected| *selected) {
+                paths.push(path.clone());
+            }
+            for index in 0..data.children_count() {
+                path.push(index);
+                visit(selection, data.get_child(index), path, paths);
+                path.pop();
+            }
+        }
+        let mut paths = Vec::new();
+        visit(self, data, &mut Vec::new(), &mut paths);
+        paths
+    }
+}
+
+/// Calls `f` with the node at `path`, the indices of the children leading to it.
+fn with_node_mut<T: TreeNode>(data: &mut T, path: &[usize], f: &mut dyn FnMut(&mut T)) {
+    match path.split_first() {
+        None => f(data),
+        Some((index, rest)) => {
+            data.for_child_mut(*index, |child, _| with_node_mut(child, rest, &mut *f))
+        }
+    }
+}
+
+/// Moves the node at `from` to the children of the node at `to`, inserting it at `index`,
+/// which is relative to the children before the move.
+///
+/// Returns false without changing the data if the node can't be moved there: the root node,
+/// a node moved within itself, or a node which doesn't support insertion.
+fn move_node<T: TreeNode>(data: &mut T, from: &[usize], to: &[usize], index: usize) -> bool {
+    if from.is_empty() || to.starts_with(from) {
+        return false;
+    }
+    let node = from
+        .iter()
+        .fold(&*data, |node, index| node.get_child(*index));
+    let mut node = Some(node.clone());
+
+    let mut inserted = false;
+    with_node_mut(data, to, &mut |parent| {
+        let count = parent.children_count();
+        parent.insert_child(index, node.take().unwrap());
+        inserted = parent.children_count() > count;
+    });
+    if !inserted {
+        return false;
+    }
+
+    // the insertion shifts the node if it comes after it in the same parent
+    let mut from = from.to_vec();
+    if from.len() > to.len() && from.starts_with(to) && from[to.len()] >= index {
+        from[to.len()] += 1;
+    }
+    let (removed, parent) = from.split_last().unwrap();
+    with_node_mut(data, parent, &mut |parent| parent.rm_child(*removed));
+    true
+}
+
+/// The paths of the nodes which are shown, those whose ancestors are all expanded, in
+/// the order they are shown. When the tree is chrooted, only the virtual root and its
+/// descendants are shown.
+fn visible_paths<T: TreeNode, L: Lens<T, bool>>(data: &T, expand_lens: &L) -> Vec<Vec<usize>> {
+    fn visit<T: TreeNode, L: Lens<T, bool>>(
+        data: &T,
+        expand_lens: &L,
+        path: &mut Vec<usize>,
+        paths: &mut Vec<Vec<usize>>,
+    ) {
+        paths.push(path.clone());
+        if data.is_branch() && expand_lens.get(data) {
+            for index in 0..data.children_count() {
+                path.push(index);
+                visit(data.get_child(index), expand_lens, path, paths);
+                path.pop();
+            }
+        }
+    }
+    let mut path = Vec::new();
+    let mut root = data;
+    while let Some(index) = root.get_chroot() {
+        path.push(index);
+        root = root.get_child(index);
+    }
+    let mut paths = Vec::new();
+    visit(root, expand_lens, &mut path, &mut paths);
+    paths
 }
 
 /// A tree node `Data`. This is the data expected by the tree widget.
@@ -79,6 +281,11 @@ where
 /// abstracted away in the data as long as `children_count()`, `get_child()`,
 /// rm_child() and for_child_mut()` accessors give coherent results. This is
 /// a way to implement filtering and sorting at the app data level.
+///
+/// With the `derive` feature, this can be derived for structs storing their children
+/// in a `Vector` of `Self` or `Arc<Self>`, in a field marked with
+/// `#[tree_children]`. A `bool` field marked with `#[tree_branch]` is used for
+/// [`is_branch`](TreeNode::is_branch).
 pub trait TreeNode
 where
     Self: Data + std::fmt::Debug,
@@ -125,6 +332,35 @@ where
     /// Remove the child at `index`
     #[allow(unused_variables)]
     fn rm_child(&mut self, index: usize) {}
+
+    /// Insert `child` at `index`, moving the following children after it.
+    ///
+    /// Along with [`rm_child`](TreeNode::rm_child), this is required to move the nodes of
+    /// a [`draggable`](Tree::draggable) tree.
+    #[allow(unused_variables)]
+    fn insert_child(&mut self, index: usize, child: Self) {}
+
+    /// Returns `false` if this node has children which are not loaded yet, so that the
+    /// loader of the tree fetches them when the node is expanded, see
+    /// `Tree::with_loader`. Such a node should also return `true` from
+    /// [`is_branch`](TreeNode::is_branch).
+    ///
+    /// The default implementation returns always `true`.
+    fn children_loaded(&self) -> bool {
+        true
+    }
+
+    /// Store the children fetched by the loader of the tree. Afterwards,
+    /// [`children_loaded`](TreeNode::children_loaded) should return `true`.
+    ///
+    /// The default implementation adds them after the existing children with
+    /// [`insert_child`](TreeNode::insert_child).
+    fn load_children(&mut self, children: Vec<Self>) {
+        let count = self.children_count();
+        for (index, child) in children.into_iter().enumerate() {
+            self.insert_child(count + index, child);
+        }
+    }
 }
 
 // Wrapper widget that reacts to clicks by sending a TREE_ACTIVATE_NODE command to
@@ -188,11 +424,13 @@ where
     L: Lens<T, bool>,
 {
     expand_lens: L,
+    rotation: WedgeRotation,
     phantom: PhantomData<T>,
 }
 
 impl<T: TreeNode, L: Lens<T, bool>> Widget<T> for Wedge<T, L> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, _env: &Env) {
+        self.rotation.event(ctx, event);
         match event {
             Event::Command(cmd) if cmd.is(TREE_ACTIVATE_NODE) => {
                 self.expand_lens.put(data, !self.expand_lens.get(data));
@@ -202,9 +440,18 @@ impl<T: TreeNode, L: Lens<T, bool>> Widget<T> for Wedge<T, L> {
         }
     }
 
-    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &T, _env: &Env) {}
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, _env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            self.rotation.jump(self.expand_lens.get(data));
+        }
+    }
 
-    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &T, _data: &T, _env: &Env) {}
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        let expanded = self.expand_lens.get(data);
+        if self.expand_lens.get(old_data) != expanded {
+            self.rotation.rotate(ctx, expanded, env);
+        }
+    }
 
     fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, env: &Env) -> Size {
         let size = env.get(theme::BASIC_WIDGET_HEIGHT);
@@ -222,32 +469,20 @@ impl<T: TreeNode, L: Lens<T, bool>> Widget<T> for Wedge<T, L> {
         };
 
         // Paint the opener
-        let mut path = BezPath::new();
-        if self.expand_lens.get(data) {
-            // expanded: 'V' shape
-            path.move_to((5.0, 7.0));
-            path.line_to((9.0, 13.0));
-            path.line_to((13.0, 7.0));
-        } else {
-            // collapsed: '>' shape
-            path.move_to((7.0, 5.0));
-            path.line_to((13.0, 9.0));
-            path.line_to((7.0, 13.0));
-        }
-        let style = StrokeStyle::new()
-            .line_cap(LineCap::Round)
-            .line_join(LineJoin::Round);
-
-        ctx.stroke_styled(path, &stroke_color, 2.5, &style);
+        self.rotation.paint(ctx, 18.0, &stroke_color);
     }
 }
 
 type TreeItemFactory<T> = Arc<dyn Fn() -> Box<dyn Widget<T>>>;
 type OpenerFactory<T> = dyn Fn() -> Box<dyn Widget<T>>;
+#[cfg(feature = "async")]
+type ChildrenLoader<T> =
+    Arc<dyn Fn(&T) -> Pin<Box<dyn Future<Output = Box<dyn Any + Send>> + Send>>>;
 
 fn make_wedge<T: TreeNode, L: Lens<T, bool>>(expand_lens: L) -> Wedge<T, L> {
     Wedge {
         phantom: PhantomData,
+        rotation: WedgeRotation::new(),
         expand_lens,
     }
 }
@@ -274,6 +509,14 @@ where
     /// The user must provide a Lens<T, bool> that tells if
     /// the node is expanded or not.
     expand_lens: L,
+    /// The loader fetching the children of nodes which are not loaded yet.
+    #[cfg(feature = "async")]
+    loader: Option<ChildrenLoader<T>>,
+    /// Shown instead of the opener while the children are loading.
+    spinner: Option<WidgetPod<T, Spinner>>,
+    /// Counts the loads of the children, so that the children of a cancelled load are
+    /// ignored.
+    load_generation: u64,
 }
 
 impl<T: TreeNode, L: Lens<T, bool> + Clone> TreeNodeWidget<T, L> {
@@ -295,6 +538,10 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> TreeNodeWidget<T, L> {
             make_widget,
             make_opener,
             expand_lens,
+            #[cfg(feature = "async")]
+            loader: None,
+            spinner: None,
+            load_generation: 0,
         }
     }
 
@@ -311,17 +558,137 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> TreeNodeWidget<T, L> {
                 changed |= index >= self.children.len();
                 match self.children.get_mut(index) {
                     Some(c) => c.widget_mut().index = index,
-                    None => self.children.push(WidgetPod::new(TreeNodeWidget::new(
-                        self.make_widget.clone(),
-                        self.make_opener.clone(),
-                        index,
-                        self.expand_lens.clone(),
-                    ))),
+                    None => {
+                        #[allow(unused_mut)]
+                        let mut child = TreeNodeWidget::new(
+                            self.make_widget.clone(),
+                            self.make_opener.clone(),
+                            index,
+                            self.expand_lens.clone(),
+                        );
+                        #[cfg(feature = "async")]
+                        {
+                            child.loader = self.loader.clone();
+                        }
+                        self.children.push(WidgetPod::new(child))
+                    }
                 }
             }
         }
         changed
     }
+
+    /// Spawn the loader of the tree if the node has children which are not loaded yet,
+    /// showing a spinner until they are.
+    #[cfg(feature = "async")]
+    fn spawn_loader(&mut self, ctx: &mut EventCtx, data: &T) {
+        let loader = match &self.loader {
+            Some(loader) if !data.children_loaded() && self.spinner.is_none() => loader,
+            _ => return,
+        };
+        let future = loader(data);
+        let sink = ctx.get_external_handle();
+        let widget_id = ctx.widget_id();
+        self.load_generation += 1;
+        let generation = self.load_generation;
+        tokio::spawn(async move {
+            let children = future.await;
+            let _ = sink.submit_command(
+                TREE_CHILDREN_LOADED,
+                SingleUse::new((generation, children)),
+                Target::Widget(widget_id),
+            );
+        });
+        self.spinner = Some(WidgetPod::new(Spinner::new()));
+        ctx.children_changed();
+    }
+
+    /// Store the children fetched by the loader, and show them if the node is expanded.
+    ///
+    /// The children are ignored if the load was cancelled.
+    fn children_loaded(
+        &mut self,
+        ctx: &mut EventCtx,
+        data: &mut T,
+        generation: u64,
+        children: Box<dyn Any>,
+    ) {
+        if self.spinner.is_none() || generation != self.load_generation {
+            return;
+        }
+        match children.downcast::<Vec<T>>() {
+            Ok(children) => data.load_children(*children),
+            Err(_) => log::error!("the loader of the tree returned children of the wrong type"),
+        }
+        self.spinner = None;
+        self.update_children(data);
+        if self.expand_lens.get(data) {
+            for child_widget_node in self.children.iter_mut() {
+                ctx.submit_command(TREE_CHILD_SHOW.to(child_widget_node.id()))
+            }
+        }
+        ctx.children_changed();
+        ctx.request_layout();
+    }
+
+    /// Cancel the loads of the children of the node and its descendants, for example
+    /// because the nodes were moved and the widgets now show other nodes.
+    ///
+    /// Returns whether a load was cancelled.
+    fn cancel_loads(&mut self) -> bool {
+        let cancelled = self.spinner.take().is_some();
+        self.children
+            .iter_mut()
+            .fold(cancelled, |cancelled, child| {
+                child.widget_mut().cancel_loads() | cancelled
+            })
+    }
+
+    /// Expand or collapse the node and all its descendants, creating the child widgets
+    /// as needed, and tell the children of the nodes which changed.
+    fn set_expanded_recursive(&mut self, ctx: &mut EventCtx, data: &mut T, expanded: bool) {
+        if !data.is_branch() {
+            return;
+        }
+        let before = self.expand_lens.get(data);
+        self.expand_lens.put(data, expanded);
+        self.update_children(data);
+
+        let children = &mut self.children;
+        let expand_lens = &self.expand_lens;
+        for index in 0..data.children_count() {
+            if let (true, Some(child)) = (expanded != before, children.get(index)) {
+                let cmd = if expanded {
+                    TREE_CHILD_SHOW
+                } else {
+                    TREE_CHILD_HIDE
+                };
+                ctx.submit_command(cmd.to(child.id()));
+            }
+            data.for_child_mut(index, |data: &mut T, _index: usize| {
+                match children.get_mut(index) {
+                    Some(child) => child
+                        .widget_mut()
+                        .set_expanded_recursive(ctx, data, expanded),
+                    // collapsed nodes may have no widgets for their children yet
+                    None => set_data_expanded(expand_lens, data, expanded),
+                }
+            });
+        }
+    }
+}
+
+/// Expand or collapse a node and all its descendants in the data only.
+fn set_data_expanded<T: TreeNode, L: Lens<T, bool>>(expand_lens: &L, data: &mut T, expanded: bool) {
+    if !data.is_branch() {
+        return;
+    }
+    expand_lens.put(data, expanded);
+    for index in 0..data.children_count() {
+        data.for_child_mut(index, |data: &mut T, _index: usize| {
+            set_data_expanded(expand_lens, data, expanded)
+        });
+    }
 }
 
 impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
@@ -336,6 +703,8 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
                     ctx.set_handled();
                     if !self.expand_lens.get(data) {
                         self.expand_lens.put(data, true);
+                        #[cfg(feature = "async")]
+                        self.spawn_loader(ctx, data);
                         if self.update_children(data) {
                             ctx.children_changed();
                         }
@@ -348,6 +717,43 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
                     Some(event)
                 }
             }
+            Event::Notification(notif) if notif.is(TREE_CLOSE) => {
+                if data.is_branch() {
+                    ctx.set_handled();
+                    if self.expand_lens.get(data) {
+                        self.expand_lens.put(data, false);
+                        for child_widget_node in self.children.iter_mut() {
+                            ctx.submit_command(TREE_CHILD_HIDE.to(child_widget_node.id()))
+                        }
+                        ctx.request_layout();
+                    }
+                    None
+                } else {
+                    Some(event)
+                }
+            }
+            Event::Notification(notif) if notif.is(TREE_OPEN_ALL) || notif.is(TREE_CLOSE_ALL) => {
+                if data.is_branch() {
+                    ctx.set_handled();
+                    self.set_expanded_recursive(ctx, data, notif.is(TREE_OPEN_ALL));
+                    ctx.children_changed();
+                    ctx.request_layout();
+                    None
+                } else {
+                    Some(event)
+                }
+            }
+            Event::Command(cmd)
+                if cmd.is(TREE_CHILDREN_LOADED)
+                    && cmd.target() == Target::Widget(ctx.widget_id()) =>
+            {
+                if let Some((generation, children)) = cmd.get_unchecked(TREE_CHILDREN_LOADED).take()
+                {
+                    self.children_loaded(ctx, data, generation, children);
+                }
+                ctx.set_handled();
+                None
+            }
             Event::Notification(notif) if notif.is(TREE_NODE_REMOVE) => {
                 // we were commanded to remove ourselves. Let's tell our parent.
                 ctx.submit_notification(TREE_CHILD_REMOVE_INTERNAL.with(self.index as i32));
@@ -395,6 +801,38 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
                 }
                 None
             }
+            Event::Notification(notif)
+                if notif.is(TREE_NODE_EXPANDED) || notif.is(TREE_NODE_COLLAPSED) =>
+            {
+                // a descendant was toggled, prepend our index to its path
+                let (selector, path) = match notif.get(TREE_NODE_EXPANDED) {
+                    Some(path) => (TREE_NODE_EXPANDED, path),
+                    None => (TREE_NODE_COLLAPSED, notif.get(TREE_NODE_COLLAPSED).unwrap()),
+                };
+                let mut path = path.clone();
+                path.insert(0, self.index);
+                ctx.submit_notification(selector.with(path));
+                ctx.set_handled();
+                None
+            }
+            Event::Notification(notif) if notif.is(TREE_NODE_CLICKED) => {
+                let click = notif.get(TREE_NODE_CLICKED).unwrap();
+                let mut path = click.path.clone();
+                path.insert(0, self.index);
+                ctx.submit_notification(TREE_NODE_CLICKED.with(NodeClick {
+                    path,
+                    mods: click.mods,
+                }));
+                ctx.set_handled();
+                None
+            }
+            Event::Notification(notif) if notif.is(TREE_NODE_MOVE) => {
+                let mut path = notif.get(TREE_NODE_MOVE).unwrap().clone();
+                path.insert(0, self.index);
+                ctx.submit_notification(TREE_NODE_MOVE.with(path));
+                ctx.set_handled();
+                None
+            }
             Event::Notification(notif) if notif.is(TREE_NOTIFY_PARENT) => {
                 if self.widget.id() != notif.source() {
                     let notif = notif.get(TREE_NOTIFY_PARENT).unwrap();
@@ -418,6 +856,24 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
         let chrooted = data.get_chroot();
         if chrooted.is_none() | event.should_propagate_to_hidden() {
             self.widget.event(ctx, event, data, env);
+            match event {
+                Event::MouseDown(mouse)
+                    if self.widget.layout_rect().contains(mouse.pos)
+                        && mouse.button.is_left()
+                        && !ctx.is_handled() =>
+                {
+                    ctx.submit_notification(TREE_NODE_CLICKED.with(NodeClick {
+                        path: vec![self.index],
+                        mods: mouse.mods,
+                    }));
+                }
+                Event::MouseUp(mouse)
+                    if self.widget.layout_rect().contains(mouse.pos) && mouse.button.is_left() =>
+                {
+                    ctx.submit_notification(TREE_NODE_MOVE.with(vec![self.index]));
+                }
+                _ => (),
+            }
         }
 
         if data.is_branch() {
@@ -426,6 +882,9 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
             let before = self.expand_lens.get(data);
             if chrooted.is_none() | event.should_propagate_to_hidden() {
                 self.opener.event(ctx, event, data, env);
+                if let Some(spinner) = &mut self.spinner {
+                    spinner.event(ctx, event, data, env);
+                }
             }
             let expanded = self.expand_lens.get(data);
 
@@ -436,6 +895,8 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
                 let cmd: Selector;
                 if expanded {
                     cmd = TREE_CHILD_SHOW;
+                    #[cfg(feature = "async")]
+                    self.spawn_loader(ctx, data);
                     // create child widgets if needed.
                     if self.update_children(data) {
                         // New children were created, inform the context.
@@ -448,6 +909,12 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
                 for child_widget_node in self.children.iter_mut() {
                     ctx.submit_command(cmd.to(child_widget_node.id()))
                 }
+                let toggled = if expanded {
+                    TREE_NODE_EXPANDED
+                } else {
+                    TREE_NODE_COLLAPSED
+                };
+                ctx.submit_notification(toggled.with(vec![self.index]));
                 ctx.request_layout();
             }
             // Forward to children nodes
@@ -487,6 +954,9 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
             }
         }
         self.opener.lifecycle(ctx, event, data, env);
+        if let Some(spinner) = &mut self.spinner {
+            spinner.lifecycle(ctx, event, data, env);
+        }
         self.widget.lifecycle(ctx, event, data, env);
         if data.is_branch() & (event.should_propagate_to_hidden() | self.expand_lens.get(data)) {
             for (index, child_widget_node) in self.children.iter_mut().enumerate() {
@@ -499,6 +969,9 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
     fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
         self.widget.update(ctx, data, env);
         self.opener.update(ctx, data, env);
+        if let Some(spinner) = &mut self.spinner {
+            spinner.update(ctx, data, env);
+        }
 
         if self.update_children(data) {
             if self.expand_lens.get(data) {
@@ -519,7 +992,6 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
         }
     }
 
-    // TODO: the height calculation ignores the inner widget height. issue #61
     fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
         // if we're in the chroot path, just compute and return the chroot child's layout
         if let Some(idx) = data.get_chroot() {
@@ -536,29 +1008,35 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
         let mut max_width = bc.max().width;
 
         // Top left, the opener
-        self.opener.layout(
-            ctx,
-            &BoxConstraints::tight(Size::new(basic_size, basic_size)),
-            data,
-            env,
+        let opener_bc = BoxConstraints::new(
+            Size::new(basic_size, basic_size),
+            Size::new(basic_size, f64::INFINITY),
         );
+        let opener_size = self.opener.layout(ctx, &opener_bc, data, env);
         self.opener.set_origin(ctx, Point::ORIGIN);
+        if let Some(spinner) = &mut self.spinner {
+            spinner.layout(ctx, &BoxConstraints::tight(opener_size), data, env);
+            spinner.set_origin(ctx, Point::ORIGIN);
+        }
 
         // Immediately on the right, the node widget
         let widget_size = self.widget.layout(
             ctx,
             &BoxConstraints::new(
                 Size::new(min_width, basic_size),
-                Size::new(max_width, basic_size),
+                Size::new(max_width, f64::INFINITY),
             ),
             data,
             env,
         );
         self.widget.set_origin(ctx, Point::new(basic_size, 0.0));
 
+        // The row of this node is as high as the highest of the opener and the widget.
+        let row_height = basic_size.max(widget_size.height).max(opener_size.height);
+
         // This is the computed size of this node. We start with the size of the widget,
         // and will increase for each child node.
-        let mut size = Size::new(indent + widget_size.width, basic_size);
+        let mut size = Size::new(indent + widget_size.width, row_height);
 
         // Below, the children nodes, but only if expanded
         if self.expand_lens.get(data) && max_width > indent {
@@ -574,7 +1052,7 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
                 // In case we have lazily instantiated children nodes,
                 // we may skip some indices. This catches up the correct height.
                 if index != next_index {
-                    size.height += (index - next_index) as f64 * basic_size;
+                    size.height += (index - next_index) as f64 * row_height;
                 }
                 next_index = index + 1;
 
@@ -601,7 +1079,10 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone> Widget<T> for TreeNodeWidget<T, L> {
         if let Some(idx) = data.get_chroot() {
             return self.children[idx].paint(ctx, data.get_child(idx), env);
         }
-        self.opener.paint(ctx, data, env);
+        match &mut self.spinner {
+            Some(spinner) => spinner.paint(ctx, data, env),
+            None => self.opener.paint(ctx, data, env),
+        }
         self.widget.paint(ctx, data, env);
         if data.is_branch() & self.expand_lens.get(data) {
             for (index, child_widget_node) in self.children.iter_mut().enumerate() {
@@ -632,9 +1113,41 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone + 'static> Tree<T, L> {
             )),
             // dummy chroot id at creation.
             chroot: WidgetId::next(),
+            selection: None,
+            draggable: false,
+            drag: None,
         }
     }
 
+    /// Builder-style method to let the user select nodes, storing whether each node is
+    /// selected through `selection_lens`.
+    ///
+    /// Clicking the widget of a node selects only this node. With Ctrl (or Cmd), the
+    /// click toggles the node instead, and with Shift, it selects all the nodes shown
+    /// between the previously clicked node and this one. The nodes within collapsed
+    /// branches are not part of such a range. Clicks handled by the node widgets
+    /// themselves are ignored.
+    ///
+    /// The tree submits a [`TREE_SELECTION_CHANGED`] notification when the user changed
+    /// the selection. Showing which nodes are selected is up to the node widgets.
+    pub fn with_selection(mut self, selection_lens: impl Lens<T, bool> + 'static) -> Self {
+        self.selection = Some(Selection {
+            lens: Box::new(selection_lens),
+            anchor: None,
+        });
+        self
+    }
+
+    /// Builder-style method to let the user move nodes by dragging them with the mouse.
+    ///
+    /// A node dropped on a branch becomes its last child, and a node dropped on a leaf is
+    /// inserted before it. The data must implement [`TreeNode::insert_child`] and
+    /// [`TreeNode::rm_child`].
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
     /// Pass a closure to define your own opener widget
     pub fn with_opener<W: Widget<T> + 'static>(
         mut self,
@@ -647,6 +1160,40 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone + 'static> Tree<T, L> {
         self
     }
 
+    /// Builder-style method to load the children of nodes lazily, when they are
+    /// expanded.
+    ///
+    /// When a node whose [`children_loaded`](TreeNode::children_loaded) returns `false`
+    /// is expanded, the future returned by `loader` is spawned, and a spinner is shown
+    /// instead of the opener until it resolves. The children are then stored with
+    /// [`TreeNode::load_children`]. As for [`FutureWidget`](crate::FutureWidget), this
+    /// must run within a tokio runtime.
+    ///
+    /// Moving nodes in a [`draggable`](Tree::draggable) tree cancels the pending loads,
+    /// the children of these nodes are loaded when they are expanded again.
+    #[cfg(feature = "async")]
+    pub fn with_loader<Fut>(mut self, loader: impl Fn(&T) -> Fut + 'static) -> Self
+    where
+        T: Send,
+        Fut: Future<Output = Vec<T>> + Send + 'static,
+    {
+        self.root_node.widget_mut().loader = Some(Arc::new(move |data| {
+            let future = loader(data);
+            Box::pin(async move { Box::new(future.await) as _ })
+        }));
+        self
+    }
+
+    /// Returns the paths of the nodes shown by the tree, in the order they are shown.
+    ///
+    /// The nodes within collapsed branches are skipped, and when the tree is chrooted,
+    /// only the virtual root and its descendants are returned. A path holds the index of
+    /// each node from the root, the root itself having an empty path. This helps to
+    /// implement keyboard navigation, or going to the next node matching a search.
+    pub fn visible_nodes(&self, data: &T) -> Vec<Vec<usize>> {
+        visible_paths(data, &self.root_node.widget().expand_lens)
+    }
+
     fn get_chroot_from<'a>(
         widget: &'a mut WidgetPod<T, TreeNodeWidget<T, L>>,
         data: &'a T,
@@ -679,6 +1226,9 @@ impl<T: TreeNode + Display, L: Lens<T, bool> + Clone + 'static> Tree<T, L> {
                 expand_lens,
             )),
             chroot: WidgetId::next(),
+            selection: None,
+            draggable: false,
+            drag: None,
         }
     }
 }
@@ -706,8 +1256,62 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone + 'static> Widget<T> for Tree<T, L> {
                 }
                 ctx.children_changed();
             }
+            if let Some(path) = notif.get(TREE_NODE_EXPANDED) {
+                // the path starts with the index of the root node, which is meaningless
+                ctx.submit_notification(TREE_NODE_EXPANDED.with(path[1..].to_vec()));
+                ctx.set_handled();
+            }
+            if let Some(path) = notif.get(TREE_NODE_COLLAPSED) {
+                ctx.submit_notification(TREE_NODE_COLLAPSED.with(path[1..].to_vec()));
+                ctx.set_handled();
+            }
+            if let Some(click) = notif.get(TREE_NODE_CLICKED) {
+                if self.draggable {
+                    self.drag = Some(click.path[1..].to_vec());
+                }
+                if let Some(selection) = &mut self.selection {
+                    let expand_lens = &self.root_node.widget().expand_lens;
+                    let visible = visible_paths(data, expand_lens);
+                    selection.click(data, &click.path[1..], click.mods, &visible);
+                    let selected = selection.selected_paths(data);
+                    ctx.submit_notification(TREE_SELECTION_CHANGED.with(selected));
+                }
+                ctx.set_handled();
+            }
+            if let Some(path) = notif.get(TREE_NODE_MOVE) {
+                if let Some(from) = self.drag.take() {
+                    let target = &path[1..];
+                    let node = target
+                        .iter()
+                        .fold(&*data, |node, index| node.get_child(*index));
+                    let moved = match target.split_last() {
+                        // a click without dragging
+                        _ if target == from => false,
+                        _ if node.is_branch() => {
+                            move_node(data, &from, target, node.children_count())
+                        }
+                        Some((index, parent)) => move_node(data, &from, parent, *index),
+                        None => false,
+                    };
+                    if moved {
+                        if let Some(selection) = &mut self.selection {
+                            selection.anchor = None;
+                        }
+                        // the widgets stay in place, a pending load would give the
+                        // children to the node which is now there
+                        if self.root_node.widget_mut().cancel_loads() {
+                            ctx.children_changed();
+                        }
+                    }
+                }
+                ctx.set_handled();
+            }
             return;
         }
+        if let Event::MouseDown(_) = event {
+            // a drag starts with the click notification of the node, after this event
+            self.drag = None;
+        }
         // self.chroot_up.event(ctx, event, &mut (), env);
         self.root_node.event(ctx, event, data, env);
     }
@@ -757,3 +1361,293 @@ impl<T: TreeNode, L: Lens<T, bool> + Clone + 'static> Widget<T> for Tree<T, L> {
         root.paint(ctx, chroot_data, env);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use druid::tests::harness::Harness;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Clone, Data, Lens, Debug)]
+    struct Node {
+        name: &'static str,
+        expanded: bool,
+        selected: bool,
+        chroot: Option<usize>,
+        children: Arc<Vec<Node>>,
+    }
+
+    impl Node {
+        fn new(name: &'static str, children: Vec<Node>) -> Self {
+            Node {
+                name,
+                expanded: true,
+                selected: false,
+                chroot: None,
+                children: Arc::new(children),
+            }
+        }
+    }
+
+    impl TreeNode for Node {
+        fn children_count(&self) -> usize {
+            self.children.len()
+        }
+
+        fn get_child(&self, index: usize) -> &Self {
+            &self.children[index]
+        }
+
+        fn for_child_mut(&mut self, index: usize, mut cb: impl FnMut(&mut Self, usize)) {
+            cb(&mut Arc::make_mut(&mut self.children)[index], index);
+        }
+
+        fn rm_child(&mut self, index: usize) {
+            Arc::make_mut(&mut self.children).remove(index);
+        }
+
+        fn insert_child(&mut self, index: usize, child: Self) {
+            Arc::make_mut(&mut self.children).insert(index, child);
+        }
+
+        fn get_chroot(&self) -> Option<usize> {
+            self.chroot
+        }
+
+        fn chroot(&mut self, idx: Option<usize>) {
+            self.chroot = idx;
+        }
+    }
+
+    /// The names of the nodes, with the children of each node in parentheses.
+    fn outline(node: &Node) -> String {
+        match node.children.len() {
+            0 => node.name.to_string(),
+            _ => {
+                let children: Vec<_> = node.children.iter().map(outline).collect();
+                format!("{}({})", node.name, children.join(" "))
+            }
+        }
+    }
+
+    #[test]
+    fn selection() {
+        // root
+        // - [0] branch: [0, 0], [0, 1]
+        // - [1]
+        let mut data = Node::new(
+            "root",
+            vec![
+                Node::new("a", vec![Node::new("b", vec![]), Node::new("c", vec![])]),
+                Node::new("d", vec![]),
+            ],
+        );
+        let mut selection = Selection {
+            lens: Box::new(Node::selected),
+            anchor: None,
+        };
+        let mut click = |data: &mut Node, path: &[usize], mods| {
+            let visible = visible_paths(data, &Node::expanded);
+            selection.click(data, path, mods, &visible);
+            selection.selected_paths(data)
+        };
+
+        assert_eq!(
+            click(&mut data, &[0, 1], Modifiers::empty()),
+            vec![vec![0, 1]]
+        );
+        assert_eq!(
+            click(&mut data, &[1], Modifiers::CONTROL),
+            vec![vec![0, 1], vec![1]]
+        );
+        assert_eq!(click(&mut data, &[0, 1], Modifiers::CONTROL), vec![vec![1]]);
+        // the range goes from the last clicked node
+        assert_eq!(
+            click(&mut data, &[0, 0], Modifiers::SHIFT),
+            vec![vec![0, 0], vec![0, 1]]
+        );
+        assert_eq!(
+            click(&mut data, &[], Modifiers::empty()),
+            vec![Vec::<usize>::new()]
+        );
+
+        // the children of collapsed branches are skipped by ranges
+        with_node_mut(&mut data, &[0], &mut |node| node.expanded = false);
+        assert_eq!(
+            click(&mut data, &[1], Modifiers::SHIFT),
+            vec![vec![], vec![0], vec![1]]
+        );
+    }
+
+    #[test]
+    fn move_nodes() {
+        let mut data = Node::new(
+            "root",
+            vec![
+                Node::new("a", vec![Node::new("b", vec![]), Node::new("c", vec![])]),
+                Node::new("d", vec![Node::new("e", vec![])]),
+            ],
+        );
+
+        // a leaf to the end of another branch
+        assert!(move_node(&mut data, &[0, 0], &[1], 1));
+        assert_eq!(outline(&data), "root(a(c) d(e b))");
+        // before a node of its parent
+        assert!(move_node(&mut data, &[1, 1], &[1], 0));
+        assert_eq!(outline(&data), "root(a(c) d(b e))");
+        // a branch before itself, in the parent of its parent
+        assert!(move_node(&mut data, &[1], &[], 0));
+        assert_eq!(outline(&data), "root(d(b e) a(c))");
+
+        // a node can't be moved within itself
+        assert!(!move_node(&mut data, &[0], &[0, 1], 0));
+        assert!(!move_node(&mut data, &[], &[0], 0));
+        assert_eq!(outline(&data), "root(d(b e) a(c))");
+    }
+
+    #[test]
+    fn visible_nodes() {
+        // root
+        // - [0] a
+        //   - [0, 0] b, collapsed: [0, 0, 0]
+        //   - [0, 1] c
+        // - [1] d, collapsed: [1, 0]
+        // - [2] f
+        let mut data = Node::new(
+            "root",
+            vec![
+                Node::new(
+                    "a",
+                    vec![
+                        Node::new("b", vec![Node::new("g", vec![])]),
+                        Node::new("c", vec![]),
+                    ],
+                ),
+                Node::new("d", vec![Node::new("e", vec![])]),
+                Node::new("f", vec![]),
+            ],
+        );
+        with_node_mut(&mut data, &[0, 0], &mut |node| node.expanded = false);
+        with_node_mut(&mut data, &[1], &mut |node| node.expanded = false);
+        let tree = Tree::new(|| Label::new("node"), Node::expanded);
+
+        assert_eq!(
+            tree.visible_nodes(&data),
+            vec![vec![], vec![0], vec![0, 0], vec![0, 1], vec![1], vec![2]]
+        );
+
+        // chrooted on a
+        data.chroot(Some(0));
+        assert_eq!(
+            tree.visible_nodes(&data),
+            vec![vec![0], vec![0, 0], vec![0, 1]]
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn lazy_children() {
+        let tree = Tree::new(|| Label::new("node"), Node::expanded).with_loader(|node: &Node| {
+            let name = node.name;
+            async move {
+                futures::future::ready(()).await;
+                vec![Node::new(name, vec![]), Node::new("b", vec![])]
+            }
+        });
+        let mut data = Node::new("a", vec![]);
+
+        let loader = tree.root_node.widget().loader.as_ref().unwrap();
+        let children = futures::executor::block_on(loader(&data));
+        data.load_children(*children.downcast::<Vec<Node>>().unwrap());
+        assert_eq!(outline(&data), "a(a b)");
+    }
+
+    type NodeWidget = WidgetPod<Node, TreeNodeWidget<Node, node_derived_lenses::expanded>>;
+
+    /// A node widget shared with the test, to check its state.
+    struct SharedNode(Rc<RefCell<NodeWidget>>);
+
+    impl Widget<Node> for SharedNode {
+        fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut Node, env: &Env) {
+            self.0.borrow_mut().event(ctx, event, data, env)
+        }
+
+        fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &Node, env: &Env) {
+            self.0.borrow_mut().lifecycle(ctx, event, data, env)
+        }
+
+        fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &Node, data: &Node, env: &Env) {
+            self.0.borrow_mut().update(ctx, data, env)
+        }
+
+        fn layout(
+            &mut self,
+            ctx: &mut LayoutCtx,
+            bc: &BoxConstraints,
+            data: &Node,
+            env: &Env,
+        ) -> Size {
+            let size = self.0.borrow_mut().layout(ctx, bc, data, env);
+            self.0.borrow_mut().set_origin(ctx, Point::ORIGIN);
+            size
+        }
+
+        fn paint(&mut self, ctx: &mut PaintCtx, data: &Node, env: &Env) {
+            self.0.borrow_mut().paint(ctx, data, env)
+        }
+    }
+
+    #[test]
+    fn children_loaded() {
+        let make_opener: Arc<Box<OpenerFactory<Node>>> =
+            Arc::new(Box::new(|| Box::new(Label::new(">"))));
+        let mut node = TreeNodeWidget::new(
+            Arc::new(|| Box::new(Label::new("node"))),
+            make_opener,
+            0,
+            Node::expanded,
+        );
+        // as if the node was expanded with a loader
+        node.spinner = Some(WidgetPod::new(Spinner::new()));
+        node.load_generation = 2;
+        let node = Rc::new(RefCell::new(WidgetPod::new(node)));
+        let node_id = node.borrow().id();
+        let loaded = |generation: u64, names: &[&'static str]| {
+            let children: Vec<_> = names.iter().map(|name| Node::new(name, vec![])).collect();
+            TREE_CHILDREN_LOADED
+                .with(SingleUse::new((generation, Box::new(children) as _)))
+                .to(node_id)
+        };
+
+        Harness::create_simple(
+            Node::new("a", vec![]),
+            SharedNode(node.clone()),
+            |harness| {
+                harness.send_initial_events();
+
+                // a cancelled load
+                harness.submit_command(loaded(1, &["x"]));
+                assert_eq!(outline(harness.data()), "a");
+                assert!(node.borrow().widget().children.is_empty());
+                assert!(node.borrow().widget().spinner.is_some());
+
+                harness.submit_command(loaded(2, &["b", "c"]));
+                assert_eq!(outline(harness.data()), "a(b c)");
+                assert_eq!(node.borrow().widget().children.len(), 2);
+                assert!(node.borrow().widget().spinner.is_none());
+
+                // a move cancels the loads of the node and its descendants
+                node.borrow_mut().widget_mut().children[1]
+                    .widget_mut()
+                    .spinner = Some(WidgetPod::new(Spinner::new()));
+                assert!(node.borrow_mut().widget_mut().cancel_loads());
+                assert!(node.borrow().widget().children[1]
+                    .widget()
+                    .spinner
+                    .is_none());
+                assert!(!node.borrow_mut().widget_mut().cancel_loads());
+            },
+        );
+    }
+}
@@ -3,7 +3,12 @@
 
 use std::ops;
 
-use druid::Data;
+use druid::widget::prelude::*;
+use druid::widget::Controller;
+use druid::{Data, Selector};
+
+// Command sent by `OnVersionBump` to its widget, as an `EventCtx` is needed for the callback.
+const VERSION_BUMPED: Selector = Selector::new("versioned.version-bumped");
 
 /// Data with explicit version.
 ///
@@ -25,6 +30,11 @@ impl<T> Versioned<T> {
     pub fn changed(&mut self) {
         self.version += 1;
     }
+
+    /// The number of times the data was marked as changed.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
 }
 
 impl<T> ops::Deref for Versioned<T> {
@@ -46,3 +56,114 @@ impl Data for Versioned<String> {
         self.version == other.version
     }
 }
+
+/// A controller calling a function after the version of its [`Versioned`] data was
+/// bumped, for example to scroll to the top or to reset the focus when the content
+/// was replaced.
+///
+/// The function is called once per update in which the version changed, in the event
+/// following that update.
+#[allow(clippy::type_complexity)]
+pub struct OnVersionBump<T>(Box<dyn Fn(&mut EventCtx, &Versioned<T>)>);
+
+impl<T> OnVersionBump<T> {
+    /// Create the controller calling `f` with the new data.
+    pub fn new(f: impl Fn(&mut EventCtx, &Versioned<T>) + 'static) -> Self {
+        Self(Box::new(f))
+    }
+}
+
+impl<T, W> Controller<Versioned<T>, W> for OnVersionBump<T>
+where
+    Versioned<T>: Data,
+    W: Widget<Versioned<T>>,
+{
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut Versioned<T>,
+        env: &Env,
+    ) {
+        if let Event::Command(cmd) = event {
+            if cmd.is(VERSION_BUMPED) {
+                (self.0)(ctx, data);
+                ctx.set_handled();
+                return;
+            }
+        }
+        child.event(ctx, event, data, env);
+    }
+
+    fn update(
+        &mut self,
+        child: &mut W,
+        ctx: &mut UpdateCtx,
+        old_data: &Versioned<T>,
+        data: &Versioned<T>,
+        env: &Env,
+    ) {
+        if old_data.version != data.version {
+            ctx.submit_command(VERSION_BUMPED.to(ctx.widget_id()));
+        }
+        child.update(ctx, old_data, data, env);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WidgetExt as _;
+    use druid::tests::harness::Harness;
+    use druid::widget::SizedBox;
+    use druid::{TimerToken, WidgetExt as _};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Appends to the text, marking it as changed when the payload is true.
+    const EDIT: Selector<bool> = Selector::new("druid-widget-nursery.test.edit");
+
+    #[test]
+    fn version_is_bumped_once_per_change() {
+        let old = Versioned::new("text".to_string());
+        let mut new = old.clone();
+        new.push('!');
+        assert!(old.same(&new));
+
+        new.changed();
+        assert_eq!(new.version(), old.version() + 1);
+        assert!(!old.same(&new));
+    }
+
+    #[test]
+    fn callback_on_version_bump() {
+        let bumps = Rc::new(RefCell::new(Vec::<u64>::new()));
+        let widget = SizedBox::empty()
+            .controller(OnVersionBump::new({
+                let bumps = bumps.clone();
+                move |_, data: &Versioned<String>| bumps.borrow_mut().push(data.version())
+            }))
+            .on_command(EDIT, |_, bump, data| {
+                data.push('!');
+                if *bump {
+                    data.changed();
+                }
+            });
+
+        Harness::create_simple(Versioned::new(String::new()), widget, |harness| {
+            harness.send_initial_events();
+            // the harness dispatches the commands submitted in `update` with the next
+            // event
+            let mut edit = |bump: bool| {
+                harness.submit_command(EDIT.with(bump));
+                harness.event(Event::Timer(TimerToken::INVALID));
+                bumps.take()
+            };
+            assert_eq!(edit(false), Vec::<u64>::new());
+            assert_eq!(edit(true), vec![1]);
+            assert_eq!(edit(false), Vec::<u64>::new());
+            assert_eq!(edit(true), vec![2]);
+        });
+    }
+}
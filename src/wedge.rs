@@ -1,21 +1,101 @@
 // Copyright 2021 the Druid Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use druid::kurbo::{BezPath, Size};
-use druid::piet::{LineCap, LineJoin, RenderContext, StrokeStyle};
+use druid::kurbo::{Affine, BezPath, Size, Vec2};
+use druid::piet::{Color, LineCap, LineJoin, RenderContext, StrokeStyle};
 use druid::theme;
 use druid::{
     BoxConstraints, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, PaintCtx, UpdateCtx,
     Widget,
 };
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::animation::{Animated, AnimationCurve};
+use crate::RequestCtx;
+
+crate::keys! {
+    /// The duration, in seconds, of the rotation of wedges between their collapsed
+    /// and expanded state. This applies to [`Wedge`] and the default opener of the
+    /// [`Tree`](crate::Tree), unless overridden with [`Wedge::with_animation_duration`].
+    ///
+    /// If the key isn't set, wedges snap between both states.
+    WEDGE_ANIMATION_DURATION: f64,
+}
 
 /// Wedge is an arbitrary name for the arrow-like icon marking whether a node is expanded or collapsed.
-pub struct Wedge;
+///
+/// By default it snaps between both states, see [`WEDGE_ANIMATION_DURATION`] to animate it.
+pub struct Wedge {
+    rotation: WedgeRotation,
+}
 
 // Is "Chevron" a better name?
 impl Wedge {
     pub fn new() -> Self {
-        Wedge {}
+        Wedge {
+            rotation: WedgeRotation::new(),
+        }
+    }
+
+    /// Builder-style method for setting the duration of the rotation, in seconds,
+    /// overriding [`WEDGE_ANIMATION_DURATION`]. Zero snaps between both states.
+    pub fn with_animation_duration(mut self, duration: f64) -> Self {
+        self.set_animation_duration(duration);
+        self
+    }
+
+    /// Set the duration of the rotation, in seconds, overriding [`WEDGE_ANIMATION_DURATION`].
+    pub fn set_animation_duration(&mut self, duration: f64) {
+        self.rotation.duration = Some(duration);
+    }
+
+    /// Builder-style method for setting the [`AnimationCurve`] of the rotation.
+    pub fn with_curve(mut self, curve: impl Into<AnimationCurve>) -> Self {
+        self.set_curve(curve);
+        self
+    }
+
+    /// Set the [`AnimationCurve`] of the rotation. The default is
+    /// [`EASE_OUT`](AnimationCurve::EASE_OUT).
+    pub fn set_curve(&mut self, curve: impl Into<AnimationCurve>) {
+        self.rotation.rotation.set_curve(curve.into());
+    }
+
+    /// Builder-style method for setting the direction the collapsed wedge points to.
+    pub fn with_orientation(mut self, orientation: WedgeOrientation) -> Self {
+        self.set_orientation(orientation);
+        self
+    }
+
+    /// Set the direction the collapsed wedge points to. The default is
+    /// [`WedgeOrientation::Right`].
+    pub fn set_orientation(&mut self, orientation: WedgeOrientation) {
+        self.rotation.orientation = orientation;
+    }
+}
+
+/// The direction a collapsed [`Wedge`] points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WedgeOrientation {
+    /// Points right when collapsed and down when expanded, turning clockwise.
+    Right,
+    /// Points left when collapsed and down when expanded, turning counterclockwise,
+    /// for right-to-left layouts.
+    Left,
+    /// Points down when collapsed and up when expanded, for content unfolding
+    /// below, like an accordion.
+    Down,
+}
+
+impl WedgeOrientation {
+    /// The angle of the wedge, relative to pointing right, where `rotation` is 0.0
+    /// when collapsed and 1.0 when expanded.
+    fn angle(self, rotation: f64) -> f64 {
+        match self {
+            WedgeOrientation::Right => rotation * FRAC_PI_2,
+            WedgeOrientation::Left => PI - rotation * FRAC_PI_2,
+            WedgeOrientation::Down => FRAC_PI_2 + rotation * PI,
+        }
     }
 }
 
@@ -25,10 +105,80 @@ impl Default for Wedge {
     }
 }
 
+/// The rotation of a wedge, shared by [`Wedge`] and the default opener of the tree.
+pub(crate) struct WedgeRotation {
+    /// 0.0 when collapsed, 1.0 when expanded.
+    rotation: Animated<f64>,
+    duration: Option<f64>,
+    orientation: WedgeOrientation,
+}
+
+impl WedgeRotation {
+    pub(crate) fn new() -> Self {
+        WedgeRotation {
+            rotation: Animated::jump(0.0).curve(AnimationCurve::EASE_OUT),
+            duration: None,
+            orientation: WedgeOrientation::Right,
+        }
+    }
+
+    /// Sets the state without animating.
+    pub(crate) fn jump(&mut self, expanded: bool) {
+        self.rotation
+            .jump_to_value(if expanded { 1.0 } else { 0.0 });
+    }
+
+    /// Rotates the wedge to the new state, animating only if a duration is set.
+    pub(crate) fn rotate(&mut self, ctx: &mut impl RequestCtx, expanded: bool, env: &Env) {
+        let duration = self
+            .duration
+            .or_else(|| env.try_get(WEDGE_ANIMATION_DURATION).ok())
+            .unwrap_or(0.0);
+        self.rotation.set_duration(duration);
+        self.rotation.animate(ctx, if expanded { 1.0 } else { 0.0 });
+        ctx.request_paint();
+    }
+
+    pub(crate) fn event(&mut self, ctx: &mut impl RequestCtx, event: &Event) {
+        if let Event::AnimFrame(nanos) = event {
+            self.rotation.update(ctx, *nanos);
+        }
+    }
+
+    /// The current angle of the wedge, relative to pointing right.
+    fn angle(&self) -> f64 {
+        self.orientation.angle(self.rotation.get())
+    }
+
+    /// Paints the wedge in the top left 18x18 square, vertically centered in `height`.
+    pub(crate) fn paint(&self, ctx: &mut PaintCtx, height: f64, color: &Color) {
+        let y_offset = ((height - 8.0) / 2.0).floor();
+        let angle = self.angle();
+
+        // '>' shape, rotated around its center
+        let mut path = BezPath::new();
+        path.move_to((7.0, y_offset));
+        path.line_to((13.0, y_offset + 4.0));
+        path.line_to((7.0, y_offset + 8.0));
+        if angle != 0.0 {
+            let center = Vec2::new(10.0, y_offset + 4.0);
+            path.apply_affine(
+                Affine::translate(center) * Affine::rotate(angle) * Affine::translate(-center),
+            );
+        }
+        let style = StrokeStyle::new()
+            .line_cap(LineCap::Round)
+            .line_join(LineJoin::Round);
+
+        ctx.stroke_styled(path, color, 2.5, &style);
+    }
+}
+
 /// Implementing Widget for the wedge.
 /// This widget's data is simply a boolean telling whether is is expanded or collapsed.
 impl Widget<bool> for Wedge {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, expanded: &mut bool, _env: &Env) {
+        self.rotation.event(ctx, event);
         match event {
             Event::MouseDown(_) => {
                 ctx.set_active(true);
@@ -47,10 +197,17 @@ impl Widget<bool> for Wedge {
         }
     }
 
-    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &bool, _env: &Env) {
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &bool, _env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            self.rotation.jump(*data);
+        }
     }
 
-    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &bool, _data: &bool, _env: &Env) {}
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &bool, data: &bool, env: &Env) {
+        if old_data != data {
+            self.rotation.rotate(ctx, *data, env);
+        }
+    }
 
     fn layout(
         &mut self,
@@ -63,31 +220,53 @@ impl Widget<bool> for Wedge {
         bc.constrain(Size::new(size, size))
     }
 
-    fn paint(&mut self, ctx: &mut PaintCtx, expanded: &bool, env: &Env) {
-        let y_offset = ((ctx.size().height - 8.0) / 2.0).floor();
+    fn paint(&mut self, ctx: &mut PaintCtx, _expanded: &bool, env: &Env) {
         let stroke_color = if ctx.is_hot() {
             env.get(theme::FOREGROUND_LIGHT)
         } else {
             env.get(theme::FOREGROUND_DARK)
         };
+        self.rotation.paint(ctx, ctx.size().height, &stroke_color);
+    }
+}
 
-        // Paint the wedge
-        let mut path = BezPath::new();
-        if *expanded {
-            // expanded: 'V' shape
-            path.move_to((5.0, y_offset + 2.0));
-            path.line_to((9.0, y_offset + 8.0));
-            path.line_to((13.0, y_offset + 2.0));
-        } else {
-            // collapsed: '>' shape
-            path.move_to((7.0, y_offset));
-            path.line_to((13.0, y_offset + 4.0));
-            path.line_to((7.0, y_offset + 8.0));
-        }
-        let style = StrokeStyle::new()
-            .line_cap(LineCap::Round)
-            .line_join(LineJoin::Round);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::test::TestCtx;
+
+    #[test]
+    fn orientation_angles() {
+        use WedgeOrientation::*;
+        // every orientation ends pointing down, except Down which ends pointing up
+        assert_eq!(Right.angle(0.0), 0.0);
+        assert_eq!(Right.angle(1.0), FRAC_PI_2);
+        assert_eq!(Left.angle(0.0), PI);
+        assert_eq!(Left.angle(1.0), FRAC_PI_2);
+        assert_eq!(Down.angle(0.0), FRAC_PI_2);
+        assert_eq!(Down.angle(1.0), FRAC_PI_2 + PI);
+
+        // the angle changes steadily while animating
+        let samples: Vec<f64> = (0..=4).map(|i| Left.angle(i as f64 / 4.0)).collect();
+        assert!(samples.windows(2).all(|pair| pair[1] < pair[0]));
+    }
+
+    #[test]
+    fn rotation_frames() {
+        let mut rotation = WedgeRotation::new();
+        rotation.rotation.set_curve(AnimationCurve::LINEAR);
+        rotation.jump(false);
+        let env = Env::empty().adding(WEDGE_ANIMATION_DURATION, 0.2);
+
+        rotation.rotate(&mut TestCtx, true, &env);
+        assert_eq!(rotation.angle(), 0.0);
+
+        // halfway through, the wedge points diagonally
+        rotation.event(&mut TestCtx, &Event::AnimFrame(100_000_000));
+        assert!((rotation.angle() - FRAC_PI_2 / 2.0).abs() < 1e-9);
 
-        ctx.stroke_styled(path, &stroke_color, 2.5, &style);
+        rotation.event(&mut TestCtx, &Event::AnimFrame(100_000_000));
+        assert_eq!(rotation.angle(), FRAC_PI_2);
+        assert!(!rotation.rotation.animating());
     }
 }
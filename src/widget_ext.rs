@@ -7,8 +7,7 @@ use druid::{Point, Selector, WidgetExt as _, WindowHandle};
 
 use crate::on_cmd::OnCmd;
 use crate::stack_tooltip::{PlainOrRich, StackTooltip, ADVISE_TOOLTIP_SHOW, CANCEL_TOOLTIP_SHOW};
-use crate::tooltip::TooltipState;
-use crate::{OnChange, OnMonitor, TooltipController};
+use crate::{OnChange, OnMonitor, OnRemoved, ScrollObserver, TooltipController};
 
 pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
     fn on_command<CT: 'static>(
@@ -29,24 +28,53 @@ pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
         self.controller(OnChange::new(f))
     }
 
+    /// Calls the function once when this widget is removed from the widget tree, see
+    /// [`OnRemoved`].
+    fn on_removed(self, f: impl FnOnce() + 'static) -> ControllerHost<Self, OnRemoved> {
+        self.controller(OnRemoved::new(f))
+    }
+
     /// Open a tooltip when the mouse is hovered over this widget.
     fn tooltip<LT: Into<LabelText<T>>>(
         self,
         text: LT,
     ) -> ControllerHost<Self, TooltipController<T>> {
-        self.controller(TooltipController {
-            text: text.into(),
-            state: TooltipState::Off,
-        })
+        self.controller(TooltipController::new(text))
+    }
+
+    /// Open a tooltip showing a widget built by `make_widget` when the mouse is hovered
+    /// over this widget, see [`TooltipController::with_widget`].
+    fn tooltip_widget<W: Widget<T> + 'static>(
+        self,
+        make_widget: impl Fn() -> W + 'static,
+    ) -> ControllerHost<Self, TooltipController<T>> {
+        self.controller(TooltipController::with_widget(make_widget))
     }
 
     /// A convenience method for ensuring that this widget is fully visible on the same monitor as
     /// some other window.
     fn on_monitor(self, parent: &WindowHandle) -> OnMonitor<Self> {
-        OnMonitor {
-            inner: self,
-            parent: parent.clone(),
-        }
+        OnMonitor::new(self, parent.clone())
+    }
+
+    /// Send [`VIEWPORT_CHANGED`] to this widget and its descendants whenever the
+    /// visible part of this widget changes, for example when it is scrolled.
+    ///
+    /// [`VIEWPORT_CHANGED`]: crate::VIEWPORT_CHANGED
+    fn observe_scroll(self) -> ControllerHost<Self, ScrollObserver> {
+        self.controller(ScrollObserver::new())
+    }
+
+    /// Scroll this widget into view in the [`Scroll`]s containing it when it receives a
+    /// command with `selector`, for example to reveal the node selected in a [`Tree`].
+    ///
+    /// [`Scroll`]: druid::widget::Scroll
+    /// [`Tree`]: crate::Tree
+    fn scroll_to_view_on<CT: 'static>(
+        self,
+        selector: Selector<CT>,
+    ) -> ControllerHost<Self, OnCmd<CT, T>> {
+        self.controller(OnCmd::new(selector, |ctx, _, _| ctx.scroll_to_view()))
     }
 
     /// A convenience method to cancel the display of a tooltip from a parent/ancestor widget.
@@ -63,6 +91,12 @@ pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
     fn stack_tooltip(self, label: impl Into<PlainOrRich>) -> StackTooltip<T> {
         StackTooltip::new(self, label)
     }
+
+    /// Open a stack based tooltip showing the widget `content` when the mouse is hovered
+    /// over this widget, see [`StackTooltip::with_widget`].
+    fn stack_tooltip_widget(self, content: impl Widget<T> + 'static) -> StackTooltip<T> {
+        StackTooltip::with_widget(self, content)
+    }
 }
 
 impl<T: Data, W: Widget<T> + 'static> WidgetExt<T> for W {}
@@ -3,7 +3,7 @@
 
 use druid::widget::prelude::*;
 use druid::widget::Axis;
-use druid::{KeyOrValue, Widget, WidgetPod};
+use druid::{KeyOrValue, Point, Widget, WidgetPod};
 
 pub struct Wrap<T> {
     children: Vec<WidgetPod<T, Box<dyn Widget<T>>>>,
@@ -124,37 +124,21 @@ impl<T> Wrap<T> {
         self.cross_alignment = cross_alignment;
         self
     }
-}
-
-impl<T: Data> Widget<T> for Wrap<T> {
-    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
-        for x in &mut self.children {
-            x.event(ctx, event, data, env);
-        }
-    }
 
-    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
-        for x in &mut self.children {
-            x.lifecycle(ctx, event, data, env);
-        }
-    }
-
-    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
-        for x in &mut self.children {
-            x.update(ctx, data, env);
-        }
-    }
-
-    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
-        if self.children.is_empty() {
-            return bc.min();
-        }
+    /// Computes the size of the wrap and the origins of its children, given their sizes.
+    ///
+    /// Children flow along the major axis of the direction and wrap into runs which are
+    /// stacked along the minor axis: rows from top to bottom when horizontal, columns
+    /// from left to right when vertical.
+    fn place_children(
+        &self,
+        child_sizes: &[Size],
+        bc: &BoxConstraints,
+        spacing: f64,
+        run_spacing: f64,
+    ) -> (Size, Vec<Point>) {
         let dir = self.direction;
-        let child_bc =
-            BoxConstraints::tight(dir.pack(dir.major(bc.max()), f64::INFINITY).into()).loosen();
         let main_axis_limit = dir.major(bc.max());
-        let spacing = self.spacing.resolve(env);
-        let run_spacing = self.run_spacing.resolve(env);
 
         let mut main_axis_extent = 0.0;
         let mut cross_axis_extent = 0.0;
@@ -162,8 +146,7 @@ impl<T: Data> Widget<T> for Wrap<T> {
         let mut run_cross_axis_extent = 0.0;
         let mut child_count = 0;
         let mut run_metrics = Vec::new();
-        for child in &mut self.children {
-            let child_size = child.layout(ctx, &child_bc, data, env);
+        for &child_size in child_sizes {
             let child_main_axis_extent = dir.major(child_size);
             let child_cross_axis_extent = dir.minor(child_size);
             if child_count > 0
@@ -224,7 +207,8 @@ impl<T: Data> Widget<T> for Wrap<T> {
         run_between_spacing += run_spacing;
         let mut cross_axis_offset = run_leading_space;
 
-        let mut childs = self.children.iter_mut();
+        let mut origins = Vec::with_capacity(child_sizes.len());
+        let mut sizes = child_sizes.iter();
         for (run_main_axis_extent, run_cross_axis_extent, child_count) in run_metrics {
             let main_axis_free_space =
                 f64::max(0.0, container_main_axis_extent - run_main_axis_extent);
@@ -233,24 +217,23 @@ impl<T: Data> Widget<T> for Wrap<T> {
                 WrapAlignment::Start => (0., 0.),
                 WrapAlignment::End => (main_axis_free_space, 0.),
                 WrapAlignment::Center => (main_axis_free_space / 2., 0.),
-                WrapAlignment::SpaceBetween if run_count > 1 => {
-                    (0., main_axis_free_space / (run_count as f64 - 1.))
+                WrapAlignment::SpaceBetween if child_count > 1 => {
+                    (0., main_axis_free_space / (child_count as f64 - 1.))
                 }
                 WrapAlignment::SpaceBetween => (0., 0.),
                 WrapAlignment::SpaceAround => (
-                    main_axis_free_space / run_count as f64 / 2.,
-                    main_axis_free_space / run_count as f64,
+                    main_axis_free_space / child_count as f64 / 2.,
+                    main_axis_free_space / child_count as f64,
                 ),
                 WrapAlignment::SpaceEvenly => (
-                    main_axis_free_space / (run_count as f64 + 1.),
-                    main_axis_free_space / (run_count as f64 + 1.),
+                    main_axis_free_space / (child_count as f64 + 1.),
+                    main_axis_free_space / (child_count as f64 + 1.),
                 ),
             };
             child_between_space += spacing;
             let mut child_main_position = child_leading_space;
 
-            for child in (&mut childs).take(child_count) {
-                let child_size = child.layout_rect().size();
+            for &child_size in (&mut sizes).take(child_count) {
                 let free_space = run_cross_axis_extent - dir.minor(child_size);
 
                 let child_cross_axis_offset = match self.cross_alignment {
@@ -259,8 +242,7 @@ impl<T: Data> Widget<T> for Wrap<T> {
                     WrapCrossAlignment::Center => cross_axis_offset + free_space / 2.,
                 };
 
-                child.set_origin(
-                    ctx,
+                origins.push(
                     dir.pack(child_main_position, child_cross_axis_offset)
                         .into(),
                 );
@@ -269,6 +251,52 @@ impl<T: Data> Widget<T> for Wrap<T> {
 
             cross_axis_offset += run_cross_axis_extent + run_between_spacing;
         }
+        (size, origins)
+    }
+}
+
+impl<T: Data> Widget<T> for Wrap<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        for x in &mut self.children {
+            x.event(ctx, event, data, env);
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        for x in &mut self.children {
+            x.lifecycle(ctx, event, data, env);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        if ctx.env_key_changed(&self.spacing) || ctx.env_key_changed(&self.run_spacing) {
+            ctx.request_layout();
+        }
+
+        for x in &mut self.children {
+            x.update(ctx, data, env);
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        if self.children.is_empty() {
+            return bc.min();
+        }
+        let dir = self.direction;
+        let child_bc =
+            BoxConstraints::tight(dir.pack(dir.major(bc.max()), f64::INFINITY).into()).loosen();
+        let child_sizes: Vec<Size> = self
+            .children
+            .iter_mut()
+            .map(|child| child.layout(ctx, &child_bc, data, env))
+            .collect();
+
+        let spacing = self.spacing.resolve(env);
+        let run_spacing = self.run_spacing.resolve(env);
+        let (size, origins) = self.place_children(&child_sizes, bc, spacing, run_spacing);
+        for (child, origin) in self.children.iter_mut().zip(origins) {
+            child.set_origin(ctx, origin);
+        }
         size
     }
 
@@ -278,3 +306,84 @@ impl<T: Data> Widget<T> for Wrap<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WidgetExt as _;
+    use druid::tests::harness::Harness;
+    use druid::tests::helpers::{Record, Recording, TestWidgetExt};
+    use druid::widget::SizedBox;
+    use druid::{Key, Selector, WidgetExt as _};
+
+    const SIZES: [Size; 3] = [
+        Size::new(30., 10.),
+        Size::new(40., 20.),
+        Size::new(50., 15.),
+    ];
+
+    fn place(wrap: Wrap<()>, bc: BoxConstraints, sizes: &[Size]) -> (Size, Vec<Point>) {
+        wrap.place_children(sizes, &bc, 0., 0.)
+    }
+
+    #[test]
+    fn horizontal_runs() {
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(100., 100.));
+        let (size, origins) = place(Wrap::new(), bc, &SIZES);
+        assert_eq!(size, Size::new(70., 35.));
+        assert_eq!(
+            origins,
+            vec![Point::new(0., 0.), Point::new(30., 0.), Point::new(0., 20.)]
+        );
+    }
+
+    #[test]
+    fn vertical_runs() {
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(100., 40.));
+        let (size, origins) = place(Wrap::new().direction(Axis::Vertical), bc, &SIZES);
+        assert_eq!(size, Size::new(90., 30.));
+        assert_eq!(
+            origins,
+            vec![Point::new(0., 0.), Point::new(0., 10.), Point::new(40., 0.)]
+        );
+    }
+
+    #[test]
+    fn space_between_in_run() {
+        let bc = BoxConstraints::new(Size::new(100., 0.), Size::new(100., 100.));
+        let wrap = Wrap::new().alignment(WrapAlignment::SpaceBetween);
+        let (_, origins) = place(wrap, bc, &[Size::new(20., 10.); 3]);
+        assert_eq!(
+            origins,
+            vec![Point::new(0., 0.), Point::new(40., 0.), Point::new(80., 0.)]
+        );
+    }
+
+    #[test]
+    fn relayout_on_spacing_change() {
+        const SPACING: Key<f64> = Key::new("druid-widget-nursery.test.wrap-spacing");
+        const SET_SPACING: Selector<f64> = Selector::new("druid-widget-nursery.test.set-spacing");
+
+        let recording = Recording::default();
+        let child = || SizedBox::empty().width(30.).height(10.);
+        let wrap = Wrap::new()
+            .spacing(SPACING)
+            .with_child(child())
+            .with_child(child().record(&recording))
+            .env_scope(|env, spacing: &f64| env.set(SPACING, *spacing))
+            .on_command(SET_SPACING, |_, spacing, data| *data = *spacing);
+
+        Harness::create_simple(0., wrap, |harness| {
+            harness.send_initial_events();
+            harness.paint();
+            recording.clear();
+
+            // the data changes the spacing key, and nothing else asks for a layout
+            harness.submit_command(SET_SPACING.with(10.));
+            harness.paint();
+            assert!(recording
+                .drain()
+                .any(|record| matches!(record, Record::Layout(_))));
+        });
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2022 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks how much memory a `FlexTable` allocates for the envs of its cells. This is an
+//! integration test because it replaces the global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use druid::tests::harness::Harness;
+use druid::widget::SizedBox;
+use druid_widget_nursery::table::{FlexTable, TableRow};
+
+const ROWS: usize = 1000;
+const COLUMNS: usize = 3;
+
+thread_local! {
+    /// The number of bytes allocated on this thread.
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // the thread local is gone while the thread shuts down
+        let _ = ALLOCATED.try_with(|allocated| allocated.set(allocated.get() + layout.size()));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of bytes allocated by `f` on this thread.
+fn allocated_by(f: impl FnOnce()) -> usize {
+    let before = ALLOCATED.with(Cell::get);
+    f();
+    ALLOCATED.with(Cell::get) - before
+}
+
+#[test]
+fn cell_envs_are_not_cloned() {
+    let table = (0..ROWS).fold(FlexTable::<()>::new(), |table, _| {
+        table.with_row((0..COLUMNS).fold(TableRow::new(), |row, _| {
+            row.with_child(SizedBox::empty().height(20.0))
+        }))
+    });
+
+    Harness::create_simple((), table, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        // cloning the env of the harness for a cell allocates about 4KB, setting the
+        // position keys of the cell only allocates the keys
+        let bytes = allocated_by(|| harness.just_layout());
+        assert!(
+            bytes / (ROWS * COLUMNS) < 512,
+            "a layout pass allocated {} bytes",
+            bytes
+        );
+    });
+}
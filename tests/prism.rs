@@ -38,3 +38,52 @@ where
     V2(T),
     V3(PhantomData<T>, Box<(U, U)>),
 }
+
+#[derive(Clone, Debug, PartialEq, Prism)]
+enum MyResult<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+#[derive(Clone, Debug, PartialEq, Prism)]
+enum Shape<T> {
+    Point,
+    Circle { radius: f64 },
+    Rect { width: T, height: T },
+}
+
+#[test]
+fn generic_variants() {
+    let mut data: MyResult<u32, String> = MyResult::Ok(1);
+    assert_eq!(MyResultOk.get(&data), Some(1));
+    assert_eq!(MyResultErr.get(&data), None);
+
+    MyResultErr.put(&mut data, "error".to_string());
+    assert_eq!(data, MyResult::Err("error".to_string()));
+}
+
+#[test]
+fn named_fields() {
+    let mut data = Shape::Rect {
+        width: 2,
+        height: 3,
+    };
+    assert_eq!(ShapeRect.get(&data), Some((2, 3)));
+    assert_eq!(ShapeCircle.get(&data), None);
+
+    // a single field is focused as its own type
+    ShapeCircle.put(&mut data, 1.5);
+    assert_eq!(data, Shape::Circle { radius: 1.5 });
+    assert_eq!(ShapeCircle.get(&data), Some(1.5));
+
+    ShapeRect.put(&mut data, (4, 5));
+    assert_eq!(
+        data,
+        Shape::Rect {
+            width: 4,
+            height: 5
+        }
+    );
+    ShapePoint.put(&mut data, ());
+    assert_eq!(data, Shape::Point);
+}
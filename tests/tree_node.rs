@@ -0,0 +1,76 @@
+// Copyright 2022 the Druid Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "derive")]
+
+use std::sync::Arc;
+
+use druid::im::Vector;
+use druid::Data;
+use druid_widget_nursery::TreeNode;
+
+#[derive(Clone, Data, Debug, TreeNode)]
+struct Directory {
+    name: String,
+    #[tree_children]
+    children: Vector<Arc<Directory>>,
+    #[tree_branch]
+    is_dir: bool,
+}
+
+#[derive(Clone, Data, Debug, TreeNode)]
+struct Plain<T: Data + std::fmt::Debug> {
+    value: T,
+    #[tree_children]
+    children: Vector<Plain<T>>,
+}
+
+fn dir(name: &str, children: Vec<Directory>) -> Directory {
+    Directory {
+        name: name.to_string(),
+        children: children.into_iter().map(Arc::new).collect(),
+        is_dir: true,
+    }
+}
+
+#[test]
+fn arc_children() {
+    let mut root = dir("root", vec![dir("a", vec![]), dir("b", vec![])]);
+    root.children[1] = Arc::new(Directory {
+        is_dir: false,
+        ..dir("b", vec![])
+    });
+
+    assert_eq!(root.children_count(), 2);
+    assert_eq!(root.get_child(1).name, "b");
+    assert!(root.get_child(0).is_branch());
+    assert!(!root.get_child(1).is_branch());
+
+    // the child is only replaced when it changes
+    let before = root.clone();
+    root.for_child_mut(0, |_, _| ());
+    assert!(root.same(&before));
+    root.for_child_mut(0, |child, _| child.name = "c".to_string());
+    assert_eq!(root.get_child(0).name, "c");
+
+    root.rm_child(0);
+    root.insert_child(1, dir("d", vec![]));
+    let names: Vec<_> = root.children.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["b", "d"]);
+}
+
+#[test]
+fn plain_children() {
+    let mut root = Plain {
+        value: 0,
+        children: Vector::unit(Plain {
+            value: 1,
+            children: Vector::new(),
+        }),
+    };
+    root.for_child_mut(0, |child, _| child.value = 2);
+    assert_eq!(root.get_child(0).value, 2);
+    // without `#[tree_branch]`, nodes with children are branches
+    assert!(root.is_branch());
+    assert!(!root.get_child(0).is_branch());
+}